@@ -1,21 +1,128 @@
 use rand::Rng;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::io;
 
-fn avaliacao_usuario(codigo: &str, chute: &str) -> (usize, usize) {
+// Regras de uma partida: tamanho do código, símbolos, repetição e limite de tentativas
+struct Regras {
+    tamanho: usize,
+    simbolos: Vec<char>,
+    repeticao: bool,
+    max_tentativas: u32,
+}
+
+impl Regras {
+    fn new(
+        tamanho: usize,
+        simbolos: &str,
+        repeticao: bool,
+        max_tentativas: u32,
+    ) -> Result<Regras, String> {
+        let simbolos: Vec<char> = simbolos.to_uppercase().chars().collect();
+        let unicos: HashSet<char> = simbolos.iter().copied().collect();
+
+        if tamanho == 0 || max_tentativas == 0 {
+            return Err("O código e o limite de tentativas precisam ser maiores que zero".into());
+        }
+        if simbolos.is_empty() || unicos.len() != simbolos.len() {
+            return Err("Os símbolos não podem ser vazios nem repetidos".into());
+        }
+        if !repeticao && tamanho > simbolos.len() {
+            return Err(
+                "Sem repetição, o código não pode ser maior que o número de símbolos".into(),
+            );
+        }
+
+        Ok(Regras {
+            tamanho,
+            simbolos,
+            repeticao,
+            max_tentativas,
+        })
+    }
+
+    // Níveis de dificuldade oferecidos no início do jogo
+    fn presets() -> Vec<(&'static str, Regras)> {
+        vec![
+            ("Fácil", Regras::new(4, "ABCD", false, 10).unwrap()),
+            ("Médio", Regras::new(4, "ABCDEF", true, 10).unwrap()),
+            ("Difícil", Regras::new(5, "ABCDEFGH", true, 12).unwrap()),
+        ]
+    }
+
+    fn simbolos_texto(&self) -> String {
+        self.simbolos
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // Retorna a mensagem de erro quando o chute não segue as regras
+    fn validar_chute(&self, chute: &str) -> Result<(), String> {
+        // validação 1: tamanho
+        if chute.chars().count() != self.tamanho {
+            return Err(format!("Digite exatamente {} letras!", self.tamanho));
+        }
+
+        // validação 2: apenas os símbolos do jogo
+        if !chute.chars().all(|c| self.simbolos.contains(&c)) {
+            return Err(format!(" Use apenas as letras {}!", self.simbolos_texto()));
+        }
+
+        // validação 3: não pode repetir letras (quando as regras não permitem)
+        let caracter_unico: HashSet<char> = chute.chars().collect();
+        if !self.repeticao && caracter_unico.len() != self.tamanho {
+            return Err("⚠️ Não repita letras!".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Regras {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} letras ({}), {}, {} tentativas",
+            self.tamanho,
+            self.simbolos_texto(),
+            if self.repeticao {
+                "com repetição"
+            } else {
+                "sem repetir"
+            },
+            self.max_tentativas
+        )
+    }
+}
+
+// Pinos pretos (letra certa na posição certa) e brancos (letra certa em outra posição)
+//
+// Cada letra do código só é contada uma vez: com repetição, as letras que
+// sobram depois dos pretos são comparadas como multiconjuntos.
+fn avaliacao_usuario(regras: &Regras, codigo: &str, chute: &str) -> (usize, usize) {
+    debug_assert_eq!(codigo.chars().count(), regras.tamanho);
+    debug_assert_eq!(chute.chars().count(), regras.tamanho);
+
     let mut posicao_correta = 0;
-    let mut letra_correta = 0;
-
-    for (i, g_char) in chute.chars().enumerate() {
-        if let Some(s_char) = codigo.chars().nth(i) {
-            if g_char == s_char {
-                posicao_correta += 1;
-            } else if codigo.contains(g_char) {
-                letra_correta += 1;
-            }
+    let mut sobra_codigo: HashMap<char, usize> = HashMap::new();
+    let mut sobra_chute: HashMap<char, usize> = HashMap::new();
+
+    for (s_char, g_char) in codigo.chars().zip(chute.chars()) {
+        if g_char == s_char {
+            posicao_correta += 1;
+        } else {
+            *sobra_codigo.entry(s_char).or_insert(0) += 1;
+            *sobra_chute.entry(g_char).or_insert(0) += 1;
         }
     }
 
+    let letra_correta = sobra_chute
+        .iter()
+        .map(|(letra, &quantidade)| quantidade.min(*sobra_codigo.get(letra).unwrap_or(&0)))
+        .sum();
+
     (posicao_correta, letra_correta)
 }
 
@@ -27,63 +134,168 @@ fn get_user_input() -> String {
     input.trim().to_uppercase()
 }
 
-fn generate_secret_code() -> String {
-    let mut letters = String::from("ABCD");
+fn generate_secret_code(regras: &Regras) -> String {
+    let mut letters = regras.simbolos.clone();
     let mut secret_code = String::new();
+    let mut rng = rand::thread_rng();
 
-    while !letters.is_empty() {
-        let index = rand::thread_rng().gen_range(0..letters.len());
-        secret_code.push(letters.remove(index));
+    while secret_code.chars().count() < regras.tamanho {
+        let index = rng.gen_range(0..letters.len());
+        if regras.repeticao {
+            secret_code.push(letters[index]);
+        } else {
+            secret_code.push(letters.remove(index));
+        }
     }
 
     secret_code
 }
 
+fn escolher_dificuldade() -> Regras {
+    let mut presets = Regras::presets();
+    println!("Escolha a dificuldade:");
+    for (i, (nome, regras)) in presets.iter().enumerate() {
+        println!("  {}. {} - {}", i + 1, nome, regras);
+    }
+
+    loop {
+        match get_user_input().parse::<usize>() {
+            Ok(opcao) if (1..=presets.len()).contains(&opcao) => {
+                return presets.swap_remove(opcao - 1).1;
+            }
+            _ => println!("Escolha um número de 1 a {}!", presets.len()),
+        }
+    }
+}
+
 fn main() {
     println!("=== Jogo Mastermind ===");
-    println!("Adivinhe o código secreto (A, B, C, D sem repetir).");
+    let regras = escolher_dificuldade();
+    println!("Adivinhe o código secreto: {}.", regras);
 
-    let codigo_secreto = generate_secret_code();
+    let codigo_secreto = generate_secret_code(&regras);
 
-    let mut tentativas: i8 = 0;
+    let mut tentativas: u32 = 0;
 
-    loop {
-        println!("\nDigite sua tentativa:");
+    while tentativas < regras.max_tentativas {
+        println!(
+            "\nDigite sua tentativa ({} de {}):",
+            tentativas + 1,
+            regras.max_tentativas
+        );
         let chute = get_user_input();
 
-        // validação 1: tamanho
-        if chute.len() != 4 {
-            println!("Digite exatamente 4 letras!");
-            continue;
-        }
-
-        // validação 2: apenas letras A, B, C ou D
-        if !chute.chars().all(|c| "ABCD".contains(c)) {
-            println!(" Use apenas as letras A, B, C e D!");
-            continue;
-        }
-
-        // validação 3: não pode repetir letras
-        let caracter_unico: HashSet<char> = chute.chars().collect();
-        if caracter_unico.len() != chute.len() {
-            println!("⚠️ Não repita letras!");
+        if let Err(erro) = regras.validar_chute(&chute) {
+            println!("{}", erro);
             continue;
         }
 
         tentativas += 1;
-        let (posicoes, letras) = avaliacao_usuario(&codigo_secreto, &chute);
+        let (posicoes, letras) = avaliacao_usuario(&regras, &codigo_secreto, &chute);
 
         println!(
             "Posições corretas: {} | Letras corretas em posição errada: {}",
             posicoes, letras
         );
 
-        if posicoes == 4 {
+        if posicoes == regras.tamanho {
             println!(
                 "\n Parabéns! Você acertou o código {} em {} tentativas!",
                 codigo_secreto, tentativas
             );
-            break;
+            return;
+        }
+    }
+
+    println!(
+        "\nSuas {} tentativas acabaram! O código era {}.",
+        regras.max_tentativas, codigo_secreto
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Referência: brancos = soma dos mínimos das contagens de cada símbolo - pretos
+    fn avaliacao_referencia(simbolos: &[char], codigo: &str, chute: &str) -> (usize, usize) {
+        let pretos = codigo
+            .chars()
+            .zip(chute.chars())
+            .filter(|(a, b)| a == b)
+            .count();
+        let em_comum: usize = simbolos
+            .iter()
+            .map(|s| {
+                let no_codigo = codigo.chars().filter(|c| c == s).count();
+                let no_chute = chute.chars().filter(|c| c == s).count();
+                no_codigo.min(no_chute)
+            })
+            .sum();
+        (pretos, em_comum - pretos)
+    }
+
+    fn todos_os_codigos(regras: &Regras) -> Vec<String> {
+        let mut codigos = vec![String::new()];
+        for _ in 0..regras.tamanho {
+            codigos = codigos
+                .iter()
+                .flat_map(|prefixo| {
+                    regras
+                        .simbolos
+                        .iter()
+                        .map(move |s| format!("{}{}", prefixo, s))
+                })
+                .collect();
+        }
+        codigos
+    }
+
+    #[test]
+    fn test_casos_conhecidos() {
+        let regras = Regras::new(4, "ABCDEF", true, 10).unwrap();
+        let casos = [
+            ("ABCD", "ABCD", (4, 0)),
+            ("ABCD", "DCBA", (0, 4)),
+            ("ABCD", "EEEE", (0, 0)),
+            // uma letra do código não pode render dois pinos
+            ("AABB", "AAAA", (2, 0)),
+            ("ABCD", "AAAA", (1, 0)),
+            ("AABC", "CAAD", (1, 2)),
+            ("ABBB", "BAAA", (0, 2)),
+            ("AABB", "BBAA", (0, 4)),
+            ("ABCA", "AAFF", (1, 1)),
+        ];
+        for (codigo, chute, esperado) in casos {
+            assert_eq!(
+                avaliacao_usuario(&regras, codigo, chute),
+                esperado,
+                "código {} chute {}",
+                codigo,
+                chute
+            );
+        }
+    }
+
+    #[test]
+    fn test_todas_as_combinacoes_contra_referencia() {
+        for regras in [
+            Regras::new(4, "ABCD", true, 10).unwrap(),
+            Regras::new(3, "ABCDE", true, 10).unwrap(),
+            Regras::new(5, "AB", true, 10).unwrap(),
+        ] {
+            let codigos = todos_os_codigos(&regras);
+            for codigo in &codigos {
+                for chute in &codigos {
+                    assert_eq!(
+                        avaliacao_usuario(&regras, codigo, chute),
+                        avaliacao_referencia(&regras.simbolos, codigo, chute),
+                        "código {} chute {}",
+                        codigo,
+                        chute
+                    );
+                }
+            }
         }
     }
 }
@@ -0,0 +1,550 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::ErroZoologico;
+use crate::persistencia::{self, Descartes};
+
+/// Estoque mínimo padrão, em dias de consumo, antes de gerar alerta
+pub const DIAS_MINIMOS_PADRAO: u32 = 7;
+
+/// Horário do dia no formato HH:MM
+///
+/// Gravado no arquivo como texto (ex: "08:30").
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Horario {
+    pub hora: u8,
+    pub minuto: u8,
+}
+
+impl Horario {
+    pub fn new(hora: u8, minuto: u8) -> Result<Horario, ErroZoologico> {
+        if hora > 23 || minuto > 59 {
+            return Err(ErroZoologico::HorarioInvalido(format!(
+                "{:02}:{:02}",
+                hora, minuto
+            )));
+        }
+        Ok(Horario { hora, minuto })
+    }
+}
+
+impl FromStr for Horario {
+    type Err = ErroZoologico;
+
+    fn from_str(texto: &str) -> Result<Self, Self::Err> {
+        let invalido = || ErroZoologico::HorarioInvalido(texto.trim().to_string());
+        let (hora, minuto) = texto.trim().split_once(':').ok_or_else(invalido)?;
+        let hora = hora.parse().map_err(|_| invalido())?;
+        let minuto = minuto.parse().map_err(|_| invalido())?;
+        Horario::new(hora, minuto).map_err(|_| invalido())
+    }
+}
+
+impl TryFrom<String> for Horario {
+    type Error = ErroZoologico;
+
+    fn try_from(texto: String) -> Result<Self, Self::Error> {
+        texto.parse()
+    }
+}
+
+impl From<Horario> for String {
+    fn from(horario: Horario) -> String {
+        horario.to_string()
+    }
+}
+
+impl fmt::Display for Horario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hora, self.minuto)
+    }
+}
+
+/// Lê uma lista de horários separados por vírgula (ex: "08:00, 16:30")
+///
+/// # Retorno
+/// Horários em ordem crescente e sem repetição
+pub fn ler_horarios(texto: &str) -> Result<Vec<Horario>, ErroZoologico> {
+    let mut horarios = texto
+        .split(',')
+        .filter(|parte| !parte.trim().is_empty())
+        .map(Horario::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    horarios.sort();
+    horarios.dedup();
+    Ok(horarios)
+}
+
+/// Plano alimentar de um animal: um alimento servido em certos horários
+///
+/// # Campos
+/// - `id`: Identificador estável do plano
+/// - `id_animal`: Animal que recebe a refeição
+/// - `alimento`: Tipo de alimento (ex: Carne bovina, Feno)
+/// - `quantidade_g`: Quantidade servida em cada horário, em gramas
+/// - `horarios`: Horários do dia em que a refeição é servida
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PlanoAlimentar {
+    pub id: u32,
+    pub id_animal: u32,
+    pub alimento: String,
+    pub quantidade_g: u32,
+    pub horarios: Vec<Horario>,
+}
+
+impl PlanoAlimentar {
+    /// Quantidade consumida por dia segundo o plano, em gramas
+    pub fn consumo_diario_g(&self) -> u64 {
+        self.quantidade_g as u64 * self.horarios.len() as u64
+    }
+}
+
+/// Estoque de um alimento
+///
+/// # Campos
+/// - `alimento`: Nome do alimento, comparado sem diferenciar maiúsculas
+/// - `quantidade_g`: Quantidade disponível, em gramas
+/// - `dias_minimos`: Alerta quando o estoque durar menos que esses dias
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ItemEstoque {
+    pub alimento: String,
+    pub quantidade_g: u64,
+    pub dias_minimos: u32,
+}
+
+/// Registro de uma refeição servida, usado para marcar o checklist e
+/// como histórico de consumo
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlimentacaoRegistrada {
+    pub id_plano: u32,
+    pub id_animal: u32,
+    pub alimento: String,
+    pub quantidade_g: u32,
+    pub data: NaiveDate,
+    pub horario: Horario,
+    pub registrada_em: NaiveDateTime,
+}
+
+/// Refeição a ser servida no dia
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemChecklist {
+    pub id_plano: u32,
+    pub id_animal: u32,
+    pub nome_animal: String,
+    pub alimento: String,
+    pub quantidade_g: u32,
+    pub horario: Horario,
+    pub feito: bool,
+}
+
+/// Refeições do dia de um recinto, com o tratador responsável
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrupoChecklist {
+    /// `None` para animais que ainda não estão em nenhum recinto
+    pub recinto: Option<String>,
+    pub tratador: Option<String>,
+    pub itens: Vec<ItemChecklist>,
+}
+
+/// Alimento cujo estoque está abaixo do mínimo
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertaEstoque {
+    pub alimento: String,
+    pub quantidade_g: u64,
+    pub consumo_diario_g: u64,
+    /// Dias completos que o estoque ainda dura no consumo atual
+    pub dias_restantes: u64,
+    pub dias_minimos: u32,
+}
+
+/// Planos alimentares, estoque de alimentos e refeições servidas
+///
+/// Os planos têm ids estáveis, nunca reutilizados. Os animais ficam no
+/// `Zoologico`; por isso criar um plano e montar o checklist passam por ele.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Nutricao {
+    planos: BTreeMap<u32, PlanoAlimentar>,
+    proximo_id_plano: u32,
+    /// Estoque de alimentos indexado por `chave_alimento`
+    estoque: BTreeMap<String, ItemEstoque>,
+    alimentacoes: Vec<AlimentacaoRegistrada>,
+}
+
+impl Default for Nutricao {
+    fn default() -> Self {
+        Nutricao {
+            planos: BTreeMap::new(),
+            proximo_id_plano: 1,
+            estoque: BTreeMap::new(),
+            alimentacoes: Vec::new(),
+        }
+    }
+}
+
+impl Nutricao {
+    /// Monta os dados de alimentação lidos do arquivo de dados
+    ///
+    /// Planos com id repetido ou fora da faixa são contados em `descartes`.
+    pub(crate) fn carregar(
+        proximo_id_plano: u32,
+        planos: Vec<PlanoAlimentar>,
+        estoque: Vec<ItemEstoque>,
+        alimentacoes: Vec<AlimentacaoRegistrada>,
+        descartes: &mut Descartes,
+    ) -> Self {
+        let mut nutricao = Nutricao {
+            proximo_id_plano,
+            alimentacoes,
+            ..Nutricao::default()
+        };
+        for plano in planos {
+            persistencia::inserir_com_id(
+                &mut nutricao.planos,
+                plano.id,
+                plano,
+                &mut nutricao.proximo_id_plano,
+                descartes,
+            );
+        }
+        for item in estoque {
+            nutricao
+                .estoque
+                .insert(chave_alimento(&item.alimento), item);
+        }
+        nutricao
+    }
+
+    pub(crate) fn proximo_id_plano(&self) -> u32 {
+        self.proximo_id_plano
+    }
+
+    pub(crate) fn alimentacoes(&self) -> &[AlimentacaoRegistrada] {
+        &self.alimentacoes
+    }
+
+    /// Cria um plano alimentar para o animal, que o `Zoologico` já conferiu
+    ///
+    /// # Retorno
+    /// Id do plano criado, ou erro se o alimento, a quantidade ou os horários
+    /// estiverem vazios
+    pub(crate) fn incluir_plano(
+        &mut self,
+        id_animal: u32,
+        alimento: &str,
+        quantidade_g: u32,
+        mut horarios: Vec<Horario>,
+    ) -> Result<u32, ErroZoologico> {
+        let alimento = alimento.trim();
+        if alimento.is_empty() {
+            return Err(ErroZoologico::CampoVazio("alimento".to_string()));
+        }
+        if quantidade_g == 0 {
+            return Err(ErroZoologico::CampoVazio("quantidade".to_string()));
+        }
+        if horarios.is_empty() {
+            return Err(ErroZoologico::CampoVazio("horários".to_string()));
+        }
+        horarios.sort();
+        horarios.dedup();
+
+        let id = persistencia::reservar_id(&mut self.proximo_id_plano, "planos alimentares")?;
+        self.planos.insert(
+            id,
+            PlanoAlimentar {
+                id,
+                id_animal,
+                alimento: alimento.to_string(),
+                quantidade_g,
+                horarios,
+            },
+        );
+        Ok(id)
+    }
+
+    pub(crate) fn remover_plano(&mut self, id: u32) -> Result<PlanoAlimentar, ErroZoologico> {
+        self.planos
+            .remove(&id)
+            .ok_or(ErroZoologico::PlanoNaoEncontrado(id))
+    }
+
+    /// Mantém só os planos aceitos pelo filtro
+    ///
+    /// # Retorno
+    /// Quantidade de planos removidos
+    pub(crate) fn reter_planos(&mut self, manter: impl Fn(&PlanoAlimentar) -> bool) -> usize {
+        let antes = self.planos.len();
+        self.planos.retain(|_, plano| manter(plano));
+        antes - self.planos.len()
+    }
+
+    pub fn buscar_plano(&self, id: u32) -> Option<&PlanoAlimentar> {
+        self.planos.get(&id)
+    }
+
+    pub fn listar_planos(&self) -> Vec<&PlanoAlimentar> {
+        self.planos.values().collect()
+    }
+
+    pub fn planos_do_animal(&self, id_animal: u32) -> Vec<&PlanoAlimentar> {
+        self.planos
+            .values()
+            .filter(|plano| plano.id_animal == id_animal)
+            .collect()
+    }
+
+    /// Soma a quantidade informada ao estoque do alimento, criando-o se preciso
+    pub fn adicionar_estoque(
+        &mut self,
+        alimento: &str,
+        quantidade_g: u64,
+    ) -> Result<(), ErroZoologico> {
+        let alimento = alimento.trim();
+        if alimento.is_empty() {
+            return Err(ErroZoologico::CampoVazio("alimento".to_string()));
+        }
+
+        self.estoque
+            .entry(chave_alimento(alimento))
+            .or_insert_with(|| ItemEstoque {
+                alimento: alimento.to_string(),
+                quantidade_g: 0,
+                dias_minimos: DIAS_MINIMOS_PADRAO,
+            })
+            .quantidade_g += quantidade_g;
+        Ok(())
+    }
+
+    /// Define a partir de quantos dias restantes o alimento gera alerta
+    pub fn definir_dias_minimos(&mut self, alimento: &str, dias: u32) -> Result<(), ErroZoologico> {
+        let item = self
+            .estoque
+            .get_mut(&chave_alimento(alimento))
+            .ok_or_else(|| ErroZoologico::AlimentoNaoEncontrado(alimento.trim().to_string()))?;
+        item.dias_minimos = dias;
+        Ok(())
+    }
+
+    pub fn listar_estoque(&self) -> Vec<&ItemEstoque> {
+        self.estoque.values().collect()
+    }
+
+    /// Consumo diário do alimento previsto pelos planos alimentares, em gramas
+    pub fn consumo_diario_g(&self, alimento: &str) -> u64 {
+        let chave = chave_alimento(alimento);
+        self.planos
+            .values()
+            .filter(|plano| chave_alimento(&plano.alimento) == chave)
+            .map(PlanoAlimentar::consumo_diario_g)
+            .sum()
+    }
+
+    /// Registra que a refeição do plano foi servida, descontando do estoque
+    ///
+    /// # Retorno
+    /// Erro se o horário não fizer parte do plano, se a refeição já tiver
+    /// sido registrada na data ou se o estoque não for suficiente
+    pub(crate) fn registrar_alimentacao(
+        &mut self,
+        id_plano: u32,
+        data: NaiveDate,
+        horario: Horario,
+        registrada_em: NaiveDateTime,
+    ) -> Result<(), ErroZoologico> {
+        let plano = self
+            .planos
+            .get(&id_plano)
+            .ok_or(ErroZoologico::PlanoNaoEncontrado(id_plano))?;
+        if !plano.horarios.contains(&horario) {
+            return Err(ErroZoologico::HorarioInvalido(horario.to_string()));
+        }
+        if self.refeicao_registrada(id_plano, data, horario).is_some() {
+            return Err(ErroZoologico::AlimentacaoJaRegistrada {
+                id_plano,
+                horario: horario.to_string(),
+            });
+        }
+
+        let necessario = plano.quantidade_g as u64;
+        let disponivel = self
+            .estoque
+            .get(&chave_alimento(&plano.alimento))
+            .map_or(0, |item| item.quantidade_g);
+        if disponivel < necessario {
+            return Err(ErroZoologico::EstoqueInsuficiente {
+                alimento: plano.alimento.clone(),
+                disponivel_g: disponivel,
+                necessario_g: necessario,
+            });
+        }
+
+        let registro = AlimentacaoRegistrada {
+            id_plano,
+            id_animal: plano.id_animal,
+            alimento: plano.alimento.clone(),
+            quantidade_g: plano.quantidade_g,
+            data,
+            horario,
+            registrada_em,
+        };
+        if let Some(item) = self.estoque.get_mut(&chave_alimento(&registro.alimento)) {
+            item.quantidade_g -= necessario;
+        }
+        self.alimentacoes.push(registro);
+        Ok(())
+    }
+
+    /// Registro da refeição do plano servida na data e horário, se houver
+    pub fn refeicao_registrada(
+        &self,
+        id_plano: u32,
+        data: NaiveDate,
+        horario: Horario,
+    ) -> Option<&AlimentacaoRegistrada> {
+        self.alimentacoes
+            .iter()
+            .find(|r| r.id_plano == id_plano && r.data == data && r.horario == horario)
+    }
+
+    /// Alimentos cujo estoque dura menos que o mínimo de dias configurado
+    ///
+    /// A projeção usa o consumo diário previsto pelos planos alimentares.
+    /// Alimentos usados em planos mas sem estoque cadastrado também geram alerta.
+    ///
+    /// # Retorno
+    /// Alertas em ordem do alimento que acaba primeiro
+    pub fn alertas_estoque(&self) -> Vec<AlertaEstoque> {
+        let mut alimentos: BTreeMap<String, (&str, u64, u32)> = BTreeMap::new();
+        for item in self.estoque.values() {
+            alimentos.insert(
+                chave_alimento(&item.alimento),
+                (&item.alimento, item.quantidade_g, item.dias_minimos),
+            );
+        }
+        for plano in self.planos.values() {
+            alimentos.entry(chave_alimento(&plano.alimento)).or_insert((
+                &plano.alimento,
+                0,
+                DIAS_MINIMOS_PADRAO,
+            ));
+        }
+
+        let mut alertas: Vec<AlertaEstoque> = alimentos
+            .into_values()
+            .filter_map(|(alimento, quantidade_g, dias_minimos)| {
+                let consumo_diario_g = self.consumo_diario_g(alimento);
+                if consumo_diario_g == 0 {
+                    return None;
+                }
+                let dias_restantes = quantidade_g / consumo_diario_g;
+                (dias_restantes < dias_minimos as u64).then(|| AlertaEstoque {
+                    alimento: alimento.to_string(),
+                    quantidade_g,
+                    consumo_diario_g,
+                    dias_restantes,
+                    dias_minimos,
+                })
+            })
+            .collect();
+
+        alertas.sort_by(|a, b| {
+            a.dias_restantes
+                .cmp(&b.dias_restantes)
+                .then_with(|| a.alimento.cmp(&b.alimento))
+        });
+        alertas
+    }
+}
+
+/// Chave usada para comparar nomes de alimentos
+pub fn chave_alimento(alimento: &str) -> String {
+    alimento.trim().to_lowercase()
+}
+
+/// Formata uma quantidade em gramas como quilos (ex: 1500 → "1.5 kg")
+pub fn formatar_quantidade(gramas: u64) -> String {
+    if gramas >= 1000 {
+        format!("{} kg", gramas as f64 / 1000.0)
+    } else {
+        format!("{} g", gramas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ler_horario() {
+        assert_eq!(
+            "08:30".parse::<Horario>().unwrap(),
+            Horario::new(8, 30).unwrap()
+        );
+        assert_eq!(Horario::new(7, 5).unwrap().to_string(), "07:05");
+        assert!("24:00".parse::<Horario>().is_err());
+        assert!("oito".parse::<Horario>().is_err());
+    }
+
+    #[test]
+    fn test_ler_horarios_ordena_e_remove_repetidos() {
+        let horarios = ler_horarios("16:00, 08:00,16:00").unwrap();
+        assert_eq!(
+            horarios,
+            vec![Horario::new(8, 0).unwrap(), Horario::new(16, 0).unwrap()]
+        );
+        assert!(ler_horarios("08:00, 25:00").is_err());
+    }
+
+    #[test]
+    fn test_horario_serializado_como_texto() {
+        let horario = Horario::new(9, 15).unwrap();
+        let json = serde_json::to_string(&horario).unwrap();
+        assert_eq!(json, "\"09:15\"");
+        assert_eq!(serde_json::from_str::<Horario>(&json).unwrap(), horario);
+    }
+
+    #[test]
+    fn test_nutricao_desconta_estoque_e_projeta_alertas() {
+        let mut nutricao = Nutricao::default();
+        let plano = nutricao
+            .incluir_plano(1, "Feno", 5000, ler_horarios("16:00, 07:00").unwrap())
+            .unwrap();
+        nutricao.adicionar_estoque("feno", 12_000).unwrap();
+        assert_eq!(nutricao.consumo_diario_g("FENO"), 10_000);
+        assert_eq!(nutricao.alertas_estoque()[0].dias_restantes, 1);
+
+        let data = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+        let sete = Horario::new(7, 0).unwrap();
+        let momento = data.and_hms_opt(7, 5, 0).unwrap();
+        nutricao
+            .registrar_alimentacao(plano, data, sete, momento)
+            .unwrap();
+        assert_eq!(nutricao.listar_estoque()[0].quantidade_g, 7000);
+        assert_eq!(
+            nutricao
+                .refeicao_registrada(plano, data, sete)
+                .map(|r| r.registrada_em),
+            Some(momento)
+        );
+        assert!(matches!(
+            nutricao.registrar_alimentacao(plano, data, Horario::new(16, 0).unwrap(), momento),
+            Ok(())
+        ));
+        assert!(matches!(
+            nutricao.registrar_alimentacao(plano, data, sete, momento),
+            Err(ErroZoologico::AlimentacaoJaRegistrada { .. })
+        ));
+
+        assert_eq!(nutricao.reter_planos(|plano| plano.id_animal != 1), 1);
+        assert!(nutricao.planos_do_animal(1).is_empty());
+    }
+
+    #[test]
+    fn test_formatar_quantidade() {
+        assert_eq!(formatar_quantidade(1500), "1.5 kg");
+        assert_eq!(formatar_quantidade(250), "250 g");
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Estrutura que representa as informações de um animal
+///
+/// # Campos
+/// - `id`: Identificador estável do animal, nunca reutilizado
+/// - `tipo`: O tipo do animal (ex: Elefante, Leão, etc.)
+/// - `nome`: O nome específico do animal
+/// - `id_recinto`: Recinto onde o animal está, se já foi alocado
+/// - `id_tratador`: Tratador responsável pelo animal; sem ele, vale o tratador do recinto
+/// - `id_mae`, `id_pai`: Pais registrados no nascimento, usados na genealogia
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InfoAnimal {
+    pub id: u32,
+    pub tipo: String,
+    pub nome: String,
+    #[serde(default)]
+    pub id_recinto: Option<u32>,
+    #[serde(default)]
+    pub id_tratador: Option<u32>,
+    #[serde(default)]
+    pub id_mae: Option<u32>,
+    #[serde(default)]
+    pub id_pai: Option<u32>,
+}
+
+impl InfoAnimal {
+    /// Cria uma nova instância de InfoAnimal
+    ///
+    /// # Parâmetros
+    /// - `id`: Identificador do animal
+    /// - `tipo`: Tipo do animal
+    /// - `nome`: Nome do animal
+    ///
+    /// # Retorno
+    /// Nova instância de InfoAnimal
+    pub fn new(id: u32, tipo: String, nome: String) -> InfoAnimal {
+        InfoAnimal {
+            id,
+            tipo,
+            nome,
+            id_recinto: None,
+            id_tratador: None,
+            id_mae: None,
+            id_pai: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_criar_animal() {
+        let animal = InfoAnimal::new(1, "Leão".to_string(), "Simba".to_string());
+        assert_eq!(animal.id, 1);
+        assert_eq!(animal.tipo, "Leão");
+        assert_eq!(animal.nome, "Simba");
+        assert_eq!(animal.id_recinto, None);
+    }
+}
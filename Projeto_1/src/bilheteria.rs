@@ -0,0 +1,694 @@
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::alimentacao::Horario;
+use crate::errors::ErroZoologico;
+use crate::persistencia::{self, Descartes};
+
+/// Tipo de ingresso vendido na bilheteria
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TipoIngresso {
+    Adulto,
+    Crianca,
+    Idoso,
+    /// Preço por pessoa, vendido a partir de um número mínimo de pessoas
+    Grupo,
+}
+
+/// Todos os tipos de ingresso, na ordem de exibição
+pub const TIPOS_INGRESSO: [TipoIngresso; 4] = [
+    TipoIngresso::Adulto,
+    TipoIngresso::Crianca,
+    TipoIngresso::Idoso,
+    TipoIngresso::Grupo,
+];
+
+impl fmt::Display for TipoIngresso {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            TipoIngresso::Adulto => "Adulto",
+            TipoIngresso::Crianca => "Criança",
+            TipoIngresso::Idoso => "Idoso",
+            TipoIngresso::Grupo => "Grupo",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Regra de preço de um tipo de ingresso
+///
+/// # Campos
+/// - `tipo`: Tipo de ingresso
+/// - `centavos`: Preço por pessoa, em centavos
+/// - `minimo_pessoas`: Menor quantidade de pessoas aceita numa venda desse tipo
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct PrecoIngresso {
+    pub tipo: TipoIngresso,
+    pub centavos: u64,
+    pub minimo_pessoas: u32,
+}
+
+/// Tabela de preços usada quando o arquivo ainda não tem preços cadastrados
+pub fn precos_padrao() -> Vec<PrecoIngresso> {
+    TIPOS_INGRESSO.into_iter().map(preco_padrao).collect()
+}
+
+/// Preço padrão de um tipo: meia-entrada para criança e idoso, desconto
+/// para grupos de 10 pessoas ou mais
+pub fn preco_padrao(tipo: TipoIngresso) -> PrecoIngresso {
+    let (centavos, minimo_pessoas) = match tipo {
+        TipoIngresso::Adulto => (4000, 1),
+        TipoIngresso::Crianca | TipoIngresso::Idoso => (2000, 1),
+        TipoIngresso::Grupo => (3000, 10),
+    };
+    PrecoIngresso {
+        tipo,
+        centavos,
+        minimo_pessoas,
+    }
+}
+
+/// Faixa de horário de entrada de visitantes
+///
+/// # Campos
+/// - `inicio`: Horário de início da faixa, que também a identifica
+/// - `capacidade`: Número máximo de visitantes na faixa, por dia
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct FaixaVisitacao {
+    pub inicio: Horario,
+    pub capacidade: u32,
+}
+
+/// Faixas usadas quando o arquivo ainda não tem faixas cadastradas
+pub fn faixas_padrao() -> Vec<FaixaVisitacao> {
+    [9, 11, 13, 15]
+        .into_iter()
+        .map(|hora| FaixaVisitacao {
+            inicio: Horario { hora, minuto: 0 },
+            capacidade: 200,
+        })
+        .collect()
+}
+
+/// Ingressos de um mesmo tipo numa venda
+///
+/// O preço é guardado na venda para que mudanças na tabela de preços não
+/// alterem a receita já registrada.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct ItemVenda {
+    pub tipo: TipoIngresso,
+    pub quantidade: u32,
+    pub centavos_por_pessoa: u64,
+}
+
+impl ItemVenda {
+    /// Quantidade vezes o preço por pessoa
+    ///
+    /// # Retorno
+    /// `ValorInvalido` se o valor não couber num `u64`
+    pub fn subtotal_centavos(&self) -> Result<u64, ErroZoologico> {
+        (self.quantidade as u64)
+            .checked_mul(self.centavos_por_pessoa)
+            .ok_or_else(|| {
+                ErroZoologico::ValorInvalido(format!(
+                    "{} ingressos de {}",
+                    self.quantidade,
+                    formatar_centavos(self.centavos_por_pessoa)
+                ))
+            })
+    }
+}
+
+/// Venda de ingressos para uma data e faixa de horário
+///
+/// # Campos
+/// - `id`: Identificador estável da venda
+/// - `vendida_em`: Data e hora da venda
+/// - `data_visita`: Dia da visita
+/// - `faixa`: Início da faixa de horário de entrada
+/// - `itens`: Ingressos vendidos, por tipo
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Venda {
+    pub id: u32,
+    pub vendida_em: NaiveDateTime,
+    pub data_visita: NaiveDate,
+    pub faixa: Horario,
+    pub itens: Vec<ItemVenda>,
+}
+
+impl Venda {
+    pub fn visitantes(&self) -> u32 {
+        self.itens.iter().map(|item| item.quantidade).sum()
+    }
+
+    /// Soma dos subtotais dos itens
+    ///
+    /// # Retorno
+    /// `ValorInvalido` se algum subtotal ou o total não couber num `u64`
+    pub fn total_centavos(&self) -> Result<u64, ErroZoologico> {
+        total_centavos(&self.itens)
+    }
+}
+
+/// Lotação de uma faixa de horário num dia
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcupacaoFaixa {
+    pub inicio: Horario,
+    pub capacidade: u32,
+    pub vendidos: u32,
+}
+
+impl OcupacaoFaixa {
+    pub fn disponiveis(&self) -> u32 {
+        self.capacidade.saturating_sub(self.vendidos)
+    }
+}
+
+/// Período de um relatório de público
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Periodo {
+    Dia,
+    /// Semana de segunda a domingo
+    Semana,
+    /// Mês do calendário
+    Mes,
+}
+
+impl Periodo {
+    /// Primeiro e último dia do período que contém a data
+    pub fn intervalo(self, data: NaiveDate) -> (NaiveDate, NaiveDate) {
+        match self {
+            Periodo::Dia => (data, data),
+            Periodo::Semana => {
+                let dias = data.weekday().num_days_from_monday() as u64;
+                let inicio = data - Days::new(dias);
+                (inicio, inicio + Days::new(6))
+            }
+            Periodo::Mes => {
+                let inicio = data.with_day(1).unwrap_or(data);
+                let fim = inicio
+                    .checked_add_months(Months::new(1))
+                    .and_then(|proximo| proximo.pred_opt())
+                    .unwrap_or(data);
+                (inicio, fim)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Periodo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            Periodo::Dia => "Diário",
+            Periodo::Semana => "Semanal",
+            Periodo::Mes => "Mensal",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Público e receita de um dia ou de um tipo de ingresso
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Movimento {
+    pub visitantes: u32,
+    pub receita_centavos: u64,
+}
+
+impl Movimento {
+    /// Acrescenta visitantes e receita ao movimento
+    ///
+    /// # Retorno
+    /// `ValorInvalido` se algum dos totais estourar; o movimento não é alterado
+    pub fn somar(&mut self, visitantes: u32, receita_centavos: u64) -> Result<(), ErroZoologico> {
+        let total_visitantes = self.visitantes.checked_add(visitantes).ok_or_else(|| {
+            ErroZoologico::ValorInvalido(format!("{} + {} visitantes", self.visitantes, visitantes))
+        })?;
+        self.receita_centavos = somar_centavos(self.receita_centavos, receita_centavos)?;
+        self.visitantes = total_visitantes;
+        Ok(())
+    }
+}
+
+/// Relatório de público e receita de um período
+///
+/// Visitantes e receita contam no dia da visita, não no dia da venda.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatorioPublico {
+    pub periodo: Periodo,
+    pub inicio: NaiveDate,
+    pub fim: NaiveDate,
+    /// Todos os dias do período, inclusive os sem visitantes
+    pub dias: Vec<(NaiveDate, Movimento)>,
+    /// Totais por tipo de ingresso, na ordem de `TIPOS_INGRESSO`
+    pub por_tipo: Vec<(TipoIngresso, Movimento)>,
+}
+
+impl RelatorioPublico {
+    /// Soma dos movimentos de todos os dias
+    ///
+    /// # Retorno
+    /// `ValorInvalido` se o público ou a receita do período estourar
+    pub fn total(&self) -> Result<Movimento, ErroZoologico> {
+        let mut total = Movimento::default();
+        for (_, movimento) in &self.dias {
+            total.somar(movimento.visitantes, movimento.receita_centavos)?;
+        }
+        Ok(total)
+    }
+
+    /// Média de visitantes por dia do período
+    pub fn media_diaria(&self) -> Result<f64, ErroZoologico> {
+        Ok(self.total()?.visitantes as f64 / self.dias.len().max(1) as f64)
+    }
+}
+
+/// Tabela de preços, faixas de visitação e vendas de ingressos
+pub struct Bilheteria {
+    /// Preços indexados pelo tipo; todo tipo de ingresso tem um preço
+    precos: BTreeMap<TipoIngresso, PrecoIngresso>,
+    /// Faixas de visitação indexadas pelo horário de início
+    faixas: BTreeMap<Horario, FaixaVisitacao>,
+    vendas: BTreeMap<u32, Venda>,
+    proximo_id_venda: u32,
+}
+
+impl Default for Bilheteria {
+    fn default() -> Self {
+        Bilheteria {
+            precos: precos_padrao()
+                .into_iter()
+                .map(|preco| (preco.tipo, preco))
+                .collect(),
+            faixas: faixas_padrao()
+                .into_iter()
+                .map(|faixa| (faixa.inicio, faixa))
+                .collect(),
+            vendas: BTreeMap::new(),
+            proximo_id_venda: 1,
+        }
+    }
+}
+
+impl Bilheteria {
+    /// Monta a bilheteria lida do arquivo de dados
+    ///
+    /// Tipos sem preço no arquivo ficam com o preço padrão. Vendas com id
+    /// repetido ou fora da faixa são contadas em `descartes`.
+    pub(crate) fn carregar(
+        precos: Vec<PrecoIngresso>,
+        faixas: Vec<FaixaVisitacao>,
+        proximo_id_venda: u32,
+        vendas: Vec<Venda>,
+        descartes: &mut Descartes,
+    ) -> Self {
+        let mut bilheteria = Bilheteria {
+            faixas: faixas
+                .into_iter()
+                .map(|faixa| (faixa.inicio, faixa))
+                .collect(),
+            proximo_id_venda,
+            ..Bilheteria::default()
+        };
+        for preco in precos {
+            bilheteria.precos.insert(preco.tipo, preco);
+        }
+        for venda in vendas {
+            persistencia::inserir_com_id(
+                &mut bilheteria.vendas,
+                venda.id,
+                venda,
+                &mut bilheteria.proximo_id_venda,
+                descartes,
+            );
+        }
+        bilheteria
+    }
+
+    pub(crate) fn proximo_id_venda(&self) -> u32 {
+        self.proximo_id_venda
+    }
+
+    /// Vendas em ordem de id
+    pub fn listar_vendas(&self) -> Vec<&Venda> {
+        self.vendas.values().collect()
+    }
+
+    /// Tabela de preços, na ordem de `TIPOS_INGRESSO`
+    pub fn precos(&self) -> Vec<&PrecoIngresso> {
+        self.precos.values().collect()
+    }
+
+    fn preco_ingresso(&self, tipo: TipoIngresso) -> PrecoIngresso {
+        self.precos
+            .get(&tipo)
+            .copied()
+            .unwrap_or(preco_padrao(tipo))
+    }
+
+    /// Altera o preço por pessoa e o mínimo de pessoas de um tipo de ingresso
+    ///
+    /// Vendas já registradas mantêm o preço da época.
+    pub(crate) fn definir_preco(
+        &mut self,
+        tipo: TipoIngresso,
+        centavos: u64,
+        minimo_pessoas: u32,
+    ) -> Result<(), ErroZoologico> {
+        if minimo_pessoas == 0 {
+            return Err(ErroZoologico::ValorInvalido(
+                "mínimo de 0 pessoas".to_string(),
+            ));
+        }
+        self.precos.insert(
+            tipo,
+            PrecoIngresso {
+                tipo,
+                centavos,
+                minimo_pessoas,
+            },
+        );
+        Ok(())
+    }
+
+    /// Faixas de visitação em ordem de horário
+    pub fn faixas(&self) -> Vec<&FaixaVisitacao> {
+        self.faixas.values().collect()
+    }
+
+    /// Cadastra a faixa de visitação ou altera a capacidade de uma existente
+    pub(crate) fn definir_faixa(
+        &mut self,
+        inicio: Horario,
+        capacidade: u32,
+    ) -> Result<(), ErroZoologico> {
+        if capacidade == 0 {
+            return Err(ErroZoologico::ValorInvalido("capacidade 0".to_string()));
+        }
+        self.faixas
+            .insert(inicio, FaixaVisitacao { inicio, capacidade });
+        Ok(())
+    }
+
+    /// Remove a faixa de visitação; as vendas já feitas para ela continuam
+    /// nos relatórios
+    pub(crate) fn excluir_faixa(
+        &mut self,
+        inicio: Horario,
+    ) -> Result<FaixaVisitacao, ErroZoologico> {
+        self.faixas
+            .remove(&inicio)
+            .ok_or(ErroZoologico::FaixaNaoEncontrada(inicio.to_string()))
+    }
+
+    /// Vende ingressos para uma data e faixa de horário
+    ///
+    /// # Parâmetros
+    /// - `pedido`: Quantidade de pessoas por tipo de ingresso; quantidades
+    ///   zero são ignoradas e linhas do mesmo tipo são somadas
+    /// - `vendida_em`: Momento da venda
+    ///
+    /// # Retorno
+    /// Id da venda, ou erro se a faixa não existir, não tiver vagas para
+    /// todos, se algum tipo tiver menos pessoas que o mínimo, se o total de
+    /// ingressos não couber num `u32` ou se o valor da venda não couber num `u64`
+    pub(crate) fn vender_ingressos(
+        &mut self,
+        data_visita: NaiveDate,
+        faixa: Horario,
+        pedido: &[(TipoIngresso, u32)],
+        vendida_em: NaiveDateTime,
+    ) -> Result<u32, ErroZoologico> {
+        let ocupacao = self
+            .ocupacao_faixa(data_visita, faixa)
+            .ok_or(ErroZoologico::FaixaNaoEncontrada(faixa.to_string()))?;
+
+        // Linhas repetidas do mesmo tipo são somadas antes de conferir o mínimo
+        let mut itens: Vec<ItemVenda> = Vec::new();
+        for &(tipo, quantidade) in pedido.iter().filter(|(_, quantidade)| *quantidade > 0) {
+            match itens.iter_mut().find(|item| item.tipo == tipo) {
+                Some(item) => {
+                    item.quantidade = somar_ingressos(item.quantidade, quantidade)?;
+                }
+                None => itens.push(ItemVenda {
+                    tipo,
+                    quantidade,
+                    centavos_por_pessoa: self.preco_ingresso(tipo).centavos,
+                }),
+            }
+        }
+        if itens.is_empty() {
+            return Err(ErroZoologico::CampoVazio("ingressos".to_string()));
+        }
+        for item in &itens {
+            let minimo = self.preco_ingresso(item.tipo).minimo_pessoas;
+            if item.quantidade < minimo {
+                return Err(ErroZoologico::QuantidadeAbaixoDoMinimo {
+                    tipo: item.tipo.to_string(),
+                    minimo,
+                    pessoas: item.quantidade,
+                });
+            }
+        }
+
+        total_centavos(&itens)?;
+        let solicitados = itens
+            .iter()
+            .try_fold(0, |total, item| somar_ingressos(total, item.quantidade))?;
+        if solicitados > ocupacao.disponiveis() {
+            return Err(ErroZoologico::LotacaoEsgotada {
+                faixa: faixa.to_string(),
+                disponiveis: ocupacao.disponiveis(),
+                solicitados,
+            });
+        }
+
+        let id = persistencia::reservar_id(&mut self.proximo_id_venda, "vendas")?;
+        self.vendas.insert(
+            id,
+            Venda {
+                id,
+                vendida_em,
+                data_visita,
+                faixa,
+                itens,
+            },
+        );
+        Ok(id)
+    }
+
+    pub fn buscar_venda(&self, id: u32) -> Option<&Venda> {
+        self.vendas.get(&id)
+    }
+
+    /// Ingressos vendidos e vagas de cada faixa de visitação no dia
+    pub fn ocupacao_faixas(&self, data: NaiveDate) -> Vec<OcupacaoFaixa> {
+        self.faixas
+            .keys()
+            .filter_map(|&inicio| self.ocupacao_faixa(data, inicio))
+            .collect()
+    }
+
+    /// Relatório de público e receita do dia, da semana ou do mês que contém a data
+    ///
+    /// # Retorno
+    /// `ValorInvalido` se o público ou a receita do período estourar
+    pub fn relatorio_publico(
+        &self,
+        periodo: Periodo,
+        data: NaiveDate,
+    ) -> Result<RelatorioPublico, ErroZoologico> {
+        let (inicio, fim) = periodo.intervalo(data);
+        let mut dias: BTreeMap<NaiveDate, Movimento> = inicio
+            .iter_days()
+            .take_while(|dia| *dia <= fim)
+            .map(|dia| (dia, Movimento::default()))
+            .collect();
+        let mut por_tipo: Vec<(TipoIngresso, Movimento)> = TIPOS_INGRESSO
+            .iter()
+            .map(|&tipo| (tipo, Movimento::default()))
+            .collect();
+
+        for venda in self.vendas.values() {
+            let Some(dia) = dias.get_mut(&venda.data_visita) else {
+                continue;
+            };
+            for item in &venda.itens {
+                let subtotal = item.subtotal_centavos()?;
+                dia.somar(item.quantidade, subtotal)?;
+                if let Some((_, movimento)) =
+                    por_tipo.iter_mut().find(|(tipo, _)| *tipo == item.tipo)
+                {
+                    movimento.somar(item.quantidade, subtotal)?;
+                }
+            }
+        }
+
+        let relatorio = RelatorioPublico {
+            periodo,
+            inicio,
+            fim,
+            dias: dias.into_iter().collect(),
+            por_tipo,
+        };
+        relatorio.total()?;
+        Ok(relatorio)
+    }
+
+    fn ocupacao_faixa(&self, data: NaiveDate, inicio: Horario) -> Option<OcupacaoFaixa> {
+        let faixa = self.faixas.get(&inicio)?;
+        let vendidos = self
+            .vendas
+            .values()
+            .filter(|venda| venda.data_visita == data && venda.faixa == inicio)
+            .map(Venda::visitantes)
+            .sum();
+        Some(OcupacaoFaixa {
+            inicio,
+            capacidade: faixa.capacidade,
+            vendidos,
+        })
+    }
+}
+
+/// Soma dos subtotais dos itens de uma venda, sem estourar
+fn total_centavos(itens: &[ItemVenda]) -> Result<u64, ErroZoologico> {
+    itens.iter().try_fold(0, |total, item| {
+        somar_centavos(total, item.subtotal_centavos()?)
+    })
+}
+
+fn somar_centavos(total: u64, valor: u64) -> Result<u64, ErroZoologico> {
+    total.checked_add(valor).ok_or_else(|| {
+        ErroZoologico::ValorInvalido(format!(
+            "{} + {}",
+            formatar_centavos(total),
+            formatar_centavos(valor)
+        ))
+    })
+}
+
+/// Soma quantidades de ingressos de um pedido
+fn somar_ingressos(total: u32, quantidade: u32) -> Result<u32, ErroZoologico> {
+    total.checked_add(quantidade).ok_or_else(|| {
+        ErroZoologico::ValorInvalido(format!("{} + {} ingressos", total, quantidade))
+    })
+}
+
+/// Formata um valor em centavos como reais (ex: 123456 → "R$ 1.234,56")
+pub fn formatar_centavos(centavos: u64) -> String {
+    let reais = (centavos / 100).to_string();
+    let mut milhares = String::new();
+    for (posicao, digito) in reais.chars().enumerate() {
+        if posicao > 0 && (reais.len() - posicao).is_multiple_of(3) {
+            milhares.push('.');
+        }
+        milhares.push(digito);
+    }
+    format!("R$ {},{:02}", milhares, centavos % 100)
+}
+
+/// Lê um valor em reais digitado pelo usuário (ex: "40", "12,50", "R$ 7.5")
+///
+/// # Retorno
+/// Valor em centavos; o valor é lido sem passar por ponto flutuante
+pub fn ler_centavos(texto: &str) -> Result<u64, ErroZoologico> {
+    let invalido = || ErroZoologico::ValorInvalido(texto.trim().to_string());
+    let valor = texto.trim().trim_start_matches("R$").trim();
+
+    let (reais, fracao) = match valor.rsplit_once([',', '.']) {
+        Some((reais, fracao)) => (reais, fracao),
+        None => (valor, ""),
+    };
+    if reais.is_empty() || fracao.len() > 2 || !fracao.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalido());
+    }
+    let reais: u64 = reais.parse().map_err(|_| invalido())?;
+    let centavos = match fracao.len() {
+        0 => 0,
+        1 => fracao.parse::<u64>().map_err(|_| invalido())? * 10,
+        _ => fracao.parse::<u64>().map_err(|_| invalido())?,
+    };
+
+    reais
+        .checked_mul(100)
+        .and_then(|total| total.checked_add(centavos))
+        .ok_or_else(invalido)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formatar_e_ler_centavos() {
+        assert_eq!(formatar_centavos(0), "R$ 0,00");
+        assert_eq!(formatar_centavos(4005), "R$ 40,05");
+        assert_eq!(formatar_centavos(123_456_789), "R$ 1.234.567,89");
+
+        assert_eq!(ler_centavos("40"), Ok(4000));
+        assert_eq!(ler_centavos("R$ 12,5"), Ok(1250));
+        assert_eq!(ler_centavos("0.07"), Ok(7));
+        assert!(ler_centavos("12,345").is_err());
+        assert!(ler_centavos("-3").is_err());
+        assert!(ler_centavos(",50").is_err());
+    }
+
+    #[test]
+    fn test_intervalo_dos_periodos() {
+        let data = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+        let dia = |d| NaiveDate::from_ymd_opt(2024, 2, d).unwrap();
+
+        assert_eq!(Periodo::Dia.intervalo(data), (data, data));
+        // 15/02/2024 foi uma quinta-feira
+        assert_eq!(Periodo::Semana.intervalo(data), (dia(12), dia(18)));
+        assert_eq!(Periodo::Mes.intervalo(data), (dia(1), dia(29)));
+        assert_eq!(
+            Periodo::Mes.intervalo(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()),
+            (
+                NaiveDate::from_ymd_opt(2024, 12, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_bilheteria_carregada_completa_precos_e_controla_lotacao() {
+        let mut descartes = Descartes::default();
+        let nove = Horario::new(9, 0).unwrap();
+        let mut bilheteria = Bilheteria::carregar(
+            vec![PrecoIngresso {
+                tipo: TipoIngresso::Adulto,
+                centavos: 5000,
+                minimo_pessoas: 1,
+            }],
+            vec![FaixaVisitacao {
+                inicio: nove,
+                capacidade: 3,
+            }],
+            1,
+            Vec::new(),
+            &mut descartes,
+        );
+        assert_eq!(bilheteria.precos().len(), TIPOS_INGRESSO.len());
+        assert_eq!(bilheteria.precos()[0].centavos, 5000);
+
+        let data = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+        let momento = data.and_hms_opt(8, 0, 0).unwrap();
+        let id = bilheteria
+            .vender_ingressos(data, nove, &[(TipoIngresso::Adulto, 2)], momento)
+            .unwrap();
+        assert_eq!(
+            bilheteria.buscar_venda(id).unwrap().total_centavos(),
+            Ok(10000)
+        );
+        assert!(matches!(
+            bilheteria.vender_ingressos(data, nove, &[(TipoIngresso::Crianca, 2)], momento),
+            Err(ErroZoologico::LotacaoEsgotada { disponiveis: 1, .. })
+        ));
+        assert_eq!(bilheteria.ocupacao_faixas(data)[0].disponiveis(), 1);
+        assert_eq!(bilheteria.listar_vendas().len(), 1);
+    }
+}
@@ -0,0 +1,656 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::animais::InfoAnimal;
+use crate::errors::ErroZoologico;
+use crate::persistencia::{self, Descartes};
+
+/// Parentesco a partir do qual os filhotes de um casal têm endogamia próxima
+///
+/// 0,0625 é o parentesco entre primos de primeiro grau.
+pub const LIMITE_ENDOGAMIA: f64 = 0.0625;
+
+/// Fato registrado na vida de um animal
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "evento", rename_all = "snake_case")]
+pub enum Evento {
+    /// Chegada vinda de outra instituição ou resgate
+    Chegada { origem: String },
+    /// Nascimento no zoológico, com os pais conhecidos
+    Nascimento {
+        id_mae: Option<u32>,
+        id_pai: Option<u32>,
+    },
+    /// Saída para outra instituição
+    Transferencia { destino: String },
+    /// Morte do animal
+    Obito { causa: String },
+}
+
+impl Evento {
+    /// O evento encerra a permanência do animal no zoológico
+    pub fn e_saida(&self) -> bool {
+        matches!(self, Evento::Transferencia { .. } | Evento::Obito { .. })
+    }
+}
+
+impl fmt::Display for Evento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Evento::Chegada { origem } => write!(f, "Chegada (origem: {})", origem),
+            Evento::Nascimento { .. } => write!(f, "Nascimento"),
+            Evento::Transferencia { destino } => write!(f, "Transferência para {}", destino),
+            Evento::Obito { causa } => write!(f, "Óbito (causa: {})", causa),
+        }
+    }
+}
+
+/// Evento do ciclo de vida de um animal
+///
+/// # Campos
+/// - `id`: Identificador estável do evento
+/// - `id_animal`: Animal a que o evento se refere
+/// - `data`: Dia em que o evento aconteceu
+/// - `evento`: O que aconteceu
+/// - `observacao`: Anotação livre (pode ficar vazia)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct EventoVida {
+    pub id: u32,
+    pub id_animal: u32,
+    pub data: NaiveDate,
+    #[serde(flatten)]
+    pub evento: Evento,
+    #[serde(default)]
+    pub observacao: String,
+}
+
+/// Árvore de ancestrais de um animal
+///
+/// Pais desconhecidos ou além do número de gerações pedido ficam em `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Genealogia {
+    pub id: u32,
+    pub nome: String,
+    pub tipo: String,
+    /// O animal não está mais no zoológico (transferido ou morto)
+    pub baixado: bool,
+    pub mae: Option<Box<Genealogia>>,
+    pub pai: Option<Box<Genealogia>>,
+}
+
+/// Parentesco mais próximo entre dois animais
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parentesco {
+    PaiOuMaeEFilho,
+    Irmaos,
+    MeioIrmaos,
+    AvoENeto,
+    /// Parentes mais distantes (tios, primos...)
+    Distante,
+    Nenhum,
+}
+
+impl fmt::Display for Parentesco {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            Parentesco::PaiOuMaeEFilho => "pai ou mãe e filho",
+            Parentesco::Irmaos => "irmãos",
+            Parentesco::MeioIrmaos => "meio-irmãos",
+            Parentesco::AvoENeto => "avô ou avó e neto",
+            Parentesco::Distante => "parentes distantes",
+            Parentesco::Nenhum => "sem parentesco registrado",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Resultado da verificação de compatibilidade para reprodução
+///
+/// # Campos
+/// - `parentesco`: Parentesco mais próximo entre os dois animais
+/// - `coeficiente`: Coeficiente de parentesco do casal, que é também o
+///   coeficiente de endogamia esperado dos filhotes (0 a 1)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AvaliacaoReproducao {
+    pub parentesco: Parentesco,
+    pub coeficiente: f64,
+}
+
+impl AvaliacaoReproducao {
+    /// Os filhotes do casal teriam endogamia próxima
+    pub fn endogamia_proxima(&self) -> bool {
+        self.coeficiente >= LIMITE_ENDOGAMIA
+    }
+}
+
+/// Calcula o coeficiente de parentesco entre dois animais pela genealogia
+///
+/// Usa o método recursivo clássico: o parentesco com um animal é a média do
+/// parentesco com seus pais, e o de um animal consigo mesmo é (1 + F) / 2,
+/// onde F é o parentesco entre seus pais. Pais desconhecidos contam como
+/// não aparentados.
+///
+/// # Parâmetros
+/// - `pais`: Mãe e pai registrados de cada animal
+pub fn coeficiente_parentesco(
+    a: u32,
+    b: u32,
+    pais: &impl Fn(u32) -> (Option<u32>, Option<u32>),
+) -> f64 {
+    let mut calculo = Calculo {
+        pais,
+        geracoes: HashMap::new(),
+        parentescos: HashMap::new(),
+    };
+    calculo.parentesco(Some(a), Some(b))
+}
+
+/// Estado do cálculo de parentesco, com os resultados já conhecidos
+struct Calculo<'a, F> {
+    pais: &'a F,
+    geracoes: HashMap<u32, u32>,
+    parentescos: HashMap<(u32, u32), f64>,
+}
+
+impl<F: Fn(u32) -> (Option<u32>, Option<u32>)> Calculo<'_, F> {
+    fn parentesco(&mut self, a: Option<u32>, b: Option<u32>) -> f64 {
+        let (Some(a), Some(b)) = (a, b) else {
+            return 0.0;
+        };
+        let chave = (a.min(b), a.max(b));
+        if let Some(&valor) = self.parentescos.get(&chave) {
+            return valor;
+        }
+
+        let valor = if a == b {
+            let (mae, pai) = (self.pais)(a);
+            (1.0 + self.parentesco(mae, pai)) / 2.0
+        } else {
+            // Sobe pela genealogia do mais novo: ele não pode ser ancestral do outro
+            let (novo, outro) = if self.geracao(a) >= self.geracao(b) {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            let (mae, pai) = (self.pais)(novo);
+            (self.parentesco(mae, Some(outro)) + self.parentesco(pai, Some(outro))) / 2.0
+        };
+        self.parentescos.insert(chave, valor);
+        valor
+    }
+
+    /// Número de gerações de ancestrais registradas acima do animal
+    fn geracao(&mut self, id: u32) -> u32 {
+        if let Some(&geracao) = self.geracoes.get(&id) {
+            return geracao;
+        }
+        let (mae, pai) = (self.pais)(id);
+        let geracao = [mae, pai]
+            .into_iter()
+            .flatten()
+            .map(|pai| self.geracao(pai) + 1)
+            .max()
+            .unwrap_or(0);
+        self.geracoes.insert(id, geracao);
+        geracao
+    }
+}
+
+/// Classifica o parentesco mais próximo entre dois animais diferentes
+pub fn classificar_parentesco(
+    a: u32,
+    b: u32,
+    coeficiente: f64,
+    pais: &impl Fn(u32) -> (Option<u32>, Option<u32>),
+) -> Parentesco {
+    let lista = |id: u32| {
+        let (mae, pai) = pais(id);
+        [mae, pai].into_iter().flatten().collect::<Vec<u32>>()
+    };
+    let (pais_a, pais_b) = (lista(a), lista(b));
+    let avos = |pais_do_animal: &[u32]| {
+        pais_do_animal
+            .iter()
+            .flat_map(|&id| lista(id))
+            .collect::<Vec<u32>>()
+    };
+
+    if pais_a.contains(&b) || pais_b.contains(&a) {
+        return Parentesco::PaiOuMaeEFilho;
+    }
+    let em_comum = pais_a.iter().filter(|id| pais_b.contains(id)).count();
+    if em_comum == 2 {
+        Parentesco::Irmaos
+    } else if em_comum == 1 {
+        Parentesco::MeioIrmaos
+    } else if avos(&pais_a).contains(&b) || avos(&pais_b).contains(&a) {
+        Parentesco::AvoENeto
+    } else if coeficiente > 0.0 {
+        Parentesco::Distante
+    } else {
+        Parentesco::Nenhum
+    }
+}
+
+/// Animais que deixaram o zoológico e os eventos do ciclo de vida
+///
+/// Animais transferidos ou mortos ficam guardados para o histórico e a
+/// genealogia, e seus ids continuam reservados. A saída em si passa pelo
+/// `Zoologico`, que também tira o animal do recinto, dos planos e das tarefas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Historico {
+    animais_baixados: BTreeMap<u32, InfoAnimal>,
+    eventos: BTreeMap<u32, EventoVida>,
+    proximo_id_evento: u32,
+}
+
+impl Default for Historico {
+    fn default() -> Self {
+        Historico {
+            animais_baixados: BTreeMap::new(),
+            eventos: BTreeMap::new(),
+            proximo_id_evento: 1,
+        }
+    }
+}
+
+impl Historico {
+    /// Monta o histórico com os registros lidos do arquivo de dados
+    ///
+    /// Um animal baixado com o id de um animal presente conta como repetido.
+    /// Os demais avançam `proximo_id_animal`, já que seus ids seguem reservados.
+    pub(crate) fn carregar(
+        baixados: Vec<InfoAnimal>,
+        presentes: &BTreeMap<u32, InfoAnimal>,
+        proximo_id_animal: &mut u32,
+        proximo_id_evento: u32,
+        eventos: Vec<EventoVida>,
+        descartes: &mut Descartes,
+    ) -> Self {
+        let mut historico = Historico {
+            proximo_id_evento,
+            ..Historico::default()
+        };
+        for animal in baixados {
+            if presentes.contains_key(&animal.id) {
+                descartes.repetidos += 1;
+                continue;
+            }
+            persistencia::inserir_com_id(
+                &mut historico.animais_baixados,
+                animal.id,
+                animal,
+                proximo_id_animal,
+                descartes,
+            );
+        }
+        for evento in eventos {
+            persistencia::inserir_com_id(
+                &mut historico.eventos,
+                evento.id,
+                evento,
+                &mut historico.proximo_id_evento,
+                descartes,
+            );
+        }
+        historico
+    }
+
+    pub(crate) fn proximo_id_evento(&self) -> u32 {
+        self.proximo_id_evento
+    }
+
+    /// Todos os eventos, em ordem de id
+    pub(crate) fn eventos(&self) -> impl Iterator<Item = &EventoVida> {
+        self.eventos.values()
+    }
+
+    /// Animal que já deixou o zoológico (transferido ou morto)
+    pub fn buscar_animal_baixado(&self, id: u32) -> Option<&InfoAnimal> {
+        self.animais_baixados.get(&id)
+    }
+
+    pub fn listar_animais_baixados(&self) -> Vec<&InfoAnimal> {
+        self.animais_baixados.values().collect()
+    }
+
+    pub(crate) fn animais_baixados_mut(&mut self) -> impl Iterator<Item = &mut InfoAnimal> {
+        self.animais_baixados.values_mut()
+    }
+
+    /// Eventos do animal em ordem de data
+    pub fn eventos_do_animal(&self, id_animal: u32) -> Vec<&EventoVida> {
+        let mut eventos: Vec<&EventoVida> = self
+            .eventos
+            .values()
+            .filter(|evento| evento.id_animal == id_animal)
+            .collect();
+        eventos.sort_by_key(|evento| (evento.data, evento.id));
+        eventos
+    }
+
+    /// Evento de saída (transferência ou óbito) de um animal baixado
+    pub fn saida_do_animal(&self, id_animal: u32) -> Option<&EventoVida> {
+        self.eventos_do_animal(id_animal)
+            .into_iter()
+            .rfind(|evento| evento.evento.e_saida())
+    }
+
+    pub(crate) fn registrar_evento(
+        &mut self,
+        id_animal: u32,
+        data: NaiveDate,
+        evento: Evento,
+        observacao: &str,
+    ) -> Result<(), ErroZoologico> {
+        let id = persistencia::reservar_id(&mut self.proximo_id_evento, "eventos")?;
+        self.eventos.insert(
+            id,
+            EventoVida {
+                id,
+                id_animal,
+                data,
+                evento,
+                observacao: observacao.trim().to_string(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Guarda o animal que saiu do zoológico e registra o evento de saída
+    pub(crate) fn baixar(
+        &mut self,
+        animal: InfoAnimal,
+        data: NaiveDate,
+        evento: Evento,
+        observacao: &str,
+    ) -> Result<(), ErroZoologico> {
+        self.registrar_evento(animal.id, data, evento, observacao)?;
+        self.animais_baixados.insert(animal.id, animal);
+        Ok(())
+    }
+
+    /// Apaga os eventos de um animal excluído e a ligação dos filhos com ele
+    pub(crate) fn esquecer_animal(&mut self, presentes: &mut BTreeMap<u32, InfoAnimal>, id: u32) {
+        self.eventos.retain(|_, evento| evento.id_animal != id);
+        for filho in presentes
+            .values_mut()
+            .chain(self.animais_baixados.values_mut())
+        {
+            if filho.id_mae == Some(id) {
+                filho.id_mae = None;
+            }
+            if filho.id_pai == Some(id) {
+                filho.id_pai = None;
+            }
+        }
+    }
+
+    /// Tira da genealogia os pais que não estão registrados e remove os
+    /// eventos de animais que não existem mais
+    ///
+    /// # Retorno
+    /// Quantidade de ligações e eventos removidos
+    pub(crate) fn desfazer_referencias_perdidas(
+        &mut self,
+        presentes: &mut BTreeMap<u32, InfoAnimal>,
+    ) -> usize {
+        let linhagem = Linhagem {
+            presentes: &*presentes,
+            baixados: &self.animais_baixados,
+        };
+        let correcoes: Vec<(u32, Option<u32>, Option<u32>)> = presentes
+            .values()
+            .chain(self.animais_baixados.values())
+            .map(|animal| {
+                let registrado = |id: &u32| linhagem.registro(*id).is_some();
+                (
+                    animal.id,
+                    animal.id_mae.filter(registrado),
+                    animal.id_pai.filter(registrado),
+                )
+            })
+            .collect();
+
+        let mut removidas = 0;
+        for (id, mae, pai) in correcoes {
+            if let Some(animal) = presentes
+                .get_mut(&id)
+                .or_else(|| self.animais_baixados.get_mut(&id))
+            {
+                removidas += usize::from(animal.id_mae != mae) + usize::from(animal.id_pai != pai);
+                animal.id_mae = mae;
+                animal.id_pai = pai;
+            }
+        }
+
+        let antes = self.eventos.len();
+        let baixados = &self.animais_baixados;
+        self.eventos.retain(|_, evento| {
+            presentes.contains_key(&evento.id_animal) || baixados.contains_key(&evento.id_animal)
+        });
+        removidas + antes - self.eventos.len()
+    }
+}
+
+/// Genealogia dos animais registrados, no zoológico ou no histórico
+#[derive(Debug, Clone, Copy)]
+pub struct Linhagem<'a> {
+    presentes: &'a BTreeMap<u32, InfoAnimal>,
+    baixados: &'a BTreeMap<u32, InfoAnimal>,
+}
+
+impl<'a> Linhagem<'a> {
+    pub(crate) fn new(presentes: &'a BTreeMap<u32, InfoAnimal>, historico: &'a Historico) -> Self {
+        Linhagem {
+            presentes,
+            baixados: &historico.animais_baixados,
+        }
+    }
+
+    /// Animal no zoológico ou no histórico
+    pub fn registro(&self, id: u32) -> Option<&'a InfoAnimal> {
+        self.presentes.get(&id).or_else(|| self.baixados.get(&id))
+    }
+
+    /// Mãe e pai registrados do animal
+    pub fn pais_de(&self, id: u32) -> (Option<u32>, Option<u32>) {
+        self.registro(id)
+            .map_or((None, None), |animal| (animal.id_mae, animal.id_pai))
+    }
+
+    /// Filhos registrados do animal, inclusive os que já deixaram o zoológico
+    pub fn filhos(&self, id_animal: u32) -> Vec<&'a InfoAnimal> {
+        let mut filhos: Vec<&InfoAnimal> = self
+            .presentes
+            .values()
+            .chain(self.baixados.values())
+            .filter(|animal| animal.id_mae == Some(id_animal) || animal.id_pai == Some(id_animal))
+            .collect();
+        filhos.sort_by_key(|animal| animal.id);
+        filhos
+    }
+
+    /// Árvore de ancestrais do animal até o número de gerações informado
+    ///
+    /// # Retorno
+    /// `None` se o animal não existir nem no histórico
+    pub fn genealogia(&self, id_animal: u32, geracoes: u32) -> Option<Genealogia> {
+        let animal = self.registro(id_animal)?;
+        let ancestral = |id: Option<u32>| {
+            id.filter(|_| geracoes > 0)
+                .and_then(|id| self.genealogia(id, geracoes - 1))
+                .map(Box::new)
+        };
+        Some(Genealogia {
+            id: animal.id,
+            nome: animal.nome.clone(),
+            tipo: animal.tipo.clone(),
+            baixado: self.baixados.contains_key(&animal.id),
+            mae: ancestral(animal.id_mae),
+            pai: ancestral(animal.id_pai),
+        })
+    }
+
+    /// Confere os pais informados para um filhote do tipo `tipo`
+    ///
+    /// Os pais precisam ser da mesma espécie do filhote. A mãe precisa estar
+    /// no zoológico; o pai pode já ter sido transferido ou morto.
+    pub(crate) fn verificar_pais(
+        &self,
+        tipo: &str,
+        id_mae: Option<u32>,
+        id_pai: Option<u32>,
+    ) -> Result<(), ErroZoologico> {
+        if id_mae.is_some() && id_mae == id_pai {
+            return Err(ErroZoologico::ParentescoInvalido(
+                "a mãe e o pai são o mesmo animal".to_string(),
+            ));
+        }
+        if let Some(mae) = id_mae.and_then(|id| self.baixados.get(&id)) {
+            return Err(ErroZoologico::ParentescoInvalido(format!(
+                "a mãe {} não está mais no zoológico",
+                mae.nome
+            )));
+        }
+        for id in [id_mae, id_pai].into_iter().flatten() {
+            let pai = self
+                .registro(id)
+                .ok_or(ErroZoologico::AnimalNaoEncontrado(id))?;
+            if pai.tipo != tipo {
+                return Err(ErroZoologico::ParentescoInvalido(format!(
+                    "{} é {}, não {}",
+                    pai.nome, pai.tipo, tipo
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifica se dois animais podem formar um casal para reprodução
+    ///
+    /// Os dois precisam estar no zoológico e ser da mesma espécie. O
+    /// parentesco é calculado pela genealogia registrada; use
+    /// `AvaliacaoReproducao::endogamia_proxima` para o alerta de endogamia.
+    pub fn avaliar_reproducao(
+        &self,
+        id_a: u32,
+        id_b: u32,
+    ) -> Result<AvaliacaoReproducao, ErroZoologico> {
+        let a = self
+            .presentes
+            .get(&id_a)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id_a))?;
+        let b = self
+            .presentes
+            .get(&id_b)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id_b))?;
+        if a.id == b.id {
+            return Err(ErroZoologico::ReproducaoIncompativel(
+                "informe dois animais diferentes".to_string(),
+            ));
+        }
+        if a.tipo != b.tipo {
+            return Err(ErroZoologico::ReproducaoIncompativel(format!(
+                "{} é {} e {} é {}",
+                a.nome, a.tipo, b.nome, b.tipo
+            )));
+        }
+
+        let pais = |id: u32| self.pais_de(id);
+        let coeficiente = coeficiente_parentesco(a.id, b.id, &pais);
+        Ok(AvaliacaoReproducao {
+            parentesco: classificar_parentesco(a.id, b.id, coeficiente, &pais),
+            coeficiente,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Família de teste: 1 e 2 são o casal fundador; 3 e 4 são seus filhos;
+    /// 5 é filho de 3 com 6 (de fora); 7 é filho de 4 com 8 (de fora);
+    /// 9 é meio-irmão de 3 (filho de 1 com 10)
+    fn familia(id: u32) -> (Option<u32>, Option<u32>) {
+        let pais: HashMap<u32, (u32, u32)> = HashMap::from([
+            (3, (2, 1)),
+            (4, (2, 1)),
+            (5, (6, 3)),
+            (7, (4, 8)),
+            (9, (10, 1)),
+        ]);
+        pais.get(&id)
+            .map_or((None, None), |&(mae, pai)| (Some(mae), Some(pai)))
+    }
+
+    #[test]
+    fn test_coeficiente_parentesco() {
+        let coeficiente = |a, b| coeficiente_parentesco(a, b, &familia);
+
+        assert_eq!(coeficiente(1, 1), 0.5);
+        assert_eq!(coeficiente(1, 2), 0.0);
+        assert_eq!(coeficiente(1, 3), 0.25);
+        assert_eq!(coeficiente(3, 4), 0.25);
+        assert_eq!(coeficiente(3, 9), 0.125);
+        assert_eq!(coeficiente(1, 5), 0.125);
+        // Primos de primeiro grau ficam exatamente no limite
+        assert_eq!(coeficiente(5, 7), LIMITE_ENDOGAMIA);
+        assert_eq!(coeficiente(6, 8), 0.0);
+    }
+
+    #[test]
+    fn test_classificar_parentesco() {
+        let classificar =
+            |a, b| classificar_parentesco(a, b, coeficiente_parentesco(a, b, &familia), &familia);
+
+        assert_eq!(classificar(3, 1), Parentesco::PaiOuMaeEFilho);
+        assert_eq!(classificar(3, 4), Parentesco::Irmaos);
+        assert_eq!(classificar(9, 4), Parentesco::MeioIrmaos);
+        assert_eq!(classificar(2, 5), Parentesco::AvoENeto);
+        assert_eq!(classificar(5, 7), Parentesco::Distante);
+        assert_eq!(classificar(6, 8), Parentesco::Nenhum);
+    }
+
+    #[test]
+    fn test_linhagem_com_animais_baixados() {
+        let animal = |id, tipo: &str, nome: &str| InfoAnimal::new(id, tipo.into(), nome.into());
+        let mut filhote = animal(3, "Leão", "Simba");
+        filhote.id_mae = Some(1);
+        filhote.id_pai = Some(2);
+        let presentes = BTreeMap::from([(1, animal(1, "Leão", "Sarabi")), (3, filhote)]);
+        let mut historico = Historico::default();
+        let data = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let obito = Evento::Obito {
+            causa: "idade".to_string(),
+        };
+        historico
+            .baixar(animal(2, "Leão", "Mufasa"), data, obito, "")
+            .unwrap();
+
+        let linhagem = Linhagem::new(&presentes, &historico);
+        assert_eq!(linhagem.filhos(2)[0].nome, "Simba");
+        assert!(linhagem.genealogia(3, 1).unwrap().pai.unwrap().baixado);
+        // O pai pode ter saído do zoológico; a mãe não
+        assert!(linhagem.verificar_pais("Leão", Some(1), Some(2)).is_ok());
+        assert!(matches!(
+            linhagem.verificar_pais("Leão", Some(2), None),
+            Err(ErroZoologico::ParentescoInvalido(_))
+        ));
+        assert!(matches!(
+            linhagem.verificar_pais("Zebra", Some(1), None),
+            Err(ErroZoologico::ParentescoInvalido(_))
+        ));
+        assert!(matches!(
+            linhagem.avaliar_reproducao(1, 2),
+            Err(ErroZoologico::AnimalNaoEncontrado(2))
+        ));
+        assert_eq!(
+            linhagem.avaliar_reproducao(1, 3).unwrap().parentesco,
+            Parentesco::PaiOuMaeEFilho
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::consulta::{CampoOrdenacao, ConsultaAnimais, FiltroRecinto};
+use crate::errors::ErroZoologico;
+use crate::saude::SituacaoSaude;
+
+/// Arquivo de dados usado quando `--arquivo` não é informado
+pub const ARQUIVO_PADRAO: &str = "animais.json";
+
+/// Código de saída: comando executado com sucesso
+pub const SAIDA_OK: i32 = 0;
+/// Código de saída: a operação foi recusada (ex: animal não encontrado, espécie desconhecida)
+pub const SAIDA_ERRO: i32 = 1;
+/// Código de saída: argumentos inválidos na linha de comando
+pub const SAIDA_USO: i32 = 2;
+/// Código de saída: o arquivo de dados não pôde ser lido ou gravado
+pub const SAIDA_DADOS: i32 = 3;
+
+/// Texto exibido por `--help`
+pub const AJUDA: &str = "\
+Uso: Projeto_1 [--arquivo <caminho>] [comando]
+
+Sem comando, abre o menu interativo.
+
+Comandos:
+  list [filtros] [--formato tabela|csv|json]
+                              Lista os animais
+  add --tipo <espécie> --nome <nome>
+                              Inclui um animal e mostra o id atribuído
+  edit <id> [--tipo <espécie>] [--nome <nome>]
+                              Altera o tipo e/ou o nome de um animal
+  remove <id>                 Exclui um animal
+  import <arquivo.csv|arquivo.json>
+                              Inclui os animais do arquivo (colunas tipo ou especie, e nome)
+  export <arquivo.csv|arquivo.json> [filtros]
+                              Exporta os animais para o arquivo
+  recuperar                   Recupera os registros válidos de um arquivo de dados corrompido
+  help                        Mostra esta ajuda
+
+Filtros de list e export:
+  --especie <espécie>   --nome <trecho>   --recinto <id, ou 0 para sem recinto>
+  --saude saudavel|em_tratamento|em_quarentena
+  --ordenar id|especie|nome|recinto|saude   --desc
+
+Opções gerais:
+  -a, --arquivo <caminho>     Arquivo de dados (padrão: animais.json)
+
+Códigos de saída:
+  0 sucesso, 1 operação recusada, 2 argumentos inválidos, 3 erro no arquivo de dados";
+
+/// Forma de exibir a lista de animais no comando `list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormatoSaida {
+    #[default]
+    Tabela,
+    Csv,
+    Json,
+}
+
+/// Ação pedida na linha de comando
+#[derive(Debug, Clone, PartialEq)]
+pub enum Comando {
+    Menu,
+    Ajuda,
+    Recuperar,
+    Listar {
+        consulta: ConsultaAnimais,
+        formato: FormatoSaida,
+    },
+    Incluir {
+        tipo: String,
+        nome: String,
+    },
+    Editar {
+        id: u32,
+        tipo: Option<String>,
+        nome: Option<String>,
+    },
+    Remover {
+        id: u32,
+    },
+    Importar {
+        origem: PathBuf,
+    },
+    Exportar {
+        destino: PathBuf,
+        consulta: ConsultaAnimais,
+    },
+}
+
+/// Linha de comando interpretada
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invocacao {
+    pub arquivo: PathBuf,
+    pub comando: Comando,
+}
+
+/// Argumentos inválidos na linha de comando
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErroUso(pub String);
+
+impl fmt::Display for ErroUso {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Código de saída correspondente ao erro de uma operação
+pub fn codigo_saida(erro: &ErroZoologico) -> i32 {
+    match erro {
+        ErroZoologico::ArquivoCorrompido(_) | ErroZoologico::ErroPersistencia(_) => SAIDA_DADOS,
+        _ => SAIDA_ERRO,
+    }
+}
+
+/// Opções aceitas pelos comandos de listagem (`list` e `export`)
+const OPCOES_FILTRO: [&str; 6] = [
+    "--especie",
+    "--nome",
+    "--recinto",
+    "--saude",
+    "--ordenar",
+    "--desc",
+];
+
+/// Opções que não recebem valor
+const OPCOES_SEM_VALOR: [&str; 1] = ["--desc"];
+
+/// Interpreta os argumentos do programa (sem o nome do executável)
+///
+/// As opções podem ser escritas como `--nome Simba` ou `--nome=Simba`,
+/// antes ou depois dos argumentos posicionais.
+pub fn interpretar<I>(args: I) -> Result<Invocacao, ErroUso>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut ajuda = false;
+    let mut posicionais = Vec::new();
+    let mut opcoes: BTreeMap<String, String> = BTreeMap::new();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            ajuda = true;
+            continue;
+        }
+        if !arg.starts_with('-') || arg == "-" {
+            posicionais.push(arg);
+            continue;
+        }
+
+        let (nome, valor) = match arg.split_once('=') {
+            Some((nome, valor)) => (nome.to_string(), Some(valor.to_string())),
+            None => (arg, None),
+        };
+        let nome = if nome == "-a" {
+            "--arquivo".to_string()
+        } else {
+            nome
+        };
+        let valor = if OPCOES_SEM_VALOR.contains(&nome.as_str()) {
+            if valor.is_some() {
+                return Err(ErroUso(format!("A opção {} não recebe valor", nome)));
+            }
+            String::new()
+        } else {
+            match valor.or_else(|| args.next()) {
+                Some(valor) => valor,
+                None => return Err(ErroUso(format!("Falta o valor da opção {}", nome))),
+            }
+        };
+        if opcoes.insert(nome.clone(), valor).is_some() {
+            return Err(ErroUso(format!("Opção repetida: {}", nome)));
+        }
+    }
+
+    let arquivo = PathBuf::from(
+        opcoes
+            .remove("--arquivo")
+            .unwrap_or_else(|| ARQUIVO_PADRAO.to_string()),
+    );
+    if ajuda || posicionais.first().is_some_and(|comando| comando == "help") {
+        return Ok(Invocacao {
+            arquivo,
+            comando: Comando::Ajuda,
+        });
+    }
+
+    let mut posicionais = posicionais.into_iter();
+    let nome_comando = posicionais.next();
+
+    let comando = match nome_comando.as_deref() {
+        None => {
+            aceitar_opcoes(&opcoes, &[])?;
+            Comando::Menu
+        }
+        Some("recuperar") => {
+            aceitar_opcoes(&opcoes, &[])?;
+            Comando::Recuperar
+        }
+        Some("list") => {
+            aceitar_opcoes(&opcoes, &[&OPCOES_FILTRO[..], &["--formato"]].concat())?;
+            let formato = match opcoes.get("--formato").map(String::as_str) {
+                None | Some("tabela") => FormatoSaida::Tabela,
+                Some("csv") => FormatoSaida::Csv,
+                Some("json") => FormatoSaida::Json,
+                Some(outro) => {
+                    return Err(ErroUso(format!(
+                        "Formato inválido: {} (use tabela, csv ou json)",
+                        outro
+                    )));
+                }
+            };
+            Comando::Listar {
+                consulta: ler_consulta(&opcoes)?,
+                formato,
+            }
+        }
+        Some("add") => {
+            aceitar_opcoes(&opcoes, &["--tipo", "--nome"])?;
+            Comando::Incluir {
+                tipo: obrigatoria(&opcoes, "--tipo")?,
+                nome: obrigatoria(&opcoes, "--nome")?,
+            }
+        }
+        Some("edit") => {
+            aceitar_opcoes(&opcoes, &["--tipo", "--nome"])?;
+            let id = ler_id(posicionais.next())?;
+            let tipo = opcoes.get("--tipo").cloned();
+            let nome = opcoes.get("--nome").cloned();
+            if tipo.is_none() && nome.is_none() {
+                return Err(ErroUso(
+                    "Informe --tipo e/ou --nome com os novos dados".to_string(),
+                ));
+            }
+            Comando::Editar { id, tipo, nome }
+        }
+        Some("remove") => {
+            aceitar_opcoes(&opcoes, &[])?;
+            Comando::Remover {
+                id: ler_id(posicionais.next())?,
+            }
+        }
+        Some("import") => {
+            aceitar_opcoes(&opcoes, &[])?;
+            Comando::Importar {
+                origem: ler_caminho(posicionais.next(), "de origem")?,
+            }
+        }
+        Some("export") => {
+            aceitar_opcoes(&opcoes, &OPCOES_FILTRO)?;
+            Comando::Exportar {
+                destino: ler_caminho(posicionais.next(), "de destino")?,
+                consulta: ler_consulta(&opcoes)?,
+            }
+        }
+        Some(outro) => return Err(ErroUso(format!("Comando desconhecido: {}", outro))),
+    };
+
+    if let Some(sobra) = posicionais.next() {
+        return Err(ErroUso(format!("Argumento inesperado: {}", sobra)));
+    }
+
+    Ok(Invocacao { arquivo, comando })
+}
+
+/// Rejeita opções que o comando não conhece
+fn aceitar_opcoes(opcoes: &BTreeMap<String, String>, aceitas: &[&str]) -> Result<(), ErroUso> {
+    match opcoes.keys().find(|nome| !aceitas.contains(&nome.as_str())) {
+        Some(nome) => Err(ErroUso(format!("Opção desconhecida: {}", nome))),
+        None => Ok(()),
+    }
+}
+
+fn obrigatoria(opcoes: &BTreeMap<String, String>, nome: &str) -> Result<String, ErroUso> {
+    opcoes
+        .get(nome)
+        .cloned()
+        .ok_or_else(|| ErroUso(format!("A opção {} é obrigatória", nome)))
+}
+
+fn ler_id(texto: Option<String>) -> Result<u32, ErroUso> {
+    let texto = texto.ok_or_else(|| ErroUso("Informe o id do animal".to_string()))?;
+    texto
+        .parse()
+        .map_err(|_| ErroUso(format!("Id inválido: {}", texto)))
+}
+
+fn ler_caminho(texto: Option<String>, descricao: &str) -> Result<PathBuf, ErroUso> {
+    texto
+        .map(PathBuf::from)
+        .ok_or_else(|| ErroUso(format!("Informe o arquivo {}", descricao)))
+}
+
+/// Monta a consulta a partir das opções de filtro e ordenação
+fn ler_consulta(opcoes: &BTreeMap<String, String>) -> Result<ConsultaAnimais, ErroUso> {
+    let recinto = match opcoes.get("--recinto") {
+        None => None,
+        Some(texto) => match texto.parse() {
+            Ok(0) => Some(FiltroRecinto::SemRecinto),
+            Ok(id) => Some(FiltroRecinto::Recinto(id)),
+            Err(_) => return Err(ErroUso(format!("Id de recinto inválido: {}", texto))),
+        },
+    };
+    let saude = match opcoes.get("--saude").map(String::as_str) {
+        None => None,
+        Some("saudavel") => Some(SituacaoSaude::Saudavel),
+        Some("em_tratamento") => Some(SituacaoSaude::EmTratamento),
+        Some("em_quarentena") => Some(SituacaoSaude::EmQuarentena),
+        Some(outro) => {
+            return Err(ErroUso(format!(
+                "Situação de saúde inválida: {} (use saudavel, em_tratamento ou em_quarentena)",
+                outro
+            )));
+        }
+    };
+    let ordenar_por = match opcoes.get("--ordenar").map(String::as_str) {
+        None | Some("id") => CampoOrdenacao::Id,
+        Some("especie") => CampoOrdenacao::Especie,
+        Some("nome") => CampoOrdenacao::Nome,
+        Some("recinto") => CampoOrdenacao::Recinto,
+        Some("saude") => CampoOrdenacao::Saude,
+        Some(outro) => return Err(ErroUso(format!("Campo de ordenação inválido: {}", outro))),
+    };
+
+    Ok(ConsultaAnimais {
+        especie: opcoes.get("--especie").cloned(),
+        nome: opcoes.get("--nome").cloned(),
+        recinto,
+        saude,
+        ordenar_por,
+        decrescente: opcoes.contains_key("--desc"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interpretar_texto(linha: &str) -> Result<Invocacao, ErroUso> {
+        interpretar(linha.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn test_sem_argumentos_abre_o_menu() {
+        let invocacao = interpretar_texto("").unwrap();
+        assert_eq!(invocacao.comando, Comando::Menu);
+        assert_eq!(invocacao.arquivo, PathBuf::from(ARQUIVO_PADRAO));
+    }
+
+    #[test]
+    fn test_add_com_arquivo() {
+        let invocacao = interpretar_texto("add --tipo Leão --nome=Simba -a /tmp/zoo.json").unwrap();
+        assert_eq!(invocacao.arquivo, PathBuf::from("/tmp/zoo.json"));
+        assert_eq!(
+            invocacao.comando,
+            Comando::Incluir {
+                tipo: "Leão".to_string(),
+                nome: "Simba".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_list_com_filtros() {
+        let invocacao =
+            interpretar_texto("list --recinto 0 --ordenar nome --desc --formato csv").unwrap();
+        let Comando::Listar { consulta, formato } = invocacao.comando else {
+            panic!("comando inesperado");
+        };
+        assert_eq!(formato, FormatoSaida::Csv);
+        assert_eq!(consulta.recinto, Some(FiltroRecinto::SemRecinto));
+        assert_eq!(consulta.ordenar_por, CampoOrdenacao::Nome);
+        assert!(consulta.decrescente);
+    }
+
+    #[test]
+    fn test_erros_de_uso() {
+        assert!(interpretar_texto("add --tipo Leão").is_err());
+        assert!(interpretar_texto("edit 3").is_err());
+        assert!(interpretar_texto("remove abc").is_err());
+        assert!(interpretar_texto("remove 1 2").is_err());
+        assert!(interpretar_texto("list --cor azul").is_err());
+        assert!(interpretar_texto("list --desc=sim").is_err());
+        assert!(interpretar_texto("voar").is_err());
+        assert!(interpretar_texto("add --tipo").is_err());
+        assert_eq!(
+            interpretar_texto("add --help").unwrap().comando,
+            Comando::Ajuda
+        );
+        assert_eq!(
+            interpretar_texto("remove 7").unwrap().comando,
+            Comando::Remover { id: 7 }
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use zoologico::cli::{Comando, FormatoSaida};
+use zoologico::consulta;
+use zoologico::errors::ErroZoologico;
+use zoologico::tabela::Tabela;
+use zoologico::zoologico::Zoologico;
+
+use crate::menus::animais::{CABECALHO_ANIMAIS, celulas_animal};
+
+/// Recupera os registros válidos de um arquivo de dados corrompido
+///
+/// Executado com `cargo run -- recuperar`
+pub fn recuperar(caminho: &Path) -> Result<(), ErroZoologico> {
+    let (zoologico, relatorio) = Zoologico::recuperar(caminho)?;
+    println!("Arquivo recuperado: {}", caminho.display());
+    for (lista, quantidade) in &relatorio.recuperados {
+        println!("  {}: {} registro(s) recuperado(s)", lista, quantidade);
+    }
+    println!("  Registros descartados: {}", relatorio.descartados);
+    println!(
+        "  Referências desfeitas: {}",
+        relatorio.referencias_removidas
+    );
+    println!("  Animais no arquivo: {}", zoologico.listar_animais().len());
+    if let Some(copia) = relatorio.copia_original {
+        println!("  Cópia do arquivo original: {}", copia.display());
+    }
+    Ok(())
+}
+
+/// Executa um comando não interativo
+///
+/// Comandos que alteram os dados gravam o arquivo ao terminar.
+pub fn executar(zoologico: &mut Zoologico, comando: Comando) -> Result<(), ErroZoologico> {
+    match comando {
+        Comando::Listar { consulta, formato } => {
+            let linhas = zoologico.consultar_animais(&consulta);
+            match formato {
+                FormatoSaida::Tabela => {
+                    let mut tabela = Tabela::new(&CABECALHO_ANIMAIS);
+                    for linha in &linhas {
+                        tabela.adicionar_linha(celulas_animal(linha));
+                    }
+                    print!("{}", tabela.renderizar(0));
+                }
+                FormatoSaida::Csv => print!("{}", consulta::para_csv(&linhas)?),
+                FormatoSaida::Json => println!("{}", consulta::para_json(&linhas)?),
+            }
+        }
+        Comando::Incluir { tipo, nome } => {
+            let id = zoologico.incluir_animal(&tipo, &nome)?;
+            zoologico.salvar()?;
+            // Só o id, para facilitar o uso em scripts
+            println!("{}", id);
+        }
+        Comando::Editar { id, tipo, nome } => {
+            let atual = zoologico
+                .buscar_animal(id)
+                .ok_or(ErroZoologico::AnimalNaoEncontrado(id))?
+                .clone();
+            zoologico.editar_animal(
+                id,
+                tipo.as_deref().unwrap_or(&atual.tipo),
+                nome.as_deref().unwrap_or(&atual.nome),
+            )?;
+            zoologico.salvar()?;
+            let animal = zoologico.buscar_animal(id).unwrap_or(&atual);
+            println!(
+                "Animal {} atualizado: {} ({})",
+                id, animal.nome, animal.tipo
+            );
+        }
+        Comando::Remover { id } => {
+            let animal = zoologico.excluir_animal(id)?;
+            zoologico.salvar()?;
+            println!("Animal {} removido: {} ({})", id, animal.nome, animal.tipo);
+        }
+        Comando::Importar { origem } => {
+            let animais = consulta::ler_importacao(&origem)?;
+            let ids = zoologico.importar_animais(&animais)?;
+            zoologico.salvar()?;
+            println!("{} animais importados de {}", ids.len(), origem.display());
+        }
+        Comando::Exportar { destino, consulta } => {
+            let linhas = zoologico.consultar_animais(&consulta);
+            consulta::exportar(&linhas, &destino)?;
+            println!(
+                "{} animais exportados para {}",
+                linhas.len(),
+                destino.display()
+            );
+        }
+        Comando::Menu | Comando::Ajuda | Comando::Recuperar => {}
+    }
+    Ok(())
+}
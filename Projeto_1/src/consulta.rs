@@ -0,0 +1,242 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use crate::errors::ErroZoologico;
+use crate::persistencia;
+use crate::saude::SituacaoSaude;
+
+/// Filtro de recinto de uma consulta
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiltroRecinto {
+    /// Animais do recinto com este id
+    Recinto(u32),
+    /// Animais que ainda não estão em nenhum recinto
+    SemRecinto,
+}
+
+/// Campo usado para ordenar o resultado de uma consulta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CampoOrdenacao {
+    #[default]
+    Id,
+    Especie,
+    Nome,
+    Recinto,
+    Saude,
+}
+
+/// Critérios de pesquisa de animais
+///
+/// Filtros em `None` não restringem o resultado.
+///
+/// # Campos
+/// - `especie`: Espécie pelo nome oficial ou sinônimo (ex: "Onça"); nomes fora
+///   do catálogo são procurados como parte do tipo
+/// - `nome`: Trecho do nome do animal, sem diferenciar maiúsculas e acentos
+/// - `recinto`: Recinto em que o animal está
+/// - `saude`: Situação de saúde atual
+/// - `ordenar_por`, `decrescente`: Ordem do resultado; empates ficam na ordem do id
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConsultaAnimais {
+    pub especie: Option<String>,
+    pub nome: Option<String>,
+    pub recinto: Option<FiltroRecinto>,
+    pub saude: Option<SituacaoSaude>,
+    pub ordenar_por: CampoOrdenacao,
+    pub decrescente: bool,
+}
+
+/// Animal no resultado de uma consulta, com os dados já resolvidos para exibição
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct LinhaAnimal {
+    pub id: u32,
+    pub especie: String,
+    pub nome: String,
+    pub recinto: Option<String>,
+    pub saude: SituacaoSaude,
+}
+
+/// Trecho de uma lista exibido de cada vez
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pagina<'a, T> {
+    pub itens: &'a [T],
+    /// Número da página, começando em 1
+    pub numero: usize,
+    pub total_paginas: usize,
+    pub total_itens: usize,
+}
+
+/// Separa a página `numero` (começando em 1) de uma lista
+///
+/// Números fora do intervalo são ajustados para a primeira ou a última página.
+/// Uma lista vazia tem uma única página, vazia.
+pub fn paginar<T>(itens: &[T], numero: usize, tamanho: usize) -> Pagina<'_, T> {
+    let tamanho = tamanho.max(1);
+    let total_paginas = itens.len().div_ceil(tamanho).max(1);
+    let numero = numero.clamp(1, total_paginas);
+    let inicio = (numero - 1) * tamanho;
+    let fim = (inicio + tamanho).min(itens.len());
+
+    Pagina {
+        itens: &itens[inicio..fim],
+        numero,
+        total_paginas,
+        total_itens: itens.len(),
+    }
+}
+
+/// Formato de arquivo usado na exportação
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatoExportacao {
+    Csv,
+    Json,
+}
+
+impl FormatoExportacao {
+    /// Formato correspondente à extensão do arquivo (.csv ou .json)
+    pub fn do_caminho(caminho: &Path) -> Result<FormatoExportacao, ErroZoologico> {
+        let extensao = caminho
+            .extension()
+            .and_then(|extensao| extensao.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match extensao.as_str() {
+            "csv" => Ok(FormatoExportacao::Csv),
+            "json" => Ok(FormatoExportacao::Json),
+            _ => Err(ErroZoologico::FormatoExportacaoInvalido(
+                caminho.display().to_string(),
+            )),
+        }
+    }
+}
+
+/// Converte as linhas para CSV, com cabeçalho
+pub fn para_csv(linhas: &[LinhaAnimal]) -> Result<String, ErroZoologico> {
+    let mut escritor = csv::Writer::from_writer(Vec::new());
+    for linha in linhas {
+        escritor.serialize(linha).map_err(erro_csv)?;
+    }
+    if linhas.is_empty() {
+        escritor
+            .write_record(["id", "especie", "nome", "recinto", "saude"])
+            .map_err(erro_csv)?;
+    }
+    let bytes = escritor.into_inner().map_err(erro_csv)?;
+    String::from_utf8(bytes).map_err(erro_csv)
+}
+
+fn erro_csv(e: impl fmt::Display) -> ErroZoologico {
+    ErroZoologico::ErroPersistencia(format!("Erro ao gerar CSV: {}", e))
+}
+
+/// Converte as linhas para uma lista JSON
+pub fn para_json(linhas: &[LinhaAnimal]) -> Result<String, ErroZoologico> {
+    serde_json::to_string_pretty(linhas)
+        .map_err(|e| ErroZoologico::ErroPersistencia(format!("Erro ao gerar JSON: {}", e)))
+}
+
+/// Grava as linhas no arquivo, em CSV ou JSON conforme a extensão
+pub fn exportar(linhas: &[LinhaAnimal], caminho: &Path) -> Result<(), ErroZoologico> {
+    let conteudo = match FormatoExportacao::do_caminho(caminho)? {
+        FormatoExportacao::Csv => para_csv(linhas)?,
+        FormatoExportacao::Json => para_json(linhas)?,
+    };
+    persistencia::gravar_atomico(caminho, conteudo.as_bytes())
+}
+
+/// Animal lido de um arquivo de importação
+///
+/// Aceita os arquivos gerados pela exportação: a coluna `especie` vale como
+/// `tipo` e as demais colunas (id, recinto, saúde) são ignoradas.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct AnimalImportado {
+    #[serde(alias = "especie")]
+    pub tipo: String,
+    pub nome: String,
+}
+
+/// Lê os animais de um arquivo CSV (com cabeçalho) ou JSON (lista de objetos)
+pub fn ler_importacao(caminho: &Path) -> Result<Vec<AnimalImportado>, ErroZoologico> {
+    let erro = |e: &dyn fmt::Display| {
+        ErroZoologico::ErroPersistencia(format!("Erro ao ler {}: {}", caminho.display(), e))
+    };
+    let conteudo = std::fs::read(caminho).map_err(|e| erro(&e))?;
+
+    match FormatoExportacao::do_caminho(caminho)? {
+        FormatoExportacao::Csv => csv::Reader::from_reader(conteudo.as_slice())
+            .deserialize()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| erro(&e)),
+        FormatoExportacao::Json => serde_json::from_slice(&conteudo).map_err(|e| erro(&e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linha(id: u32, nome: &str, recinto: Option<&str>) -> LinhaAnimal {
+        LinhaAnimal {
+            id,
+            especie: "Leão".to_string(),
+            nome: nome.to_string(),
+            recinto: recinto.map(str::to_string),
+            saude: SituacaoSaude::Saudavel,
+        }
+    }
+
+    #[test]
+    fn test_paginar() {
+        let itens: Vec<u32> = (1..=25).collect();
+
+        let pagina = paginar(&itens, 3, 10);
+        assert_eq!(pagina.itens, &[21, 22, 23, 24, 25]);
+        assert_eq!(pagina.total_paginas, 3);
+
+        assert_eq!(paginar(&itens, 9, 10).numero, 3);
+        assert_eq!(paginar(&itens, 0, 10).itens[0], 1);
+        assert_eq!(paginar::<u32>(&[], 1, 10).total_paginas, 1);
+    }
+
+    #[test]
+    fn test_csv_com_aspas_e_virgulas() {
+        let csv = para_csv(&[
+            linha(1, "Simba", Some("Savana, Leste")),
+            linha(2, "\"Rei\"", None),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            csv,
+            "id,especie,nome,recinto,saude\n\
+             1,Leão,Simba,\"Savana, Leste\",saudavel\n\
+             2,Leão,\"\"\"Rei\"\"\",,saudavel\n"
+        );
+        assert_eq!(para_csv(&[]).unwrap(), "id,especie,nome,recinto,saude\n");
+    }
+
+    #[test]
+    fn test_importar_arquivo_exportado() {
+        let dir = tempfile::tempdir().unwrap();
+        let linhas = [linha(1, "Simba", Some("Savana")), linha(2, "Nala", None)];
+
+        for nome in ["animais.csv", "animais.json"] {
+            let caminho = dir.path().join(nome);
+            exportar(&linhas, &caminho).unwrap();
+            let importados = ler_importacao(&caminho).unwrap();
+            assert_eq!(importados.len(), 2);
+            assert_eq!(importados[1].tipo, "Leão");
+            assert_eq!(importados[1].nome, "Nala");
+        }
+    }
+
+    #[test]
+    fn test_formato_pela_extensao() {
+        assert_eq!(
+            FormatoExportacao::do_caminho(Path::new("animais.CSV")),
+            Ok(FormatoExportacao::Csv)
+        );
+        assert!(FormatoExportacao::do_caminho(Path::new("animais.txt")).is_err());
+    }
+}
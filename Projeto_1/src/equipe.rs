@@ -0,0 +1,576 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::alimentacao::Horario;
+use crate::errors::ErroZoologico;
+use crate::especies;
+use crate::persistencia::{self, Descartes};
+use crate::recintos::Recinto;
+
+/// Prazo das tarefas de limpeza de recinto
+pub const PRAZO_LIMPEZA: Horario = Horario {
+    hora: 12,
+    minuto: 0,
+};
+
+/// Prazo dos exames de acompanhamento e das consultas agendadas
+pub const PRAZO_EXAME: Horario = Horario {
+    hora: 17,
+    minuto: 0,
+};
+
+/// Funcionário responsável pelo cuidado diário dos animais
+///
+/// # Campos
+/// - `id`: Identificador estável do tratador, nunca reutilizado
+/// - `nome`: Nome do tratador, único sem diferenciar maiúsculas
+///
+/// As atribuições ficam nos próprios recintos e animais (`id_tratador`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Tratador {
+    pub id: u32,
+    pub nome: String,
+}
+
+/// Tipo de tarefa diária
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TipoTarefa {
+    Alimentacao,
+    Limpeza,
+    ExameMedico,
+}
+
+impl fmt::Display for TipoTarefa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            TipoTarefa::Alimentacao => "Alimentação",
+            TipoTarefa::Limpeza => "Limpeza",
+            TipoTarefa::ExameMedico => "Exame médico",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Registro que deu origem à tarefa
+///
+/// Também identifica a tarefa: gerar as tarefas de um dia de novo não
+/// duplica as que já existem com a mesma origem.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(tag = "origem", rename_all = "snake_case")]
+pub enum OrigemTarefa {
+    /// Refeição de um plano alimentar
+    Alimentacao {
+        id_plano: u32,
+        id_animal: u32,
+        horario: Horario,
+    },
+    /// Limpeza de um recinto ocupado
+    Limpeza { id_recinto: u32 },
+    /// Acompanhamento diário de animal em quarentena ou em tratamento
+    Acompanhamento { id_animal: u32 },
+    /// Consulta agendada na ficha médica (`numero` começa em 1)
+    Consulta { id_animal: u32, numero: usize },
+}
+
+impl OrigemTarefa {
+    pub fn tipo(&self) -> TipoTarefa {
+        match self {
+            OrigemTarefa::Alimentacao { .. } => TipoTarefa::Alimentacao,
+            OrigemTarefa::Limpeza { .. } => TipoTarefa::Limpeza,
+            OrigemTarefa::Acompanhamento { .. } | OrigemTarefa::Consulta { .. } => {
+                TipoTarefa::ExameMedico
+            }
+        }
+    }
+
+    /// Animal a que a tarefa se refere, se houver
+    pub fn id_animal(&self) -> Option<u32> {
+        match *self {
+            OrigemTarefa::Alimentacao { id_animal, .. }
+            | OrigemTarefa::Acompanhamento { id_animal }
+            | OrigemTarefa::Consulta { id_animal, .. } => Some(id_animal),
+            OrigemTarefa::Limpeza { .. } => None,
+        }
+    }
+}
+
+/// Tarefa do dia atribuída a um tratador
+///
+/// # Campos
+/// - `id`: Identificador estável da tarefa
+/// - `data`: Dia a que a tarefa pertence
+/// - `origem`: Registro que gerou a tarefa
+/// - `descricao`: Texto exibido ao tratador (ex: "Servir 5 kg de Carne a Simba")
+/// - `id_tratador`: Responsável no momento em que a tarefa foi gerada
+/// - `prazo`: Data e hora limite
+/// - `concluida_em`: Quando a tarefa foi concluída
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Tarefa {
+    pub id: u32,
+    pub data: NaiveDate,
+    #[serde(flatten)]
+    pub origem: OrigemTarefa,
+    pub descricao: String,
+    pub id_tratador: Option<u32>,
+    pub prazo: NaiveDateTime,
+    pub concluida_em: Option<NaiveDateTime>,
+}
+
+impl Tarefa {
+    pub fn tipo(&self) -> TipoTarefa {
+        self.origem.tipo()
+    }
+
+    pub fn concluida(&self) -> bool {
+        self.concluida_em.is_some()
+    }
+
+    /// A tarefa passou do prazo sem ser concluída
+    pub fn atrasada(&self, agora: NaiveDateTime) -> bool {
+        !self.concluida() && self.prazo < agora
+    }
+}
+
+/// Tarefas de um tratador em um relatório
+#[derive(Debug, Clone, PartialEq)]
+pub struct TarefasTratador {
+    /// `None` para tarefas sem tratador responsável
+    pub tratador: Option<Tratador>,
+    pub tarefas: Vec<Tarefa>,
+}
+
+/// Data e hora do prazo de uma tarefa
+pub fn prazo(data: NaiveDate, horario: Horario) -> NaiveDateTime {
+    data.and_hms_opt(horario.hora as u32, horario.minuto as u32, 0)
+        .unwrap_or_else(|| data.and_time(chrono::NaiveTime::MIN))
+}
+
+/// Tarefa calculada a partir dos dados do zoológico, antes de ser criada
+///
+/// # Campos
+/// - `origem`: Registro que gera a tarefa; identifica a tarefa no dia
+/// - `descricao`: Texto exibido ao tratador
+/// - `horario`: Prazo no dia da tarefa
+/// - `id_tratador`: Responsável atual pelo animal ou recinto
+/// - `concluida_em`: Quando o trabalho já foi registrado por outro caminho
+///   (ex: refeição registrada antes de a tarefa existir)
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TarefaPrevista {
+    pub origem: OrigemTarefa,
+    pub descricao: String,
+    pub horario: Horario,
+    pub id_tratador: Option<u32>,
+    pub concluida_em: Option<NaiveDateTime>,
+}
+
+/// Tratadores e tarefas do zoológico
+///
+/// Tratadores e tarefas têm ids estáveis, nunca reutilizados. As atribuições
+/// ficam nos recintos e animais; por isso excluir um tratador e gerar as
+/// tarefas do dia passam pelo `Zoologico`, que conhece esses registros.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equipe {
+    tratadores: BTreeMap<u32, Tratador>,
+    proximo_id_tratador: u32,
+    tarefas: BTreeMap<u32, Tarefa>,
+    proximo_id_tarefa: u32,
+}
+
+impl Default for Equipe {
+    fn default() -> Self {
+        Equipe {
+            tratadores: BTreeMap::new(),
+            proximo_id_tratador: 1,
+            tarefas: BTreeMap::new(),
+            proximo_id_tarefa: 1,
+        }
+    }
+}
+
+impl Equipe {
+    /// Monta a equipe com os registros lidos do arquivo de dados
+    ///
+    /// Registros com id repetido ou fora da faixa são contados em `descartes`.
+    pub(crate) fn carregar(
+        proximo_id_tratador: u32,
+        tratadores: Vec<Tratador>,
+        proximo_id_tarefa: u32,
+        tarefas: Vec<Tarefa>,
+        descartes: &mut Descartes,
+    ) -> Self {
+        let mut equipe = Equipe {
+            proximo_id_tratador,
+            proximo_id_tarefa,
+            ..Equipe::default()
+        };
+        for tratador in tratadores {
+            persistencia::inserir_com_id(
+                &mut equipe.tratadores,
+                tratador.id,
+                tratador,
+                &mut equipe.proximo_id_tratador,
+                descartes,
+            );
+        }
+        for tarefa in tarefas {
+            persistencia::inserir_com_id(
+                &mut equipe.tarefas,
+                tarefa.id,
+                tarefa,
+                &mut equipe.proximo_id_tarefa,
+                descartes,
+            );
+        }
+        equipe
+    }
+
+    pub(crate) fn proximo_id_tratador(&self) -> u32 {
+        self.proximo_id_tratador
+    }
+
+    pub(crate) fn proximo_id_tarefa(&self) -> u32 {
+        self.proximo_id_tarefa
+    }
+
+    /// Cadastra um tratador
+    ///
+    /// # Retorno
+    /// Id atribuído ao tratador, ou erro se o nome estiver vazio ou já existir
+    pub fn incluir_tratador(&mut self, nome: &str) -> Result<u32, ErroZoologico> {
+        let nome = nome.trim();
+        if nome.is_empty() {
+            return Err(ErroZoologico::CampoVazio("nome".to_string()));
+        }
+        if self.buscar_tratador_por_nome(nome).is_some() {
+            return Err(ErroZoologico::TratadorJaCadastrado(nome.to_string()));
+        }
+
+        let id = persistencia::reservar_id(&mut self.proximo_id_tratador, "tratadores")?;
+        self.tratadores.insert(
+            id,
+            Tratador {
+                id,
+                nome: nome.to_string(),
+            },
+        );
+        Ok(id)
+    }
+
+    /// Remove o tratador; suas tarefas pendentes ficam sem responsável
+    pub(crate) fn remover_tratador(&mut self, id: u32) -> Result<Tratador, ErroZoologico> {
+        let tratador = self
+            .tratadores
+            .remove(&id)
+            .ok_or(ErroZoologico::TratadorNaoEncontrado(id))?;
+        for tarefa in self.tarefas.values_mut() {
+            if !tarefa.concluida() && tarefa.id_tratador == Some(id) {
+                tarefa.id_tratador = None;
+            }
+        }
+        Ok(tratador)
+    }
+
+    pub fn buscar_tratador(&self, id: u32) -> Option<&Tratador> {
+        self.tratadores.get(&id)
+    }
+
+    pub fn listar_tratadores(&self) -> Vec<&Tratador> {
+        self.tratadores.values().collect()
+    }
+
+    /// Confere se o tratador informado existe (`None` é sempre aceito)
+    pub(crate) fn verificar_tratador(&self, id_tratador: Option<u32>) -> Result<(), ErroZoologico> {
+        match id_tratador {
+            Some(id) if !self.tratadores.contains_key(&id) => {
+                Err(ErroZoologico::TratadorNaoEncontrado(id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Converte os nomes de tratador gravados nos recintos pelas versões
+    /// antigas em cadastros de tratador
+    pub(crate) fn converter_tratadores_legados<'a>(
+        &mut self,
+        recintos: impl Iterator<Item = &'a mut Recinto>,
+    ) {
+        for recinto in recintos {
+            let Some(nome) = recinto.tratador_legado.take() else {
+                continue;
+            };
+            let id_tratador = match self.buscar_tratador_por_nome(&nome) {
+                Some(tratador) => Some(tratador.id),
+                None => self.incluir_tratador(&nome).ok(),
+            };
+            recinto.id_tratador = recinto.id_tratador.or(id_tratador);
+        }
+    }
+
+    /// Tira das tarefas os responsáveis que não estão mais cadastrados
+    ///
+    /// # Retorno
+    /// Quantidade de tarefas alteradas
+    pub(crate) fn desfazer_tratadores_perdidos(&mut self) -> usize {
+        let mut removidos = 0;
+        for tarefa in self.tarefas.values_mut() {
+            if tarefa
+                .id_tratador
+                .is_some_and(|id| !self.tratadores.contains_key(&id))
+            {
+                tarefa.id_tratador = None;
+                removidos += 1;
+            }
+        }
+        removidos
+    }
+
+    /// Cria as tarefas previstas para o dia que ainda não existem
+    ///
+    /// Tarefas já existentes, pendentes e sem responsável recebem o
+    /// responsável previsto.
+    ///
+    /// # Retorno
+    /// Quantidade de tarefas criadas
+    pub(crate) fn gerar_tarefas(
+        &mut self,
+        data: NaiveDate,
+        previstas: Vec<TarefaPrevista>,
+    ) -> Result<usize, ErroZoologico> {
+        let mut criadas = 0;
+        for prevista in previstas {
+            if let Some(tarefa) = self
+                .tarefas
+                .values_mut()
+                .find(|tarefa| tarefa.data == data && tarefa.origem == prevista.origem)
+            {
+                if !tarefa.concluida() && tarefa.id_tratador.is_none() {
+                    tarefa.id_tratador = prevista.id_tratador;
+                }
+                continue;
+            }
+
+            let id = persistencia::reservar_id(&mut self.proximo_id_tarefa, "tarefas")?;
+            self.tarefas.insert(
+                id,
+                Tarefa {
+                    id,
+                    data,
+                    origem: prevista.origem,
+                    descricao: prevista.descricao,
+                    id_tratador: prevista.id_tratador,
+                    prazo: prazo(data, prevista.horario),
+                    concluida_em: prevista.concluida_em,
+                },
+            );
+            criadas += 1;
+        }
+        Ok(criadas)
+    }
+
+    pub fn buscar_tarefa(&self, id: u32) -> Option<&Tarefa> {
+        self.tarefas.get(&id)
+    }
+
+    /// Todas as tarefas, em ordem de id
+    pub(crate) fn tarefas(&self) -> impl Iterator<Item = &Tarefa> {
+        self.tarefas.values()
+    }
+
+    /// Tarefa que ainda pode ser concluída
+    ///
+    /// # Retorno
+    /// A tarefa, ou erro se ela não existir ou já estiver concluída
+    pub(crate) fn tarefa_pendente(&self, id: u32) -> Result<&Tarefa, ErroZoologico> {
+        let tarefa = self
+            .tarefas
+            .get(&id)
+            .ok_or(ErroZoologico::TarefaNaoEncontrada(id))?;
+        if tarefa.concluida() {
+            return Err(ErroZoologico::TarefaJaConcluida(id));
+        }
+        Ok(tarefa)
+    }
+
+    /// Marca a tarefa como concluída, mantendo o primeiro momento registrado
+    pub(crate) fn concluir_tarefa(&mut self, id: u32, quando: NaiveDateTime) {
+        if let Some(tarefa) = self.tarefas.get_mut(&id) {
+            tarefa.concluida_em.get_or_insert(quando);
+        }
+    }
+
+    /// Conclui as tarefas pendentes do dia cuja origem passa no filtro
+    pub(crate) fn concluir_pela_origem(
+        &mut self,
+        data: NaiveDate,
+        quando: NaiveDateTime,
+        origem: impl Fn(&OrigemTarefa) -> bool,
+    ) {
+        for tarefa in self.tarefas.values_mut() {
+            if tarefa.data == data && !tarefa.concluida() && origem(&tarefa.origem) {
+                tarefa.concluida_em = Some(quando);
+            }
+        }
+    }
+
+    /// Remove as tarefas pendentes cuja origem passa no filtro; as concluídas
+    /// ficam no histórico
+    pub(crate) fn descartar_pendentes(&mut self, origem: impl Fn(&OrigemTarefa) -> bool) {
+        self.tarefas
+            .retain(|_, tarefa| tarefa.concluida() || !origem(&tarefa.origem));
+    }
+
+    /// Tarefas do dia agrupadas por tratador
+    pub fn tarefas_do_dia(&self, data: NaiveDate) -> Vec<TarefasTratador> {
+        self.agrupar_por_tratador(self.tarefas.values().filter(|tarefa| tarefa.data == data))
+    }
+
+    /// Tarefas pendentes com o prazo vencido, agrupadas por tratador
+    pub fn tarefas_atrasadas(&self, agora: NaiveDateTime) -> Vec<TarefasTratador> {
+        self.agrupar_por_tratador(
+            self.tarefas
+                .values()
+                .filter(|tarefa| tarefa.atrasada(agora)),
+        )
+    }
+
+    fn buscar_tratador_por_nome(&self, nome: &str) -> Option<&Tratador> {
+        let nome = especies::normalizar(nome);
+        self.tratadores
+            .values()
+            .find(|tratador| especies::normalizar(&tratador.nome) == nome)
+    }
+
+    /// Agrupa as tarefas por tratador, em ordem de nome; tarefas sem
+    /// responsável ficam no fim. Cada grupo fica em ordem de prazo.
+    fn agrupar_por_tratador<'a>(
+        &self,
+        tarefas: impl Iterator<Item = &'a Tarefa>,
+    ) -> Vec<TarefasTratador> {
+        let mut grupos: BTreeMap<Option<u32>, Vec<Tarefa>> = BTreeMap::new();
+        for tarefa in tarefas {
+            let id_tratador = tarefa
+                .id_tratador
+                .filter(|id| self.tratadores.contains_key(id));
+            grupos.entry(id_tratador).or_default().push(tarefa.clone());
+        }
+
+        let mut grupos: Vec<TarefasTratador> = grupos
+            .into_iter()
+            .map(|(id_tratador, mut tarefas)| {
+                tarefas.sort_by_key(|tarefa| (tarefa.prazo, tarefa.id));
+                TarefasTratador {
+                    tratador: id_tratador.and_then(|id| self.buscar_tratador(id)).cloned(),
+                    tarefas,
+                }
+            })
+            .collect();
+        grupos.sort_by_cached_key(|grupo| {
+            (
+                grupo.tratador.is_none(),
+                grupo
+                    .tratador
+                    .as_ref()
+                    .map(|t| especies::normalizar(&t.nome)),
+            )
+        });
+        grupos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tarefa_atrasada() {
+        let data = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let mut tarefa = Tarefa {
+            id: 1,
+            data,
+            origem: OrigemTarefa::Limpeza { id_recinto: 1 },
+            descricao: "Limpar recinto Savana".to_string(),
+            id_tratador: None,
+            prazo: prazo(data, PRAZO_LIMPEZA),
+            concluida_em: None,
+        };
+
+        assert!(!tarefa.atrasada(prazo(data, Horario::new(11, 59).unwrap())));
+        assert!(tarefa.atrasada(prazo(data, Horario::new(12, 1).unwrap())));
+        tarefa.concluida_em = Some(prazo(data, Horario::new(13, 0).unwrap()));
+        assert!(!tarefa.atrasada(prazo(data, Horario::new(13, 1).unwrap())));
+    }
+
+    #[test]
+    fn test_equipe_agrupa_tarefas_por_tratador() {
+        let data = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let mut equipe = Equipe::default();
+        let bruno = equipe.incluir_tratador("Bruno").unwrap();
+        let ana = equipe.incluir_tratador(" Ana ").unwrap();
+        assert_eq!(
+            equipe.incluir_tratador("ANA"),
+            Err(ErroZoologico::TratadorJaCadastrado("ANA".to_string()))
+        );
+
+        let limpeza = |id_recinto, id_tratador| TarefaPrevista {
+            origem: OrigemTarefa::Limpeza { id_recinto },
+            descricao: format!("Limpar o recinto {}", id_recinto),
+            horario: PRAZO_LIMPEZA,
+            id_tratador,
+            concluida_em: None,
+        };
+        let previstas = vec![
+            limpeza(1, Some(bruno)),
+            limpeza(2, Some(ana)),
+            limpeza(3, None),
+        ];
+        assert_eq!(equipe.gerar_tarefas(data, previstas.clone()), Ok(3));
+        assert_eq!(equipe.gerar_tarefas(data, previstas), Ok(0));
+
+        let nomes = |equipe: &Equipe| {
+            equipe
+                .tarefas_do_dia(data)
+                .into_iter()
+                .map(|grupo| (grupo.tratador.map(|t| t.nome), grupo.tarefas.len()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            nomes(&equipe),
+            vec![
+                (Some("Ana".to_string()), 1),
+                (Some("Bruno".to_string()), 1),
+                (None, 1)
+            ]
+        );
+
+        // As tarefas pendentes do tratador removido ficam sem responsável
+        equipe.remover_tratador(bruno).unwrap();
+        assert_eq!(
+            nomes(&equipe),
+            vec![(Some("Ana".to_string()), 1), (None, 2)]
+        );
+    }
+
+    #[test]
+    fn test_tarefa_gravada_com_origem() {
+        let data = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let tarefa = Tarefa {
+            id: 1,
+            data,
+            origem: OrigemTarefa::Alimentacao {
+                id_plano: 2,
+                id_animal: 3,
+                horario: Horario::new(8, 0).unwrap(),
+            },
+            descricao: "Servir".to_string(),
+            id_tratador: Some(1),
+            prazo: prazo(data, Horario::new(8, 0).unwrap()),
+            concluida_em: None,
+        };
+
+        let json = serde_json::to_string(&tarefa).unwrap();
+        assert!(json.contains(r#""origem":"alimentacao""#));
+        assert_eq!(serde_json::from_str::<Tarefa>(&json).unwrap(), tarefa);
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+
+use crate::especies::Habitat;
+
+/// Erros retornados pelas operações do zoológico
+#[derive(Debug, PartialEq)]
+pub enum ErroZoologico {
+    /// Nenhum animal possui o id informado
+    AnimalNaoEncontrado(u32),
+    /// Um campo obrigatório foi informado vazio (ex: "tipo", "nome")
+    CampoVazio(String),
+    /// O tipo informado não está no catálogo de espécies
+    EspecieDesconhecida {
+        tipo: String,
+        sugestoes: Vec<String>,
+    },
+    /// Nenhum recinto possui o id informado
+    RecintoNaoEncontrado(u32),
+    /// O recinto já está com a capacidade máxima de animais
+    RecintoLotado { recinto: String, capacidade: u32 },
+    /// O recinto não tem área livre suficiente para a espécie
+    AreaInsuficiente {
+        recinto: String,
+        area_livre_m2: u32,
+        area_necessaria_m2: u32,
+    },
+    /// O habitat do recinto não atende a espécie
+    HabitatIncompativel {
+        especie: String,
+        habitat_especie: Habitat,
+        habitat_recinto: Habitat,
+    },
+    /// A alocação colocaria um predador junto de uma presa
+    PredadorEPresa { predador: String, presa: String },
+    /// O recinto ainda tem animais e não pode ser excluído
+    RecintoOcupado(u32),
+    /// O alimento não está cadastrado no estoque
+    AlimentoNaoEncontrado(String),
+    /// Nenhum plano alimentar possui o id informado
+    PlanoNaoEncontrado(u32),
+    /// Horário fora do formato HH:MM ou que não faz parte do plano
+    HorarioInvalido(String),
+    /// Não há alimento suficiente no estoque para a refeição
+    EstoqueInsuficiente {
+        alimento: String,
+        disponivel_g: u64,
+        necessario_g: u64,
+    },
+    /// A refeição desse plano, data e horário já foi registrada
+    AlimentacaoJaRegistrada { id_plano: u32, horario: String },
+    /// O animal está em quarentena e não pode mudar de recinto
+    AnimalEmQuarentena(u32),
+    /// O animal não está em quarentena
+    AnimalSemQuarentena(u32),
+    /// Não existe tratamento ou consulta com esse número na ficha do animal
+    RegistroMedicoNaoEncontrado { id_animal: u32, numero: usize },
+    /// Um registro de um arquivo de importação foi recusado; nada foi importado
+    RegistroImportacaoInvalido {
+        numero: usize,
+        erro: Box<ErroZoologico>,
+    },
+    /// Nenhum tratador possui o id informado
+    TratadorNaoEncontrado(u32),
+    /// Já existe um tratador com o mesmo nome
+    TratadorJaCadastrado(String),
+    /// Nenhuma tarefa possui o id informado
+    TarefaNaoEncontrada(u32),
+    /// A tarefa já foi concluída
+    TarefaJaConcluida(u32),
+    /// Os pais informados num nascimento não podem ser pais do filhote
+    ParentescoInvalido(String),
+    /// Os dois animais não podem formar um casal (ex: espécies diferentes)
+    ReproducaoIncompativel(String),
+    /// Nenhuma faixa de visitação começa no horário informado
+    FaixaNaoEncontrada(String),
+    /// A faixa de horário não tem vagas para todos os ingressos pedidos
+    LotacaoEsgotada {
+        faixa: String,
+        disponiveis: u32,
+        solicitados: u32,
+    },
+    /// A venda tem menos pessoas que o mínimo do tipo de ingresso
+    QuantidadeAbaixoDoMinimo {
+        tipo: String,
+        minimo: u32,
+        pessoas: u32,
+    },
+    /// Valor em reais ou quantidade que não pode ser usado (ex: "12,345", capacidade 0)
+    ValorInvalido(String),
+    /// Exportação para um arquivo com extensão diferente de .csv ou .json
+    FormatoExportacaoInvalido(String),
+    /// O contador de ids do cadastro chegou ao limite (ex: "animais")
+    IdsEsgotados(String),
+    /// O arquivo de dados não pôde ser interpretado e não será sobrescrito
+    ArquivoCorrompido(String),
+    /// Falha ao ler ou gravar o arquivo de dados
+    ErroPersistencia(String),
+}
+
+impl fmt::Display for ErroZoologico {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErroZoologico::AnimalNaoEncontrado(id) => {
+                write!(f, "Animal não encontrado: {}", id)
+            }
+            ErroZoologico::CampoVazio(campo) => {
+                write!(f, "O campo {} não pode estar vazio", campo)
+            }
+            ErroZoologico::EspecieDesconhecida { tipo, sugestoes } => {
+                write!(f, "Espécie desconhecida: {}", tipo)?;
+                if !sugestoes.is_empty() {
+                    write!(f, ". Você quis dizer: {}?", sugestoes.join(", "))?;
+                }
+                Ok(())
+            }
+            ErroZoologico::RecintoNaoEncontrado(id) => {
+                write!(f, "Recinto não encontrado: {}", id)
+            }
+            ErroZoologico::RecintoLotado {
+                recinto,
+                capacidade,
+            } => {
+                write!(
+                    f,
+                    "O recinto {} já está lotado (capacidade: {})",
+                    recinto, capacidade
+                )
+            }
+            ErroZoologico::AreaInsuficiente {
+                recinto,
+                area_livre_m2,
+                area_necessaria_m2,
+            } => {
+                write!(
+                    f,
+                    "O recinto {} tem {} m² livres, mas a espécie precisa de {} m²",
+                    recinto, area_livre_m2, area_necessaria_m2
+                )
+            }
+            ErroZoologico::HabitatIncompativel {
+                especie,
+                habitat_especie,
+                habitat_recinto,
+            } => {
+                write!(
+                    f,
+                    "{} precisa de habitat {}, mas o recinto é {}",
+                    especie, habitat_especie, habitat_recinto
+                )
+            }
+            ErroZoologico::PredadorEPresa { predador, presa } => {
+                write!(f, "{} não pode dividir o recinto com {}", predador, presa)
+            }
+            ErroZoologico::RecintoOcupado(id) => {
+                write!(f, "O recinto {} ainda tem animais alocados", id)
+            }
+            ErroZoologico::AlimentoNaoEncontrado(alimento) => {
+                write!(f, "Alimento não encontrado no estoque: {}", alimento)
+            }
+            ErroZoologico::PlanoNaoEncontrado(id) => {
+                write!(f, "Plano alimentar não encontrado: {}", id)
+            }
+            ErroZoologico::HorarioInvalido(horario) => {
+                write!(f, "Horário inválido: {} (use o formato HH:MM)", horario)
+            }
+            ErroZoologico::EstoqueInsuficiente {
+                alimento,
+                disponivel_g,
+                necessario_g,
+            } => {
+                write!(
+                    f,
+                    "Estoque insuficiente de {}: {} g disponíveis, {} g necessários",
+                    alimento, disponivel_g, necessario_g
+                )
+            }
+            ErroZoologico::AlimentacaoJaRegistrada { id_plano, horario } => {
+                write!(
+                    f,
+                    "A refeição do plano {} às {} já foi registrada nesse dia",
+                    id_plano, horario
+                )
+            }
+            ErroZoologico::AnimalEmQuarentena(id) => {
+                write!(
+                    f,
+                    "O animal {} está em quarentena e não pode mudar de recinto",
+                    id
+                )
+            }
+            ErroZoologico::AnimalSemQuarentena(id) => {
+                write!(f, "O animal {} não está em quarentena", id)
+            }
+            ErroZoologico::RegistroMedicoNaoEncontrado { id_animal, numero } => {
+                write!(
+                    f,
+                    "Registro {} não encontrado na ficha do animal {}",
+                    numero, id_animal
+                )
+            }
+            ErroZoologico::RegistroImportacaoInvalido { numero, erro } => {
+                write!(
+                    f,
+                    "Registro {} do arquivo de importação: {} (nenhum animal foi importado)",
+                    numero, erro
+                )
+            }
+            ErroZoologico::TratadorNaoEncontrado(id) => {
+                write!(f, "Tratador não encontrado: {}", id)
+            }
+            ErroZoologico::TratadorJaCadastrado(nome) => {
+                write!(f, "Já existe um tratador chamado {}", nome)
+            }
+            ErroZoologico::TarefaNaoEncontrada(id) => write!(f, "Tarefa não encontrada: {}", id),
+            ErroZoologico::TarefaJaConcluida(id) => write!(f, "A tarefa {} já foi concluída", id),
+            ErroZoologico::ParentescoInvalido(motivo) => {
+                write!(f, "Parentesco inválido: {}", motivo)
+            }
+            ErroZoologico::ReproducaoIncompativel(motivo) => {
+                write!(f, "Os animais não podem formar um casal: {}", motivo)
+            }
+            ErroZoologico::FaixaNaoEncontrada(faixa) => {
+                write!(f, "Não há faixa de visitação às {}", faixa)
+            }
+            ErroZoologico::LotacaoEsgotada {
+                faixa,
+                disponiveis,
+                solicitados,
+            } => {
+                write!(
+                    f,
+                    "A faixa das {} tem {} vaga(s), mas foram pedidos {} ingressos",
+                    faixa, disponiveis, solicitados
+                )
+            }
+            ErroZoologico::QuantidadeAbaixoDoMinimo {
+                tipo,
+                minimo,
+                pessoas,
+            } => {
+                write!(
+                    f,
+                    "O ingresso {} exige no mínimo {} pessoas (informado: {})",
+                    tipo, minimo, pessoas
+                )
+            }
+            ErroZoologico::ValorInvalido(valor) => write!(f, "Valor inválido: {}", valor),
+            ErroZoologico::FormatoExportacaoInvalido(caminho) => {
+                write!(
+                    f,
+                    "Formato de exportação não suportado: {} (use .csv ou .json)",
+                    caminho
+                )
+            }
+            ErroZoologico::IdsEsgotados(cadastro) => {
+                write!(f, "Não há mais ids disponíveis para {}", cadastro)
+            }
+            ErroZoologico::ArquivoCorrompido(msg) => {
+                write!(
+                    f,
+                    "Arquivo de dados corrompido ({}); ele não será sobrescrito até ser recuperado",
+                    msg
+                )
+            }
+            ErroZoologico::ErroPersistencia(msg) => {
+                write!(f, "Erro de persistência: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ErroZoologico {}
@@ -0,0 +1,333 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::errors::ErroZoologico;
+
+/// Catálogo embutido, usado quando não há um arquivo de espécies próprio
+const CATALOGO_PADRAO: &str = include_str!("../especies.json");
+
+/// Quantidade máxima de sugestões para uma espécie desconhecida
+const MAXIMO_SUGESTOES: usize = 3;
+
+/// Categoria de conservação segundo a Lista Vermelha da IUCN
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum StatusConservacao {
+    #[serde(rename = "NE")]
+    NaoAvaliado,
+    #[serde(rename = "LC")]
+    PoucoPreocupante,
+    #[serde(rename = "NT")]
+    QuaseAmeacado,
+    #[serde(rename = "VU")]
+    Vulneravel,
+    #[serde(rename = "EN")]
+    EmPerigo,
+    #[serde(rename = "CR")]
+    CriticamenteEmPerigo,
+    #[serde(rename = "EW")]
+    ExtintoNaNatureza,
+}
+
+impl fmt::Display for StatusConservacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            StatusConservacao::NaoAvaliado => "Não avaliado",
+            StatusConservacao::PoucoPreocupante => "Pouco preocupante",
+            StatusConservacao::QuaseAmeacado => "Quase ameaçado",
+            StatusConservacao::Vulneravel => "Vulnerável",
+            StatusConservacao::EmPerigo => "Em perigo",
+            StatusConservacao::CriticamenteEmPerigo => "Criticamente em perigo",
+            StatusConservacao::ExtintoNaNatureza => "Extinto na natureza",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Tipo de alimentação da espécie
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Dieta {
+    Carnivoro,
+    Herbivoro,
+    Onivoro,
+}
+
+impl fmt::Display for Dieta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            Dieta::Carnivoro => "Carnívoro",
+            Dieta::Herbivoro => "Herbívoro",
+            Dieta::Onivoro => "Onívoro",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Ambiente que a espécie precisa para viver
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Habitat {
+    Savana,
+    Floresta,
+    Pastagem,
+    Alagado,
+    Aquatico,
+    Polar,
+    Deserto,
+}
+
+impl Habitat {
+    /// Todos os habitats, na ordem usada nos menus
+    pub const TODOS: [Habitat; 7] = [
+        Habitat::Savana,
+        Habitat::Floresta,
+        Habitat::Pastagem,
+        Habitat::Alagado,
+        Habitat::Aquatico,
+        Habitat::Polar,
+        Habitat::Deserto,
+    ];
+}
+
+impl fmt::Display for Habitat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            Habitat::Savana => "Savana",
+            Habitat::Floresta => "Floresta",
+            Habitat::Pastagem => "Pastagem",
+            Habitat::Alagado => "Alagado",
+            Habitat::Aquatico => "Aquático",
+            Habitat::Polar => "Polar",
+            Habitat::Deserto => "Deserto",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Espécie registrada no catálogo
+///
+/// # Campos
+/// - `nome`: Nome popular usado como `tipo` dos animais (ex: Leão)
+/// - `nome_cientifico`, `classe`, `ordem`, `familia`: Taxonomia da espécie
+/// - `area_minima_m2`: Área mínima de recinto por indivíduo
+/// - `sinonimos`: Outros nomes aceitos e convertidos para `nome` (ex: Bovino → Boi)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Especie {
+    pub nome: String,
+    pub nome_cientifico: String,
+    pub classe: String,
+    pub ordem: String,
+    pub familia: String,
+    pub status_conservacao: StatusConservacao,
+    pub dieta: Dieta,
+    pub habitat: Habitat,
+    pub area_minima_m2: u32,
+    #[serde(default)]
+    pub sinonimos: Vec<String>,
+}
+
+/// Registro das espécies aceitas pelo zoológico
+///
+/// As buscas ignoram maiúsculas, acentos e espaços extras, e aceitam os
+/// sinônimos de cada espécie.
+#[derive(Debug, Clone)]
+pub struct CatalogoEspecies {
+    especies: Vec<Especie>,
+    /// Nome normalizado (popular ou sinônimo) → posição em `especies`
+    indice: HashMap<String, usize>,
+}
+
+impl CatalogoEspecies {
+    /// Monta o catálogo a partir de uma lista de espécies
+    ///
+    /// # Retorno
+    /// Erro se algum nome estiver vazio ou aparecer em mais de uma espécie
+    pub fn new(especies: Vec<Especie>) -> Result<Self, ErroZoologico> {
+        let mut indice = HashMap::new();
+
+        for (posicao, especie) in especies.iter().enumerate() {
+            if especie.nome.trim().is_empty() {
+                return Err(ErroZoologico::CampoVazio("nome da espécie".to_string()));
+            }
+            for nome in std::iter::once(&especie.nome).chain(&especie.sinonimos) {
+                if indice.insert(normalizar(nome), posicao).is_some() {
+                    return Err(ErroZoologico::ErroPersistencia(format!(
+                        "Nome de espécie duplicado no catálogo: {}",
+                        nome
+                    )));
+                }
+            }
+        }
+
+        Ok(CatalogoEspecies { especies, indice })
+    }
+
+    /// Catálogo embutido no programa (cópia de `especies.json`)
+    pub fn padrao() -> Self {
+        let especies = serde_json::from_str(CATALOGO_PADRAO).expect("Catálogo embutido inválido");
+        CatalogoEspecies::new(especies).expect("Catálogo embutido inválido")
+    }
+
+    /// Carrega o catálogo de um arquivo JSON com a lista de espécies
+    ///
+    /// # Retorno
+    /// Catálogo padrão se o arquivo não existir, ou erro se o conteúdo for inválido
+    pub fn carregar(caminho: &Path) -> Result<Self, ErroZoologico> {
+        if !caminho.exists() {
+            return Ok(CatalogoEspecies::padrao());
+        }
+
+        let conteudo = fs::read_to_string(caminho).map_err(|e| {
+            ErroZoologico::ErroPersistencia(format!("Erro ao abrir catálogo de espécies: {}", e))
+        })?;
+        let especies = serde_json::from_str(&conteudo).map_err(|e| {
+            ErroZoologico::ErroPersistencia(format!("Erro ao ler catálogo de espécies: {}", e))
+        })?;
+
+        CatalogoEspecies::new(especies)
+    }
+
+    /// Procura uma espécie pelo nome popular ou por um sinônimo
+    pub fn buscar(&self, nome: &str) -> Option<&Especie> {
+        self.indice
+            .get(&normalizar(nome))
+            .map(|&posicao| &self.especies[posicao])
+    }
+
+    /// Resolve o tipo informado para a espécie do catálogo
+    ///
+    /// # Retorno
+    /// A espécie encontrada, ou `EspecieDesconhecida` com sugestões de nomes parecidos
+    pub fn resolver(&self, nome: &str) -> Result<&Especie, ErroZoologico> {
+        self.buscar(nome)
+            .ok_or_else(|| ErroZoologico::EspecieDesconhecida {
+                tipo: nome.trim().to_string(),
+                sugestoes: self.sugerir(nome),
+            })
+    }
+
+    /// Sugere espécies com nome parecido ao informado ("você quis dizer...")
+    ///
+    /// Compara pela distância de edição entre os nomes normalizados (trocar a
+    /// ordem de duas letras vizinhas conta como um único erro),
+    /// tolerando cerca de um erro a cada três letras.
+    pub fn sugerir(&self, nome: &str) -> Vec<String> {
+        let procurado = normalizar(nome);
+        let tolerancia = (procurado.chars().count() / 3).max(1);
+
+        let mut candidatos: Vec<(usize, &str)> = Vec::new();
+        for (chave, &posicao) in &self.indice {
+            let distancia = strsim::osa_distance(&procurado, chave);
+            if distancia > tolerancia {
+                continue;
+            }
+
+            let especie = self.especies[posicao].nome.as_str();
+            match candidatos.iter_mut().find(|(_, nome)| *nome == especie) {
+                Some(existente) => existente.0 = existente.0.min(distancia),
+                None => candidatos.push((distancia, especie)),
+            }
+        }
+
+        candidatos.sort();
+        candidatos
+            .into_iter()
+            .take(MAXIMO_SUGESTOES)
+            .map(|(_, nome)| nome.to_string())
+            .collect()
+    }
+
+    /// Lista as espécies na ordem do arquivo de catálogo
+    pub fn listar(&self) -> &[Especie] {
+        &self.especies
+    }
+}
+
+/// Forma usada para comparar nomes: minúsculas, sem acentos e com espaços simples
+pub(crate) fn normalizar(nome: &str) -> String {
+    nome.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            outro => outro,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_catalogo_padrao() {
+        let catalogo = CatalogoEspecies::padrao();
+        let leao = catalogo.buscar("Leão").unwrap();
+        assert_eq!(leao.nome_cientifico, "Panthera leo");
+        assert_eq!(leao.dieta, Dieta::Carnivoro);
+        assert_eq!(leao.habitat, Habitat::Savana);
+    }
+
+    #[test]
+    fn test_buscar_ignora_caixa_acentos_e_sinonimos() {
+        let catalogo = CatalogoEspecies::padrao();
+        assert_eq!(catalogo.buscar("  LEAO ").unwrap().nome, "Leão");
+        assert_eq!(catalogo.buscar("boi").unwrap().nome, "Boi");
+        assert_eq!(catalogo.buscar("bovino").unwrap().nome, "Boi");
+        assert!(catalogo.buscar("dragão").is_none());
+    }
+
+    #[test]
+    fn test_sugestoes_para_especie_desconhecida() {
+        let catalogo = CatalogoEspecies::padrao();
+        assert_eq!(catalogo.sugerir("Girrafa"), vec!["Girafa"]);
+        assert_eq!(catalogo.sugerir("bovno"), vec!["Boi"]);
+        assert!(catalogo.sugerir("xyzxyzxyz").is_empty());
+
+        match catalogo.resolver("Zebar") {
+            Err(ErroZoologico::EspecieDesconhecida { tipo, sugestoes }) => {
+                assert_eq!(tipo, "Zebar");
+                assert_eq!(sugestoes, vec!["Zebra"]);
+            }
+            outro => panic!("resultado inesperado: {:?}", outro),
+        }
+    }
+
+    #[test]
+    fn test_carregar_catalogo_de_arquivo() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("especies.json");
+        fs::write(
+            &caminho,
+            r#"[{"nome":"Lhama","nome_cientifico":"Lama glama","classe":"Mammalia",
+                "ordem":"Artiodactyla","familia":"Camelidae","status_conservacao":"NE",
+                "dieta":"Herbivoro","habitat":"Pastagem","area_minima_m2":150}]"#,
+        )
+        .unwrap();
+
+        let catalogo = CatalogoEspecies::carregar(&caminho).unwrap();
+        assert_eq!(catalogo.listar().len(), 1);
+        assert!(catalogo.buscar("lhama").is_some());
+        assert!(catalogo.buscar("Leão").is_none());
+    }
+
+    #[test]
+    fn test_catalogo_com_nome_duplicado() {
+        let mut especie = CatalogoEspecies::padrao().buscar("Boi").unwrap().clone();
+        especie.sinonimos = vec!["BOI".to_string()];
+
+        assert!(matches!(
+            CatalogoEspecies::new(vec![especie]),
+            Err(ErroZoologico::ErroPersistencia(_))
+        ));
+    }
+}
@@ -0,0 +1,14 @@
+pub mod alimentacao;
+pub mod animais;
+pub mod bilheteria;
+pub mod ciclo_vida;
+pub mod cli;
+pub mod consulta;
+pub mod equipe;
+pub mod errors;
+pub mod especies;
+pub mod persistencia;
+pub mod recintos;
+pub mod saude;
+pub mod tabela;
+pub mod zoologico;
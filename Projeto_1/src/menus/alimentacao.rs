@@ -0,0 +1,236 @@
+use chrono::Local;
+
+use zoologico::alimentacao::{self, Horario, formatar_quantidade};
+use zoologico::zoologico::Zoologico;
+
+use super::animais::listar_animais;
+use super::{ler_numero, read_input, salvar};
+
+/// Exibe as refeições de hoje agrupadas por tratador e recinto
+fn checklist_do_dia(zoologico: &Zoologico) {
+    let hoje = Local::now().date_naive();
+    println!(
+        "\n  === CHECKLIST DE ALIMENTAÇÃO ({}) ===",
+        hoje.format("%d/%m/%Y")
+    );
+
+    let checklist = zoologico.checklist(hoje);
+    if checklist.is_empty() {
+        println!("   Nenhum plano alimentar cadastrado.");
+        return;
+    }
+
+    for grupo in checklist {
+        println!();
+        println!(
+            "   Recinto: {} | Tratador: {}",
+            grupo.recinto.as_deref().unwrap_or("(sem recinto)"),
+            grupo.tratador.as_deref().unwrap_or("(sem tratador)")
+        );
+        for item in grupo.itens {
+            println!(
+                "   [{}] {} plano {}: {} — {} de {}",
+                if item.feito { "x" } else { " " },
+                item.horario,
+                item.id_plano,
+                item.nome_animal,
+                formatar_quantidade(item.quantidade_g as u64),
+                item.alimento
+            );
+        }
+    }
+}
+
+/// Marca uma refeição de hoje como servida
+fn registrar_alimentacao(zoologico: &mut Zoologico) {
+    checklist_do_dia(zoologico);
+
+    let Some(id_plano) = ler_numero("Digite o ID do plano: ") else {
+        return;
+    };
+    print!("Digite o horário da refeição (HH:MM): ");
+    let horario = match read_input().parse::<Horario>() {
+        Ok(horario) => horario,
+        Err(e) => {
+            println!("  Erro: {}", e);
+            return;
+        }
+    };
+
+    let hoje = Local::now().date_naive();
+    match zoologico.registrar_alimentacao(id_plano, hoje, horario) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Refeição registrada e descontada do estoque!");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Cria um plano alimentar para um animal
+fn incluir_plano(zoologico: &mut Zoologico) {
+    listar_animais(zoologico);
+    let Some(id_animal) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+
+    print!("Digite o ALIMENTO: ");
+    let alimento = read_input();
+    let Some(quantidade) = ler_numero("Digite a QUANTIDADE por refeição (gramas): ") else {
+        return;
+    };
+    print!("Digite os HORÁRIOS separados por vírgula (ex: 08:00, 16:00): ");
+    let horarios = match alimentacao::ler_horarios(&read_input()) {
+        Ok(horarios) => horarios,
+        Err(e) => {
+            println!("  Erro: {}", e);
+            return;
+        }
+    };
+
+    match zoologico.incluir_plano(id_animal, &alimento, quantidade, horarios) {
+        Ok(id) => {
+            salvar(zoologico);
+            println!("  Plano alimentar criado! ID: {}", id);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exibe os planos alimentares de um animal
+fn listar_planos(zoologico: &Zoologico) {
+    listar_animais(zoologico);
+    let Some(id_animal) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+
+    let planos = zoologico.nutricao().planos_do_animal(id_animal);
+    if planos.is_empty() {
+        println!("   Nenhum plano alimentar para esse animal.");
+        return;
+    }
+
+    for plano in planos {
+        let horarios: Vec<String> = plano.horarios.iter().map(|h| h.to_string()).collect();
+        println!(
+            "   [{}] {} de {} às {}",
+            plano.id,
+            formatar_quantidade(plano.quantidade_g as u64),
+            plano.alimento,
+            horarios.join(", ")
+        );
+    }
+}
+
+/// Remove um plano alimentar
+fn excluir_plano(zoologico: &mut Zoologico) {
+    let Some(id_plano) = ler_numero("Digite o ID do plano a ser excluído: ") else {
+        return;
+    };
+
+    match zoologico.excluir_plano(id_plano) {
+        Ok(plano) => {
+            salvar(zoologico);
+            println!("  Plano de {} excluído!", plano.alimento);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exibe o estoque de alimentos e os alertas de estoque baixo
+fn relatorio_estoque(zoologico: &Zoologico) {
+    println!("\n  === ESTOQUE DE ALIMENTOS ===");
+
+    let estoque = zoologico.nutricao().listar_estoque();
+    if estoque.is_empty() {
+        println!("   Nenhum alimento em estoque.");
+    }
+    for item in estoque {
+        println!(
+            "   {}: {} (consumo diário: {}, alerta abaixo de {} dias)",
+            item.alimento,
+            formatar_quantidade(item.quantidade_g),
+            formatar_quantidade(zoologico.nutricao().consumo_diario_g(&item.alimento)),
+            item.dias_minimos
+        );
+    }
+
+    let alertas = zoologico.nutricao().alertas_estoque();
+    if !alertas.is_empty() {
+        println!();
+        println!("  Atenção: estoque baixo!");
+        for alerta in alertas {
+            println!(
+                "   {}: {} restantes, dura {} dia(s) no consumo de {} por dia",
+                alerta.alimento,
+                formatar_quantidade(alerta.quantidade_g),
+                alerta.dias_restantes,
+                formatar_quantidade(alerta.consumo_diario_g)
+            );
+        }
+    }
+}
+
+/// Registra a entrada de alimento no estoque
+fn adicionar_estoque(zoologico: &mut Zoologico) {
+    print!("Digite o ALIMENTO: ");
+    let alimento = read_input();
+    let Some(quantidade) = ler_numero("Digite a QUANTIDADE recebida (gramas): ") else {
+        return;
+    };
+
+    match zoologico.adicionar_estoque(&alimento, quantidade as u64) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Estoque atualizado!");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Altera a partir de quantos dias restantes o alimento gera alerta
+fn definir_dias_minimos(zoologico: &mut Zoologico) {
+    print!("Digite o ALIMENTO: ");
+    let alimento = read_input();
+    let Some(dias) = ler_numero("Digite o estoque mínimo (dias de consumo): ") else {
+        return;
+    };
+
+    match zoologico.definir_dias_minimos(&alimento, dias) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Estoque mínimo atualizado!");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Submenu de alimentação e estoque
+pub(super) fn menu_alimentacao(zoologico: &mut Zoologico) {
+    loop {
+        println!("\n=== ALIMENTAÇÃO ===");
+        println!("1. Checklist de hoje");
+        println!("2. Registrar refeição servida");
+        println!("3. Ver planos de um animal");
+        println!("4. Incluir plano alimentar");
+        println!("5. Excluir plano alimentar");
+        println!("6. Estoque e alertas");
+        println!("7. Registrar entrada no estoque");
+        println!("8. Definir estoque mínimo");
+        println!("0. Voltar");
+        print!("Digite o número da opção desejada: ");
+
+        match read_input().as_str() {
+            "1" => checklist_do_dia(zoologico),
+            "2" => registrar_alimentacao(zoologico),
+            "3" => listar_planos(zoologico),
+            "4" => incluir_plano(zoologico),
+            "5" => excluir_plano(zoologico),
+            "6" => relatorio_estoque(zoologico),
+            "7" => adicionar_estoque(zoologico),
+            "8" => definir_dias_minimos(zoologico),
+            "0" => break,
+            _ => println!("Opção inválida!"),
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use std::path::Path;
+
+use zoologico::consulta::{self, CampoOrdenacao, ConsultaAnimais, FiltroRecinto, LinhaAnimal};
+use zoologico::saude::SituacaoSaude;
+use zoologico::tabela::Tabela;
+use zoologico::zoologico::Zoologico;
+
+use super::{ler_numero, read_input, salvar};
+
+/// Quantidade de animais exibidos em cada página da listagem
+const TAMANHO_PAGINA: usize = 10;
+
+/// Exibe todos os animais cadastrados na base de dados
+///
+/// Mostra o id, tipo, nome, recinto e saúde de cada animal, em páginas
+pub(super) fn listar_animais(zoologico: &Zoologico) {
+    println!("\n  === ANIMAIS CADASTRADOS ===");
+
+    let linhas = zoologico.consultar_animais(&ConsultaAnimais::default());
+    if linhas.is_empty() {
+        println!("   Nenhum animal cadastrado no sistema.");
+        println!("   Use a opção 2 para incluir o primeiro animal!");
+        return;
+    }
+
+    exibir_animais(&linhas);
+}
+
+/// Pesquisa animais por espécie, nome, recinto e saúde, com ordenação
+pub(super) fn pesquisar_animais(zoologico: &Zoologico) {
+    println!("\n=== PESQUISAR ANIMAIS ===");
+    println!("Deixe em branco os filtros que não quiser usar.");
+
+    let mut consulta = ConsultaAnimais {
+        especie: ler_opcional("Espécie: "),
+        nome: ler_opcional("Nome (ou parte do nome): "),
+        ..ConsultaAnimais::default()
+    };
+
+    if let Some(texto) = ler_opcional("Id do recinto (0 para animais sem recinto): ") {
+        consulta.recinto = match texto.parse() {
+            Ok(0) => Some(FiltroRecinto::SemRecinto),
+            Ok(id) => Some(FiltroRecinto::Recinto(id)),
+            Err(_) => {
+                println!("  Erro: Por favor, insira um número válido.");
+                return;
+            }
+        };
+    }
+
+    println!("Saúde: 1. Saudável  2. Em tratamento  3. Em quarentena");
+    consulta.saude = match ler_opcional("Situação de saúde: ").as_deref() {
+        None => None,
+        Some("1") => Some(SituacaoSaude::Saudavel),
+        Some("2") => Some(SituacaoSaude::EmTratamento),
+        Some("3") => Some(SituacaoSaude::EmQuarentena),
+        Some(_) => {
+            println!("  Erro: Opção inválida.");
+            return;
+        }
+    };
+
+    println!("Ordenar por: 1. Id  2. Espécie  3. Nome  4. Recinto  5. Saúde");
+    consulta.ordenar_por = match ler_opcional("Ordenação (vazio para id): ").as_deref() {
+        None | Some("1") => CampoOrdenacao::Id,
+        Some("2") => CampoOrdenacao::Especie,
+        Some("3") => CampoOrdenacao::Nome,
+        Some("4") => CampoOrdenacao::Recinto,
+        Some("5") => CampoOrdenacao::Saude,
+        Some(_) => {
+            println!("  Erro: Opção inválida.");
+            return;
+        }
+    };
+    print!("Ordem decrescente? (s/N): ");
+    consulta.decrescente = read_input().eq_ignore_ascii_case("s");
+
+    let linhas = zoologico.consultar_animais(&consulta);
+    if linhas.is_empty() {
+        println!("   Nenhum animal encontrado.");
+        return;
+    }
+    exibir_animais(&linhas);
+}
+
+/// Lê um texto opcional
+///
+/// # Retorno
+/// `None` se a entrada for vazia
+fn ler_opcional(mensagem: &str) -> Option<String> {
+    print!("{}", mensagem);
+    let texto = read_input();
+    if texto.is_empty() { None } else { Some(texto) }
+}
+
+/// Exibe os animais em páginas, com a opção de exportar a lista
+fn exibir_animais(linhas: &[LinhaAnimal]) {
+    let mut numero = 1;
+    loop {
+        let pagina = consulta::paginar(linhas, numero, TAMANHO_PAGINA);
+
+        let mut tabela = Tabela::new(&CABECALHO_ANIMAIS);
+        for linha in pagina.itens {
+            tabela.adicionar_linha(celulas_animal(linha));
+        }
+        println!();
+        print!("{}", tabela.renderizar(3));
+        println!(
+            "\n  Página {} de {} ({} animais)",
+            pagina.numero, pagina.total_paginas, pagina.total_itens
+        );
+
+        print!("  [P] Próxima  [A] Anterior  [E] Exportar  Enter para voltar: ");
+        match read_input().to_lowercase().as_str() {
+            "p" if pagina.numero < pagina.total_paginas => numero = pagina.numero + 1,
+            "a" if pagina.numero > 1 => numero = pagina.numero - 1,
+            "p" | "a" => println!("  Não há mais páginas nessa direção."),
+            "e" => {
+                exportar_animais(linhas);
+                break;
+            }
+            _ => break,
+        }
+    }
+}
+
+/// Colunas das tabelas de listagem de animais
+pub(crate) const CABECALHO_ANIMAIS: [&str; 5] =
+    ["ID", "TIPO (Espécie)", "NOME", "RECINTO", "SAÚDE"];
+
+/// Células de um animal nas tabelas de listagem
+pub(crate) fn celulas_animal(linha: &LinhaAnimal) -> Vec<String> {
+    vec![
+        linha.id.to_string(),
+        linha.especie.clone(),
+        linha.nome.clone(),
+        linha.recinto.clone().unwrap_or_else(|| "-".to_string()),
+        linha.saude.to_string(),
+    ]
+}
+
+/// Exporta a lista de animais para um arquivo CSV ou JSON
+fn exportar_animais(linhas: &[LinhaAnimal]) {
+    let destino = ler_opcional("Arquivo de destino (.csv ou .json, vazio para exportacao.csv): ")
+        .unwrap_or_else(|| "exportacao.csv".to_string());
+
+    match consulta::exportar(linhas, Path::new(&destino)) {
+        Ok(()) => println!(" {} animais exportados para {}", linhas.len(), destino),
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Inclui um novo animal na base de dados
+///
+/// Solicita tipo e nome do usuário e salva no arquivo
+pub(super) fn incluir_animal(zoologico: &mut Zoologico) {
+    println!("\n=== INCLUIR NOVO ANIMAL ===");
+    println!("Para cadastrar um animal, você precisa informar:");
+    println!("- TIPO: A espécie do animal (veja a opção 5 para o catálogo)");
+    println!("- NOME: O nome específico do animal");
+    println!();
+
+    print!("Digite o TIPO do animal (espécie): ");
+    let tipo = read_input();
+
+    print!("Digite o NOME do animal: ");
+    let nome = read_input();
+
+    match zoologico.incluir_animal(&tipo, &nome) {
+        Ok(id) => {
+            salvar(zoologico);
+            println!(" Animal incluído com sucesso!");
+            println!("   ID: {}", id);
+            println!("   Tipo: {}", tipo);
+            println!("   Nome: {}", nome);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Edita um animal existente na base de dados
+///
+/// Solicita o id do animal a ser editado e os novos dados
+pub(super) fn editar_animal(zoologico: &mut Zoologico) {
+    println!("\n=== EDITAR ANIMAL ===");
+
+    if zoologico.listar_animais().is_empty() {
+        println!("  Nenhum animal cadastrado para editar.");
+        return;
+    }
+
+    listar_animais(zoologico);
+
+    let Some(id) = ler_numero("Digite o ID do animal a ser editado: ") else {
+        return;
+    };
+
+    let Some(animal) = zoologico.buscar_animal(id) else {
+        println!("  Erro: Nenhum animal com o ID {}.", id);
+        return;
+    };
+
+    println!("\n Animal atual:");
+    println!("   Tipo: {}", animal.tipo);
+    println!("   Nome: {}", animal.nome);
+    println!();
+
+    print!("Digite o novo TIPO do animal (espécie): ");
+    let novo_tipo = read_input();
+
+    print!("Digite o novo NOME do animal: ");
+    let novo_nome = read_input();
+
+    match zoologico.editar_animal(id, &novo_tipo, &novo_nome) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("   Animal editado com sucesso!");
+            println!("   Novo tipo: {}", novo_tipo);
+            println!("   Novo nome: {}", novo_nome);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exclui um animal da base de dados
+///
+/// Solicita o id do animal a ser excluído
+pub(super) fn excluir_animal(zoologico: &mut Zoologico) {
+    println!("\n=== EXCLUIR ANIMAL ===");
+
+    if zoologico.listar_animais().is_empty() {
+        println!("Nenhum animal cadastrado para excluir.");
+        return;
+    }
+
+    listar_animais(zoologico);
+
+    println!("Use a exclusão só para cadastros feitos por engano: transferências e");
+    println!("óbitos devem ser registrados em \"Ciclo de vida\" para manter o histórico.");
+    let Some(id) = ler_numero("Digite o ID do animal a ser excluído: ") else {
+        return;
+    };
+
+    match zoologico.excluir_animal(id) {
+        Ok(animal_removido) => {
+            salvar(zoologico);
+            println!("  Animal excluído com sucesso!");
+            println!(
+                "   Animal removido: {} ({})",
+                animal_removido.nome, animal_removido.tipo
+            );
+        }
+        Err(e) => println!("Erro: {}", e),
+    }
+}
+
+/// Exibe as espécies aceitas pelo zoológico
+///
+/// Mostra taxonomia, estado de conservação, dieta e habitat de cada espécie
+pub(super) fn consultar_catalogo(zoologico: &Zoologico) {
+    println!("\n=== CATÁLOGO DE ESPÉCIES ===");
+
+    for especie in zoologico.catalogo().listar() {
+        println!();
+        println!("   {} ({})", especie.nome, especie.nome_cientifico);
+        println!(
+            "   Classe: {} | Ordem: {} | Família: {}",
+            especie.classe, especie.ordem, especie.familia
+        );
+        println!("   Conservação: {}", especie.status_conservacao);
+        println!(
+            "   Dieta: {} | Habitat: {} | Área mínima: {} m²",
+            especie.dieta, especie.habitat, especie.area_minima_m2
+        );
+        if !especie.sinonimos.is_empty() {
+            println!("   Também aceito como: {}", especie.sinonimos.join(", "));
+        }
+    }
+
+    let fora = zoologico.animais_fora_do_catalogo();
+    if !fora.is_empty() {
+        println!();
+        println!("  Atenção: animais com tipo fora do catálogo (use a opção 3 para corrigir):");
+        for animal in fora {
+            println!("   ID {}: {} ({})", animal.id, animal.nome, animal.tipo);
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use chrono::{Local, NaiveDate};
+
+use zoologico::alimentacao::Horario;
+use zoologico::bilheteria::{Periodo, TIPOS_INGRESSO, formatar_centavos, ler_centavos};
+use zoologico::tabela::Tabela;
+use zoologico::zoologico::Zoologico;
+
+use super::{formatar_data, ler_data, ler_numero, read_input, salvar};
+
+/// Lê um horário no formato HH:MM
+fn ler_horario(mensagem: &str) -> Option<Horario> {
+    print!("{}", mensagem);
+    match read_input().parse::<Horario>() {
+        Ok(horario) => Some(horario),
+        Err(e) => {
+            println!("  Erro: {}", e);
+            None
+        }
+    }
+}
+
+/// Exibe as vagas de cada faixa de visitação no dia
+fn exibir_ocupacao_faixas(zoologico: &Zoologico, data: NaiveDate) {
+    println!("\n  === FAIXAS DE VISITAÇÃO ({}) ===", formatar_data(data));
+
+    let mut tabela = Tabela::new(&["FAIXA", "VENDIDOS", "CAPACIDADE", "VAGAS"]);
+    for ocupacao in zoologico.bilheteria().ocupacao_faixas(data) {
+        tabela.adicionar_linha(vec![
+            ocupacao.inicio.to_string(),
+            ocupacao.vendidos.to_string(),
+            ocupacao.capacidade.to_string(),
+            ocupacao.disponiveis().to_string(),
+        ]);
+    }
+
+    if tabela.is_empty() {
+        println!("   Nenhuma faixa de visitação cadastrada.");
+    } else {
+        print!("{}", tabela.renderizar(3));
+    }
+}
+
+/// Consulta a lotação das faixas de um dia
+fn ocupacao_faixas(zoologico: &Zoologico) {
+    let Some(data) = ler_data("Digite a data da visita") else {
+        return;
+    };
+    exibir_ocupacao_faixas(zoologico, data);
+}
+
+/// Vende ingressos para uma data e faixa de horário
+fn vender_ingressos(zoologico: &mut Zoologico) {
+    println!("\n=== VENDER INGRESSOS ===");
+    let Some(data) = ler_data("Digite a data da visita") else {
+        return;
+    };
+    exibir_ocupacao_faixas(zoologico, data);
+    let Some(faixa) = ler_horario("Digite o início da faixa (HH:MM): ") else {
+        return;
+    };
+
+    let mut pedido = Vec::new();
+    for preco in zoologico.bilheteria().precos() {
+        let minimo = if preco.minimo_pessoas > 1 {
+            format!(", mínimo {} pessoas", preco.minimo_pessoas)
+        } else {
+            String::new()
+        };
+        print!(
+            "Quantidade de ingressos {} ({} por pessoa{}, vazio para 0): ",
+            preco.tipo,
+            formatar_centavos(preco.centavos),
+            minimo
+        );
+        let texto = read_input();
+        if texto.is_empty() {
+            continue;
+        }
+        match texto.parse::<u32>() {
+            Ok(quantidade) => pedido.push((preco.tipo, quantidade)),
+            Err(_) => {
+                println!("  Erro: Por favor, insira um número válido.");
+                return;
+            }
+        }
+    }
+
+    match zoologico.vender_ingressos(data, faixa, &pedido, Local::now().naive_local()) {
+        Ok(id) => {
+            salvar(zoologico);
+            if let Some(venda) = zoologico.bilheteria().buscar_venda(id) {
+                println!(
+                    "  Venda {} registrada: {} visitante(s), total {}",
+                    id,
+                    venda.visitantes(),
+                    formatar_centavos(venda.total_centavos().unwrap_or_default())
+                );
+            }
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exibe o público e a receita do dia, da semana ou do mês
+fn relatorio_publico(zoologico: &Zoologico, periodo: Periodo) {
+    let Some(data) = ler_data("Digite uma data do período") else {
+        return;
+    };
+
+    let relatorio = match zoologico.bilheteria().relatorio_publico(periodo, data) {
+        Ok(relatorio) => relatorio,
+        Err(e) => {
+            println!("  Erro: {}", e);
+            return;
+        }
+    };
+    println!(
+        "\n  === RELATÓRIO {} DE PÚBLICO ({} a {}) ===",
+        periodo.to_string().to_uppercase(),
+        formatar_data(relatorio.inicio),
+        formatar_data(relatorio.fim)
+    );
+
+    if periodo != Periodo::Dia {
+        let mut dias = Tabela::new(&["DATA", "VISITANTES", "RECEITA"]);
+        for (dia, movimento) in &relatorio.dias {
+            dias.adicionar_linha(vec![
+                formatar_data(*dia),
+                movimento.visitantes.to_string(),
+                formatar_centavos(movimento.receita_centavos),
+            ]);
+        }
+        print!("{}", dias.renderizar(3));
+        println!();
+    }
+
+    let mut tipos = Tabela::new(&["INGRESSO", "VISITANTES", "RECEITA"]);
+    for (tipo, movimento) in &relatorio.por_tipo {
+        tipos.adicionar_linha(vec![
+            tipo.to_string(),
+            movimento.visitantes.to_string(),
+            formatar_centavos(movimento.receita_centavos),
+        ]);
+    }
+    print!("{}", tipos.renderizar(3));
+
+    // O total já foi conferido por `relatorio_publico`
+    let total = relatorio.total().unwrap_or_default();
+    println!(
+        "\n   Total: {} visitante(s) | Receita: {}",
+        total.visitantes,
+        formatar_centavos(total.receita_centavos)
+    );
+    if periodo != Periodo::Dia {
+        println!(
+            "   Média diária: {:.1} visitantes",
+            relatorio.media_diaria().unwrap_or_default()
+        );
+    }
+}
+
+/// Exibe a tabela de preços
+fn tabela_precos(zoologico: &Zoologico) {
+    println!("\n  === TABELA DE PREÇOS ===");
+
+    let mut tabela = Tabela::new(&["INGRESSO", "PREÇO POR PESSOA", "MÍNIMO DE PESSOAS"]);
+    for preco in zoologico.bilheteria().precos() {
+        tabela.adicionar_linha(vec![
+            preco.tipo.to_string(),
+            formatar_centavos(preco.centavos),
+            preco.minimo_pessoas.to_string(),
+        ]);
+    }
+    print!("{}", tabela.renderizar(3));
+}
+
+/// Altera o preço e o mínimo de pessoas de um tipo de ingresso
+fn alterar_preco(zoologico: &mut Zoologico) {
+    tabela_precos(zoologico);
+
+    println!("Tipos de ingresso:");
+    for (indice, tipo) in TIPOS_INGRESSO.iter().enumerate() {
+        println!("  {}. {}", indice + 1, tipo);
+    }
+    let Some(tipo) = ler_numero("Digite o número do tipo: ").and_then(|numero| {
+        TIPOS_INGRESSO
+            .get((numero as usize).wrapping_sub(1))
+            .copied()
+    }) else {
+        println!("  Erro: Tipo de ingresso inválido.");
+        return;
+    };
+
+    print!("Digite o PREÇO por pessoa (R$): ");
+    let centavos = match ler_centavos(&read_input()) {
+        Ok(centavos) => centavos,
+        Err(e) => {
+            println!("  Erro: {}", e);
+            return;
+        }
+    };
+    let Some(minimo) = ler_numero("Digite o MÍNIMO de pessoas por venda: ") else {
+        return;
+    };
+
+    match zoologico.definir_preco(tipo, centavos, minimo) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Preço atualizado!");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Cadastra uma faixa de visitação ou altera sua capacidade
+fn definir_faixa(zoologico: &mut Zoologico) {
+    let Some(inicio) = ler_horario("Digite o início da faixa (HH:MM): ") else {
+        return;
+    };
+    let Some(capacidade) = ler_numero("Digite a CAPACIDADE (visitantes por dia): ") else {
+        return;
+    };
+
+    match zoologico.definir_faixa(inicio, capacidade) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Faixa das {} salva!", inicio);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Remove uma faixa de visitação
+fn excluir_faixa(zoologico: &mut Zoologico) {
+    exibir_ocupacao_faixas(zoologico, Local::now().date_naive());
+    let Some(inicio) = ler_horario("Digite o início da faixa a ser excluída (HH:MM): ") else {
+        return;
+    };
+
+    match zoologico.excluir_faixa(inicio) {
+        Ok(_) => {
+            salvar(zoologico);
+            println!("  Faixa das {} excluída!", inicio);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Submenu de venda de ingressos e relatórios de público
+pub(super) fn menu_bilheteria(zoologico: &mut Zoologico) {
+    loop {
+        println!("\n=== BILHETERIA ===");
+        println!("1. Vender ingressos");
+        println!("2. Vagas das faixas de um dia");
+        println!("3. Relatório diário");
+        println!("4. Relatório semanal");
+        println!("5. Relatório mensal");
+        println!("6. Tabela de preços");
+        println!("7. Alterar preço");
+        println!("8. Incluir ou alterar faixa de visitação");
+        println!("9. Excluir faixa de visitação");
+        println!("0. Voltar");
+        print!("Digite o número da opção desejada: ");
+
+        match read_input().as_str() {
+            "1" => vender_ingressos(zoologico),
+            "2" => ocupacao_faixas(zoologico),
+            "3" => relatorio_publico(zoologico, Periodo::Dia),
+            "4" => relatorio_publico(zoologico, Periodo::Semana),
+            "5" => relatorio_publico(zoologico, Periodo::Mes),
+            "6" => tabela_precos(zoologico),
+            "7" => alterar_preco(zoologico),
+            "8" => definir_faixa(zoologico),
+            "9" => excluir_faixa(zoologico),
+            "0" => break,
+            _ => println!("Opção inválida!"),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use zoologico::ciclo_vida::{Evento, Genealogia};
+use zoologico::errors::ErroZoologico;
+use zoologico::tabela::Tabela;
+use zoologico::zoologico::Zoologico;
+
+use super::animais::listar_animais;
+use super::{formatar_data, ler_data, ler_numero, ler_numero_opcional, read_input, salvar};
+
+/// Gerações de ancestrais exibidas na árvore genealógica
+const GERACOES_GENEALOGIA: u32 = 3;
+
+/// Nome do animal no zoológico ou no histórico, para exibição
+fn nome_animal(zoologico: &Zoologico, id: u32) -> String {
+    zoologico
+        .buscar_animal(id)
+        .or_else(|| zoologico.historico().buscar_animal_baixado(id))
+        .map_or_else(|| format!("#{}", id), |animal| animal.nome.clone())
+}
+
+/// Descrição de um evento, com o nome dos pais nos nascimentos
+fn descrever_evento(zoologico: &Zoologico, evento: &Evento) -> String {
+    match evento {
+        Evento::Nascimento { id_mae, id_pai } => {
+            let nome = |id: &Option<u32>| {
+                id.map_or("desconhecido".to_string(), |id| nome_animal(zoologico, id))
+            };
+            format!("Nascimento (mãe: {}, pai: {})", nome(id_mae), nome(id_pai))
+        }
+        evento => evento.to_string(),
+    }
+}
+
+/// Cadastra um animal vindo de outra instituição
+fn registrar_chegada(zoologico: &mut Zoologico) {
+    println!("\n=== REGISTRAR CHEGADA ===");
+    print!("Digite o TIPO do animal (espécie): ");
+    let tipo = read_input();
+    print!("Digite o NOME do animal: ");
+    let nome = read_input();
+    let Some(data) = ler_data("Digite a data da chegada") else {
+        return;
+    };
+    print!("Digite a ORIGEM (instituição ou local de resgate): ");
+    let origem = read_input();
+    print!("Observação (opcional): ");
+    let observacao = read_input();
+
+    match zoologico.registrar_chegada(&tipo, &nome, data, &origem, &observacao) {
+        Ok(id) => {
+            salvar(zoologico);
+            println!(" Chegada registrada! ID do animal: {}", id);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Cadastra um filhote nascido no zoológico
+fn registrar_nascimento(zoologico: &mut Zoologico) {
+    println!("\n=== REGISTRAR NASCIMENTO ===");
+    listar_animais(zoologico);
+    print!("Digite o TIPO do filhote (espécie): ");
+    let tipo = read_input();
+    print!("Digite o NOME do filhote: ");
+    let nome = read_input();
+    let Some(data) = ler_data("Digite a data do nascimento") else {
+        return;
+    };
+    let Some(id_mae) = ler_numero_opcional("Digite o ID da mãe (vazio se desconhecida): ") else {
+        return;
+    };
+    let Some(id_pai) = ler_numero_opcional("Digite o ID do pai (vazio se desconhecido): ") else {
+        return;
+    };
+    print!("Observação (opcional): ");
+    let observacao = read_input();
+
+    match zoologico.registrar_nascimento(&tipo, &nome, data, id_mae, id_pai, &observacao) {
+        Ok(id) => {
+            salvar(zoologico);
+            println!(" Nascimento registrado! ID do filhote: {}", id);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Registra a transferência de um animal para outra instituição
+fn registrar_transferencia(zoologico: &mut Zoologico) {
+    listar_animais(zoologico);
+    let Some(id) = ler_numero("Digite o ID do animal transferido: ") else {
+        return;
+    };
+    let Some(data) = ler_data("Digite a data da transferência") else {
+        return;
+    };
+    print!("Digite o DESTINO: ");
+    let destino = read_input();
+    print!("Observação (opcional): ");
+    let observacao = read_input();
+
+    match zoologico.registrar_transferencia(id, data, &destino, &observacao) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Transferência registrada. O animal segue no histórico.");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Registra a morte de um animal
+fn registrar_obito(zoologico: &mut Zoologico) {
+    listar_animais(zoologico);
+    let Some(id) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+    let Some(data) = ler_data("Digite a data do óbito") else {
+        return;
+    };
+    print!("Digite a CAUSA (vazio se desconhecida): ");
+    let causa = read_input();
+    print!("Observação (opcional): ");
+    let observacao = read_input();
+
+    match zoologico.registrar_obito(id, data, &causa, &observacao) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Óbito registrado. O animal segue no histórico.");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exibe os eventos e os filhos de um animal, presente ou não
+fn historico_animal(zoologico: &Zoologico) {
+    let Some(id) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+    let Some(animal) = zoologico
+        .buscar_animal(id)
+        .or_else(|| zoologico.historico().buscar_animal_baixado(id))
+    else {
+        println!("  Erro: {}", ErroZoologico::AnimalNaoEncontrado(id));
+        return;
+    };
+
+    println!(
+        "\n  === HISTÓRICO DE {} ({}) ===",
+        animal.nome.to_uppercase(),
+        animal.tipo
+    );
+    let mut tabela = Tabela::new(&["DATA", "EVENTO", "OBSERVAÇÃO"]);
+    for evento in zoologico.historico().eventos_do_animal(id) {
+        tabela.adicionar_linha(vec![
+            formatar_data(evento.data),
+            descrever_evento(zoologico, &evento.evento),
+            evento.observacao.clone(),
+        ]);
+    }
+    if tabela.is_empty() {
+        println!("   Nenhum evento registrado.");
+    } else {
+        print!("{}", tabela.renderizar(3));
+    }
+
+    let filhos: Vec<String> = zoologico
+        .linhagem()
+        .filhos(id)
+        .iter()
+        .map(|filho| format!("{} [{}]", filho.nome, filho.id))
+        .collect();
+    if !filhos.is_empty() {
+        println!("\n   Filhos: {}", filhos.join(", "));
+    }
+}
+
+/// Escreve a árvore de ancestrais, um nível de recuo por geração
+fn exibir_genealogia(genealogia: &Genealogia, papel: &str, nivel: usize) {
+    println!(
+        "   {}{}{} [{}]{}",
+        "    ".repeat(nivel),
+        papel,
+        genealogia.nome,
+        genealogia.id,
+        if genealogia.baixado {
+            " (não está mais no zoológico)"
+        } else {
+            ""
+        }
+    );
+    let ancestrais = [
+        ("Mãe: ", "desconhecida", &genealogia.mae),
+        ("Pai: ", "desconhecido", &genealogia.pai),
+    ];
+    for (papel, desconhecido, ancestral) in ancestrais {
+        match ancestral {
+            Some(ancestral) => exibir_genealogia(ancestral, papel, nivel + 1),
+            None => println!("   {}{}{}", "    ".repeat(nivel + 1), papel, desconhecido),
+        }
+    }
+}
+
+/// Exibe a árvore genealógica de um animal
+fn arvore_genealogica(zoologico: &Zoologico) {
+    let Some(id) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+
+    match zoologico.linhagem().genealogia(id, GERACOES_GENEALOGIA) {
+        Some(genealogia) => {
+            println!("\n  === ÁRVORE GENEALÓGICA ===");
+            exibir_genealogia(&genealogia, "", 0);
+        }
+        None => println!("  Erro: {}", ErroZoologico::AnimalNaoEncontrado(id)),
+    }
+}
+
+/// Verifica o parentesco de um possível casal e alerta sobre endogamia
+fn compatibilidade_reproducao(zoologico: &Zoologico) {
+    listar_animais(zoologico);
+    let Some(id_a) = ler_numero("Digite o ID do primeiro animal: ") else {
+        return;
+    };
+    let Some(id_b) = ler_numero("Digite o ID do segundo animal: ") else {
+        return;
+    };
+
+    match zoologico.linhagem().avaliar_reproducao(id_a, id_b) {
+        Ok(avaliacao) => {
+            println!("\n   Parentesco: {}", avaliacao.parentesco);
+            println!(
+                "   Endogamia esperada dos filhotes: {:.2}%",
+                avaliacao.coeficiente * 100.0
+            );
+            if avaliacao.endogamia_proxima() {
+                println!("   ⚠ ALERTA: endogamia próxima. Evite este casal.");
+            } else {
+                println!("   Casal sem endogamia próxima pela genealogia registrada.");
+            }
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Lista os animais transferidos ou mortos
+fn listar_animais_baixados(zoologico: &Zoologico) {
+    println!("\n  === ANIMAIS QUE DEIXARAM O ZOOLÓGICO ===");
+
+    let mut tabela = Tabela::new(&["ID", "TIPO", "NOME", "DATA", "SAÍDA"]);
+    for animal in zoologico.historico().listar_animais_baixados() {
+        let saida = zoologico.historico().saida_do_animal(animal.id);
+        tabela.adicionar_linha(vec![
+            animal.id.to_string(),
+            animal.tipo.clone(),
+            animal.nome.clone(),
+            saida.map_or("-".to_string(), |evento| formatar_data(evento.data)),
+            saida.map_or("-".to_string(), |evento| evento.evento.to_string()),
+        ]);
+    }
+
+    if tabela.is_empty() {
+        println!("   Nenhum animal deixou o zoológico.");
+    } else {
+        print!("{}", tabela.renderizar(3));
+    }
+}
+
+/// Submenu de chegadas, nascimentos, saídas e genealogia
+pub(super) fn menu_ciclo_vida(zoologico: &mut Zoologico) {
+    loop {
+        println!("\n=== CICLO DE VIDA E GENEALOGIA ===");
+        println!("1. Registrar chegada");
+        println!("2. Registrar nascimento");
+        println!("3. Registrar transferência");
+        println!("4. Registrar óbito");
+        println!("5. Histórico de um animal");
+        println!("6. Árvore genealógica");
+        println!("7. Verificar compatibilidade para reprodução");
+        println!("8. Animais que deixaram o zoológico");
+        println!("0. Voltar");
+        print!("Digite o número da opção desejada: ");
+
+        match read_input().as_str() {
+            "1" => registrar_chegada(zoologico),
+            "2" => registrar_nascimento(zoologico),
+            "3" => registrar_transferencia(zoologico),
+            "4" => registrar_obito(zoologico),
+            "5" => historico_animal(zoologico),
+            "6" => arvore_genealogica(zoologico),
+            "7" => compatibilidade_reproducao(zoologico),
+            "8" => listar_animais_baixados(zoologico),
+            "0" => break,
+            _ => println!("Opção inválida!"),
+        }
+    }
+}
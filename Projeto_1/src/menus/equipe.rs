@@ -0,0 +1,215 @@
+use chrono::Local;
+
+use zoologico::equipe::TarefasTratador;
+use zoologico::tabela::Tabela;
+use zoologico::zoologico::Zoologico;
+
+use super::animais::listar_animais;
+use super::recintos::definir_tratador;
+use super::{formatar_data, ler_data, ler_numero, ler_numero_opcional, read_input, salvar};
+
+/// Exibe os tratadores com seus recintos e a quantidade de animais
+pub(super) fn listar_tratadores(zoologico: &Zoologico) {
+    println!("\n  === TRATADORES ===");
+
+    let mut tabela = Tabela::new(&["ID", "NOME", "RECINTOS", "ANIMAIS"]);
+    for tratador in zoologico.equipe().listar_tratadores() {
+        let recintos: Vec<&str> = zoologico
+            .recintos_do_tratador(tratador.id)
+            .iter()
+            .map(|recinto| recinto.nome.as_str())
+            .collect();
+        tabela.adicionar_linha(vec![
+            tratador.id.to_string(),
+            tratador.nome.clone(),
+            if recintos.is_empty() {
+                "-".to_string()
+            } else {
+                recintos.join(", ")
+            },
+            zoologico.animais_do_tratador(tratador.id).len().to_string(),
+        ]);
+    }
+
+    if tabela.is_empty() {
+        println!("   Nenhum tratador cadastrado.");
+    } else {
+        print!("{}", tabela.renderizar(3));
+    }
+}
+
+/// Cadastra um novo tratador
+fn incluir_tratador(zoologico: &mut Zoologico) {
+    print!("Digite o NOME do tratador: ");
+    let nome = read_input();
+
+    match zoologico.incluir_tratador(&nome) {
+        Ok(id) => {
+            salvar(zoologico);
+            println!(" Tratador incluído com sucesso! ID: {}", id);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exclui um tratador e desfaz suas atribuições
+fn excluir_tratador(zoologico: &mut Zoologico) {
+    listar_tratadores(zoologico);
+    let Some(id) = ler_numero("Digite o ID do tratador a ser excluído: ") else {
+        return;
+    };
+
+    match zoologico.excluir_tratador(id) {
+        Ok(tratador) => {
+            salvar(zoologico);
+            println!("  Tratador {} excluído com sucesso!", tratador.nome);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Define um tratador só para um animal, no lugar do tratador do recinto
+fn definir_tratador_animal(zoologico: &mut Zoologico) {
+    listar_animais(zoologico);
+    let Some(id_animal) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+
+    listar_tratadores(zoologico);
+    let Some(id_tratador) = ler_numero_opcional("Digite o ID do tratador (vazio para remover): ")
+    else {
+        return;
+    };
+
+    match zoologico.atribuir_tratador_animal(id_animal, id_tratador) {
+        Ok(()) => {
+            salvar(zoologico);
+            match zoologico.responsavel_pelo_animal(id_animal) {
+                Some(tratador) => println!("  Responsável pelo animal: {}", tratador.nome),
+                None => println!("  O animal está sem tratador responsável."),
+            }
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Gera as tarefas de alimentação, limpeza e exames de um dia
+fn gerar_tarefas(zoologico: &mut Zoologico) {
+    let Some(data) = ler_data("Digite a data") else {
+        return;
+    };
+
+    match zoologico.gerar_tarefas(data) {
+        Ok(criadas) => {
+            salvar(zoologico);
+            println!(
+                "  {} tarefa(s) criada(s) para {}.",
+                criadas,
+                formatar_data(data)
+            );
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exibe tarefas agrupadas por tratador
+fn exibir_tarefas(grupos: &[TarefasTratador]) {
+    for grupo in grupos {
+        println!();
+        println!(
+            "   Tratador: {}",
+            grupo
+                .tratador
+                .as_ref()
+                .map_or("(sem tratador)", |tratador| tratador.nome.as_str())
+        );
+
+        let mut tabela = Tabela::new(&["", "ID", "PRAZO", "TIPO", "TAREFA"]);
+        for tarefa in &grupo.tarefas {
+            tabela.adicionar_linha(vec![
+                if tarefa.concluida() { "[x]" } else { "[ ]" }.to_string(),
+                tarefa.id.to_string(),
+                tarefa.prazo.format("%d/%m %H:%M").to_string(),
+                tarefa.tipo().to_string(),
+                tarefa.descricao.clone(),
+            ]);
+        }
+        print!("{}", tabela.renderizar(3));
+    }
+}
+
+/// Exibe as tarefas de um dia
+fn tarefas_do_dia(zoologico: &Zoologico) {
+    let Some(data) = ler_data("Digite a data") else {
+        return;
+    };
+
+    println!("\n  === TAREFAS DE {} ===", formatar_data(data));
+    let grupos = zoologico.equipe().tarefas_do_dia(data);
+    if grupos.is_empty() {
+        println!("   Nenhuma tarefa para o dia. Gere as tarefas primeiro.");
+        return;
+    }
+    exibir_tarefas(&grupos);
+}
+
+/// Marca uma tarefa como concluída agora
+fn concluir_tarefa(zoologico: &mut Zoologico) {
+    let Some(id) = ler_numero("Digite o ID da tarefa: ") else {
+        return;
+    };
+
+    match zoologico.concluir_tarefa(id, Local::now().naive_local()) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Tarefa concluída!");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exibe as tarefas pendentes com o prazo vencido
+fn tarefas_atrasadas(zoologico: &Zoologico) {
+    println!("\n  === TAREFAS ATRASADAS ===");
+
+    let grupos = zoologico
+        .equipe()
+        .tarefas_atrasadas(Local::now().naive_local());
+    if grupos.is_empty() {
+        println!("   Nenhuma tarefa atrasada.");
+        return;
+    }
+    exibir_tarefas(&grupos);
+}
+
+/// Submenu de tratadores e tarefas diárias
+pub(super) fn menu_equipe(zoologico: &mut Zoologico) {
+    loop {
+        println!("\n=== EQUIPE E TAREFAS ===");
+        println!("1. Listar tratadores");
+        println!("2. Incluir tratador");
+        println!("3. Excluir tratador");
+        println!("4. Definir tratador de recinto");
+        println!("5. Definir tratador de animal");
+        println!("6. Gerar tarefas do dia");
+        println!("7. Tarefas do dia por tratador");
+        println!("8. Concluir tarefa");
+        println!("9. Tarefas atrasadas");
+        println!("0. Voltar");
+        print!("Digite o número da opção desejada: ");
+
+        match read_input().as_str() {
+            "1" => listar_tratadores(zoologico),
+            "2" => incluir_tratador(zoologico),
+            "3" => excluir_tratador(zoologico),
+            "4" => definir_tratador(zoologico),
+            "5" => definir_tratador_animal(zoologico),
+            "6" => gerar_tarefas(zoologico),
+            "7" => tarefas_do_dia(zoologico),
+            "8" => concluir_tarefa(zoologico),
+            "9" => tarefas_atrasadas(zoologico),
+            "0" => break,
+            _ => println!("Opção inválida!"),
+        }
+    }
+}
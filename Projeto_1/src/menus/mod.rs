@@ -0,0 +1,187 @@
+use chrono::{Local, NaiveDate};
+use std::io::{self, Write};
+use std::path::Path;
+
+use zoologico::zoologico::Zoologico;
+
+use crate::{carregar, encerrar_com_erro};
+use alimentacao::menu_alimentacao;
+use animais::{
+    consultar_catalogo, editar_animal, excluir_animal, incluir_animal, listar_animais,
+    pesquisar_animais,
+};
+use bilheteria::menu_bilheteria;
+use ciclo_vida::menu_ciclo_vida;
+use equipe::menu_equipe;
+use recintos::menu_recintos;
+use saude::menu_saude;
+
+mod alimentacao;
+pub(crate) mod animais;
+mod bilheteria;
+mod ciclo_vida;
+mod equipe;
+mod recintos;
+mod saude;
+
+/// Função auxiliar para ler entrada do usuário
+///
+/// #Retorno
+/// String com a entrada do usuário (sem quebras de linha)
+fn read_input() -> String {
+    io::stdout().flush().expect("Falha ao exibir a mensagem");
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .expect("Falha ao ler a entrada");
+    input.trim().to_string()
+}
+
+/// Lê um número (id, quantidade) digitado pelo usuário
+///
+/// # Retorno
+/// `None` se a entrada não for um número válido
+fn ler_numero(mensagem: &str) -> Option<u32> {
+    print!("{}", mensagem);
+    match read_input().parse() {
+        Ok(id) => Some(id),
+        Err(_) => {
+            println!("  Erro: Por favor, insira um número válido.");
+            None
+        }
+    }
+}
+
+/// Lê um número opcional (ex: id de um tratador ou de um dos pais)
+///
+/// # Retorno
+/// `Some(None)` se a entrada for vazia, `None` se o número for inválido
+fn ler_numero_opcional(mensagem: &str) -> Option<Option<u32>> {
+    print!("{}", mensagem);
+    let texto = read_input();
+    if texto.is_empty() {
+        return Some(None);
+    }
+    match texto.parse() {
+        Ok(numero) => Some(Some(numero)),
+        Err(_) => {
+            println!("  Erro: Por favor, insira um número válido.");
+            None
+        }
+    }
+}
+
+/// Lê uma data no formato DD/MM/AAAA; entrada vazia usa a data de hoje
+fn ler_data(mensagem: &str) -> Option<NaiveDate> {
+    print!("{} (DD/MM/AAAA, vazio para hoje): ", mensagem);
+    let texto = read_input();
+    if texto.is_empty() {
+        return Some(Local::now().date_naive());
+    }
+    match NaiveDate::parse_from_str(&texto, "%d/%m/%Y") {
+        Ok(data) => Some(data),
+        Err(_) => {
+            println!("  Erro: Data inválida.");
+            None
+        }
+    }
+}
+
+/// Lê uma data opcional no formato DD/MM/AAAA
+///
+/// # Retorno
+/// `Some(None)` se a entrada for vazia, `None` se a data for inválida
+fn ler_data_opcional(mensagem: &str) -> Option<Option<NaiveDate>> {
+    print!("{} (DD/MM/AAAA, vazio se não houver): ", mensagem);
+    let texto = read_input();
+    if texto.is_empty() {
+        return Some(None);
+    }
+    match NaiveDate::parse_from_str(&texto, "%d/%m/%Y") {
+        Ok(data) => Some(Some(data)),
+        Err(_) => {
+            println!("  Erro: Data inválida.");
+            None
+        }
+    }
+}
+
+/// Salva o zoológico e informa o resultado ao usuário
+fn salvar(zoologico: &Zoologico) {
+    match zoologico.salvar() {
+        Ok(()) => println!("Dados salvos com sucesso!"),
+        Err(e) => eprintln!("{}", e),
+    }
+}
+
+/// Formata uma data para exibição
+fn formatar_data(data: NaiveDate) -> String {
+    data.format("%d/%m/%Y").to_string()
+}
+
+/// Exibe o menu principal do sistema
+///
+/// Mostra todas as opções disponíveis para o usuário
+fn exibir_menu() {
+    println!("\n === SISTEMA DE GERENCIAMENTO DE ANIMAIS === ");
+    println!("Escolha uma das opções abaixo:");
+    println!("1. Listar animais cadastrados");
+    println!("2. Incluir novo animal");
+    println!("3. Editar animal existente");
+    println!("4. Excluir animal");
+    println!("5. Consultar catálogo de espécies");
+    println!("6. Recintos");
+    println!("7. Alimentação e estoque");
+    println!("8. Saúde veterinária");
+    println!("9. Pesquisar e exportar animais");
+    println!("10. Equipe e tarefas");
+    println!("11. Bilheteria");
+    println!("12. Ciclo de vida e genealogia");
+    println!("13. Sair do sistema");
+    println!();
+    print!("Digite o número da opção desejada (1-13): ");
+}
+
+/// Executa o loop do menu interativo
+pub fn menu_interativo(caminho: &Path) {
+    println!("Bem-vindo ao Sistema de Gerenciamento de Animais!");
+
+    // Com o arquivo ilegível o programa para, em vez de começar vazio e apagar os dados
+    let mut zoologico = match carregar(caminho) {
+        Ok(zoologico) => {
+            println!("Dados carregados com sucesso!");
+            zoologico
+        }
+        Err(e) => encerrar_com_erro(&e, caminho),
+    };
+
+    loop {
+        exibir_menu();
+        let opcao = read_input();
+
+        match opcao.as_str() {
+            "1" => listar_animais(&zoologico),
+            "2" => incluir_animal(&mut zoologico),
+            "3" => editar_animal(&mut zoologico),
+            "4" => excluir_animal(&mut zoologico),
+            "5" => consultar_catalogo(&zoologico),
+            "6" => menu_recintos(&mut zoologico),
+            "7" => menu_alimentacao(&mut zoologico),
+            "8" => menu_saude(&mut zoologico),
+            "9" => pesquisar_animais(&zoologico),
+            "10" => menu_equipe(&mut zoologico),
+            "11" => menu_bilheteria(&mut zoologico),
+            "12" => menu_ciclo_vida(&mut zoologico),
+            "13" => {
+                println!(
+                    "Saindo do sistema. Obrigado por usar o Sistema de Gerenciamento de Animais!"
+                );
+                break;
+            }
+            _ => println!("Opção inválida! Por favor, escolha uma opção de 1 a 13."),
+        }
+
+        println!("\nPressione Enter para continuar...");
+        read_input();
+    }
+}
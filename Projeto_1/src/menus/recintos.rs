@@ -0,0 +1,189 @@
+use zoologico::especies::Habitat;
+use zoologico::zoologico::Zoologico;
+
+use super::animais::listar_animais;
+use super::equipe::listar_tratadores;
+use super::{ler_numero, ler_numero_opcional, read_input, salvar};
+
+/// Mostra os habitats disponíveis e lê a escolha do usuário
+fn ler_habitat() -> Option<Habitat> {
+    println!("Habitats disponíveis:");
+    for (indice, habitat) in Habitat::TODOS.iter().enumerate() {
+        println!("{}. {}", indice + 1, habitat);
+    }
+
+    let escolha = ler_numero("Digite o número do habitat: ")?;
+    let habitat = Habitat::TODOS
+        .get((escolha as usize).wrapping_sub(1))
+        .copied();
+    if habitat.is_none() {
+        println!("  Erro: Habitat inválido.");
+    }
+    habitat
+}
+
+/// Exibe o relatório de ocupação de cada recinto
+pub(super) fn relatorio_recintos(zoologico: &Zoologico) {
+    println!("\n  === OCUPAÇÃO DOS RECINTOS ===");
+
+    let relatorio = zoologico.relatorio_ocupacao();
+    if relatorio.is_empty() {
+        println!("   Nenhum recinto cadastrado.");
+        return;
+    }
+
+    for ocupacao in relatorio {
+        println!();
+        println!(
+            "   [{}] {} ({})",
+            ocupacao.id_recinto, ocupacao.nome, ocupacao.habitat
+        );
+        println!(
+            "   Animais: {}/{} ({}%) | Vagas: {} | Área: {}/{} m²",
+            ocupacao.ocupantes,
+            ocupacao.capacidade,
+            ocupacao.percentual(),
+            ocupacao.vagas(),
+            ocupacao.area_ocupada_m2,
+            ocupacao.area_m2
+        );
+        if let Some(tratador) = zoologico
+            .buscar_recinto(ocupacao.id_recinto)
+            .and_then(|recinto| recinto.id_tratador)
+            .and_then(|id| zoologico.equipe().buscar_tratador(id))
+        {
+            println!("   Tratador: {}", tratador.nome);
+        }
+        for animal in zoologico.animais_no_recinto(ocupacao.id_recinto) {
+            println!("     - {} ({})", animal.nome, animal.tipo);
+        }
+    }
+}
+
+/// Cadastra um novo recinto
+fn incluir_recinto(zoologico: &mut Zoologico) {
+    println!("\n=== INCLUIR RECINTO ===");
+
+    print!("Digite o NOME do recinto: ");
+    let nome = read_input();
+
+    let Some(habitat) = ler_habitat() else {
+        return;
+    };
+    let Some(capacidade) = ler_numero("Digite a CAPACIDADE (número de animais): ") else {
+        return;
+    };
+    let Some(area) = ler_numero("Digite a ÁREA do recinto (m²): ") else {
+        return;
+    };
+
+    match zoologico.incluir_recinto(&nome, habitat, capacidade, area) {
+        Ok(id) => {
+            salvar(zoologico);
+            println!(" Recinto incluído com sucesso! ID: {}", id);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Exclui um recinto vazio
+fn excluir_recinto(zoologico: &mut Zoologico) {
+    relatorio_recintos(zoologico);
+
+    let Some(id) = ler_numero("Digite o ID do recinto a ser excluído: ") else {
+        return;
+    };
+
+    match zoologico.excluir_recinto(id) {
+        Ok(recinto) => {
+            salvar(zoologico);
+            println!("  Recinto {} excluído com sucesso!", recinto.nome);
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Coloca um animal num recinto
+fn alocar_animal(zoologico: &mut Zoologico) {
+    listar_animais(zoologico);
+    let Some(id_animal) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+
+    relatorio_recintos(zoologico);
+    let Some(id_recinto) = ler_numero("Digite o ID do recinto de destino: ") else {
+        return;
+    };
+
+    match zoologico.alocar_animal(id_animal, id_recinto) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Animal alocado com sucesso!");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Tira um animal do recinto em que está
+fn retirar_do_recinto(zoologico: &mut Zoologico) {
+    listar_animais(zoologico);
+    let Some(id_animal) = ler_numero("Digite o ID do animal: ") else {
+        return;
+    };
+
+    match zoologico.retirar_do_recinto(id_animal) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Animal retirado do recinto.");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Define o tratador responsável por um recinto
+pub(super) fn definir_tratador(zoologico: &mut Zoologico) {
+    relatorio_recintos(zoologico);
+    let Some(id_recinto) = ler_numero("Digite o ID do recinto: ") else {
+        return;
+    };
+
+    listar_tratadores(zoologico);
+    let Some(id_tratador) = ler_numero_opcional("Digite o ID do tratador (vazio para remover): ")
+    else {
+        return;
+    };
+
+    match zoologico.atribuir_tratador_recinto(id_recinto, id_tratador) {
+        Ok(()) => {
+            salvar(zoologico);
+            println!("  Tratador atualizado!");
+        }
+        Err(e) => println!("  Erro: {}", e),
+    }
+}
+
+/// Submenu de gerenciamento dos recintos
+pub(super) fn menu_recintos(zoologico: &mut Zoologico) {
+    loop {
+        println!("\n=== RECINTOS ===");
+        println!("1. Relatório de ocupação");
+        println!("2. Incluir recinto");
+        println!("3. Excluir recinto");
+        println!("4. Alocar animal em recinto");
+        println!("5. Retirar animal do recinto");
+        println!("6. Definir tratador do recinto");
+        println!("0. Voltar");
+        print!("Digite o número da opção desejada: ");
+
+        match read_input().as_str() {
+            "1" => relatorio_recintos(zoologico),
+            "2" => incluir_recinto(zoologico),
+            "3" => excluir_recinto(zoologico),
+            "4" => alocar_animal(zoologico),
+            "5" => retirar_do_recinto(zoologico),
+            "6" => definir_tratador(zoologico),
+            "0" => break,
+            _ => println!("Opção inválida!"),
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use chrono::Local;
+
+use zoologico::alimentacao::formatar_quantidade;
+use zoologico::zoologico::Zoologico;
+
+use super::animais::listar_animais;
+use super::{formatar_data, ler_data, ler_data_opcional, ler_numero, read_input, salvar};
+
+/// Exibe a ficha médica completa de um animal
+fn exibir_ficha(zoologico: &Zoologico, id_animal: u32) {
+    let Some(animal) = zoologico.buscar_animal(id_animal) else {
+        println!("  Erro: Nenhum animal com o ID {}.", id_animal);
+        return;
+    };
+    println!(
+        "\n  === FICHA MÉDICA: {} ({}) ===",
+        animal.nome, animal.tipo
+    );
+
+    let Some(ficha) = zoologico.clinica().ficha_medica(id_animal) else {
+        println!("   Nenhum registro médico.");
+        return;
+    };
+
+    if let Some(quarentena) = ficha.quarentena_ativa() {
+        println!(
+            "   EM QUARENTENA desde {}: {}",
+            formatar_data(quarentena.inicio),
+            quarentena.motivo
+        );
+    }
+
+    println!("   Vacinas:");
+    for dose in &ficha.vacinacoes {
+        let reforco = dose
+            .proxima_dose
+            .map(|data| format!(" (reforço em {})", formatar_data(data)))
+            .unwrap_or_default();
+        println!(
+            "     - {} em {}{}",
+            dose.vacina,
+            formatar_data(dose.data),
+            reforco
+        );
+    }
+
+    println!("   Tratamentos:");
+    for (indice, tratamento) in ficha.tratamentos.iter().enumerate() {
+        let fim = tratamento
+            .fim
+            .map(formatar_data)
+            .unwrap_or_else(|| "em andamento".to_string());
+        println!(
+            "     {}. {} ({} até {})",
+            indice + 1,
+            tratamento.descricao,
+            formatar_data(tratamento.inicio),
+            fim
+        );
+    }
+
+    println!("   Pesagens:");
+    for pesagem in &ficha.pesagens {
+        println!(
+            "     - {}: {}",
+            formatar_data(pesagem.data),
+            formatar_quantidade(pesagem.peso_g as u64)
+        );
+    }
+
+    println!("   Consultas:");
+    for (indice, consulta) in ficha.consultas.iter().enumerate() {
+        let situacao = match &consulta.observacoes {
+            Some(observacoes) => format!("realizada: {}", observacoes),
+            None => "agendada".to_string(),
+        };
+        println!(
+            "     {}. {} — {} ({})",
+            indice + 1,
+            formatar_data(consulta.data),
+            consulta.descricao,
+            situacao
+        );
+    }
+}
+
+/// Exibe reforços de vacina e consultas dos próximos 30 dias, e a tendência de peso
+fn relatorio_saude(zoologico: &Zoologico) {
+    let hoje = Local::now().date_naive();
+    let limite = hoje + chrono::Duration::days(30);
+
+    println!("\n  === VACINAS PENDENTES (próximos 30 dias) ===");
+    let pendentes = zoologico.vacinas_pendentes(hoje, limite);
+    if pendentes.is_empty() {
+        println!("   Nenhuma vacina pendente.");
+    }
+    for pendente in pendentes {
+        println!(
+            "   {} [{}] {} — {}{}",
+            formatar_data(pendente.data_prevista),
+            pendente.id_animal,
+            pendente.nome_animal,
+            pendente.vacina,
+            if pendente.atrasada { " (ATRASADA)" } else { "" }
+        );
+    }
+
+    println!("\n  === PRÓXIMAS CONSULTAS (próximos 30 dias) ===");
+    let consultas = zoologico.proximas_consultas(limite);
+    if consultas.is_empty() {
+        println!("   Nenhuma consulta agendada.");
+    }
+    for (animal, consulta) in consultas {
+        println!(
+            "   {} [{}] {} — {}",
+            formatar_data(consulta.data),
+            animal.id,
+            animal.nome,
+            consulta.descricao
+        );
+    }
+
+    println!("\n  === TENDÊNCIA DE PESO ===");
+    let relatorio = zoologico.relatorio_peso();
+    if relatorio.is_empty() {
+        println!("   Nenhum animal com duas pesagens ou mais.");
+    }
+    for (animal, tendencia) in relatorio {
+        println!(
+            "   [{}] {}: {} → {} ({:+.1}%, {:+.0} g por semana)",
+            animal.id,
+            animal.nome,
+            formatar_quantidade(tendencia.primeira.peso_g as u64),
+            formatar_quantidade(tendencia.ultima.peso_g as u64),
+            tendencia.variacao_percentual,
+            tendencia.gramas_por_semana
+        );
+    }
+}
+
+/// Submenu da ficha médica dos animais
+pub(super) fn menu_saude(zoologico: &mut Zoologico) {
+    loop {
+        println!("\n=== SAÚDE VETERINÁRIA ===");
+        println!("1. Ver ficha médica");
+        println!("2. Vacinas, consultas e peso (relatório)");
+        println!("3. Registrar vacina");
+        println!("4. Iniciar tratamento");
+        println!("5. Encerrar tratamento");
+        println!("6. Registrar pesagem");
+        println!("7. Iniciar quarentena");
+        println!("8. Encerrar quarentena");
+        println!("9. Agendar consulta");
+        println!("10. Concluir consulta");
+        println!("0. Voltar");
+        print!("Digite o número da opção desejada: ");
+
+        let opcao = read_input();
+        if opcao == "0" {
+            break;
+        }
+        if opcao == "2" {
+            relatorio_saude(zoologico);
+            continue;
+        }
+        if !["1", "3", "4", "5", "6", "7", "8", "9", "10"].contains(&opcao.as_str()) {
+            println!("Opção inválida!");
+            continue;
+        }
+
+        listar_animais(zoologico);
+        let Some(id_animal) = ler_numero("Digite o ID do animal: ") else {
+            continue;
+        };
+
+        let resultado = match opcao.as_str() {
+            "1" => {
+                exibir_ficha(zoologico, id_animal);
+                continue;
+            }
+            "3" => {
+                print!("Digite o nome da VACINA: ");
+                let vacina = read_input();
+                let Some(data) = ler_data("Data da aplicação") else {
+                    continue;
+                };
+                let Some(reforco) = ler_data_opcional("Data do reforço") else {
+                    continue;
+                };
+                zoologico.registrar_vacina(id_animal, &vacina, data, reforco)
+            }
+            "4" => {
+                print!("Descreva o TRATAMENTO: ");
+                let descricao = read_input();
+                let Some(inicio) = ler_data("Data de início") else {
+                    continue;
+                };
+                zoologico
+                    .iniciar_tratamento(id_animal, &descricao, inicio)
+                    .map(|numero| println!("  Tratamento número {} iniciado.", numero))
+            }
+            "5" => {
+                exibir_ficha(zoologico, id_animal);
+                let Some(numero) = ler_numero("Digite o número do tratamento: ") else {
+                    continue;
+                };
+                let Some(fim) = ler_data("Data de encerramento") else {
+                    continue;
+                };
+                zoologico.encerrar_tratamento(id_animal, numero as usize, fim)
+            }
+            "6" => {
+                let Some(peso) = ler_numero("Digite o PESO (gramas): ") else {
+                    continue;
+                };
+                let Some(data) = ler_data("Data da pesagem") else {
+                    continue;
+                };
+                zoologico.registrar_pesagem(id_animal, data, peso)
+            }
+            "7" => {
+                print!("Digite o MOTIVO da quarentena: ");
+                let motivo = read_input();
+                let Some(inicio) = ler_data("Data de início") else {
+                    continue;
+                };
+                zoologico.iniciar_quarentena(id_animal, &motivo, inicio)
+            }
+            "8" => {
+                let Some(fim) = ler_data("Data de encerramento") else {
+                    continue;
+                };
+                zoologico.encerrar_quarentena(id_animal, fim)
+            }
+            "9" => {
+                let Some(data) = ler_data("Data da consulta") else {
+                    continue;
+                };
+                print!("Descreva a CONSULTA: ");
+                let descricao = read_input();
+                zoologico
+                    .agendar_consulta(id_animal, data, &descricao)
+                    .map(|numero| println!("  Consulta número {} agendada.", numero))
+            }
+            _ => {
+                exibir_ficha(zoologico, id_animal);
+                let Some(numero) = ler_numero("Digite o número da consulta: ") else {
+                    continue;
+                };
+                print!("Observações do veterinário: ");
+                let observacoes = read_input();
+                zoologico.concluir_consulta(id_animal, numero as usize, &observacoes)
+            }
+        };
+
+        match resultado {
+            Ok(()) => {
+                salvar(zoologico);
+                println!("  Ficha médica atualizada!");
+            }
+            Err(e) => println!("  Erro: {}", e),
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use chrono::Local;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::errors::ErroZoologico;
+
+/// Quantidade de backups automáticos mantidos para cada arquivo de dados
+pub const MAXIMO_BACKUPS: usize = 20;
+
+/// Nome da pasta de backups, criada ao lado do arquivo de dados
+const PASTA_BACKUPS: &str = "backups";
+
+/// Grava o conteúdo de forma atômica
+///
+/// Os dados vão para um arquivo temporário na mesma pasta, que só substitui
+/// o arquivo original (com `rename`) depois de gravado por completo. Uma falha
+/// no meio da gravação nunca deixa o arquivo original pela metade.
+pub fn gravar_atomico(caminho: &Path, conteudo: &[u8]) -> Result<(), ErroZoologico> {
+    let temporario = caminho_temporario(caminho);
+
+    let resultado = (|| {
+        let mut arquivo = File::create(&temporario)?;
+        arquivo.write_all(conteudo)?;
+        arquivo.sync_all()?;
+        fs::rename(&temporario, caminho)
+    })();
+
+    resultado.map_err(|e| {
+        let _ = fs::remove_file(&temporario);
+        ErroZoologico::ErroPersistencia(format!("Erro ao salvar dados: {}", e))
+    })
+}
+
+/// Copia o arquivo de dados para a pasta de backups com a data e hora no nome
+/// e apaga os backups mais antigos além de `MAXIMO_BACKUPS`
+///
+/// # Retorno
+/// Caminho do backup criado
+pub fn criar_backup(caminho: &Path) -> Result<PathBuf, ErroZoologico> {
+    let backup = copiar_para_backups(caminho, "")?;
+
+    let antigos = listar_backups(caminho);
+    if antigos.len() > MAXIMO_BACKUPS {
+        for antigo in &antigos[..antigos.len() - MAXIMO_BACKUPS] {
+            let _ = fs::remove_file(antigo);
+        }
+    }
+
+    Ok(backup)
+}
+
+/// Guarda uma cópia de um arquivo corrompido antes de ele ser substituído
+///
+/// Essas cópias não entram na rotação dos backups automáticos.
+pub fn guardar_corrompido(caminho: &Path) -> Result<PathBuf, ErroZoologico> {
+    copiar_para_backups(caminho, "corrompido-")
+}
+
+/// Backups automáticos do arquivo de dados, do mais antigo para o mais recente
+pub fn listar_backups(caminho: &Path) -> Vec<PathBuf> {
+    let prefixo = format!("{}-", nome_base(caminho));
+    let Ok(entradas) = fs::read_dir(pasta_backups(caminho)) else {
+        return Vec::new();
+    };
+
+    let mut backups: Vec<PathBuf> = entradas
+        .filter_map(|entrada| entrada.ok().map(|e| e.path()))
+        .filter(|backup| {
+            backup
+                .file_name()
+                .and_then(|nome| nome.to_str())
+                .and_then(|nome| nome.strip_prefix(&prefixo))
+                .is_some_and(|resto| resto.starts_with(|c: char| c.is_ascii_digit()))
+        })
+        .collect();
+    // O nome começa com a data e hora, então a ordem alfabética é a cronológica
+    backups.sort();
+    backups
+}
+
+/// Registro encontrado num arquivo possivelmente corrompido
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistroBruto<'a> {
+    /// Lista em que o registro estava (ex: "animais"), ou `None` quando o
+    /// arquivo é uma lista simples no formato antigo
+    pub secao: Option<String>,
+    /// Texto JSON do objeto, de `{` até o `}` correspondente
+    pub texto: &'a str,
+}
+
+/// Procura os objetos completos guardados nas listas do arquivo de dados
+///
+/// A leitura é tolerante: um arquivo cortado no meio ou com lixo no final
+/// ainda devolve todos os registros que foram fechados antes do problema.
+/// Se a estrutura ficar inconsistente (chaves que não se fecham), a leitura
+/// para ali.
+pub fn extrair_registros(texto: &str) -> Vec<RegistroBruto<'_>> {
+    let mut registros = Vec::new();
+    let mut pilha: Vec<char> = Vec::new();
+    let mut em_texto = false;
+    let mut escape = false;
+    let mut inicio_texto = 0;
+    let mut ultimo_texto = "";
+    let mut secao: Option<String> = None;
+    let mut registro_aberto: Option<(usize, Option<String>)> = None;
+
+    for (posicao, c) in texto.char_indices() {
+        if em_texto {
+            if escape {
+                escape = false;
+            } else if c == '\\' {
+                escape = true;
+            } else if c == '"' {
+                em_texto = false;
+                ultimo_texto = &texto[inicio_texto..posicao];
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                em_texto = true;
+                inicio_texto = posicao + 1;
+            }
+            // Chave de primeiro nível: nome da lista que vem a seguir
+            ':' if pilha == ['{'] => secao = Some(ultimo_texto.to_string()),
+            '{' | '[' => {
+                if c == '{' {
+                    match pilha.as_slice() {
+                        ['['] => registro_aberto = Some((posicao, None)),
+                        ['{', '['] => registro_aberto = Some((posicao, secao.clone())),
+                        _ => {}
+                    }
+                }
+                pilha.push(c);
+            }
+            '}' | ']' => {
+                let abertura = if c == '}' { '{' } else { '[' };
+                if pilha.pop() != Some(abertura) {
+                    break;
+                }
+                if c == '}'
+                    && matches!(pilha.as_slice(), ['['] | ['{', '['])
+                    && let Some((inicio, secao)) = registro_aberto.take()
+                {
+                    registros.push(RegistroBruto {
+                        secao,
+                        texto: &texto[inicio..=posicao],
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    registros
+}
+
+/// Registros ignorados ao montar o zoológico
+///
+/// # Campos
+/// - `repetidos`: Registros com id já usado por outro registro
+/// - `fora_de_faixa`: Registros com id `u32::MAX`, que não deixa um próximo id livre
+#[derive(Debug, Default)]
+pub(crate) struct Descartes {
+    pub repetidos: usize,
+    pub fora_de_faixa: usize,
+}
+
+impl Descartes {
+    pub fn total(&self) -> usize {
+        self.repetidos + self.fora_de_faixa
+    }
+}
+
+/// Insere o registro e avança o contador para depois do seu id
+///
+/// Registros com id fora da faixa são descartados em vez de estourar o contador.
+pub(crate) fn inserir_com_id<T>(
+    mapa: &mut BTreeMap<u32, T>,
+    id: u32,
+    registro: T,
+    proximo_id: &mut u32,
+    descartes: &mut Descartes,
+) {
+    match id.checked_add(1) {
+        Some(seguinte) => {
+            *proximo_id = (*proximo_id).max(seguinte);
+            inserir_sem_repetir(mapa, id, registro, &mut descartes.repetidos);
+        }
+        None => descartes.fora_de_faixa += 1,
+    }
+}
+
+/// Reserva o próximo id do cadastro e avança o contador
+///
+/// # Retorno
+/// `IdsEsgotados` se o contador já estiver no limite, sem alterá-lo
+pub(crate) fn reservar_id(proximo_id: &mut u32, cadastro: &str) -> Result<u32, ErroZoologico> {
+    let id = *proximo_id;
+    *proximo_id = id
+        .checked_add(1)
+        .ok_or_else(|| ErroZoologico::IdsEsgotados(cadastro.to_string()))?;
+    Ok(id)
+}
+
+/// Insere o registro mantendo o primeiro em caso de id repetido
+pub(crate) fn inserir_sem_repetir<T>(
+    mapa: &mut BTreeMap<u32, T>,
+    id: u32,
+    registro: T,
+    repetidos: &mut usize,
+) {
+    match mapa.entry(id) {
+        Entry::Occupied(_) => *repetidos += 1,
+        Entry::Vacant(vaga) => {
+            vaga.insert(registro);
+        }
+    }
+}
+
+fn caminho_temporario(caminho: &Path) -> PathBuf {
+    let mut nome = caminho.file_name().unwrap_or_default().to_os_string();
+    nome.push(".tmp");
+    caminho.with_file_name(nome)
+}
+
+fn pasta_backups(caminho: &Path) -> PathBuf {
+    caminho
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(PASTA_BACKUPS)
+}
+
+fn nome_base(caminho: &Path) -> String {
+    caminho
+        .file_stem()
+        .and_then(|nome| nome.to_str())
+        .unwrap_or("dados")
+        .to_string()
+}
+
+fn copiar_para_backups(caminho: &Path, marcador: &str) -> Result<PathBuf, ErroZoologico> {
+    let pasta = pasta_backups(caminho);
+    fs::create_dir_all(&pasta).map_err(|e| {
+        ErroZoologico::ErroPersistencia(format!("Erro ao criar pasta de backups: {}", e))
+    })?;
+
+    let extensao = caminho
+        .extension()
+        .and_then(|extensao| extensao.to_str())
+        .unwrap_or("json");
+    let backup = pasta.join(format!(
+        "{}-{}{}.{}",
+        nome_base(caminho),
+        marcador,
+        Local::now().format("%Y%m%d-%H%M%S%.6f"),
+        extensao
+    ));
+
+    fs::copy(caminho, &backup)
+        .map_err(|e| ErroZoologico::ErroPersistencia(format!("Erro ao criar backup: {}", e)))?;
+    Ok(backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_gravar_atomico_substitui_arquivo() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        fs::write(&caminho, "antigo").unwrap();
+
+        gravar_atomico(&caminho, b"novo").unwrap();
+        assert_eq!(fs::read_to_string(&caminho).unwrap(), "novo");
+        assert!(!caminho_temporario(&caminho).exists());
+    }
+
+    #[test]
+    fn test_backups_em_rotacao() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        fs::write(&caminho, "dados").unwrap();
+
+        for _ in 0..MAXIMO_BACKUPS + 3 {
+            criar_backup(&caminho).unwrap();
+        }
+        guardar_corrompido(&caminho).unwrap();
+
+        let backups = listar_backups(&caminho);
+        assert_eq!(backups.len(), MAXIMO_BACKUPS);
+        assert_eq!(fs::read_to_string(&backups[0]).unwrap(), "dados");
+        // A cópia do arquivo corrompido fica fora da rotação
+        assert_eq!(
+            fs::read_dir(pasta_backups(&caminho)).unwrap().count(),
+            MAXIMO_BACKUPS + 1
+        );
+    }
+
+    #[test]
+    fn test_extrair_registros_de_arquivo_cortado() {
+        let texto = r#"{"proximo_id":4,"animais":[{"id":1,"tipo":"Leão","nome":"Sim}ba"},
+            {"id":2,"tipo":"Zebra","nome":"Marty"},{"id":3,"tipo":"Gir"#;
+
+        let registros = extrair_registros(texto);
+        assert_eq!(registros.len(), 2);
+        assert_eq!(registros[0].secao.as_deref(), Some("animais"));
+        assert_eq!(
+            registros[0].texto,
+            r#"{"id":1,"tipo":"Leão","nome":"Sim}ba"}"#
+        );
+    }
+
+    #[test]
+    fn test_extrair_registros_formato_legado() {
+        let registros = extrair_registros(r#"[{"tipo":"boi","nome":"boizinho"}, {"tipo""#);
+        assert_eq!(registros.len(), 1);
+        assert_eq!(registros[0].secao, None);
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::especies::{Dieta, Especie, Habitat};
+
+/// Estrutura que representa um recinto do zoológico
+///
+/// # Campos
+/// - `id`: Identificador estável do recinto, nunca reutilizado
+/// - `nome`: Nome do recinto (ex: Savana Africana)
+/// - `habitat`: Ambiente reproduzido no recinto
+/// - `capacidade`: Número máximo de animais
+/// - `area_m2`: Tamanho do recinto em metros quadrados
+/// - `id_tratador`: Tratador responsável pelos animais do recinto
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Recinto {
+    pub id: u32,
+    pub nome: String,
+    pub habitat: Habitat,
+    pub capacidade: u32,
+    pub area_m2: u32,
+    #[serde(default)]
+    pub id_tratador: Option<u32>,
+    /// Nome do tratador gravado pelas versões antigas, convertido em
+    /// cadastro de tratador ao carregar o arquivo
+    #[serde(default, rename = "tratador", skip_serializing)]
+    pub(crate) tratador_legado: Option<String>,
+}
+
+impl Recinto {
+    /// Cria uma nova instância de Recinto
+    ///
+    /// # Parâmetros
+    /// - `id`: Identificador do recinto
+    /// - `nome`: Nome do recinto
+    /// - `habitat`: Ambiente do recinto
+    /// - `capacidade`: Número máximo de animais
+    /// - `area_m2`: Tamanho do recinto
+    ///
+    /// # Retorno
+    /// Nova instância de Recinto
+    pub fn new(id: u32, nome: String, habitat: Habitat, capacidade: u32, area_m2: u32) -> Recinto {
+        Recinto {
+            id,
+            nome,
+            habitat,
+            capacidade,
+            area_m2,
+            id_tratador: None,
+            tratador_legado: None,
+        }
+    }
+}
+
+/// Situação de um recinto no relatório de ocupação
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcupacaoRecinto {
+    pub id_recinto: u32,
+    pub nome: String,
+    pub habitat: Habitat,
+    pub ocupantes: u32,
+    pub capacidade: u32,
+    /// Soma da área mínima exigida pelas espécies dos ocupantes
+    pub area_ocupada_m2: u32,
+    pub area_m2: u32,
+}
+
+impl OcupacaoRecinto {
+    /// Percentual da capacidade em uso (0 a 100)
+    pub fn percentual(&self) -> u32 {
+        if self.capacidade == 0 {
+            return 0;
+        }
+        self.ocupantes * 100 / self.capacidade
+    }
+
+    pub fn vagas(&self) -> u32 {
+        self.capacidade.saturating_sub(self.ocupantes)
+    }
+}
+
+/// Verifica se as duas espécies formam um par predador/presa
+///
+/// Um carnívoro só pode dividir o recinto com animais da própria espécie.
+///
+/// # Retorno
+/// O nome do predador, se houver conflito
+pub fn predador_de<'a>(a: &'a Especie, b: &'a Especie) -> Option<&'a str> {
+    if a.nome == b.nome {
+        return None;
+    }
+    if a.dieta == Dieta::Carnivoro {
+        Some(&a.nome)
+    } else if b.dieta == Dieta::Carnivoro {
+        Some(&b.nome)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::especies::CatalogoEspecies;
+
+    #[test]
+    fn test_predador_de() {
+        let catalogo = CatalogoEspecies::padrao();
+        let leao = catalogo.buscar("Leão").unwrap();
+        let zebra = catalogo.buscar("Zebra").unwrap();
+        let girafa = catalogo.buscar("Girafa").unwrap();
+
+        assert_eq!(predador_de(leao, zebra), Some("Leão"));
+        assert_eq!(predador_de(zebra, leao), Some("Leão"));
+        assert_eq!(predador_de(zebra, girafa), None);
+        assert_eq!(predador_de(leao, leao), None);
+    }
+
+    #[test]
+    fn test_percentual_ocupacao() {
+        let ocupacao = OcupacaoRecinto {
+            id_recinto: 1,
+            nome: "Savana".to_string(),
+            habitat: Habitat::Savana,
+            ocupantes: 3,
+            capacidade: 4,
+            area_ocupada_m2: 900,
+            area_m2: 2000,
+        };
+        assert_eq!(ocupacao.percentual(), 75);
+        assert_eq!(ocupacao.vagas(), 1);
+    }
+}
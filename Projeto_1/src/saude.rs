@@ -0,0 +1,559 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::animais::InfoAnimal;
+use crate::errors::ErroZoologico;
+use crate::persistencia::{self, Descartes};
+
+/// Dose de vacina aplicada
+///
+/// # Campos
+/// - `vacina`: Nome da vacina (ex: Raiva)
+/// - `data`: Data da aplicação
+/// - `proxima_dose`: Data prevista do reforço, se houver
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Vacinacao {
+    pub vacina: String,
+    pub data: NaiveDate,
+    pub proxima_dose: Option<NaiveDate>,
+}
+
+/// Tratamento médico, em andamento enquanto `fim` for `None`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Tratamento {
+    pub descricao: String,
+    pub inicio: NaiveDate,
+    pub fim: Option<NaiveDate>,
+}
+
+/// Peso medido numa data, em gramas
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Pesagem {
+    pub data: NaiveDate,
+    pub peso_g: u32,
+}
+
+/// Período de quarentena, ativo enquanto `fim` for `None`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Quarentena {
+    pub motivo: String,
+    pub inicio: NaiveDate,
+    pub fim: Option<NaiveDate>,
+}
+
+/// Consulta veterinária agendada
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Consulta {
+    pub data: NaiveDate,
+    pub descricao: String,
+    /// Anotações do veterinário; preenchido quando a consulta é realizada
+    pub observacoes: Option<String>,
+}
+
+impl Consulta {
+    pub fn realizada(&self) -> bool {
+        self.observacoes.is_some()
+    }
+}
+
+/// Ficha médica de um animal
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct FichaMedica {
+    pub id_animal: u32,
+    #[serde(default)]
+    pub vacinacoes: Vec<Vacinacao>,
+    #[serde(default)]
+    pub tratamentos: Vec<Tratamento>,
+    #[serde(default)]
+    pub pesagens: Vec<Pesagem>,
+    #[serde(default)]
+    pub quarentenas: Vec<Quarentena>,
+    #[serde(default)]
+    pub consultas: Vec<Consulta>,
+}
+
+impl FichaMedica {
+    pub fn new(id_animal: u32) -> FichaMedica {
+        FichaMedica {
+            id_animal,
+            ..FichaMedica::default()
+        }
+    }
+
+    pub fn quarentena_ativa(&self) -> Option<&Quarentena> {
+        self.quarentenas.iter().find(|q| q.fim.is_none())
+    }
+
+    /// Situação de saúde atual; a quarentena prevalece sobre o tratamento
+    pub fn situacao(&self) -> SituacaoSaude {
+        if self.quarentena_ativa().is_some() {
+            SituacaoSaude::EmQuarentena
+        } else if self.tratamentos.iter().any(|t| t.fim.is_none()) {
+            SituacaoSaude::EmTratamento
+        } else {
+            SituacaoSaude::Saudavel
+        }
+    }
+
+    /// Última dose de cada vacina, em ordem alfabética do nome da vacina
+    pub fn ultimas_doses(&self) -> Vec<&Vacinacao> {
+        let mut ultimas: Vec<&Vacinacao> = Vec::new();
+        for dose in &self.vacinacoes {
+            match ultimas
+                .iter_mut()
+                .find(|u| u.vacina.to_lowercase() == dose.vacina.to_lowercase())
+            {
+                Some(ultima) if ultima.data <= dose.data => *ultima = dose,
+                Some(_) => {}
+                None => ultimas.push(dose),
+            }
+        }
+        ultimas.sort_by(|a, b| a.vacina.cmp(&b.vacina));
+        ultimas
+    }
+
+    /// Variação de peso entre a primeira e a última pesagem
+    ///
+    /// # Retorno
+    /// `None` se houver menos de duas pesagens em datas diferentes
+    pub fn tendencia_peso(&self) -> Option<TendenciaPeso> {
+        let primeira = self.pesagens.iter().min_by_key(|p| p.data)?;
+        let ultima = self.pesagens.iter().max_by_key(|p| p.data)?;
+        let dias = (ultima.data - primeira.data).num_days();
+        if dias == 0 {
+            return None;
+        }
+
+        let variacao_g = ultima.peso_g as i64 - primeira.peso_g as i64;
+        Some(TendenciaPeso {
+            primeira: primeira.clone(),
+            ultima: ultima.clone(),
+            variacao_g,
+            variacao_percentual: variacao_g as f64 * 100.0 / primeira.peso_g as f64,
+            gramas_por_semana: variacao_g as f64 * 7.0 / dias as f64,
+        })
+    }
+}
+
+/// Situação de saúde de um animal, usada em consultas e relatórios
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SituacaoSaude {
+    Saudavel,
+    EmTratamento,
+    EmQuarentena,
+}
+
+impl fmt::Display for SituacaoSaude {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            SituacaoSaude::Saudavel => "Saudável",
+            SituacaoSaude::EmTratamento => "Em tratamento",
+            SituacaoSaude::EmQuarentena => "Em quarentena",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Resultado da comparação entre a primeira e a última pesagem
+#[derive(Debug, Clone, PartialEq)]
+pub struct TendenciaPeso {
+    pub primeira: Pesagem,
+    pub ultima: Pesagem,
+    pub variacao_g: i64,
+    pub variacao_percentual: f64,
+    pub gramas_por_semana: f64,
+}
+
+/// Reforço de vacina previsto até a data consultada
+#[derive(Debug, Clone, PartialEq)]
+pub struct VacinaPendente {
+    pub id_animal: u32,
+    pub nome_animal: String,
+    pub vacina: String,
+    pub data_prevista: NaiveDate,
+    /// A data prevista já passou
+    pub atrasada: bool,
+}
+
+/// Fichas médicas dos animais, indexadas pelo id do animal
+///
+/// A ficha é criada no primeiro registro feito para o animal. Quem chama
+/// confere se o animal existe antes de registrar.
+#[derive(Debug, Default)]
+pub struct Clinica {
+    fichas: BTreeMap<u32, FichaMedica>,
+}
+
+impl Clinica {
+    /// Monta as fichas lidas do arquivo de dados
+    ///
+    /// Fichas repetidas para o mesmo animal são contadas em `descartes`.
+    pub(crate) fn carregar(fichas: Vec<FichaMedica>, descartes: &mut Descartes) -> Self {
+        let mut clinica = Clinica::default();
+        for ficha in fichas {
+            persistencia::inserir_sem_repetir(
+                &mut clinica.fichas,
+                ficha.id_animal,
+                ficha,
+                &mut descartes.repetidos,
+            );
+        }
+        clinica
+    }
+
+    pub fn ficha_medica(&self, id_animal: u32) -> Option<&FichaMedica> {
+        self.fichas.get(&id_animal)
+    }
+
+    pub fn listar_fichas(&self) -> Vec<&FichaMedica> {
+        self.fichas.values().collect()
+    }
+
+    pub fn em_quarentena(&self, id_animal: u32) -> bool {
+        self.ficha_medica(id_animal)
+            .is_some_and(|ficha| ficha.quarentena_ativa().is_some())
+    }
+
+    /// Remove a ficha do animal, se houver
+    pub(crate) fn remover_ficha(&mut self, id_animal: u32) {
+        self.fichas.remove(&id_animal);
+    }
+
+    /// Mantém só as fichas dos animais aceitos por `manter`
+    ///
+    /// # Retorno
+    /// Quantidade de fichas removidas
+    pub(crate) fn reter_fichas(&mut self, manter: impl Fn(u32) -> bool) -> usize {
+        let antes = self.fichas.len();
+        self.fichas.retain(|id, _| manter(*id));
+        antes - self.fichas.len()
+    }
+
+    /// Registra uma dose de vacina aplicada
+    ///
+    /// # Parâmetros
+    /// - `proxima_dose`: Data prevista do reforço, se a vacina tiver reforço
+    pub(crate) fn registrar_vacina(
+        &mut self,
+        id_animal: u32,
+        vacina: &str,
+        data: NaiveDate,
+        proxima_dose: Option<NaiveDate>,
+    ) -> Result<(), ErroZoologico> {
+        let vacina = vacina.trim();
+        if vacina.is_empty() {
+            return Err(ErroZoologico::CampoVazio("vacina".to_string()));
+        }
+        self.ficha_mut(id_animal).vacinacoes.push(Vacinacao {
+            vacina: vacina.to_string(),
+            data,
+            proxima_dose,
+        });
+        Ok(())
+    }
+
+    /// Inicia um tratamento
+    ///
+    /// # Retorno
+    /// Número do tratamento na ficha (a partir de 1), usado para encerrá-lo
+    pub(crate) fn iniciar_tratamento(
+        &mut self,
+        id_animal: u32,
+        descricao: &str,
+        inicio: NaiveDate,
+    ) -> Result<usize, ErroZoologico> {
+        let descricao = descricao.trim();
+        if descricao.is_empty() {
+            return Err(ErroZoologico::CampoVazio("descrição".to_string()));
+        }
+        let ficha = self.ficha_mut(id_animal);
+        ficha.tratamentos.push(Tratamento {
+            descricao: descricao.to_string(),
+            inicio,
+            fim: None,
+        });
+        Ok(ficha.tratamentos.len())
+    }
+
+    pub(crate) fn encerrar_tratamento(
+        &mut self,
+        id_animal: u32,
+        numero: usize,
+        fim: NaiveDate,
+    ) -> Result<(), ErroZoologico> {
+        let tratamento = self
+            .fichas
+            .get_mut(&id_animal)
+            .and_then(|ficha| ficha.tratamentos.get_mut(numero.wrapping_sub(1)))
+            .filter(|t| t.fim.is_none())
+            .ok_or(ErroZoologico::RegistroMedicoNaoEncontrado { id_animal, numero })?;
+        tratamento.fim = Some(fim);
+        Ok(())
+    }
+
+    /// Registra o peso do animal, em gramas
+    pub(crate) fn registrar_pesagem(
+        &mut self,
+        id_animal: u32,
+        data: NaiveDate,
+        peso_g: u32,
+    ) -> Result<(), ErroZoologico> {
+        if peso_g == 0 {
+            return Err(ErroZoologico::CampoVazio("peso".to_string()));
+        }
+        let ficha = self.ficha_mut(id_animal);
+        ficha.pesagens.push(Pesagem { data, peso_g });
+        ficha.pesagens.sort_by_key(|p| p.data);
+        Ok(())
+    }
+
+    /// Coloca o animal em quarentena
+    pub(crate) fn iniciar_quarentena(
+        &mut self,
+        id_animal: u32,
+        motivo: &str,
+        inicio: NaiveDate,
+    ) -> Result<(), ErroZoologico> {
+        let motivo = motivo.trim();
+        if motivo.is_empty() {
+            return Err(ErroZoologico::CampoVazio("motivo".to_string()));
+        }
+        if self.em_quarentena(id_animal) {
+            return Err(ErroZoologico::AnimalEmQuarentena(id_animal));
+        }
+        self.ficha_mut(id_animal).quarentenas.push(Quarentena {
+            motivo: motivo.to_string(),
+            inicio,
+            fim: None,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn encerrar_quarentena(
+        &mut self,
+        id_animal: u32,
+        fim: NaiveDate,
+    ) -> Result<(), ErroZoologico> {
+        let quarentena = self
+            .fichas
+            .get_mut(&id_animal)
+            .and_then(|ficha| ficha.quarentenas.iter_mut().find(|q| q.fim.is_none()))
+            .ok_or(ErroZoologico::AnimalSemQuarentena(id_animal))?;
+        quarentena.fim = Some(fim);
+        Ok(())
+    }
+
+    /// Agenda uma consulta veterinária
+    ///
+    /// # Retorno
+    /// Número da consulta na ficha (a partir de 1), usado para concluí-la
+    pub(crate) fn agendar_consulta(
+        &mut self,
+        id_animal: u32,
+        data: NaiveDate,
+        descricao: &str,
+    ) -> Result<usize, ErroZoologico> {
+        let descricao = descricao.trim();
+        if descricao.is_empty() {
+            return Err(ErroZoologico::CampoVazio("descrição".to_string()));
+        }
+        let ficha = self.ficha_mut(id_animal);
+        ficha.consultas.push(Consulta {
+            data,
+            descricao: descricao.to_string(),
+            observacoes: None,
+        });
+        Ok(ficha.consultas.len())
+    }
+
+    /// Marca a consulta como realizada, com as anotações do veterinário
+    ///
+    /// # Retorno
+    /// Data em que a consulta estava marcada
+    pub(crate) fn concluir_consulta(
+        &mut self,
+        id_animal: u32,
+        numero: usize,
+        observacoes: &str,
+    ) -> Result<NaiveDate, ErroZoologico> {
+        let consulta = self
+            .fichas
+            .get_mut(&id_animal)
+            .and_then(|ficha| ficha.consultas.get_mut(numero.wrapping_sub(1)))
+            .filter(|c| !c.realizada())
+            .ok_or(ErroZoologico::RegistroMedicoNaoEncontrado { id_animal, numero })?;
+        consulta.observacoes = Some(observacoes.trim().to_string());
+        Ok(consulta.data)
+    }
+
+    /// Consultas ainda não realizadas marcadas até a data informada (inclusive)
+    ///
+    /// Fichas de animais que não estão em `animais` são ignoradas.
+    ///
+    /// # Retorno
+    /// Pares (animal, consulta) em ordem de data
+    pub(crate) fn proximas_consultas<'a>(
+        &'a self,
+        animais: &'a BTreeMap<u32, InfoAnimal>,
+        ate: NaiveDate,
+    ) -> Vec<(&'a InfoAnimal, &'a Consulta)> {
+        let mut consultas: Vec<(&InfoAnimal, &Consulta)> = self
+            .fichas
+            .values()
+            .filter_map(|ficha| Some((animais.get(&ficha.id_animal)?, ficha)))
+            .flat_map(|(animal, ficha)| {
+                ficha
+                    .consultas
+                    .iter()
+                    .filter(move |c| !c.realizada() && c.data <= ate)
+                    .map(move |c| (animal, c))
+            })
+            .collect();
+        consultas.sort_by_key(|(animal, consulta)| (consulta.data, animal.id));
+        consultas
+    }
+
+    /// Reforços de vacina previstos até a data informada
+    ///
+    /// Considera apenas a dose mais recente de cada vacina; doses com data
+    /// anterior a `hoje` são marcadas como atrasadas.
+    pub(crate) fn vacinas_pendentes(
+        &self,
+        animais: &BTreeMap<u32, InfoAnimal>,
+        hoje: NaiveDate,
+        ate: NaiveDate,
+    ) -> Vec<VacinaPendente> {
+        let mut pendentes = Vec::new();
+        for ficha in self.fichas.values() {
+            let Some(animal) = animais.get(&ficha.id_animal) else {
+                continue;
+            };
+            for dose in ficha.ultimas_doses() {
+                let Some(data_prevista) = dose.proxima_dose else {
+                    continue;
+                };
+                if data_prevista <= ate {
+                    pendentes.push(VacinaPendente {
+                        id_animal: animal.id,
+                        nome_animal: animal.nome.clone(),
+                        vacina: dose.vacina.clone(),
+                        data_prevista,
+                        atrasada: data_prevista < hoje,
+                    });
+                }
+            }
+        }
+        pendentes.sort_by(|a, b| {
+            a.data_prevista
+                .cmp(&b.data_prevista)
+                .then_with(|| a.id_animal.cmp(&b.id_animal))
+        });
+        pendentes
+    }
+
+    /// Tendência de peso de cada animal com ao menos duas pesagens
+    pub(crate) fn relatorio_peso<'a>(
+        &'a self,
+        animais: &'a BTreeMap<u32, InfoAnimal>,
+    ) -> Vec<(&'a InfoAnimal, TendenciaPeso)> {
+        self.fichas
+            .values()
+            .filter_map(|ficha| {
+                let animal = animais.get(&ficha.id_animal)?;
+                Some((animal, ficha.tendencia_peso()?))
+            })
+            .collect()
+    }
+
+    fn ficha_mut(&mut self, id_animal: u32) -> &mut FichaMedica {
+        self.fichas
+            .entry(id_animal)
+            .or_insert_with(|| FichaMedica::new(id_animal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, dia).unwrap()
+    }
+
+    #[test]
+    fn test_ultimas_doses() {
+        let mut ficha = FichaMedica::new(1);
+        for (vacina, dia) in [("Raiva", 10), ("Raiva", 3), ("Tétano", 5)] {
+            ficha.vacinacoes.push(Vacinacao {
+                vacina: vacina.to_string(),
+                data: data(dia),
+                proxima_dose: None,
+            });
+        }
+
+        let ultimas = ficha.ultimas_doses();
+        assert_eq!(ultimas.len(), 2);
+        assert_eq!(ultimas[0].vacina, "Raiva");
+        assert_eq!(ultimas[0].data, data(10));
+    }
+
+    #[test]
+    fn test_tendencia_peso() {
+        let mut ficha = FichaMedica::new(1);
+        ficha.pesagens.push(Pesagem {
+            data: data(1),
+            peso_g: 100_000,
+        });
+        assert!(ficha.tendencia_peso().is_none());
+
+        ficha.pesagens.push(Pesagem {
+            data: data(15),
+            peso_g: 90_000,
+        });
+        let tendencia = ficha.tendencia_peso().unwrap();
+        assert_eq!(tendencia.variacao_g, -10_000);
+        assert_eq!(tendencia.variacao_percentual, -10.0);
+        assert_eq!(tendencia.gramas_por_semana, -5_000.0);
+    }
+
+    #[test]
+    fn test_clinica_registra_e_ignora_animais_ausentes() {
+        let mut clinica = Clinica::default();
+        assert!(matches!(
+            clinica.encerrar_quarentena(1, data(2)),
+            Err(ErroZoologico::AnimalSemQuarentena(1))
+        ));
+        assert!(clinica.ficha_medica(1).is_none());
+
+        clinica.iniciar_quarentena(1, "Chegada", data(1)).unwrap();
+        assert!(clinica.em_quarentena(1));
+        assert!(matches!(
+            clinica.iniciar_quarentena(1, "Outra", data(2)),
+            Err(ErroZoologico::AnimalEmQuarentena(1))
+        ));
+        let consulta = clinica.agendar_consulta(2, data(5), "Retorno").unwrap();
+        assert_eq!(
+            clinica
+                .concluir_consulta(2, consulta, " Tudo certo ")
+                .unwrap(),
+            data(5)
+        );
+        assert!(clinica.concluir_consulta(2, consulta, "").is_err());
+
+        let animais = BTreeMap::from([(
+            1,
+            InfoAnimal::new(1, "Zebra".to_string(), "Listrada".to_string()),
+        )]);
+        clinica.agendar_consulta(1, data(3), "Exame").unwrap();
+        clinica.agendar_consulta(2, data(3), "Exame").unwrap();
+        let consultas = clinica.proximas_consultas(&animais, data(31));
+        assert_eq!(consultas.len(), 1);
+        assert_eq!(consultas[0].0.id, 1);
+
+        assert_eq!(clinica.reter_fichas(|id| animais.contains_key(&id)), 1);
+        assert_eq!(clinica.listar_fichas().len(), 1);
+    }
+}
@@ -0,0 +1,106 @@
+use unicode_width::UnicodeWidthStr;
+
+/// Tabela de texto com colunas alinhadas pelo conteúdo
+///
+/// A largura de cada coluna é a da maior célula, medida em colunas do
+/// terminal: letras acentuadas ocupam uma coluna e ideogramas ocupam duas.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tabela {
+    cabecalho: Vec<String>,
+    linhas: Vec<Vec<String>>,
+}
+
+impl Tabela {
+    /// Cria uma tabela vazia com os títulos das colunas
+    pub fn new(cabecalho: &[&str]) -> Tabela {
+        Tabela {
+            cabecalho: cabecalho.iter().map(|titulo| titulo.to_string()).collect(),
+            linhas: Vec::new(),
+        }
+    }
+
+    /// Acrescenta uma linha; células a mais que o cabeçalho são ignoradas
+    /// e as que faltarem ficam em branco
+    pub fn adicionar_linha(&mut self, celulas: Vec<String>) {
+        let mut celulas = celulas;
+        celulas.resize(self.cabecalho.len(), String::new());
+        self.linhas.push(celulas);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.linhas.is_empty()
+    }
+
+    /// Monta o texto da tabela, uma linha por registro
+    ///
+    /// # Parâmetros
+    /// - `recuo`: Espaços antes de cada linha
+    pub fn renderizar(&self, recuo: usize) -> String {
+        let larguras: Vec<usize> = (0..self.cabecalho.len())
+            .map(|coluna| {
+                self.linhas
+                    .iter()
+                    .map(|linha| linha[coluna].width())
+                    .chain([self.cabecalho[coluna].width()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let recuo = " ".repeat(recuo);
+        let separador: Vec<String> = larguras.iter().map(|&l| "-".repeat(l)).collect();
+
+        let mut texto = format!("{}{}\n", recuo, formatar_linha(&self.cabecalho, &larguras));
+        texto.push_str(&format!("{}{}\n", recuo, separador.join("-|-")));
+        for linha in &self.linhas {
+            texto.push_str(&format!("{}{}\n", recuo, formatar_linha(linha, &larguras)));
+        }
+        texto
+    }
+}
+
+/// Completa cada célula com espaços até a largura da coluna
+fn formatar_linha(celulas: &[String], larguras: &[usize]) -> String {
+    let colunas: Vec<String> = celulas
+        .iter()
+        .zip(larguras)
+        .map(|(celula, &largura)| {
+            format!(
+                "{}{}",
+                celula,
+                " ".repeat(largura.saturating_sub(celula.width()))
+            )
+        })
+        .collect();
+    colunas.join(" | ").trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colunas_alinhadas_com_acentos() {
+        let mut tabela = Tabela::new(&["ID", "ESPÉCIE", "NOME"]);
+        tabela.adicionar_linha(vec!["1".into(), "Onça-pintada".into(), "Pintada".into()]);
+        tabela.adicionar_linha(vec!["12".into(), "Leão".into(), "Simba".into()]);
+
+        let texto = tabela.renderizar(0);
+        let linhas: Vec<&str> = texto.lines().collect();
+        assert_eq!(linhas[0], "ID | ESPÉCIE      | NOME");
+        assert_eq!(linhas[1], "---|--------------|--------");
+        assert_eq!(linhas[3], "12 | Leão         | Simba");
+    }
+
+    #[test]
+    fn test_caracteres_largos_ocupam_duas_colunas() {
+        let mut tabela = Tabela::new(&["NOME", "RECINTO"]);
+        tabela.adicionar_linha(vec!["熊猫".into(), "Floresta".into()]);
+        tabela.adicionar_linha(vec!["Po".into(), "-".into()]);
+
+        let texto = tabela.renderizar(2);
+        let linhas: Vec<&str> = texto.lines().collect();
+        assert_eq!(linhas[2], "  熊猫 | Floresta");
+        assert_eq!(linhas[3], "  Po   | -");
+    }
+}
@@ -0,0 +1,3189 @@
+use chrono::{Local, NaiveDate, NaiveDateTime};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::alimentacao::{
+    AlimentacaoRegistrada, GrupoChecklist, Horario, ItemChecklist, ItemEstoque, Nutricao,
+    PlanoAlimentar, formatar_quantidade,
+};
+use crate::animais::InfoAnimal;
+use crate::bilheteria::{self, Bilheteria, FaixaVisitacao, PrecoIngresso, TipoIngresso, Venda};
+use crate::ciclo_vida::{Evento, EventoVida, Historico, Linhagem};
+use crate::consulta::{
+    AnimalImportado, CampoOrdenacao, ConsultaAnimais, FiltroRecinto, LinhaAnimal,
+};
+use crate::equipe::{self, Equipe, OrigemTarefa, Tarefa, TarefaPrevista, Tratador};
+use crate::errors::ErroZoologico;
+use crate::especies::{self, CatalogoEspecies, Especie, Habitat};
+use crate::persistencia::{self, Descartes};
+use crate::recintos::{self, OcupacaoRecinto, Recinto};
+use crate::saude::{Clinica, Consulta, FichaMedica, SituacaoSaude, TendenciaPeso, VacinaPendente};
+
+/// Conteúdo gravado no arquivo de dados
+#[derive(Serialize, Deserialize)]
+struct DadosZoologico {
+    proximo_id: u32,
+    animais: Vec<InfoAnimal>,
+    #[serde(default = "primeiro_id")]
+    proximo_id_recinto: u32,
+    #[serde(default)]
+    recintos: Vec<Recinto>,
+    #[serde(default = "primeiro_id")]
+    proximo_id_plano: u32,
+    #[serde(default)]
+    planos: Vec<PlanoAlimentar>,
+    #[serde(default)]
+    estoque: Vec<ItemEstoque>,
+    #[serde(default)]
+    alimentacoes: Vec<AlimentacaoRegistrada>,
+    #[serde(default)]
+    fichas_medicas: Vec<FichaMedica>,
+    #[serde(default = "primeiro_id")]
+    proximo_id_tratador: u32,
+    #[serde(default)]
+    tratadores: Vec<Tratador>,
+    #[serde(default = "primeiro_id")]
+    proximo_id_tarefa: u32,
+    #[serde(default)]
+    tarefas: Vec<Tarefa>,
+    #[serde(default = "bilheteria::precos_padrao")]
+    precos: Vec<PrecoIngresso>,
+    #[serde(default = "bilheteria::faixas_padrao")]
+    faixas: Vec<FaixaVisitacao>,
+    #[serde(default = "primeiro_id")]
+    proximo_id_venda: u32,
+    #[serde(default)]
+    vendas: Vec<Venda>,
+    #[serde(default)]
+    animais_baixados: Vec<InfoAnimal>,
+    #[serde(default = "primeiro_id")]
+    proximo_id_evento: u32,
+    #[serde(default)]
+    eventos: Vec<EventoVida>,
+}
+
+fn primeiro_id() -> u32 {
+    1
+}
+
+/// Registro do formato antigo, gravado como uma lista simples sem ids
+#[derive(Deserialize)]
+struct AnimalLegado {
+    tipo: String,
+    nome: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FormatoArquivo {
+    Atual(Box<DadosZoologico>),
+    Legado(Vec<AnimalLegado>),
+}
+
+/// Resultado da recuperação de um arquivo de dados corrompido
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RelatorioRecuperacao {
+    /// Quantidade de registros aproveitados em cada lista (ex: "animais")
+    pub recuperados: BTreeMap<String, usize>,
+    /// Registros encontrados mas ilegíveis, repetidos ou com id fora da faixa
+    pub descartados: usize,
+    /// Referências a registros perdidos que foram desfeitas
+    /// (ex: animal num recinto que não foi recuperado)
+    pub referencias_removidas: usize,
+    /// Cópia do arquivo original, guardada antes de ser substituído
+    pub copia_original: Option<PathBuf>,
+}
+
+/// Serviço que gerencia os animais do zoológico
+///
+/// Os animais são identificados por ids estáveis: excluir um animal
+/// não altera o id dos demais e ids nunca são reutilizados.
+/// O tipo de cada animal precisa existir no catálogo de espécies.
+pub struct Zoologico {
+    caminho_arquivo: PathBuf,
+    animais: BTreeMap<u32, InfoAnimal>,
+    proximo_id: u32,
+    recintos: BTreeMap<u32, Recinto>,
+    proximo_id_recinto: u32,
+    nutricao: Nutricao,
+    clinica: Clinica,
+    equipe: Equipe,
+    bilheteria: Bilheteria,
+    /// Animais transferidos ou mortos e os eventos do ciclo de vida
+    historico: Historico,
+    catalogo: CatalogoEspecies,
+    /// Registros ignorados ao montar o zoológico a partir do arquivo
+    descartes: Descartes,
+}
+
+impl Zoologico {
+    /// Cria um zoológico vazio associado ao arquivo de dados informado
+    pub fn new(caminho_arquivo: PathBuf) -> Self {
+        Zoologico {
+            caminho_arquivo,
+            animais: BTreeMap::new(),
+            proximo_id: 1,
+            recintos: BTreeMap::new(),
+            proximo_id_recinto: 1,
+            nutricao: Nutricao::default(),
+            clinica: Clinica::default(),
+            equipe: Equipe::default(),
+            bilheteria: Bilheteria::default(),
+            historico: Historico::default(),
+            catalogo: CatalogoEspecies::padrao(),
+            descartes: Descartes::default(),
+        }
+    }
+
+    /// Substitui o catálogo padrão pelo informado
+    ///
+    /// Os tipos dos animais já cadastrados são padronizados pelo novo catálogo.
+    pub fn com_catalogo(mut self, catalogo: CatalogoEspecies) -> Self {
+        self.catalogo = catalogo;
+        self.padronizar_tipos();
+        self
+    }
+
+    pub fn catalogo(&self) -> &CatalogoEspecies {
+        &self.catalogo
+    }
+
+    /// Carrega o zoológico do arquivo JSON
+    ///
+    /// # Retorno
+    /// Zoológico vazio se o arquivo não existir, ou `ArquivoCorrompido` se o
+    /// conteúdo for inválido ou tiver ids repetidos ou fora da faixa (use
+    /// `recuperar` nesse caso).
+    /// Arquivos no formato antigo (lista sem ids) recebem ids sequenciais.
+    /// Tipos reconhecidos pelo catálogo são convertidos para o nome oficial
+    /// da espécie (ex: "bovino" → "Boi"); os demais são mantidos como estão.
+    pub fn carregar(caminho: &Path) -> Result<Self, ErroZoologico> {
+        if !caminho.exists() {
+            return Ok(Zoologico::new(caminho.to_path_buf()));
+        }
+
+        let formato = ler_arquivo(caminho)?;
+        let zoologico = Zoologico::de_formato(caminho, formato);
+        // Carregar sem eles faria o próximo salvamento apagá-los do arquivo
+        if zoologico.descartes.total() > 0 {
+            return Err(ErroZoologico::ArquivoCorrompido(format!(
+                "{}: {} registro(s) com id repetido ou fora da faixa; \
+                 execute `recuperar` para descartá-los",
+                caminho.display(),
+                zoologico.descartes.total()
+            )));
+        }
+        Ok(zoologico)
+    }
+
+    /// Recupera os registros válidos de um arquivo de dados corrompido
+    ///
+    /// O arquivo original é copiado para a pasta de backups e depois
+    /// substituído pelos dados recuperados. Registros ilegíveis, repetidos ou
+    /// com id fora da faixa são descartados e referências a registros perdidos
+    /// são desfeitas. Como o contador de ids
+    /// pode ter se perdido, os próximos ids continuam a partir do maior id recuperado.
+    pub fn recuperar(caminho: &Path) -> Result<(Self, RelatorioRecuperacao), ErroZoologico> {
+        let bytes = fs::read(caminho).map_err(|e| {
+            ErroZoologico::ErroPersistencia(format!("Erro ao abrir arquivo: {}", e))
+        })?;
+        let texto = String::from_utf8_lossy(&bytes);
+
+        let mut relatorio = RelatorioRecuperacao::default();
+        let mut dados = DadosZoologico {
+            proximo_id: 1,
+            animais: Vec::new(),
+            proximo_id_recinto: 1,
+            recintos: Vec::new(),
+            proximo_id_plano: 1,
+            planos: Vec::new(),
+            estoque: Vec::new(),
+            alimentacoes: Vec::new(),
+            fichas_medicas: Vec::new(),
+            proximo_id_tratador: 1,
+            tratadores: Vec::new(),
+            proximo_id_tarefa: 1,
+            tarefas: Vec::new(),
+            precos: Vec::new(),
+            faixas: Vec::new(),
+            proximo_id_venda: 1,
+            vendas: Vec::new(),
+            animais_baixados: Vec::new(),
+            proximo_id_evento: 1,
+            eventos: Vec::new(),
+        };
+        let mut legado = Vec::new();
+
+        for registro in persistencia::extrair_registros(&texto) {
+            let secao = registro.secao.as_deref().unwrap_or("animais");
+            let aproveitado = match registro.secao.as_deref() {
+                None => guardar(&mut legado, registro.texto),
+                Some("animais") => guardar(&mut dados.animais, registro.texto),
+                Some("recintos") => guardar(&mut dados.recintos, registro.texto),
+                Some("planos") => guardar(&mut dados.planos, registro.texto),
+                Some("estoque") => guardar(&mut dados.estoque, registro.texto),
+                Some("alimentacoes") => guardar(&mut dados.alimentacoes, registro.texto),
+                Some("fichas_medicas") => guardar(&mut dados.fichas_medicas, registro.texto),
+                Some("tratadores") => guardar(&mut dados.tratadores, registro.texto),
+                Some("tarefas") => guardar(&mut dados.tarefas, registro.texto),
+                Some("precos") => guardar(&mut dados.precos, registro.texto),
+                Some("faixas") => guardar(&mut dados.faixas, registro.texto),
+                Some("vendas") => guardar(&mut dados.vendas, registro.texto),
+                Some("animais_baixados") => guardar(&mut dados.animais_baixados, registro.texto),
+                Some("eventos") => guardar(&mut dados.eventos, registro.texto),
+                Some(_) => false,
+            };
+            if aproveitado {
+                *relatorio.recuperados.entry(secao.to_string()).or_default() += 1;
+            } else {
+                relatorio.descartados += 1;
+            }
+        }
+
+        // Sem nenhuma faixa recuperada, a bilheteria volta às faixas padrão
+        if dados.faixas.is_empty() {
+            dados.faixas = bilheteria::faixas_padrao();
+        }
+
+        let formato = if legado.is_empty() {
+            FormatoArquivo::Atual(Box::new(dados))
+        } else {
+            FormatoArquivo::Legado(legado)
+        };
+        let mut zoologico = Zoologico::de_formato(caminho, formato);
+        relatorio.descartados += zoologico.descartes.total();
+        relatorio.referencias_removidas = zoologico.desfazer_referencias_perdidas();
+
+        relatorio.copia_original = Some(persistencia::guardar_corrompido(caminho)?);
+        zoologico.gravar()?;
+        Ok((zoologico, relatorio))
+    }
+
+    /// Monta o zoológico a partir do conteúdo lido do arquivo
+    fn de_formato(caminho: &Path, formato: FormatoArquivo) -> Self {
+        let mut zoologico = Zoologico::new(caminho.to_path_buf());
+
+        match formato {
+            FormatoArquivo::Atual(dados) => {
+                zoologico.proximo_id = dados.proximo_id;
+                for animal in dados.animais {
+                    persistencia::inserir_com_id(
+                        &mut zoologico.animais,
+                        animal.id,
+                        animal,
+                        &mut zoologico.proximo_id,
+                        &mut zoologico.descartes,
+                    );
+                }
+                zoologico.proximo_id_recinto = dados.proximo_id_recinto;
+                for recinto in dados.recintos {
+                    persistencia::inserir_com_id(
+                        &mut zoologico.recintos,
+                        recinto.id,
+                        recinto,
+                        &mut zoologico.proximo_id_recinto,
+                        &mut zoologico.descartes,
+                    );
+                }
+                zoologico.nutricao = Nutricao::carregar(
+                    dados.proximo_id_plano,
+                    dados.planos,
+                    dados.estoque,
+                    dados.alimentacoes,
+                    &mut zoologico.descartes,
+                );
+                zoologico.clinica =
+                    Clinica::carregar(dados.fichas_medicas, &mut zoologico.descartes);
+                zoologico.equipe = Equipe::carregar(
+                    dados.proximo_id_tratador,
+                    dados.tratadores,
+                    dados.proximo_id_tarefa,
+                    dados.tarefas,
+                    &mut zoologico.descartes,
+                );
+                zoologico
+                    .equipe
+                    .converter_tratadores_legados(zoologico.recintos.values_mut());
+                zoologico.bilheteria = Bilheteria::carregar(
+                    dados.precos,
+                    dados.faixas,
+                    dados.proximo_id_venda,
+                    dados.vendas,
+                    &mut zoologico.descartes,
+                );
+                zoologico.historico = Historico::carregar(
+                    dados.animais_baixados,
+                    &zoologico.animais,
+                    &mut zoologico.proximo_id,
+                    dados.proximo_id_evento,
+                    dados.eventos,
+                    &mut zoologico.descartes,
+                );
+            }
+            FormatoArquivo::Legado(animais) => {
+                for animal in animais {
+                    if zoologico.inserir(animal.tipo, animal.nome).is_err() {
+                        zoologico.descartes.fora_de_faixa += 1;
+                    }
+                }
+            }
+        }
+
+        zoologico.padronizar_tipos();
+        zoologico
+    }
+
+    /// Salva todos os dados do zoológico no arquivo JSON
+    ///
+    /// A gravação é atômica e o conteúdo anterior vai para a pasta de backups.
+    /// Se o arquivo atual estiver corrompido, ele não é sobrescrito: o erro
+    /// `ArquivoCorrompido` é devolvido para que os dados sejam recuperados antes.
+    pub fn salvar(&self) -> Result<(), ErroZoologico> {
+        if self.caminho_arquivo.exists() {
+            ler_arquivo(&self.caminho_arquivo)?;
+            persistencia::criar_backup(&self.caminho_arquivo)?;
+        }
+        self.gravar()
+    }
+
+    fn gravar(&self) -> Result<(), ErroZoologico> {
+        let dados = DadosZoologico {
+            proximo_id: self.proximo_id,
+            animais: self.animais.values().cloned().collect(),
+            proximo_id_recinto: self.proximo_id_recinto,
+            recintos: self.recintos.values().cloned().collect(),
+            proximo_id_plano: self.nutricao.proximo_id_plano(),
+            planos: self.nutricao.listar_planos().into_iter().cloned().collect(),
+            estoque: self
+                .nutricao
+                .listar_estoque()
+                .into_iter()
+                .cloned()
+                .collect(),
+            alimentacoes: self.nutricao.alimentacoes().to_vec(),
+            fichas_medicas: self.clinica.listar_fichas().into_iter().cloned().collect(),
+            proximo_id_tratador: self.equipe.proximo_id_tratador(),
+            tratadores: self
+                .equipe
+                .listar_tratadores()
+                .into_iter()
+                .cloned()
+                .collect(),
+            proximo_id_tarefa: self.equipe.proximo_id_tarefa(),
+            tarefas: self.equipe.tarefas().cloned().collect(),
+            precos: self.bilheteria.precos().into_iter().copied().collect(),
+            faixas: self.bilheteria.faixas().into_iter().copied().collect(),
+            proximo_id_venda: self.bilheteria.proximo_id_venda(),
+            vendas: self
+                .bilheteria
+                .listar_vendas()
+                .into_iter()
+                .cloned()
+                .collect(),
+            animais_baixados: self
+                .historico
+                .listar_animais_baixados()
+                .into_iter()
+                .cloned()
+                .collect(),
+            proximo_id_evento: self.historico.proximo_id_evento(),
+            eventos: self.historico.eventos().cloned().collect(),
+        };
+
+        let conteudo = serde_json::to_vec(&dados)
+            .map_err(|e| ErroZoologico::ErroPersistencia(format!("Erro ao salvar dados: {}", e)))?;
+        persistencia::gravar_atomico(&self.caminho_arquivo, &conteudo)
+    }
+
+    /// Inclui um novo animal
+    ///
+    /// # Retorno
+    /// Id atribuído ao animal, ou erro se algum campo estiver vazio ou a
+    /// espécie não existir no catálogo
+    pub fn incluir_animal(&mut self, tipo: &str, nome: &str) -> Result<u32, ErroZoologico> {
+        let (tipo, nome) = self.validar_campos(tipo, nome)?;
+        self.inserir(tipo, nome)
+    }
+
+    /// Inclui vários animais de uma vez
+    ///
+    /// Todos os registros são validados antes: se algum for recusado,
+    /// nenhum animal é incluído.
+    ///
+    /// # Retorno
+    /// Ids atribuídos, na ordem dos registros
+    pub fn importar_animais(
+        &mut self,
+        animais: &[AnimalImportado],
+    ) -> Result<Vec<u32>, ErroZoologico> {
+        let validados = animais
+            .iter()
+            .enumerate()
+            .map(|(indice, animal)| {
+                self.validar_campos(&animal.tipo, &animal.nome)
+                    .map_err(|erro| ErroZoologico::RegistroImportacaoInvalido {
+                        numero: indice + 1,
+                        erro: Box::new(erro),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if validados.len() > (u32::MAX - self.proximo_id) as usize {
+            return Err(ErroZoologico::IdsEsgotados("animais".to_string()));
+        }
+
+        validados
+            .into_iter()
+            .map(|(tipo, nome)| self.inserir(tipo, nome))
+            .collect()
+    }
+
+    /// Substitui o tipo e o nome do animal com o id informado
+    ///
+    /// Se o animal estiver num recinto, a nova espécie precisa ser compatível com ele.
+    pub fn editar_animal(&mut self, id: u32, tipo: &str, nome: &str) -> Result<(), ErroZoologico> {
+        let (tipo, nome) = self.validar_campos(tipo, nome)?;
+        let id_recinto = self
+            .buscar_animal(id)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id))?
+            .id_recinto;
+
+        if let Some(id_recinto) = id_recinto {
+            let especie = self.catalogo.resolver(&tipo)?;
+            self.verificar_alocacao(id, especie, id_recinto)?;
+        }
+
+        let animal = self
+            .animais
+            .get_mut(&id)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id))?;
+
+        animal.tipo = tipo;
+        animal.nome = nome;
+        Ok(())
+    }
+
+    /// Remove o animal com o id informado, junto com seus planos alimentares,
+    /// sua ficha médica, seus eventos e suas tarefas pendentes
+    ///
+    /// Serve para desfazer cadastros feitos por engano: nada fica no
+    /// histórico e os filhos perdem a ligação com ele. Saídas de verdade devem
+    /// ser registradas com `registrar_transferencia` ou `registrar_obito`.
+    ///
+    /// # Retorno
+    /// O animal removido
+    pub fn excluir_animal(&mut self, id: u32) -> Result<InfoAnimal, ErroZoologico> {
+        let animal = self
+            .animais
+            .remove(&id)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id))?;
+        self.nutricao.reter_planos(|plano| plano.id_animal != id);
+        self.clinica.remover_ficha(id);
+        self.historico.esquecer_animal(&mut self.animais, id);
+        self.equipe
+            .descartar_pendentes(|origem| origem.id_animal() == Some(id));
+        Ok(animal)
+    }
+
+    pub fn buscar_animal(&self, id: u32) -> Option<&InfoAnimal> {
+        self.animais.get(&id)
+    }
+
+    /// Espécie do catálogo correspondente ao animal com o id informado
+    pub fn especie_do_animal(&self, id: u32) -> Option<&Especie> {
+        self.buscar_animal(id)
+            .and_then(|animal| self.catalogo.buscar(&animal.tipo))
+    }
+
+    /// Cadastra um animal vindo de outra instituição e registra a chegada
+    ///
+    /// # Retorno
+    /// Id atribuído ao animal
+    pub fn registrar_chegada(
+        &mut self,
+        tipo: &str,
+        nome: &str,
+        data: NaiveDate,
+        origem: &str,
+        observacao: &str,
+    ) -> Result<u32, ErroZoologico> {
+        let (tipo, nome) = self.validar_campos(tipo, nome)?;
+        let origem = texto_obrigatorio(origem, "origem")?;
+        // O evento vem antes do cadastro para que um erro não deixe o animal sem ele
+        let id = persistencia::reservar_id(&mut self.proximo_id, "animais")?;
+        self.historico
+            .registrar_evento(id, data, Evento::Chegada { origem }, observacao)?;
+        self.animais.insert(id, InfoAnimal::new(id, tipo, nome));
+        Ok(id)
+    }
+
+    /// Cadastra um filhote nascido no zoológico, ligado aos pais conhecidos
+    ///
+    /// Os pais precisam ser da mesma espécie do filhote. A mãe precisa estar
+    /// no zoológico; o pai pode já ter sido transferido ou morto. O filhote
+    /// começa sem recinto.
+    ///
+    /// # Retorno
+    /// Id atribuído ao filhote
+    pub fn registrar_nascimento(
+        &mut self,
+        tipo: &str,
+        nome: &str,
+        data: NaiveDate,
+        id_mae: Option<u32>,
+        id_pai: Option<u32>,
+        observacao: &str,
+    ) -> Result<u32, ErroZoologico> {
+        let (tipo, nome) = self.validar_campos(tipo, nome)?;
+        self.linhagem().verificar_pais(&tipo, id_mae, id_pai)?;
+
+        let id = persistencia::reservar_id(&mut self.proximo_id, "animais")?;
+        self.historico.registrar_evento(
+            id,
+            data,
+            Evento::Nascimento { id_mae, id_pai },
+            observacao,
+        )?;
+        let mut filhote = InfoAnimal::new(id, tipo, nome);
+        filhote.id_mae = id_mae;
+        filhote.id_pai = id_pai;
+        self.animais.insert(id, filhote);
+        Ok(id)
+    }
+
+    /// Registra a saída do animal para outra instituição
+    ///
+    /// O animal deixa o recinto e os planos alimentares e vai para o
+    /// histórico, onde continua na genealogia. Animais em quarentena não
+    /// podem ser transferidos.
+    pub fn registrar_transferencia(
+        &mut self,
+        id: u32,
+        data: NaiveDate,
+        destino: &str,
+        observacao: &str,
+    ) -> Result<(), ErroZoologico> {
+        let destino = texto_obrigatorio(destino, "destino")?;
+        if self.buscar_animal(id).is_none() {
+            return Err(ErroZoologico::AnimalNaoEncontrado(id));
+        }
+        if self.clinica.em_quarentena(id) {
+            return Err(ErroZoologico::AnimalEmQuarentena(id));
+        }
+        self.baixar_animal(id, data, Evento::Transferencia { destino }, observacao)
+    }
+
+    /// Registra a morte do animal (causa vazia fica como "desconhecida")
+    ///
+    /// Como na transferência, o animal vai para o histórico.
+    pub fn registrar_obito(
+        &mut self,
+        id: u32,
+        data: NaiveDate,
+        causa: &str,
+        observacao: &str,
+    ) -> Result<(), ErroZoologico> {
+        if self.buscar_animal(id).is_none() {
+            return Err(ErroZoologico::AnimalNaoEncontrado(id));
+        }
+        let causa = match causa.trim() {
+            "" => "desconhecida".to_string(),
+            causa => causa.to_string(),
+        };
+        self.baixar_animal(id, data, Evento::Obito { causa }, observacao)
+    }
+
+    pub fn historico(&self) -> &Historico {
+        &self.historico
+    }
+
+    /// Genealogia dos animais presentes e dos que já deixaram o zoológico
+    pub fn linhagem(&self) -> Linhagem<'_> {
+        Linhagem::new(&self.animais, &self.historico)
+    }
+
+    /// Animais cujo tipo não existe no catálogo (dados antigos ainda não corrigidos)
+    pub fn animais_fora_do_catalogo(&self) -> Vec<&InfoAnimal> {
+        self.animais
+            .values()
+            .filter(|animal| self.catalogo.buscar(&animal.tipo).is_none())
+            .collect()
+    }
+
+    /// Lista os animais em ordem de id (ordem de cadastro)
+    pub fn listar_animais(&self) -> Vec<&InfoAnimal> {
+        self.animais.values().collect()
+    }
+
+    /// Pesquisa animais por espécie, nome, recinto e situação de saúde
+    ///
+    /// # Retorno
+    /// Animais que atendem a todos os filtros, na ordem pedida
+    pub fn consultar_animais(&self, consulta: &ConsultaAnimais) -> Vec<LinhaAnimal> {
+        let especie = consulta
+            .especie
+            .as_deref()
+            .map(|texto| match self.catalogo.buscar(texto) {
+                Some(especie) => FiltroTexto::Exato(especies::normalizar(&especie.nome)),
+                None => FiltroTexto::Trecho(especies::normalizar(texto)),
+            });
+        let nome = consulta
+            .nome
+            .as_deref()
+            .map(|texto| FiltroTexto::Trecho(especies::normalizar(texto)));
+
+        let mut linhas: Vec<LinhaAnimal> = self
+            .animais
+            .values()
+            .filter(|animal| especie.as_ref().is_none_or(|f| f.aceita(&animal.tipo)))
+            .filter(|animal| nome.as_ref().is_none_or(|f| f.aceita(&animal.nome)))
+            .filter(|animal| match consulta.recinto {
+                None => true,
+                Some(FiltroRecinto::Recinto(id)) => animal.id_recinto == Some(id),
+                Some(FiltroRecinto::SemRecinto) => animal.id_recinto.is_none(),
+            })
+            .map(|animal| LinhaAnimal {
+                id: animal.id,
+                especie: animal.tipo.clone(),
+                nome: animal.nome.clone(),
+                recinto: animal
+                    .id_recinto
+                    .and_then(|id| self.recintos.get(&id))
+                    .map(|recinto| recinto.nome.clone()),
+                saude: self
+                    .clinica
+                    .ficha_medica(animal.id)
+                    .map(FichaMedica::situacao)
+                    .unwrap_or(SituacaoSaude::Saudavel),
+            })
+            .filter(|linha| consulta.saude.is_none_or(|saude| linha.saude == saude))
+            .collect();
+
+        // A lista já está em ordem de id, e a ordenação é estável
+        let decrescente = consulta.decrescente;
+        match consulta.ordenar_por {
+            CampoOrdenacao::Id => {
+                if decrescente {
+                    linhas.reverse();
+                }
+            }
+            CampoOrdenacao::Especie => ordenar_linhas(&mut linhas, decrescente, |l| {
+                especies::normalizar(&l.especie)
+            }),
+            CampoOrdenacao::Nome => {
+                ordenar_linhas(&mut linhas, decrescente, |l| especies::normalizar(&l.nome))
+            }
+            // Animais sem recinto ficam no fim (no início, em ordem decrescente)
+            CampoOrdenacao::Recinto => ordenar_linhas(&mut linhas, decrescente, |l| {
+                (
+                    l.recinto.is_none(),
+                    l.recinto.as_deref().map(especies::normalizar),
+                )
+            }),
+            CampoOrdenacao::Saude => ordenar_linhas(&mut linhas, decrescente, |l| l.saude),
+        }
+        linhas
+    }
+
+    /// Cadastra um novo recinto
+    ///
+    /// # Retorno
+    /// Id atribuído ao recinto, ou erro se o nome estiver vazio ou a capacidade for zero
+    pub fn incluir_recinto(
+        &mut self,
+        nome: &str,
+        habitat: Habitat,
+        capacidade: u32,
+        area_m2: u32,
+    ) -> Result<u32, ErroZoologico> {
+        let nome = nome.trim();
+        if nome.is_empty() {
+            return Err(ErroZoologico::CampoVazio("nome".to_string()));
+        }
+        if capacidade == 0 {
+            return Err(ErroZoologico::CampoVazio("capacidade".to_string()));
+        }
+
+        let id = persistencia::reservar_id(&mut self.proximo_id_recinto, "recintos")?;
+        self.recintos.insert(
+            id,
+            Recinto::new(id, nome.to_string(), habitat, capacidade, area_m2),
+        );
+        Ok(id)
+    }
+
+    /// Remove um recinto vazio
+    ///
+    /// # Retorno
+    /// O recinto removido, ou `RecintoOcupado` se ainda houver animais nele
+    pub fn excluir_recinto(&mut self, id: u32) -> Result<Recinto, ErroZoologico> {
+        if !self.recintos.contains_key(&id) {
+            return Err(ErroZoologico::RecintoNaoEncontrado(id));
+        }
+        if !self.animais_no_recinto(id).is_empty() {
+            return Err(ErroZoologico::RecintoOcupado(id));
+        }
+        self.equipe.descartar_pendentes(
+            |origem| matches!(origem, OrigemTarefa::Limpeza { id_recinto } if *id_recinto == id),
+        );
+        self.recintos
+            .remove(&id)
+            .ok_or(ErroZoologico::RecintoNaoEncontrado(id))
+    }
+
+    pub fn buscar_recinto(&self, id: u32) -> Option<&Recinto> {
+        self.recintos.get(&id)
+    }
+
+    pub fn listar_recintos(&self) -> Vec<&Recinto> {
+        self.recintos.values().collect()
+    }
+
+    pub fn animais_no_recinto(&self, id_recinto: u32) -> Vec<&InfoAnimal> {
+        self.animais
+            .values()
+            .filter(|animal| animal.id_recinto == Some(id_recinto))
+            .collect()
+    }
+
+    /// Coloca o animal no recinto informado, tirando-o do recinto anterior
+    ///
+    /// # Retorno
+    /// Erro se o recinto estiver lotado, não tiver área livre, tiver outro
+    /// habitat, se o animal ficaria junto de um predador ou de uma presa, ou
+    /// se o animal estiver em quarentena e já ocupar outro recinto
+    pub fn alocar_animal(&mut self, id_animal: u32, id_recinto: u32) -> Result<(), ErroZoologico> {
+        let animal = self
+            .buscar_animal(id_animal)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id_animal))?;
+        // Em quarentena, só é permitido colocar num recinto um animal que ainda não tem nenhum
+        if animal.id_recinto.is_some_and(|atual| atual != id_recinto)
+            && self.clinica.em_quarentena(id_animal)
+        {
+            return Err(ErroZoologico::AnimalEmQuarentena(id_animal));
+        }
+        let especie = self.catalogo.resolver(&animal.tipo)?;
+        self.verificar_alocacao(id_animal, especie, id_recinto)?;
+
+        if let Some(animal) = self.animais.get_mut(&id_animal) {
+            animal.id_recinto = Some(id_recinto);
+        }
+        Ok(())
+    }
+
+    pub fn equipe(&self) -> &Equipe {
+        &self.equipe
+    }
+
+    /// Cadastra um tratador
+    ///
+    /// # Retorno
+    /// Id atribuído ao tratador, ou erro se o nome estiver vazio ou já existir
+    pub fn incluir_tratador(&mut self, nome: &str) -> Result<u32, ErroZoologico> {
+        self.equipe.incluir_tratador(nome)
+    }
+
+    /// Remove o tratador e desfaz suas atribuições
+    ///
+    /// Tarefas pendentes do tratador ficam sem responsável até serem geradas de novo.
+    pub fn excluir_tratador(&mut self, id: u32) -> Result<Tratador, ErroZoologico> {
+        let tratador = self.equipe.remover_tratador(id)?;
+        for recinto in self.recintos.values_mut() {
+            if recinto.id_tratador == Some(id) {
+                recinto.id_tratador = None;
+            }
+        }
+        for animal in self.animais.values_mut() {
+            if animal.id_tratador == Some(id) {
+                animal.id_tratador = None;
+            }
+        }
+        Ok(tratador)
+    }
+
+    /// Define o tratador responsável pelo recinto (`None` remove o responsável)
+    pub fn atribuir_tratador_recinto(
+        &mut self,
+        id_recinto: u32,
+        id_tratador: Option<u32>,
+    ) -> Result<(), ErroZoologico> {
+        self.equipe.verificar_tratador(id_tratador)?;
+        let recinto = self
+            .recintos
+            .get_mut(&id_recinto)
+            .ok_or(ErroZoologico::RecintoNaoEncontrado(id_recinto))?;
+        recinto.id_tratador = id_tratador;
+        Ok(())
+    }
+
+    /// Define um tratador para o animal no lugar do tratador do recinto
+    /// (`None` volta a usar o do recinto)
+    pub fn atribuir_tratador_animal(
+        &mut self,
+        id_animal: u32,
+        id_tratador: Option<u32>,
+    ) -> Result<(), ErroZoologico> {
+        self.equipe.verificar_tratador(id_tratador)?;
+        let animal = self
+            .animais
+            .get_mut(&id_animal)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id_animal))?;
+        animal.id_tratador = id_tratador;
+        Ok(())
+    }
+
+    /// Tratador responsável pelo animal: o atribuído ao animal ou, se não
+    /// houver, o do recinto em que ele está
+    pub fn responsavel_pelo_animal(&self, id_animal: u32) -> Option<&Tratador> {
+        let animal = self.buscar_animal(id_animal)?;
+        let id_tratador = animal.id_tratador.or_else(|| {
+            animal
+                .id_recinto
+                .and_then(|id| self.buscar_recinto(id))
+                .and_then(|recinto| recinto.id_tratador)
+        })?;
+        self.equipe.buscar_tratador(id_tratador)
+    }
+
+    /// Animais sob responsabilidade do tratador, atribuídos a ele ou pelo recinto
+    pub fn animais_do_tratador(&self, id_tratador: u32) -> Vec<&InfoAnimal> {
+        self.animais
+            .values()
+            .filter(|animal| {
+                self.responsavel_pelo_animal(animal.id)
+                    .is_some_and(|t| t.id == id_tratador)
+            })
+            .collect()
+    }
+
+    pub fn recintos_do_tratador(&self, id_tratador: u32) -> Vec<&Recinto> {
+        self.recintos
+            .values()
+            .filter(|recinto| recinto.id_tratador == Some(id_tratador))
+            .collect()
+    }
+
+    /// Tira o animal do recinto em que está (bloqueado durante a quarentena)
+    pub fn retirar_do_recinto(&mut self, id_animal: u32) -> Result<(), ErroZoologico> {
+        if self.clinica.em_quarentena(id_animal) {
+            return Err(ErroZoologico::AnimalEmQuarentena(id_animal));
+        }
+        let animal = self
+            .animais
+            .get_mut(&id_animal)
+            .ok_or(ErroZoologico::AnimalNaoEncontrado(id_animal))?;
+        animal.id_recinto = None;
+        Ok(())
+    }
+
+    /// Ocupação de cada recinto, em ordem de id
+    pub fn relatorio_ocupacao(&self) -> Vec<OcupacaoRecinto> {
+        self.recintos
+            .values()
+            .map(|recinto| {
+                let ocupantes = self.animais_no_recinto(recinto.id);
+                OcupacaoRecinto {
+                    id_recinto: recinto.id,
+                    nome: recinto.nome.clone(),
+                    habitat: recinto.habitat,
+                    ocupantes: ocupantes.len() as u32,
+                    capacidade: recinto.capacidade,
+                    area_ocupada_m2: self.area_ocupada(&ocupantes),
+                    area_m2: recinto.area_m2,
+                }
+            })
+            .collect()
+    }
+
+    /// Confere se o animal (com a espécie informada) pode ficar no recinto
+    ///
+    /// O próprio animal é ignorado na contagem, para que editar ou realocar
+    /// um animal que já está no recinto não conte em dobro.
+    fn verificar_alocacao(
+        &self,
+        id_animal: u32,
+        especie: &Especie,
+        id_recinto: u32,
+    ) -> Result<(), ErroZoologico> {
+        let recinto = self
+            .buscar_recinto(id_recinto)
+            .ok_or(ErroZoologico::RecintoNaoEncontrado(id_recinto))?;
+
+        if especie.habitat != recinto.habitat {
+            return Err(ErroZoologico::HabitatIncompativel {
+                especie: especie.nome.clone(),
+                habitat_especie: especie.habitat,
+                habitat_recinto: recinto.habitat,
+            });
+        }
+
+        let ocupantes: Vec<&InfoAnimal> = self
+            .animais_no_recinto(id_recinto)
+            .into_iter()
+            .filter(|animal| animal.id != id_animal)
+            .collect();
+
+        if ocupantes.len() as u32 >= recinto.capacidade {
+            return Err(ErroZoologico::RecintoLotado {
+                recinto: recinto.nome.clone(),
+                capacidade: recinto.capacidade,
+            });
+        }
+
+        let area_livre = recinto
+            .area_m2
+            .saturating_sub(self.area_ocupada(&ocupantes));
+        if especie.area_minima_m2 > area_livre {
+            return Err(ErroZoologico::AreaInsuficiente {
+                recinto: recinto.nome.clone(),
+                area_livre_m2: area_livre,
+                area_necessaria_m2: especie.area_minima_m2,
+            });
+        }
+
+        for ocupante in ocupantes {
+            let Some(outra) = self.catalogo.buscar(&ocupante.tipo) else {
+                continue;
+            };
+            if let Some(predador) = recintos::predador_de(especie, outra) {
+                let presa = if predador == especie.nome {
+                    &outra.nome
+                } else {
+                    &especie.nome
+                };
+                return Err(ErroZoologico::PredadorEPresa {
+                    predador: predador.to_string(),
+                    presa: presa.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Soma da área mínima exigida pelas espécies dos animais informados
+    fn area_ocupada(&self, animais: &[&InfoAnimal]) -> u32 {
+        animais
+            .iter()
+            .filter_map(|animal| self.catalogo.buscar(&animal.tipo))
+            .map(|especie| especie.area_minima_m2)
+            .sum()
+    }
+
+    pub fn nutricao(&self) -> &Nutricao {
+        &self.nutricao
+    }
+
+    /// Cria um plano alimentar para o animal
+    ///
+    /// # Parâmetros
+    /// - `id_animal`: Animal que recebe a refeição
+    /// - `alimento`: Tipo de alimento
+    /// - `quantidade_g`: Quantidade por refeição, em gramas
+    /// - `horarios`: Horários do dia em que a refeição é servida
+    ///
+    /// # Retorno
+    /// Id do plano criado
+    pub fn incluir_plano(
+        &mut self,
+        id_animal: u32,
+        alimento: &str,
+        quantidade_g: u32,
+        horarios: Vec<Horario>,
+    ) -> Result<u32, ErroZoologico> {
+        if !self.animais.contains_key(&id_animal) {
+            return Err(ErroZoologico::AnimalNaoEncontrado(id_animal));
+        }
+        self.nutricao
+            .incluir_plano(id_animal, alimento, quantidade_g, horarios)
+    }
+
+    /// Remove o plano alimentar e as tarefas de alimentação pendentes do plano
+    pub fn excluir_plano(&mut self, id: u32) -> Result<PlanoAlimentar, ErroZoologico> {
+        let plano = self.nutricao.remover_plano(id)?;
+        self.equipe.descartar_pendentes(
+            |origem| matches!(origem, OrigemTarefa::Alimentacao { id_plano, .. } if *id_plano == id),
+        );
+        Ok(plano)
+    }
+
+    /// Soma a quantidade informada ao estoque do alimento, criando-o se preciso
+    pub fn adicionar_estoque(
+        &mut self,
+        alimento: &str,
+        quantidade_g: u64,
+    ) -> Result<(), ErroZoologico> {
+        self.nutricao.adicionar_estoque(alimento, quantidade_g)
+    }
+
+    /// Define a partir de quantos dias restantes o alimento gera alerta
+    pub fn definir_dias_minimos(&mut self, alimento: &str, dias: u32) -> Result<(), ErroZoologico> {
+        self.nutricao.definir_dias_minimos(alimento, dias)
+    }
+
+    /// Registra que a refeição do plano foi servida, descontando do estoque
+    ///
+    /// # Retorno
+    /// Erro se o horário não fizer parte do plano, se a refeição já tiver
+    /// sido registrada na data ou se o estoque não for suficiente
+    pub fn registrar_alimentacao(
+        &mut self,
+        id_plano: u32,
+        data: NaiveDate,
+        horario: Horario,
+    ) -> Result<(), ErroZoologico> {
+        self.registrar_alimentacao_em(id_plano, data, horario, Local::now().naive_local())
+    }
+
+    /// Registra a refeição com o momento informado e conclui a tarefa correspondente
+    fn registrar_alimentacao_em(
+        &mut self,
+        id_plano: u32,
+        data: NaiveDate,
+        horario: Horario,
+        registrada_em: NaiveDateTime,
+    ) -> Result<(), ErroZoologico> {
+        self.nutricao
+            .registrar_alimentacao(id_plano, data, horario, registrada_em)?;
+        self.equipe
+            .concluir_pela_origem(data, registrada_em, |origem| {
+                matches!(origem, OrigemTarefa::Alimentacao { id_plano: p, horario: h, .. }
+                if *p == id_plano && *h == horario)
+            });
+        Ok(())
+    }
+
+    /// Refeições do dia agrupadas por tratador e recinto
+    ///
+    /// Os grupos seguem a ordem do tratador e depois do nome do recinto; animais
+    /// sem recinto ficam no último grupo. Dentro de cada grupo, as refeições
+    /// seguem a ordem de horário.
+    pub fn checklist(&self, data: NaiveDate) -> Vec<GrupoChecklist> {
+        let mut grupos: BTreeMap<Option<u32>, Vec<ItemChecklist>> = BTreeMap::new();
+
+        for plano in self.nutricao.listar_planos() {
+            let Some(animal) = self.buscar_animal(plano.id_animal) else {
+                continue;
+            };
+            for &horario in &plano.horarios {
+                grupos
+                    .entry(animal.id_recinto)
+                    .or_default()
+                    .push(ItemChecklist {
+                        id_plano: plano.id,
+                        id_animal: animal.id,
+                        nome_animal: animal.nome.clone(),
+                        alimento: plano.alimento.clone(),
+                        quantidade_g: plano.quantidade_g,
+                        horario,
+                        feito: self
+                            .nutricao
+                            .refeicao_registrada(plano.id, data, horario)
+                            .is_some(),
+                    });
+            }
+        }
+
+        let mut checklist: Vec<GrupoChecklist> = grupos
+            .into_iter()
+            .map(|(id_recinto, mut itens)| {
+                itens.sort_by(|a, b| {
+                    a.horario
+                        .cmp(&b.horario)
+                        .then_with(|| a.nome_animal.cmp(&b.nome_animal))
+                });
+                let recinto = id_recinto.and_then(|id| self.buscar_recinto(id));
+                GrupoChecklist {
+                    recinto: recinto.map(|r| r.nome.clone()),
+                    tratador: recinto
+                        .and_then(|r| r.id_tratador)
+                        .and_then(|id| self.equipe.buscar_tratador(id))
+                        .map(|t| t.nome.clone()),
+                    itens,
+                }
+            })
+            .collect();
+
+        checklist.sort_by(|a, b| {
+            (
+                a.recinto.is_none(),
+                a.tratador.is_none(),
+                &a.tratador,
+                &a.recinto,
+            )
+                .cmp(&(
+                    b.recinto.is_none(),
+                    b.tratador.is_none(),
+                    &b.tratador,
+                    &b.recinto,
+                ))
+        });
+        checklist
+    }
+
+    /// Gera as tarefas do dia a partir dos dados dos animais
+    ///
+    /// - Alimentação: uma tarefa por refeição dos planos alimentares
+    /// - Limpeza: uma tarefa por recinto ocupado
+    /// - Exame médico: acompanhamento dos animais em quarentena ou em
+    ///   tratamento, e as consultas agendadas para o dia
+    ///
+    /// Cada tarefa vai para o tratador responsável pelo animal (ou pelo
+    /// recinto, na limpeza). Gerar o mesmo dia de novo só cria as tarefas que
+    /// faltam e completa o responsável das pendentes que estavam sem.
+    /// Refeições já registradas geram tarefas concluídas.
+    ///
+    /// # Retorno
+    /// Quantidade de tarefas criadas
+    pub fn gerar_tarefas(&mut self, data: NaiveDate) -> Result<usize, ErroZoologico> {
+        let mut previstas: Vec<(OrigemTarefa, String, Horario)> = Vec::new();
+
+        for plano in self.nutricao.listar_planos() {
+            let Some(animal) = self.buscar_animal(plano.id_animal) else {
+                continue;
+            };
+            for &horario in &plano.horarios {
+                previstas.push((
+                    OrigemTarefa::Alimentacao {
+                        id_plano: plano.id,
+                        id_animal: animal.id,
+                        horario,
+                    },
+                    format!(
+                        "Servir {} de {} a {}",
+                        formatar_quantidade(plano.quantidade_g as u64),
+                        plano.alimento,
+                        animal.nome
+                    ),
+                    horario,
+                ));
+            }
+        }
+
+        for recinto in self.recintos.values() {
+            if !self.animais_no_recinto(recinto.id).is_empty() {
+                previstas.push((
+                    OrigemTarefa::Limpeza {
+                        id_recinto: recinto.id,
+                    },
+                    format!("Limpar o recinto {}", recinto.nome),
+                    equipe::PRAZO_LIMPEZA,
+                ));
+            }
+        }
+
+        for ficha in self.clinica.listar_fichas() {
+            let Some(animal) = self.buscar_animal(ficha.id_animal) else {
+                continue;
+            };
+            let motivo = match ficha.situacao() {
+                SituacaoSaude::EmQuarentena => Some("quarentena"),
+                SituacaoSaude::EmTratamento => Some("tratamento"),
+                SituacaoSaude::Saudavel => None,
+            };
+            if let Some(motivo) = motivo {
+                previstas.push((
+                    OrigemTarefa::Acompanhamento {
+                        id_animal: animal.id,
+                    },
+                    format!("Examinar {} ({})", animal.nome, motivo),
+                    equipe::PRAZO_EXAME,
+                ));
+            }
+            for (indice, consulta) in ficha.consultas.iter().enumerate() {
+                if consulta.data == data && !consulta.realizada() {
+                    previstas.push((
+                        OrigemTarefa::Consulta {
+                            id_animal: animal.id,
+                            numero: indice + 1,
+                        },
+                        format!("Consulta de {}: {}", animal.nome, consulta.descricao),
+                        equipe::PRAZO_EXAME,
+                    ));
+                }
+            }
+        }
+
+        let previstas = previstas
+            .into_iter()
+            .map(|(origem, descricao, horario)| TarefaPrevista {
+                id_tratador: self.responsavel_pela_tarefa(&origem),
+                concluida_em: match origem {
+                    OrigemTarefa::Alimentacao {
+                        id_plano, horario, ..
+                    } => self
+                        .nutricao
+                        .refeicao_registrada(id_plano, data, horario)
+                        .map(|r| r.registrada_em),
+                    _ => None,
+                },
+                origem,
+                descricao,
+                horario,
+            })
+            .collect();
+        self.equipe.gerar_tarefas(data, previstas)
+    }
+
+    /// Marca a tarefa como concluída no momento informado
+    ///
+    /// Concluir uma tarefa de alimentação registra a refeição e desconta o
+    /// estoque, como `registrar_alimentacao`.
+    pub fn concluir_tarefa(&mut self, id: u32, quando: NaiveDateTime) -> Result<(), ErroZoologico> {
+        let tarefa = self.equipe.tarefa_pendente(id)?;
+        if let OrigemTarefa::Alimentacao {
+            id_plano, horario, ..
+        } = tarefa.origem
+        {
+            let data = tarefa.data;
+            if self
+                .nutricao
+                .refeicao_registrada(id_plano, data, horario)
+                .is_none()
+            {
+                self.registrar_alimentacao_em(id_plano, data, horario, quando)?;
+            }
+        }
+        self.equipe.concluir_tarefa(id, quando);
+        Ok(())
+    }
+
+    pub fn bilheteria(&self) -> &Bilheteria {
+        &self.bilheteria
+    }
+
+    /// Altera o preço por pessoa e o mínimo de pessoas de um tipo de ingresso
+    ///
+    /// Vendas já registradas mantêm o preço da época.
+    pub fn definir_preco(
+        &mut self,
+        tipo: TipoIngresso,
+        centavos: u64,
+        minimo_pessoas: u32,
+    ) -> Result<(), ErroZoologico> {
+        self.bilheteria
+            .definir_preco(tipo, centavos, minimo_pessoas)
+    }
+
+    /// Cadastra a faixa de visitação ou altera a capacidade de uma existente
+    pub fn definir_faixa(&mut self, inicio: Horario, capacidade: u32) -> Result<(), ErroZoologico> {
+        self.bilheteria.definir_faixa(inicio, capacidade)
+    }
+
+    /// Remove a faixa de visitação; as vendas já feitas para ela continuam
+    /// nos relatórios
+    pub fn excluir_faixa(&mut self, inicio: Horario) -> Result<FaixaVisitacao, ErroZoologico> {
+        self.bilheteria.excluir_faixa(inicio)
+    }
+
+    /// Vende ingressos para uma data e faixa de horário
+    ///
+    /// # Retorno
+    /// Id da venda
+    pub fn vender_ingressos(
+        &mut self,
+        data_visita: NaiveDate,
+        faixa: Horario,
+        pedido: &[(TipoIngresso, u32)],
+        vendida_em: NaiveDateTime,
+    ) -> Result<u32, ErroZoologico> {
+        self.bilheteria
+            .vender_ingressos(data_visita, faixa, pedido, vendida_em)
+    }
+
+    pub fn clinica(&self) -> &Clinica {
+        &self.clinica
+    }
+
+    /// Registra uma dose de vacina aplicada
+    ///
+    /// # Parâmetros
+    /// - `proxima_dose`: Data prevista do reforço, se a vacina tiver reforço
+    pub fn registrar_vacina(
+        &mut self,
+        id_animal: u32,
+        vacina: &str,
+        data: NaiveDate,
+        proxima_dose: Option<NaiveDate>,
+    ) -> Result<(), ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        self.clinica
+            .registrar_vacina(id_animal, vacina, data, proxima_dose)
+    }
+
+    /// Inicia um tratamento
+    ///
+    /// # Retorno
+    /// Número do tratamento na ficha (a partir de 1), usado para encerrá-lo
+    pub fn iniciar_tratamento(
+        &mut self,
+        id_animal: u32,
+        descricao: &str,
+        inicio: NaiveDate,
+    ) -> Result<usize, ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        self.clinica
+            .iniciar_tratamento(id_animal, descricao, inicio)
+    }
+
+    pub fn encerrar_tratamento(
+        &mut self,
+        id_animal: u32,
+        numero: usize,
+        fim: NaiveDate,
+    ) -> Result<(), ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        self.clinica.encerrar_tratamento(id_animal, numero, fim)
+    }
+
+    /// Registra o peso do animal, em gramas
+    pub fn registrar_pesagem(
+        &mut self,
+        id_animal: u32,
+        data: NaiveDate,
+        peso_g: u32,
+    ) -> Result<(), ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        self.clinica.registrar_pesagem(id_animal, data, peso_g)
+    }
+
+    /// Coloca o animal em quarentena; enquanto ela durar, o animal não muda de recinto
+    pub fn iniciar_quarentena(
+        &mut self,
+        id_animal: u32,
+        motivo: &str,
+        inicio: NaiveDate,
+    ) -> Result<(), ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        self.clinica.iniciar_quarentena(id_animal, motivo, inicio)
+    }
+
+    pub fn encerrar_quarentena(
+        &mut self,
+        id_animal: u32,
+        fim: NaiveDate,
+    ) -> Result<(), ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        self.clinica.encerrar_quarentena(id_animal, fim)
+    }
+
+    /// Agenda uma consulta veterinária
+    ///
+    /// # Retorno
+    /// Número da consulta na ficha (a partir de 1), usado para concluí-la
+    pub fn agendar_consulta(
+        &mut self,
+        id_animal: u32,
+        data: NaiveDate,
+        descricao: &str,
+    ) -> Result<usize, ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        self.clinica.agendar_consulta(id_animal, data, descricao)
+    }
+
+    /// Marca a consulta como realizada e conclui a tarefa gerada para ela
+    pub fn concluir_consulta(
+        &mut self,
+        id_animal: u32,
+        numero: usize,
+        observacoes: &str,
+    ) -> Result<(), ErroZoologico> {
+        self.exigir_animal(id_animal)?;
+        let data = self
+            .clinica
+            .concluir_consulta(id_animal, numero, observacoes)?;
+        self.equipe
+            .concluir_pela_origem(data, Local::now().naive_local(), |origem| {
+                *origem == OrigemTarefa::Consulta { id_animal, numero }
+            });
+        Ok(())
+    }
+
+    /// Consultas ainda não realizadas dos animais presentes até a data informada (inclusive)
+    ///
+    /// # Retorno
+    /// Pares (animal, consulta) em ordem de data
+    pub fn proximas_consultas(&self, ate: NaiveDate) -> Vec<(&InfoAnimal, &Consulta)> {
+        self.clinica.proximas_consultas(&self.animais, ate)
+    }
+
+    /// Reforços de vacina dos animais presentes previstos até a data informada
+    pub fn vacinas_pendentes(&self, hoje: NaiveDate, ate: NaiveDate) -> Vec<VacinaPendente> {
+        self.clinica.vacinas_pendentes(&self.animais, hoje, ate)
+    }
+
+    /// Tendência de peso de cada animal presente com ao menos duas pesagens
+    pub fn relatorio_peso(&self) -> Vec<(&InfoAnimal, TendenciaPeso)> {
+        self.clinica.relatorio_peso(&self.animais)
+    }
+
+    /// Remove ligações com registros que não existem mais
+    ///
+    /// # Retorno
+    /// Quantidade de referências desfeitas
+    fn desfazer_referencias_perdidas(&mut self) -> usize {
+        let mut removidas = 0;
+
+        for animal in self.animais.values_mut() {
+            if animal
+                .id_recinto
+                .is_some_and(|id| !self.recintos.contains_key(&id))
+            {
+                animal.id_recinto = None;
+                removidas += 1;
+            }
+        }
+
+        for animal in self.animais.values_mut() {
+            if animal
+                .id_tratador
+                .is_some_and(|id| self.equipe.buscar_tratador(id).is_none())
+            {
+                animal.id_tratador = None;
+                removidas += 1;
+            }
+        }
+        for recinto in self.recintos.values_mut() {
+            if recinto
+                .id_tratador
+                .is_some_and(|id| self.equipe.buscar_tratador(id).is_none())
+            {
+                recinto.id_tratador = None;
+                removidas += 1;
+            }
+        }
+        removidas += self.equipe.desfazer_tratadores_perdidos();
+
+        // Pais que se perderam deixam de constar na genealogia
+        removidas += self
+            .historico
+            .desfazer_referencias_perdidas(&mut self.animais);
+
+        let animais = &self.animais;
+        removidas += self
+            .nutricao
+            .reter_planos(|plano| animais.contains_key(&plano.id_animal));
+        let historico = &self.historico;
+        removidas
+            + self.clinica.reter_fichas(|id| {
+                animais.contains_key(&id) || historico.buscar_animal_baixado(id).is_some()
+            })
+    }
+
+    /// Responsável por uma tarefa: o do animal ou o do recinto a limpar
+    fn responsavel_pela_tarefa(&self, origem: &OrigemTarefa) -> Option<u32> {
+        match *origem {
+            OrigemTarefa::Limpeza { id_recinto } => self
+                .buscar_recinto(id_recinto)
+                .and_then(|recinto| recinto.id_tratador),
+            _ => origem
+                .id_animal()
+                .and_then(|id| self.responsavel_pelo_animal(id))
+                .map(|tratador| tratador.id),
+        }
+    }
+
+    /// Move o animal para o histórico, desfazendo o que só vale para animais presentes
+    fn baixar_animal(
+        &mut self,
+        id: u32,
+        data: NaiveDate,
+        evento: Evento,
+        observacao: &str,
+    ) -> Result<(), ErroZoologico> {
+        let Some(animal) = self.animais.get(&id) else {
+            return Err(ErroZoologico::AnimalNaoEncontrado(id));
+        };
+        let mut animal = animal.clone();
+        animal.id_recinto = None;
+        animal.id_tratador = None;
+        // Só sai dos animais presentes depois que o evento foi registrado
+        self.historico.baixar(animal, data, evento, observacao)?;
+        self.animais.remove(&id);
+        self.nutricao.reter_planos(|plano| plano.id_animal != id);
+        self.equipe
+            .descartar_pendentes(|origem| origem.id_animal() == Some(id));
+        Ok(())
+    }
+
+    fn exigir_animal(&self, id_animal: u32) -> Result<(), ErroZoologico> {
+        if !self.animais.contains_key(&id_animal) {
+            return Err(ErroZoologico::AnimalNaoEncontrado(id_animal));
+        }
+        Ok(())
+    }
+
+    fn inserir(&mut self, tipo: String, nome: String) -> Result<u32, ErroZoologico> {
+        let id = persistencia::reservar_id(&mut self.proximo_id, "animais")?;
+        self.animais.insert(id, InfoAnimal::new(id, tipo, nome));
+        Ok(id)
+    }
+
+    /// Remove espaços nas extremidades, rejeita campos vazios e troca o
+    /// tipo pelo nome oficial da espécie no catálogo
+    fn validar_campos(&self, tipo: &str, nome: &str) -> Result<(String, String), ErroZoologico> {
+        let tipo = tipo.trim();
+        let nome = nome.trim();
+
+        if tipo.is_empty() {
+            return Err(ErroZoologico::CampoVazio("tipo".to_string()));
+        }
+        if nome.is_empty() {
+            return Err(ErroZoologico::CampoVazio("nome".to_string()));
+        }
+
+        let especie = self.catalogo.resolver(tipo)?;
+        Ok((especie.nome.clone(), nome.to_string()))
+    }
+
+    fn padronizar_tipos(&mut self) {
+        for animal in self
+            .animais
+            .values_mut()
+            .chain(self.historico.animais_baixados_mut())
+        {
+            if let Some(especie) = self.catalogo.buscar(&animal.tipo) {
+                animal.tipo = especie.nome.clone();
+            }
+        }
+    }
+}
+
+/// Comparação de texto usada nos filtros de consulta, sobre textos normalizados
+enum FiltroTexto {
+    Exato(String),
+    Trecho(String),
+}
+
+impl FiltroTexto {
+    fn aceita(&self, texto: &str) -> bool {
+        match self {
+            FiltroTexto::Exato(esperado) => especies::normalizar(texto) == *esperado,
+            FiltroTexto::Trecho(trecho) => especies::normalizar(texto).contains(trecho.as_str()),
+        }
+    }
+}
+
+/// Lê e interpreta o arquivo de dados
+fn ler_arquivo(caminho: &Path) -> Result<FormatoArquivo, ErroZoologico> {
+    let conteudo = fs::read(caminho)
+        .map_err(|e| ErroZoologico::ErroPersistencia(format!("Erro ao abrir arquivo: {}", e)))?;
+    serde_json::from_slice(&conteudo)
+        .map_err(|e| ErroZoologico::ArquivoCorrompido(format!("{}: {}", caminho.display(), e)))
+}
+
+/// Interpreta um registro recuperado e o acrescenta à lista
+///
+/// # Retorno
+/// `false` se o registro não for válido
+fn guardar<T: DeserializeOwned>(lista: &mut Vec<T>, texto: &str) -> bool {
+    match serde_json::from_str(texto) {
+        Ok(registro) => {
+            lista.push(registro);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Ordena pela chave; só a chave é invertida, então empates continuam na ordem do id
+fn ordenar_linhas<K: Ord>(
+    linhas: &mut [LinhaAnimal],
+    decrescente: bool,
+    chave: impl Fn(&LinhaAnimal) -> K,
+) {
+    if decrescente {
+        linhas.sort_by_cached_key(|linha| Reverse(chave(linha)));
+    } else {
+        linhas.sort_by_cached_key(chave);
+    }
+}
+
+/// Remove espaços nas extremidades e rejeita texto vazio
+fn texto_obrigatorio(texto: &str, campo: &str) -> Result<String, ErroZoologico> {
+    let texto = texto.trim();
+    if texto.is_empty() {
+        return Err(ErroZoologico::CampoVazio(campo.to_string()));
+    }
+    Ok(texto.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alimentacao;
+    use crate::bilheteria::{Movimento, Periodo};
+    use crate::ciclo_vida::Parentesco;
+    use crate::equipe::TipoTarefa;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_incluir_animal() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let id = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let animal = zoologico.buscar_animal(id).unwrap();
+        assert_eq!(animal.tipo, "Leão");
+        assert_eq!(animal.nome, "Simba");
+    }
+
+    #[test]
+    fn test_incluir_animal_campo_vazio() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        assert_eq!(
+            zoologico.incluir_animal("  ", "Simba"),
+            Err(ErroZoologico::CampoVazio("tipo".to_string()))
+        );
+        assert_eq!(
+            zoologico.incluir_animal("Leão", ""),
+            Err(ErroZoologico::CampoVazio("nome".to_string()))
+        );
+        assert!(zoologico.listar_animais().is_empty());
+    }
+
+    #[test]
+    fn test_incluir_animal_valida_especie() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let id = zoologico.incluir_animal("bovino", "Mimosa").unwrap();
+        assert_eq!(zoologico.buscar_animal(id).unwrap().tipo, "Boi");
+        assert_eq!(
+            zoologico.especie_do_animal(id).unwrap().nome_cientifico,
+            "Bos taurus"
+        );
+
+        let erro = zoologico
+            .incluir_animal("Leao-marinho", "Pipo")
+            .unwrap_err();
+        assert!(matches!(erro, ErroZoologico::EspecieDesconhecida { .. }));
+        assert_eq!(
+            zoologico.editar_animal(id, "Girrafa", "Mimosa"),
+            Err(ErroZoologico::EspecieDesconhecida {
+                tipo: "Girrafa".to_string(),
+                sugestoes: vec!["Girafa".to_string()],
+            })
+        );
+        assert_eq!(zoologico.listar_animais().len(), 1);
+    }
+
+    #[test]
+    fn test_editar_animal() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let id = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        zoologico.editar_animal(id, "Leão", "Mufasa").unwrap();
+        assert_eq!(zoologico.buscar_animal(id).unwrap().nome, "Mufasa");
+        assert_eq!(
+            zoologico.editar_animal(99, "Leão", "Scar"),
+            Err(ErroZoologico::AnimalNaoEncontrado(99))
+        );
+    }
+
+    #[test]
+    fn test_ids_estaveis_apos_exclusao() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let primeiro = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let segundo = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.excluir_animal(primeiro).unwrap();
+        let terceiro = zoologico.incluir_animal("Girafa", "Melman").unwrap();
+
+        assert_eq!(zoologico.buscar_animal(segundo).unwrap().nome, "Marty");
+        assert_ne!(terceiro, primeiro);
+        assert!(zoologico.excluir_animal(primeiro).is_err());
+    }
+
+    #[test]
+    fn test_persistencia() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        let id = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let excluido = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.excluir_animal(excluido).unwrap();
+        zoologico.salvar().unwrap();
+
+        let mut carregado = Zoologico::carregar(&caminho).unwrap();
+        assert_eq!(carregado.listar_animais().len(), 1);
+        assert_eq!(carregado.buscar_animal(id).unwrap().nome, "Simba");
+        // O id excluído não é reutilizado depois de recarregar
+        assert_eq!(carregado.incluir_animal("Girafa", "Melman").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_carregar_formato_legado() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        std::fs::write(&caminho, r#"[{"tipo":"boi","nome":"boizinho"}]"#).unwrap();
+
+        let zoologico = Zoologico::carregar(&caminho).unwrap();
+        let animais = zoologico.listar_animais();
+        assert_eq!(animais.len(), 1);
+        assert_eq!(animais[0].id, 1);
+        assert_eq!(animais[0].nome, "boizinho");
+        // O tipo antigo é padronizado pelo catálogo
+        assert_eq!(animais[0].tipo, "Boi");
+    }
+
+    #[test]
+    fn test_carregar_tipo_fora_do_catalogo() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        std::fs::write(&caminho, r#"[{"tipo":"Unicórnio","nome":"Estrela"}]"#).unwrap();
+
+        let zoologico = Zoologico::carregar(&caminho).unwrap();
+        let fora = zoologico.animais_fora_do_catalogo();
+        assert_eq!(fora.len(), 1);
+        assert_eq!(fora[0].tipo, "Unicórnio");
+    }
+
+    #[test]
+    fn test_alocar_animal_em_recinto() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let savana = zoologico
+            .incluir_recinto("Savana Africana", Habitat::Savana, 2, 1000)
+            .unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        let girafa = zoologico.incluir_animal("Girafa", "Melman").unwrap();
+
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        zoologico.alocar_animal(girafa, savana).unwrap();
+        // Realocar para o mesmo recinto não conta o animal duas vezes
+        zoologico.alocar_animal(zebra, savana).unwrap();
+
+        assert_eq!(zoologico.animais_no_recinto(savana).len(), 2);
+        let ocupacao = &zoologico.relatorio_ocupacao()[0];
+        assert_eq!(ocupacao.ocupantes, 2);
+        assert_eq!(ocupacao.area_ocupada_m2, 700);
+        assert_eq!(ocupacao.percentual(), 100);
+    }
+
+    #[test]
+    fn test_alocacao_recusada() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 2, 900)
+            .unwrap();
+        let polar = zoologico
+            .incluir_recinto("Ártico", Habitat::Polar, 3, 2000)
+            .unwrap();
+        let leao = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        let girafa = zoologico.incluir_animal("Girafa", "Melman").unwrap();
+        let elefante = zoologico.incluir_animal("Elefante", "Dumbo").unwrap();
+
+        assert!(matches!(
+            zoologico.alocar_animal(leao, polar),
+            Err(ErroZoologico::HabitatIncompativel { .. })
+        ));
+        assert!(matches!(
+            zoologico.alocar_animal(elefante, savana),
+            Err(ErroZoologico::AreaInsuficiente { .. })
+        ));
+
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        assert_eq!(
+            zoologico.alocar_animal(leao, savana),
+            Err(ErroZoologico::PredadorEPresa {
+                predador: "Leão".to_string(),
+                presa: "Zebra".to_string(),
+            })
+        );
+
+        zoologico.alocar_animal(girafa, savana).unwrap();
+        let outra_zebra = zoologico.incluir_animal("Zebra", "Listrada").unwrap();
+        assert!(matches!(
+            zoologico.alocar_animal(outra_zebra, savana),
+            Err(ErroZoologico::RecintoLotado { .. })
+        ));
+        assert_eq!(
+            zoologico.alocar_animal(leao, 99),
+            Err(ErroZoologico::RecintoNaoEncontrado(99))
+        );
+
+        // A edição não pode trocar a espécie por uma incompatível com o recinto
+        assert!(zoologico.editar_animal(zebra, "Leão", "Marty").is_err());
+        assert_eq!(zoologico.buscar_animal(zebra).unwrap().tipo, "Zebra");
+    }
+
+    #[test]
+    fn test_excluir_recinto_ocupado() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let recinto = zoologico
+            .incluir_recinto("Lago", Habitat::Alagado, 5, 500)
+            .unwrap();
+        let capivara = zoologico.incluir_animal("Capivara", "Cacá").unwrap();
+        zoologico.alocar_animal(capivara, recinto).unwrap();
+
+        assert_eq!(
+            zoologico.excluir_recinto(recinto),
+            Err(ErroZoologico::RecintoOcupado(recinto))
+        );
+        zoologico.retirar_do_recinto(capivara).unwrap();
+        assert!(zoologico.excluir_recinto(recinto).is_ok());
+    }
+
+    #[test]
+    fn test_persistencia_recintos() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        let recinto = zoologico
+            .incluir_recinto("Floresta", Habitat::Floresta, 4, 800)
+            .unwrap();
+        let tigre = zoologico.incluir_animal("Tigre", "Shere Khan").unwrap();
+        zoologico.alocar_animal(tigre, recinto).unwrap();
+        zoologico.salvar().unwrap();
+
+        let mut carregado = Zoologico::carregar(&caminho).unwrap();
+        assert_eq!(carregado.buscar_recinto(recinto).unwrap().nome, "Floresta");
+        assert_eq!(
+            carregado.buscar_animal(tigre).unwrap().id_recinto,
+            Some(recinto)
+        );
+        let novo = carregado
+            .incluir_recinto("Lago", Habitat::Alagado, 1, 100)
+            .unwrap();
+        assert_ne!(novo, recinto);
+    }
+
+    fn horarios(texto: &str) -> Vec<Horario> {
+        alimentacao::ler_horarios(texto).unwrap()
+    }
+
+    #[test]
+    fn test_registrar_alimentacao_desconta_estoque() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let hoje = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        let leao = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let plano = zoologico
+            .incluir_plano(leao, "Carne bovina", 4000, horarios("08:00, 17:00"))
+            .unwrap();
+        zoologico.adicionar_estoque("carne bovina", 10000).unwrap();
+
+        let oito = Horario::new(8, 0).unwrap();
+        zoologico.registrar_alimentacao(plano, hoje, oito).unwrap();
+        assert_eq!(zoologico.nutricao().listar_estoque()[0].quantidade_g, 6000);
+
+        assert!(matches!(
+            zoologico.registrar_alimentacao(plano, hoje, oito),
+            Err(ErroZoologico::AlimentacaoJaRegistrada { .. })
+        ));
+        assert!(matches!(
+            zoologico.registrar_alimentacao(plano, hoje, Horario::new(12, 0).unwrap()),
+            Err(ErroZoologico::HorarioInvalido(_))
+        ));
+
+        let amanha = hoje.succ_opt().unwrap();
+        zoologico
+            .registrar_alimentacao(plano, amanha, oito)
+            .unwrap();
+        assert_eq!(
+            zoologico.registrar_alimentacao(plano, amanha, Horario::new(17, 0).unwrap()),
+            Err(ErroZoologico::EstoqueInsuficiente {
+                alimento: "Carne bovina".to_string(),
+                disponivel_g: 2000,
+                necessario_g: 4000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_checklist_agrupado_por_tratador_e_recinto() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let hoje = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        let lago = zoologico
+            .incluir_recinto("Lago", Habitat::Alagado, 5, 2000)
+            .unwrap();
+        let bruno = zoologico.incluir_tratador("Bruno").unwrap();
+        let ana = zoologico.incluir_tratador("Ana").unwrap();
+        zoologico
+            .atribuir_tratador_recinto(savana, Some(bruno))
+            .unwrap();
+        zoologico
+            .atribuir_tratador_recinto(lago, Some(ana))
+            .unwrap();
+
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        let capivara = zoologico.incluir_animal("Capivara", "Cacá").unwrap();
+        let boi = zoologico.incluir_animal("Boi", "Mimoso").unwrap();
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        zoologico.alocar_animal(capivara, lago).unwrap();
+
+        let plano_zebra = zoologico
+            .incluir_plano(zebra, "Feno", 5000, horarios("16:00, 07:00"))
+            .unwrap();
+        zoologico
+            .incluir_plano(capivara, "Capim", 2000, horarios("09:00"))
+            .unwrap();
+        zoologico
+            .incluir_plano(boi, "Feno", 8000, horarios("07:00"))
+            .unwrap();
+        zoologico.adicionar_estoque("Feno", 100_000).unwrap();
+        zoologico
+            .registrar_alimentacao(plano_zebra, hoje, Horario::new(7, 0).unwrap())
+            .unwrap();
+
+        let checklist = zoologico.checklist(hoje);
+        assert_eq!(checklist.len(), 3);
+        assert_eq!(checklist[0].tratador.as_deref(), Some("Ana"));
+        assert_eq!(checklist[1].recinto.as_deref(), Some("Savana"));
+        assert_eq!(checklist[2].recinto, None);
+
+        let savana = &checklist[1].itens;
+        assert_eq!(savana.len(), 2);
+        assert_eq!(savana[0].horario, Horario::new(7, 0).unwrap());
+        assert!(savana[0].feito);
+        assert!(!savana[1].feito);
+    }
+
+    #[test]
+    fn test_alertas_estoque_projetam_dias_restantes() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let girafa = zoologico.incluir_animal("Girafa", "Melman").unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico
+            .incluir_plano(girafa, "Feno", 10_000, horarios("08:00"))
+            .unwrap();
+        zoologico
+            .incluir_plano(zebra, "Feno", 5000, horarios("08:00, 16:00"))
+            .unwrap();
+        zoologico
+            .incluir_plano(zebra, "Cenoura", 500, horarios("12:00"))
+            .unwrap();
+        zoologico.adicionar_estoque("Feno", 100_000).unwrap();
+        zoologico.adicionar_estoque("Cenoura", 50_000).unwrap();
+
+        assert_eq!(zoologico.nutricao().consumo_diario_g("feno"), 20_000);
+        let alertas = zoologico.nutricao().alertas_estoque();
+        assert_eq!(alertas.len(), 1);
+        assert_eq!(alertas[0].alimento, "Feno");
+        assert_eq!(alertas[0].dias_restantes, 5);
+
+        zoologico.definir_dias_minimos("Feno", 3).unwrap();
+        assert!(zoologico.nutricao().alertas_estoque().is_empty());
+
+        // Alimento de um plano sem nenhum estoque cadastrado
+        zoologico
+            .incluir_plano(girafa, "Folhas de acácia", 1000, horarios("10:00"))
+            .unwrap();
+        let alertas = zoologico.nutricao().alertas_estoque();
+        assert_eq!(alertas[0].alimento, "Folhas de acácia");
+        assert_eq!(alertas[0].dias_restantes, 0);
+    }
+
+    #[test]
+    fn test_persistencia_alimentacao() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        let hoje = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        let tigre = zoologico.incluir_animal("Tigre", "Shere Khan").unwrap();
+        let plano = zoologico
+            .incluir_plano(tigre, "Frango", 3000, horarios("09:00"))
+            .unwrap();
+        zoologico.adicionar_estoque("Frango", 9000).unwrap();
+        zoologico
+            .registrar_alimentacao(plano, hoje, Horario::new(9, 0).unwrap())
+            .unwrap();
+        zoologico.salvar().unwrap();
+
+        let carregado = Zoologico::carregar(&caminho).unwrap();
+        assert_eq!(carregado.nutricao().planos_do_animal(tigre).len(), 1);
+        assert_eq!(carregado.nutricao().listar_estoque()[0].quantidade_g, 6000);
+        assert!(carregado.checklist(hoje)[0].itens[0].feito);
+
+        // Excluir o animal remove seus planos
+        let mut carregado = carregado;
+        carregado.excluir_animal(tigre).unwrap();
+        assert!(carregado.nutricao().buscar_plano(plano).is_none());
+    }
+
+    fn dia(dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 6, dia).unwrap()
+    }
+
+    #[test]
+    fn test_vacinas_pendentes() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let leao = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico
+            .registrar_vacina(leao, "Raiva", dia(1), Some(dia(5)))
+            .unwrap();
+        zoologico
+            .registrar_vacina(zebra, "Tétano", dia(1), Some(dia(20)))
+            .unwrap();
+        zoologico
+            .registrar_vacina(zebra, "Raiva", dia(2), None)
+            .unwrap();
+
+        let pendentes = zoologico.vacinas_pendentes(dia(10), dia(25));
+        assert_eq!(pendentes.len(), 2);
+        assert_eq!(pendentes[0].nome_animal, "Simba");
+        assert!(pendentes[0].atrasada);
+        assert!(!pendentes[1].atrasada);
+
+        // Um reforço aplicado substitui a pendência da dose anterior
+        zoologico
+            .registrar_vacina(leao, "raiva", dia(10), Some(dia(30)))
+            .unwrap();
+        let pendentes = zoologico.vacinas_pendentes(dia(10), dia(25));
+        assert_eq!(pendentes.len(), 1);
+        assert_eq!(pendentes[0].vacina, "Tétano");
+
+        assert_eq!(
+            zoologico.registrar_vacina(99, "Raiva", dia(1), None),
+            Err(ErroZoologico::AnimalNaoEncontrado(99))
+        );
+    }
+
+    #[test]
+    fn test_quarentena_bloqueia_mudanca_de_recinto() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        let isolamento = zoologico
+            .incluir_recinto("Isolamento", Habitat::Savana, 1, 500)
+            .unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        let girafa = zoologico.incluir_animal("Girafa", "Melman").unwrap();
+        zoologico.alocar_animal(zebra, savana).unwrap();
+
+        zoologico
+            .iniciar_quarentena(zebra, "Suspeita de verminose", dia(1))
+            .unwrap();
+        zoologico
+            .iniciar_quarentena(girafa, "Recém-chegada", dia(1))
+            .unwrap();
+        assert!(zoologico.clinica().em_quarentena(zebra));
+        assert_eq!(
+            zoologico.alocar_animal(zebra, isolamento),
+            Err(ErroZoologico::AnimalEmQuarentena(zebra))
+        );
+        assert_eq!(
+            zoologico.retirar_do_recinto(zebra),
+            Err(ErroZoologico::AnimalEmQuarentena(zebra))
+        );
+        // Um animal em quarentena ainda sem recinto pode ir para o isolamento
+        zoologico.alocar_animal(girafa, isolamento).unwrap();
+
+        zoologico.encerrar_quarentena(zebra, dia(15)).unwrap();
+        assert_eq!(
+            zoologico.encerrar_quarentena(zebra, dia(16)),
+            Err(ErroZoologico::AnimalSemQuarentena(zebra))
+        );
+        zoologico.retirar_do_recinto(zebra).unwrap();
+    }
+
+    #[test]
+    fn test_tratamentos_consultas_e_peso() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        let mut zoologico = Zoologico::new(caminho.clone());
+
+        let tigre = zoologico.incluir_animal("Tigre", "Shere Khan").unwrap();
+        let tratamento = zoologico
+            .iniciar_tratamento(tigre, "Antibiótico", dia(1))
+            .unwrap();
+        zoologico
+            .encerrar_tratamento(tigre, tratamento, dia(8))
+            .unwrap();
+        assert!(
+            zoologico
+                .encerrar_tratamento(tigre, tratamento, dia(9))
+                .is_err()
+        );
+
+        let consulta = zoologico
+            .agendar_consulta(tigre, dia(20), "Check-up anual")
+            .unwrap();
+        zoologico
+            .agendar_consulta(tigre, dia(28), "Dentes")
+            .unwrap();
+        assert_eq!(zoologico.proximas_consultas(dia(25)).len(), 1);
+        zoologico
+            .concluir_consulta(tigre, consulta, "Tudo certo")
+            .unwrap();
+        assert!(zoologico.proximas_consultas(dia(25)).is_empty());
+
+        zoologico
+            .registrar_pesagem(tigre, dia(15), 210_000)
+            .unwrap();
+        zoologico.registrar_pesagem(tigre, dia(1), 200_000).unwrap();
+        zoologico.salvar().unwrap();
+
+        let carregado = Zoologico::carregar(&caminho).unwrap();
+        let relatorio = carregado.relatorio_peso();
+        assert_eq!(relatorio.len(), 1);
+        assert_eq!(relatorio[0].1.variacao_g, 10_000);
+        assert_eq!(relatorio[0].1.gramas_por_semana, 5_000.0);
+        assert_eq!(
+            carregado.clinica().ficha_medica(tigre).unwrap().tratamentos[0].fim,
+            Some(dia(8))
+        );
+    }
+
+    #[test]
+    fn test_consultar_animais() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 5000)
+            .unwrap();
+        let nala = zoologico.incluir_animal("Leão", "Nala").unwrap();
+        let pintada = zoologico.incluir_animal("Onça-pintada", "Pintada").unwrap();
+        let simba = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let agata = zoologico.incluir_animal("Zebra", "Ágata").unwrap();
+        zoologico.alocar_animal(nala, savana).unwrap();
+        zoologico.alocar_animal(simba, savana).unwrap();
+        let hoje = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        zoologico
+            .iniciar_quarentena(pintada, "Chegada", hoje)
+            .unwrap();
+        let ids = |linhas: Vec<LinhaAnimal>| linhas.iter().map(|l| l.id).collect::<Vec<_>>();
+
+        // Sinônimo do catálogo encontra a espécie oficial
+        let consulta = ConsultaAnimais {
+            especie: Some("onça".to_string()),
+            ..ConsultaAnimais::default()
+        };
+        assert_eq!(ids(zoologico.consultar_animais(&consulta)), vec![pintada]);
+
+        let consulta = ConsultaAnimais {
+            nome: Some("AGA".to_string()),
+            ..ConsultaAnimais::default()
+        };
+        assert_eq!(ids(zoologico.consultar_animais(&consulta)), vec![agata]);
+
+        let consulta = ConsultaAnimais {
+            recinto: Some(FiltroRecinto::Recinto(savana)),
+            ordenar_por: CampoOrdenacao::Nome,
+            decrescente: true,
+            ..ConsultaAnimais::default()
+        };
+        assert_eq!(
+            ids(zoologico.consultar_animais(&consulta)),
+            vec![simba, nala]
+        );
+
+        let consulta = ConsultaAnimais {
+            saude: Some(SituacaoSaude::EmQuarentena),
+            ..ConsultaAnimais::default()
+        };
+        let linhas = zoologico.consultar_animais(&consulta);
+        assert_eq!(ids(linhas), vec![pintada]);
+
+        // Nomes com acento ordenam junto com os sem acento
+        let consulta = ConsultaAnimais {
+            ordenar_por: CampoOrdenacao::Nome,
+            ..ConsultaAnimais::default()
+        };
+        assert_eq!(
+            ids(zoologico.consultar_animais(&consulta)),
+            vec![agata, nala, pintada, simba]
+        );
+
+        let consulta = ConsultaAnimais {
+            ordenar_por: CampoOrdenacao::Recinto,
+            ..ConsultaAnimais::default()
+        };
+        assert_eq!(
+            ids(zoologico.consultar_animais(&consulta)),
+            vec![nala, simba, pintada, agata]
+        );
+
+        // Em ordem decrescente, empates continuam na ordem do id
+        let consulta = ConsultaAnimais {
+            ordenar_por: CampoOrdenacao::Especie,
+            decrescente: true,
+            ..ConsultaAnimais::default()
+        };
+        assert_eq!(
+            ids(zoologico.consultar_animais(&consulta)),
+            vec![agata, pintada, nala, simba]
+        );
+    }
+
+    #[test]
+    fn test_importar_animais_tudo_ou_nada() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let importado = |tipo: &str, nome: &str| AnimalImportado {
+            tipo: tipo.to_string(),
+            nome: nome.to_string(),
+        };
+
+        let erro = zoologico
+            .importar_animais(&[importado("Leão", "Simba"), importado("Dragão", "Smaug")])
+            .unwrap_err();
+        assert!(matches!(
+            erro,
+            ErroZoologico::RegistroImportacaoInvalido { numero: 2, .. }
+        ));
+        assert!(zoologico.listar_animais().is_empty());
+
+        let ids = zoologico
+            .importar_animais(&[importado("Leão", "Simba"), importado("bovino", "Mimosa")])
+            .unwrap();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(zoologico.buscar_animal(2).unwrap().tipo, "Boi");
+    }
+
+    #[test]
+    fn test_carregar_arquivo_invalido() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        std::fs::write(&caminho, "isto não é json").unwrap();
+
+        assert!(matches!(
+            Zoologico::carregar(&caminho),
+            Err(ErroZoologico::ArquivoCorrompido(_))
+        ));
+    }
+
+    #[test]
+    fn test_salvar_nao_sobrescreve_arquivo_corrompido() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        std::fs::write(&caminho, r#"{"proximo_id":2,"animais":[{"id":1"#).unwrap();
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        zoologico.incluir_animal("Leão", "Simba").unwrap();
+        assert!(matches!(
+            zoologico.salvar(),
+            Err(ErroZoologico::ArquivoCorrompido(_))
+        ));
+        assert_eq!(
+            std::fs::read_to_string(&caminho).unwrap(),
+            r#"{"proximo_id":2,"animais":[{"id":1"#
+        );
+    }
+
+    #[test]
+    fn test_salvar_cria_backup_do_conteudo_anterior() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        zoologico.incluir_animal("Leão", "Simba").unwrap();
+        zoologico.salvar().unwrap();
+        assert!(persistencia::listar_backups(&caminho).is_empty());
+
+        zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.salvar().unwrap();
+
+        let backups = persistencia::listar_backups(&caminho);
+        assert_eq!(backups.len(), 1);
+        let anterior = Zoologico::carregar(&backups[0]).unwrap();
+        assert_eq!(anterior.listar_animais().len(), 1);
+    }
+
+    #[test]
+    fn test_recuperar_descarta_id_fora_da_faixa() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        zoologico.incluir_animal("Leão", "Simba").unwrap();
+        zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.salvar().unwrap();
+
+        let conteudo = std::fs::read_to_string(&caminho).unwrap();
+        assert!(conteudo.contains(r#""id":2,"#));
+        let conteudo = conteudo.replace(r#""id":2,"#, &format!(r#""id":{},"#, u32::MAX));
+        std::fs::write(&caminho, conteudo).unwrap();
+
+        assert!(matches!(
+            Zoologico::carregar(&caminho),
+            Err(ErroZoologico::ArquivoCorrompido(_))
+        ));
+
+        let (mut recuperado, relatorio) = Zoologico::recuperar(&caminho).unwrap();
+        assert_eq!(relatorio.descartados, 1);
+        assert_eq!(recuperado.listar_animais().len(), 1);
+        assert_eq!(recuperado.incluir_animal("Girafa", "Melman").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_contador_de_ids_no_limite() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        zoologico.incluir_animal("Leão", "Simba").unwrap();
+        zoologico.incluir_tratador("Ana").unwrap();
+        zoologico.salvar().unwrap();
+
+        let conteudo = std::fs::read_to_string(&caminho).unwrap();
+        let conteudo = conteudo
+            .replace(
+                r#""proximo_id":2"#,
+                &format!(r#""proximo_id":{}"#, u32::MAX),
+            )
+            .replace(
+                r#""proximo_id_tratador":2"#,
+                &format!(r#""proximo_id_tratador":{}"#, u32::MAX),
+            );
+        std::fs::write(&caminho, conteudo).unwrap();
+
+        let mut carregado = Zoologico::carregar(&caminho).unwrap();
+        let esgotado = |cadastro: &str| Err(ErroZoologico::IdsEsgotados(cadastro.to_string()));
+        assert_eq!(
+            carregado.incluir_animal("Zebra", "Marty"),
+            esgotado("animais")
+        );
+        assert_eq!(
+            carregado.registrar_chegada("Zebra", "Marty", dia(1), "Zoo de SP", ""),
+            esgotado("animais")
+        );
+        assert_eq!(carregado.incluir_tratador("Bruno"), esgotado("tratadores"));
+        assert_eq!(carregado.listar_animais().len(), 1);
+        assert!(carregado.historico().eventos_do_animal(u32::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_carregar_recusa_id_repetido() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        zoologico.incluir_animal("Leão", "Simba").unwrap();
+        zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.salvar().unwrap();
+
+        let conteudo = std::fs::read_to_string(&caminho).unwrap();
+        std::fs::write(&caminho, conteudo.replace(r#""id":2,"#, r#""id":1,"#)).unwrap();
+
+        // Sem o erro, o próximo salvamento apagaria Marty do arquivo
+        let erro = Zoologico::carregar(&caminho).err().unwrap();
+        assert!(matches!(erro, ErroZoologico::ArquivoCorrompido(_)));
+        assert!(erro.to_string().contains("recuperar"));
+
+        let (recuperado, relatorio) = Zoologico::recuperar(&caminho).unwrap();
+        assert_eq!(relatorio.descartados, 1);
+        assert_eq!(recuperado.listar_animais().len(), 1);
+    }
+
+    #[test]
+    fn test_recuperar_arquivo_parcialmente_corrompido() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        let recinto = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        let simba = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let marty = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.incluir_animal("Girafa", "Melman").unwrap();
+        zoologico.alocar_animal(marty, recinto).unwrap();
+        zoologico.salvar().unwrap();
+
+        // Corrompe o terceiro animal e corta o arquivo antes dos recintos
+        let conteudo = std::fs::read_to_string(&caminho).unwrap();
+        let corte = conteudo.find("\"recintos\"").unwrap();
+        let corrompido = conteudo[..corte].replace(r#""nome":"Melman""#, r#""nome":42"#);
+        std::fs::write(&caminho, &corrompido).unwrap();
+        assert!(Zoologico::carregar(&caminho).is_err());
+
+        let (recuperado, relatorio) = Zoologico::recuperar(&caminho).unwrap();
+        assert_eq!(relatorio.recuperados.get("animais"), Some(&2));
+        assert_eq!(relatorio.descartados, 1);
+        // Marty estava num recinto que se perdeu
+        assert_eq!(relatorio.referencias_removidas, 1);
+        assert_eq!(recuperado.buscar_animal(simba).unwrap().nome, "Simba");
+        assert_eq!(recuperado.buscar_animal(marty).unwrap().id_recinto, None);
+
+        // O arquivo recuperado volta a carregar e o original fica guardado
+        assert_eq!(
+            Zoologico::carregar(&caminho)
+                .unwrap()
+                .listar_animais()
+                .len(),
+            2
+        );
+        let copia = relatorio.copia_original.unwrap();
+        assert_eq!(std::fs::read_to_string(copia).unwrap(), corrompido);
+    }
+
+    fn momento(data: NaiveDate, hora: u8, minuto: u8) -> NaiveDateTime {
+        equipe::prazo(data, Horario::new(hora, minuto).unwrap())
+    }
+
+    #[test]
+    fn test_responsavel_pelo_animal() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        let bruno = zoologico.incluir_tratador("Bruno").unwrap();
+        let ana = zoologico.incluir_tratador("Ana").unwrap();
+        assert_eq!(
+            zoologico.incluir_tratador("  bruno "),
+            Err(ErroZoologico::TratadorJaCadastrado("bruno".to_string()))
+        );
+        zoologico
+            .atribuir_tratador_recinto(savana, Some(bruno))
+            .unwrap();
+
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        let girafa = zoologico.incluir_animal("Girafa", "Melman").unwrap();
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        zoologico.alocar_animal(girafa, savana).unwrap();
+        zoologico
+            .atribuir_tratador_animal(girafa, Some(ana))
+            .unwrap();
+
+        assert_eq!(zoologico.responsavel_pelo_animal(zebra).unwrap().id, bruno);
+        assert_eq!(zoologico.responsavel_pelo_animal(girafa).unwrap().id, ana);
+        assert_eq!(zoologico.animais_do_tratador(bruno).len(), 1);
+        assert_eq!(
+            zoologico.atribuir_tratador_animal(zebra, Some(99)),
+            Err(ErroZoologico::TratadorNaoEncontrado(99))
+        );
+
+        // Sem a Ana, a girafa volta a ficar com o tratador do recinto
+        zoologico.excluir_tratador(ana).unwrap();
+        assert_eq!(zoologico.responsavel_pelo_animal(girafa).unwrap().id, bruno);
+    }
+
+    #[test]
+    fn test_gerar_tarefas_do_dia() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        zoologico
+            .incluir_recinto("Lago", Habitat::Alagado, 5, 2000)
+            .unwrap();
+        let bruno = zoologico.incluir_tratador("Bruno").unwrap();
+        let ana = zoologico.incluir_tratador("Ana").unwrap();
+        zoologico
+            .atribuir_tratador_recinto(savana, Some(bruno))
+            .unwrap();
+
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        let boi = zoologico.incluir_animal("Boi", "Mimoso").unwrap();
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        zoologico
+            .incluir_plano(zebra, "Feno", 5000, horarios("07:00, 16:00"))
+            .unwrap();
+        zoologico
+            .iniciar_tratamento(zebra, "Antibiótico", dia(1))
+            .unwrap();
+        zoologico
+            .agendar_consulta(boi, dia(3), "Exame de rotina")
+            .unwrap();
+        zoologico.agendar_consulta(boi, dia(4), "Retorno").unwrap();
+
+        // 2 refeições, limpeza da Savana (o Lago está vazio),
+        // acompanhamento da zebra e a consulta do boi
+        assert_eq!(zoologico.gerar_tarefas(dia(3)), Ok(5));
+        let grupos = zoologico.equipe().tarefas_do_dia(dia(3));
+        assert_eq!(grupos.len(), 2);
+        assert_eq!(grupos[0].tratador.as_ref().unwrap().nome, "Bruno");
+        let tipos: Vec<TipoTarefa> = grupos[0].tarefas.iter().map(Tarefa::tipo).collect();
+        assert_eq!(
+            tipos,
+            [
+                TipoTarefa::Alimentacao,
+                TipoTarefa::Limpeza,
+                TipoTarefa::Alimentacao,
+                TipoTarefa::ExameMedico
+            ]
+        );
+        assert_eq!(
+            grupos[0].tarefas[0].descricao,
+            "Servir 5 kg de Feno a Marty"
+        );
+        assert_eq!(grupos[1].tratador, None);
+        assert_eq!(
+            grupos[1].tarefas[0].descricao,
+            "Consulta de Mimoso: Exame de rotina"
+        );
+
+        // Gerar de novo não duplica, mas completa o responsável que faltava
+        zoologico.atribuir_tratador_animal(boi, Some(ana)).unwrap();
+        assert_eq!(zoologico.gerar_tarefas(dia(3)), Ok(0));
+        let grupos = zoologico.equipe().tarefas_do_dia(dia(3));
+        assert_eq!(grupos.len(), 2);
+        assert_eq!(grupos[0].tratador.as_ref().unwrap().nome, "Ana");
+    }
+
+    #[test]
+    fn test_concluir_tarefa_de_alimentacao() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let leao = zoologico.incluir_animal("Leão", "Simba").unwrap();
+        let plano = zoologico
+            .incluir_plano(leao, "Carne", 4000, horarios("08:00, 17:00"))
+            .unwrap();
+        zoologico.adicionar_estoque("Carne", 10000).unwrap();
+        zoologico
+            .registrar_alimentacao(plano, dia(3), Horario::new(17, 0).unwrap())
+            .unwrap();
+
+        zoologico.gerar_tarefas(dia(3)).unwrap();
+        let tarefas = &zoologico.equipe().tarefas_do_dia(dia(3))[0].tarefas;
+        let (manha, tarde) = (tarefas[0].id, tarefas[1].id);
+        // A refeição das 17:00 já estava registrada
+        assert!(tarefas[1].concluida());
+
+        zoologico
+            .concluir_tarefa(manha, momento(dia(3), 8, 10))
+            .unwrap();
+        assert_eq!(zoologico.nutricao().listar_estoque()[0].quantidade_g, 2000);
+        assert!(zoologico.checklist(dia(3))[0].itens[0].feito);
+        assert_eq!(
+            zoologico.concluir_tarefa(tarde, momento(dia(3), 18, 0)),
+            Err(ErroZoologico::TarefaJaConcluida(tarde))
+        );
+        assert_eq!(
+            zoologico.concluir_tarefa(99, momento(dia(3), 18, 0)),
+            Err(ErroZoologico::TarefaNaoEncontrada(99))
+        );
+    }
+
+    #[test]
+    fn test_tarefas_atrasadas() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        let plano = zoologico
+            .incluir_plano(zebra, "Feno", 5000, horarios("07:00"))
+            .unwrap();
+        zoologico.adicionar_estoque("Feno", 10000).unwrap();
+        zoologico.gerar_tarefas(dia(3)).unwrap();
+
+        assert!(
+            zoologico
+                .equipe()
+                .tarefas_atrasadas(momento(dia(3), 6, 0))
+                .is_empty()
+        );
+        let atrasadas = zoologico
+            .equipe()
+            .tarefas_atrasadas(momento(dia(3), 12, 30));
+        assert_eq!(atrasadas[0].tarefas.len(), 2);
+
+        // Registrar a refeição pelo plano também conclui a tarefa
+        zoologico
+            .registrar_alimentacao(plano, dia(3), Horario::new(7, 0).unwrap())
+            .unwrap();
+        let atrasadas = zoologico
+            .equipe()
+            .tarefas_atrasadas(momento(dia(3), 12, 30));
+        assert_eq!(atrasadas[0].tarefas.len(), 1);
+        assert_eq!(atrasadas[0].tarefas[0].tipo(), TipoTarefa::Limpeza);
+    }
+
+    #[test]
+    fn test_tratador_legado_vira_cadastro() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        std::fs::write(
+            &caminho,
+            r#"{"proximo_id":1,"animais":[],"proximo_id_recinto":3,"recintos":[
+                {"id":1,"nome":"Savana","habitat":"Savana","capacidade":5,"area_m2":2000,"tratador":"Bruno"},
+                {"id":2,"nome":"Lago","habitat":"Alagado","capacidade":5,"area_m2":2000,"tratador":"bruno"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let zoologico = Zoologico::carregar(&caminho).unwrap();
+        let tratadores = zoologico.equipe().listar_tratadores();
+        assert_eq!(tratadores.len(), 1);
+        assert_eq!(tratadores[0].nome, "Bruno");
+        assert_eq!(zoologico.recintos_do_tratador(tratadores[0].id).len(), 2);
+
+        // O nome antigo não volta a ser gravado
+        zoologico.salvar().unwrap();
+        let conteudo = std::fs::read_to_string(&caminho).unwrap();
+        assert!(!conteudo.contains(r#""tratador":"#));
+        assert_eq!(
+            Zoologico::carregar(&caminho)
+                .unwrap()
+                .equipe()
+                .listar_tratadores()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_persistencia_equipe() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        let bruno = zoologico.incluir_tratador("Bruno").unwrap();
+        zoologico
+            .atribuir_tratador_recinto(savana, Some(bruno))
+            .unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        zoologico.gerar_tarefas(dia(3)).unwrap();
+        let id = zoologico.equipe().tarefas_do_dia(dia(3))[0].tarefas[0].id;
+        zoologico
+            .concluir_tarefa(id, momento(dia(3), 10, 0))
+            .unwrap();
+        zoologico.salvar().unwrap();
+
+        let mut carregado = Zoologico::carregar(&caminho).unwrap();
+        assert_eq!(
+            carregado.equipe().buscar_tratador(bruno).unwrap().nome,
+            "Bruno"
+        );
+        assert_eq!(
+            carregado.equipe().tarefas_do_dia(dia(3)),
+            zoologico.equipe().tarefas_do_dia(dia(3))
+        );
+        assert_ne!(carregado.incluir_tratador("Ana").unwrap(), bruno);
+        assert_eq!(carregado.gerar_tarefas(dia(3)), Ok(0));
+    }
+
+    #[test]
+    fn test_vender_ingressos() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let nove = Horario::new(9, 0).unwrap();
+        zoologico.definir_faixa(nove, 12).unwrap();
+
+        let id = zoologico
+            .vender_ingressos(
+                dia(7),
+                nove,
+                &[
+                    (TipoIngresso::Adulto, 2),
+                    (TipoIngresso::Crianca, 1),
+                    (TipoIngresso::Idoso, 0),
+                ],
+                momento(dia(1), 10, 0),
+            )
+            .unwrap();
+        let venda = zoologico.bilheteria().buscar_venda(id).unwrap();
+        assert_eq!(venda.visitantes(), 3);
+        assert_eq!(venda.total_centavos(), Ok(2 * 4000 + 2000));
+
+        assert_eq!(
+            zoologico.vender_ingressos(
+                dia(7),
+                nove,
+                &[(TipoIngresso::Grupo, 9)],
+                momento(dia(1), 10, 0)
+            ),
+            Err(ErroZoologico::QuantidadeAbaixoDoMinimo {
+                tipo: "Grupo".to_string(),
+                minimo: 10,
+                pessoas: 9,
+            })
+        );
+        assert_eq!(
+            zoologico.vender_ingressos(
+                dia(7),
+                nove,
+                &[(TipoIngresso::Grupo, 10)],
+                momento(dia(1), 10, 0)
+            ),
+            Err(ErroZoologico::LotacaoEsgotada {
+                faixa: "09:00".to_string(),
+                disponiveis: 9,
+                solicitados: 10,
+            })
+        );
+        assert_eq!(
+            zoologico.vender_ingressos(
+                dia(7),
+                Horario::new(10, 0).unwrap(),
+                &[(TipoIngresso::Adulto, 1)],
+                momento(dia(1), 10, 0)
+            ),
+            Err(ErroZoologico::FaixaNaoEncontrada("10:00".to_string()))
+        );
+        assert_eq!(
+            zoologico.vender_ingressos(dia(7), nove, &[], momento(dia(1), 10, 0)),
+            Err(ErroZoologico::CampoVazio("ingressos".to_string()))
+        );
+
+        // A lotação é por dia: a mesma faixa de outro dia está livre
+        let ocupacao = zoologico.bilheteria().ocupacao_faixas(dia(7));
+        assert_eq!(ocupacao[0].inicio, nove);
+        assert_eq!(ocupacao[0].disponiveis(), 9);
+        assert_eq!(
+            zoologico.bilheteria().ocupacao_faixas(dia(8))[0].disponiveis(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_vender_ingressos_com_linhas_repetidas() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let nove = Horario::new(9, 0).unwrap();
+        zoologico.definir_faixa(nove, 20).unwrap();
+
+        // Duas linhas de 5 formam um grupo de 10, que atinge o mínimo
+        let id = zoologico
+            .vender_ingressos(
+                dia(7),
+                nove,
+                &[(TipoIngresso::Grupo, 5), (TipoIngresso::Grupo, 5)],
+                momento(dia(1), 10, 0),
+            )
+            .unwrap();
+        let venda = zoologico.bilheteria().buscar_venda(id).unwrap();
+        assert_eq!(venda.itens.len(), 1);
+        assert_eq!(venda.visitantes(), 10);
+
+        assert!(matches!(
+            zoologico.vender_ingressos(
+                dia(7),
+                nove,
+                &[(TipoIngresso::Adulto, u32::MAX), (TipoIngresso::Adulto, 1)],
+                momento(dia(1), 10, 0)
+            ),
+            Err(ErroZoologico::ValorInvalido(_))
+        ));
+        assert!(matches!(
+            zoologico.vender_ingressos(
+                dia(7),
+                nove,
+                &[(TipoIngresso::Adulto, u32::MAX), (TipoIngresso::Crianca, 1)],
+                momento(dia(1), 10, 0)
+            ),
+            Err(ErroZoologico::ValorInvalido(_))
+        ));
+    }
+
+    #[test]
+    fn test_relatorio_publico() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let onze = Horario::new(11, 0).unwrap();
+        let vender = |zoologico: &mut Zoologico, data, pedido: &[(TipoIngresso, u32)]| {
+            zoologico
+                .vender_ingressos(data, onze, pedido, momento(dia(1), 9, 0))
+                .unwrap();
+        };
+
+        // 02/06/2025 foi uma segunda-feira
+        vender(&mut zoologico, dia(2), &[(TipoIngresso::Adulto, 2)]);
+        vender(&mut zoologico, dia(4), &[(TipoIngresso::Grupo, 10)]);
+        zoologico
+            .definir_preco(TipoIngresso::Adulto, 5000, 1)
+            .unwrap();
+        vender(&mut zoologico, dia(4), &[(TipoIngresso::Adulto, 1)]);
+        vender(&mut zoologico, dia(9), &[(TipoIngresso::Idoso, 1)]);
+
+        let dia_4 = zoologico
+            .bilheteria()
+            .relatorio_publico(Periodo::Dia, dia(4))
+            .unwrap();
+        assert_eq!(
+            dia_4.total(),
+            Ok(Movimento {
+                visitantes: 11,
+                receita_centavos: 30000 + 5000,
+            })
+        );
+
+        let semana = zoologico
+            .bilheteria()
+            .relatorio_publico(Periodo::Semana, dia(4))
+            .unwrap();
+        assert_eq!(semana.dias.len(), 7);
+        assert_eq!(semana.dias[1].1, Movimento::default());
+        // A venda anterior ao aumento mantém o preço antigo
+        assert_eq!(
+            semana.por_tipo[0],
+            (
+                TipoIngresso::Adulto,
+                Movimento {
+                    visitantes: 3,
+                    receita_centavos: 2 * 4000 + 5000,
+                }
+            )
+        );
+        assert_eq!(semana.total().unwrap().visitantes, 13);
+
+        let mes = zoologico
+            .bilheteria()
+            .relatorio_publico(Periodo::Mes, dia(4))
+            .unwrap();
+        assert_eq!(mes.dias.len(), 30);
+        assert_eq!(mes.total().unwrap().visitantes, 14);
+        assert_eq!(
+            mes.total().unwrap().receita_centavos,
+            8000 + 30000 + 5000 + 2000
+        );
+    }
+
+    #[test]
+    fn test_vender_ingressos_com_valor_acima_do_limite() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+        let onze = Horario::new(11, 0).unwrap();
+        zoologico
+            .definir_preco(TipoIngresso::Adulto, u64::MAX / 2 + 1, 1)
+            .unwrap();
+
+        let resultado = zoologico.vender_ingressos(
+            dia(2),
+            onze,
+            &[(TipoIngresso::Adulto, 2)],
+            momento(dia(1), 9, 0),
+        );
+        assert!(matches!(resultado, Err(ErroZoologico::ValorInvalido(_))));
+
+        // Cada venda cabe num u64, mas a receita do dia não
+        for _ in 0..2 {
+            zoologico
+                .vender_ingressos(
+                    dia(2),
+                    onze,
+                    &[(TipoIngresso::Adulto, 1)],
+                    momento(dia(1), 9, 0),
+                )
+                .unwrap();
+        }
+        assert!(matches!(
+            zoologico
+                .bilheteria()
+                .relatorio_publico(Periodo::Dia, dia(2)),
+            Err(ErroZoologico::ValorInvalido(_))
+        ));
+    }
+
+    #[test]
+    fn test_persistencia_bilheteria() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+
+        let mut zoologico = Zoologico::new(caminho.clone());
+        let meio_dia = Horario::new(12, 0).unwrap();
+        zoologico.definir_faixa(meio_dia, 50).unwrap();
+        zoologico
+            .excluir_faixa(Horario::new(9, 0).unwrap())
+            .unwrap();
+        zoologico
+            .definir_preco(TipoIngresso::Grupo, 2500, 15)
+            .unwrap();
+        let venda = zoologico
+            .vender_ingressos(
+                dia(5),
+                meio_dia,
+                &[(TipoIngresso::Crianca, 3)],
+                momento(dia(5), 11, 30),
+            )
+            .unwrap();
+        zoologico.salvar().unwrap();
+
+        let mut carregado = Zoologico::carregar(&caminho).unwrap();
+        assert_eq!(
+            carregado.bilheteria().buscar_venda(venda),
+            zoologico.bilheteria().buscar_venda(venda)
+        );
+        assert_eq!(
+            carregado.bilheteria().faixas(),
+            zoologico.bilheteria().faixas()
+        );
+        assert_eq!(
+            carregado.bilheteria().precos(),
+            zoologico.bilheteria().precos()
+        );
+        assert_eq!(
+            carregado.bilheteria().ocupacao_faixas(dia(5)),
+            zoologico.bilheteria().ocupacao_faixas(dia(5))
+        );
+        assert_ne!(
+            carregado
+                .vender_ingressos(
+                    dia(5),
+                    meio_dia,
+                    &[(TipoIngresso::Adulto, 1)],
+                    momento(dia(5), 11, 45)
+                )
+                .unwrap(),
+            venda
+        );
+
+        // Arquivos de versões anteriores começam com a tabela e as faixas padrão
+        let antigo = Zoologico::carregar(&dir.path().join("novo.json")).unwrap();
+        assert_eq!(antigo.bilheteria().precos().len(), 4);
+        assert_eq!(antigo.bilheteria().faixas().len(), 4);
+    }
+
+    #[test]
+    fn test_nascimento_com_pais() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let nala = zoologico
+            .registrar_chegada("Leão", "Nala", dia(1), "Zoo de Sorocaba", "")
+            .unwrap();
+        let mufasa = zoologico.incluir_animal("Leão", "Mufasa").unwrap();
+        let marty = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+
+        let simba = zoologico
+            .registrar_nascimento(
+                "Leão",
+                "Simba",
+                dia(20),
+                Some(nala),
+                Some(mufasa),
+                "Parto normal",
+            )
+            .unwrap();
+        assert_eq!(zoologico.buscar_animal(simba).unwrap().id_mae, Some(nala));
+        assert_eq!(zoologico.linhagem().filhos(mufasa)[0].id, simba);
+        let eventos = zoologico.historico().eventos_do_animal(simba);
+        assert_eq!(
+            eventos[0].evento,
+            Evento::Nascimento {
+                id_mae: Some(nala),
+                id_pai: Some(mufasa),
+            }
+        );
+
+        assert!(matches!(
+            zoologico.registrar_nascimento("Leão", "Kion", dia(21), Some(nala), Some(marty), ""),
+            Err(ErroZoologico::ParentescoInvalido(_))
+        ));
+        assert!(matches!(
+            zoologico.registrar_nascimento("Leão", "Kion", dia(21), Some(nala), Some(nala), ""),
+            Err(ErroZoologico::ParentescoInvalido(_))
+        ));
+        assert_eq!(
+            zoologico.registrar_nascimento("Leão", "Kion", dia(21), Some(99), None, ""),
+            Err(ErroZoologico::AnimalNaoEncontrado(99))
+        );
+
+        // O pai pode já ter deixado o zoológico; a mãe não
+        zoologico
+            .registrar_transferencia(mufasa, dia(22), "Zoo de Brasília", "")
+            .unwrap();
+        zoologico
+            .registrar_nascimento("Leão", "Kiara", dia(23), Some(nala), Some(mufasa), "")
+            .unwrap();
+        zoologico.registrar_obito(nala, dia(24), "", "").unwrap();
+        assert!(matches!(
+            zoologico.registrar_nascimento("Leão", "Kion", dia(25), Some(nala), None, ""),
+            Err(ErroZoologico::ParentescoInvalido(_))
+        ));
+    }
+
+    #[test]
+    fn test_transferencia_e_obito_mantem_historico() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let savana = zoologico
+            .incluir_recinto("Savana", Habitat::Savana, 5, 2000)
+            .unwrap();
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        let girafa = zoologico.incluir_animal("Girafa", "Melman").unwrap();
+        zoologico.alocar_animal(zebra, savana).unwrap();
+        zoologico
+            .incluir_plano(zebra, "Feno", 5000, horarios("07:00"))
+            .unwrap();
+        zoologico.registrar_pesagem(zebra, dia(1), 300_000).unwrap();
+
+        zoologico
+            .iniciar_quarentena(girafa, "Tosse", dia(1))
+            .unwrap();
+        assert_eq!(
+            zoologico.registrar_transferencia(girafa, dia(2), "Zoo de Brasília", ""),
+            Err(ErroZoologico::AnimalEmQuarentena(girafa))
+        );
+
+        zoologico
+            .registrar_transferencia(zebra, dia(2), "Zoo de Brasília", "Programa de reprodução")
+            .unwrap();
+        assert_eq!(zoologico.buscar_animal(zebra), None);
+        assert!(zoologico.animais_no_recinto(savana).is_empty());
+        assert!(zoologico.nutricao().planos_do_animal(zebra).is_empty());
+        assert_eq!(
+            zoologico
+                .historico()
+                .buscar_animal_baixado(zebra)
+                .unwrap()
+                .nome,
+            "Marty"
+        );
+        assert!(zoologico.clinica().ficha_medica(zebra).is_some());
+        assert_eq!(
+            zoologico.historico().saida_do_animal(zebra).unwrap().evento,
+            Evento::Transferencia {
+                destino: "Zoo de Brasília".to_string()
+            }
+        );
+        assert_eq!(
+            zoologico.registrar_obito(zebra, dia(3), "", ""),
+            Err(ErroZoologico::AnimalNaoEncontrado(zebra))
+        );
+
+        zoologico.registrar_obito(girafa, dia(3), "", "").unwrap();
+        assert_eq!(
+            zoologico
+                .historico()
+                .saida_do_animal(girafa)
+                .unwrap()
+                .evento,
+            Evento::Obito {
+                causa: "desconhecida".to_string()
+            }
+        );
+        assert_eq!(zoologico.historico().listar_animais_baixados().len(), 2);
+        // Os ids dos animais baixados não voltam a ser usados
+        assert_eq!(zoologico.incluir_animal("Leão", "Simba").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_genealogia_e_endogamia() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("animais.json");
+        let mut zoologico = Zoologico::new(caminho.clone());
+
+        let nascer = |zoologico: &mut Zoologico, nome, mae, pai| {
+            zoologico
+                .registrar_nascimento("Lobo-guará", nome, dia(1), mae, pai, "")
+                .unwrap()
+        };
+        let avo = zoologico.incluir_animal("Lobo-guará", "Aurora").unwrap();
+        let avo_macho = zoologico.incluir_animal("Lobo-guará", "Bento").unwrap();
+        let filha = nascer(&mut zoologico, "Cora", Some(avo), Some(avo_macho));
+        let filho = nascer(&mut zoologico, "Davi", Some(avo), Some(avo_macho));
+        let de_fora = zoologico.incluir_animal("Lobo-guará", "Elis").unwrap();
+        let de_fora_macho = zoologico.incluir_animal("Lobo-guará", "Fábio").unwrap();
+        let prima = nascer(&mut zoologico, "Gaia", Some(filha), Some(de_fora_macho));
+        let primo = nascer(&mut zoologico, "Hugo", Some(de_fora), Some(filho));
+        zoologico
+            .registrar_obito(avo_macho, dia(2), "Idade", "")
+            .unwrap();
+
+        let arvore = zoologico.linhagem().genealogia(prima, 2).unwrap();
+        let mae = arvore.mae.as_ref().unwrap();
+        assert_eq!(mae.nome, "Cora");
+        assert_eq!(mae.pai.as_ref().unwrap().nome, "Bento");
+        assert!(mae.pai.as_ref().unwrap().baixado);
+        assert!(
+            zoologico
+                .linhagem()
+                .genealogia(prima, 1)
+                .unwrap()
+                .mae
+                .unwrap()
+                .pai
+                .is_none()
+        );
+
+        let irmaos = zoologico
+            .linhagem()
+            .avaliar_reproducao(filha, filho)
+            .unwrap();
+        assert_eq!(irmaos.parentesco, Parentesco::Irmaos);
+        assert!(irmaos.endogamia_proxima());
+        let primos = zoologico
+            .linhagem()
+            .avaliar_reproducao(prima, primo)
+            .unwrap();
+        assert_eq!(primos.parentesco, Parentesco::Distante);
+        assert_eq!(primos.coeficiente, 0.0625);
+        assert!(primos.endogamia_proxima());
+        let sem_parentesco = zoologico
+            .linhagem()
+            .avaliar_reproducao(prima, de_fora)
+            .unwrap();
+        assert_eq!(sem_parentesco.parentesco, Parentesco::Nenhum);
+        assert!(!sem_parentesco.endogamia_proxima());
+
+        // Um animal baixado não pode mais formar casal
+        assert_eq!(
+            zoologico.linhagem().avaliar_reproducao(avo, avo_macho),
+            Err(ErroZoologico::AnimalNaoEncontrado(avo_macho))
+        );
+        let zebra = zoologico.incluir_animal("Zebra", "Marty").unwrap();
+        assert!(matches!(
+            zoologico.linhagem().avaliar_reproducao(prima, zebra),
+            Err(ErroZoologico::ReproducaoIncompativel(_))
+        ));
+
+        // A genealogia continua depois de salvar e carregar
+        zoologico.salvar().unwrap();
+        let carregado = Zoologico::carregar(&caminho).unwrap();
+        assert_eq!(
+            carregado.linhagem().genealogia(prima, 2),
+            zoologico.linhagem().genealogia(prima, 2)
+        );
+        assert_eq!(
+            carregado.historico().eventos_do_animal(avo_macho),
+            zoologico.historico().eventos_do_animal(avo_macho)
+        );
+        assert_eq!(
+            carregado.linhagem().avaliar_reproducao(prima, primo),
+            zoologico.linhagem().avaliar_reproducao(prima, primo)
+        );
+    }
+
+    #[test]
+    fn test_excluir_animal_desfaz_parentesco() {
+        let dir = tempdir().unwrap();
+        let mut zoologico = Zoologico::new(dir.path().join("animais.json"));
+
+        let mae = zoologico.incluir_animal("Leão", "Nala").unwrap();
+        let filhote = zoologico
+            .registrar_nascimento("Leão", "Kiara", dia(1), Some(mae), None, "")
+            .unwrap();
+
+        zoologico.excluir_animal(mae).unwrap();
+        assert_eq!(zoologico.buscar_animal(filhote).unwrap().id_mae, None);
+        assert!(zoologico.historico().eventos_do_animal(mae).is_empty());
+        assert_eq!(zoologico.historico().buscar_animal_baixado(mae), None);
+    }
+}
@@ -1,13 +1,17 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, BufWriter};
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
+use crate::criptografia::{self, Chave};
 use crate::emprestimos::{Emprestimo, StatusEmprestimo};
 use crate::errors::ErroBiblioteca;
 use crate::livros::{Livro, StatusLivro};
+use crate::recomendacoes::{Coocorrencias, Recomendacao};
+use crate::transferencias::{StatusTransferencia, Transferencia};
+use crate::unidades::Unidade;
 use crate::usuarios::Usuario;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -15,6 +19,32 @@ struct DadosPersistencia {
     livros: HashMap<Uuid, Livro>,
     usuarios: HashMap<Uuid, Usuario>,
     emprestimos: HashMap<Uuid, Emprestimo>,
+    // Arquivos antigos não possuem unidades nem transferências
+    #[serde(default)]
+    unidades: HashMap<Uuid, Unidade>,
+    #[serde(default)]
+    transferencias: HashMap<Uuid, Transferencia>,
+}
+
+// Grava num arquivo temporário da mesma pasta e só então o renomeia por cima
+// do original, para que uma falha no meio nunca deixe o arquivo (cifrado ou não)
+// pela metade.
+fn gravar_atomico(caminho: &Path, conteudo: &[u8]) -> Result<(), ErroBiblioteca> {
+    let mut nome = caminho.file_name().unwrap_or_default().to_os_string();
+    nome.push(".tmp");
+    let temporario = caminho.with_file_name(nome);
+
+    let resultado = (|| {
+        let mut arquivo = File::create(&temporario)?;
+        arquivo.write_all(conteudo)?;
+        arquivo.sync_all()?;
+        fs::rename(&temporario, caminho)
+    })();
+
+    resultado.map_err(|e| {
+        let _ = fs::remove_file(&temporario);
+        ErroBiblioteca::ErroPersistencia(format!("Erro ao gravar arquivo: {}", e))
+    })
 }
 
 pub struct Biblioteca {
@@ -22,6 +52,10 @@ pub struct Biblioteca {
     livros: HashMap<Uuid, Livro>,
     usuarios: HashMap<Uuid, Usuario>,
     emprestimos: HashMap<Uuid, Emprestimo>,
+    unidades: HashMap<Uuid, Unidade>,
+    transferencias: HashMap<Uuid, Transferencia>,
+    // Quando presente, o arquivo de dados é gravado cifrado
+    chave: Option<Chave>,
 }
 
 impl Biblioteca {
@@ -31,21 +65,48 @@ impl Biblioteca {
             livros: HashMap::new(),
             usuarios: HashMap::new(),
             emprestimos: HashMap::new(),
+            unidades: HashMap::new(),
+            transferencias: HashMap::new(),
+            chave: None,
         }
     }
 
     // Persistência
     pub fn carregar(caminho: &PathBuf) -> Result<Self, ErroBiblioteca> {
+        Self::carregar_arquivo(caminho, None)
+    }
+
+    // Carrega um arquivo cifrado. Um arquivo ainda em texto puro também é aceito
+    // e passa a ser cifrado no próximo salvamento.
+    pub fn carregar_cifrada(caminho: &PathBuf, chave: Chave) -> Result<Self, ErroBiblioteca> {
+        Self::carregar_arquivo(caminho, Some(chave))
+    }
+
+    fn carregar_arquivo(caminho: &PathBuf, chave: Option<Chave>) -> Result<Self, ErroBiblioteca> {
         if !caminho.exists() {
-            return Ok(Biblioteca::new(caminho.clone()));
+            let mut biblioteca = Biblioteca::new(caminho.clone());
+            biblioteca.chave = chave;
+            return Ok(biblioteca);
         }
 
-        let file = File::open(caminho).map_err(|e| {
+        let bytes = fs::read(caminho).map_err(|e| {
             ErroBiblioteca::ErroPersistencia(format!("Erro ao abrir arquivo: {}", e))
         })?;
 
-        let reader = BufReader::new(file);
-        let dados: DadosPersistencia = serde_json::from_reader(reader).map_err(|e| {
+        let conteudo = if criptografia::esta_cifrado(&bytes) {
+            match &chave {
+                Some(chave) => criptografia::decifrar(&bytes, chave)?,
+                None => {
+                    return Err(ErroBiblioteca::ErroPersistencia(
+                        "O arquivo está cifrado; informe a senha ou o arquivo de chave".to_string(),
+                    ))
+                }
+            }
+        } else {
+            bytes
+        };
+
+        let dados: DadosPersistencia = serde_json::from_slice(&conteudo).map_err(|e| {
             ErroBiblioteca::ErroPersistencia(format!("Erro ao deserializar JSON: {}", e))
         })?;
 
@@ -54,6 +115,9 @@ impl Biblioteca {
             livros: dados.livros,
             usuarios: dados.usuarios,
             emprestimos: dados.emprestimos,
+            unidades: dados.unidades,
+            transferencias: dados.transferencias,
+            chave,
         })
     }
 
@@ -62,17 +126,34 @@ impl Biblioteca {
             livros: self.livros.clone(),
             usuarios: self.usuarios.clone(),
             emprestimos: self.emprestimos.clone(),
+            unidades: self.unidades.clone(),
+            transferencias: self.transferencias.clone(),
         };
 
-        let file = File::create(&self.caminho_arquivo).map_err(|e| {
-            ErroBiblioteca::ErroPersistencia(format!("Erro ao criar arquivo: {}", e))
-        })?;
-
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &dados).map_err(|e| {
+        let json = serde_json::to_vec_pretty(&dados).map_err(|e| {
             ErroBiblioteca::ErroPersistencia(format!("Erro ao serializar JSON: {}", e))
         })?;
 
+        let conteudo = match &self.chave {
+            Some(chave) => criptografia::cifrar(&json, chave)?,
+            None => json,
+        };
+
+        gravar_atomico(&self.caminho_arquivo, &conteudo)
+    }
+
+    pub fn esta_cifrada(&self) -> bool {
+        self.chave.is_some()
+    }
+
+    // Regrava o arquivo cifrado com uma nova chave (também ativa a cifragem
+    // de um arquivo que estava em texto puro). Em caso de erro a chave anterior é mantida.
+    pub fn rotacionar_chave(&mut self, nova_chave: Chave) -> Result<(), ErroBiblioteca> {
+        let anterior = self.chave.replace(nova_chave);
+        if let Err(e) = self.salvar() {
+            self.chave = anterior;
+            return Err(e);
+        }
         Ok(())
     }
 
@@ -89,6 +170,21 @@ impl Biblioteca {
         Ok(id)
     }
 
+    pub fn adicionar_livro_na_unidade(
+        &mut self,
+        titulo: String,
+        autor: String,
+        ano: u16,
+        id_unidade: Uuid,
+    ) -> Result<Uuid, ErroBiblioteca> {
+        self.validar_unidade(id_unidade)?;
+
+        let livro = Livro::com_unidade(titulo, autor, ano, id_unidade);
+        let id = livro.id;
+        self.livros.insert(id, livro);
+        Ok(id)
+    }
+
     pub fn remover_livro(&mut self, id_livro: Uuid) -> Result<(), ErroBiblioteca> {
         if !self.livros.contains_key(&id_livro) {
             return Err(ErroBiblioteca::LivroNaoEncontrado(format!("{}", id_livro)));
@@ -106,7 +202,16 @@ impl Biblioteca {
             ));
         }
 
+        if self.livros[&id_livro].status == StatusLivro::EmTransito {
+            return Err(ErroBiblioteca::EstadoInvalido(
+                "Não é possível remover um livro em trânsito".to_string(),
+            ));
+        }
+
         self.livros.remove(&id_livro);
+        for usuario in self.usuarios.values_mut() {
+            usuario.remover_da_lista(id_livro);
+        }
         Ok(())
     }
 
@@ -185,15 +290,61 @@ impl Biblioteca {
         // Tentar emprestar o livro
         livro.emprestar()?;
 
-        // Criar empréstimo
-        let emprestimo = Emprestimo::new(id_livro, id_usuario);
+        // Criar empréstimo, registrando a unidade onde o livro estava
+        let mut emprestimo = Emprestimo::new(id_livro, id_usuario);
+        emprestimo.unidade_retirada = livro.unidade_atual;
+
+        // O livro emprestado sai da lista de leitura do usuário
+        if let Some(usuario) = self.usuarios.get_mut(&id_usuario) {
+            usuario.remover_da_lista(id_livro);
+        }
         let id_emprestimo = emprestimo.id_emprestimo;
         self.emprestimos.insert(id_emprestimo, emprestimo);
 
         Ok(id_emprestimo)
     }
 
+    pub fn emprestar_livro_na_unidade(
+        &mut self,
+        id_usuario: Uuid,
+        id_livro: Uuid,
+        id_unidade: Uuid,
+    ) -> Result<Uuid, ErroBiblioteca> {
+        self.validar_unidade(id_unidade)?;
+
+        let livro = self
+            .livros
+            .get(&id_livro)
+            .ok_or_else(|| ErroBiblioteca::LivroNaoEncontrado(format!("{}", id_livro)))?;
+
+        if livro.unidade_atual != Some(id_unidade) {
+            return Err(ErroBiblioteca::EstadoInvalido(
+                "Livro não se encontra nesta unidade".to_string(),
+            ));
+        }
+
+        self.emprestar_livro(id_usuario, id_livro)
+    }
+
     pub fn devolver_livro(&mut self, id_livro: Uuid) -> Result<(), ErroBiblioteca> {
+        self.finalizar_devolucao(id_livro, None)
+    }
+
+    // Devolução numa unidade possivelmente diferente daquela onde o livro foi retirado
+    pub fn devolver_livro_na_unidade(
+        &mut self,
+        id_livro: Uuid,
+        id_unidade: Uuid,
+    ) -> Result<(), ErroBiblioteca> {
+        self.validar_unidade(id_unidade)?;
+        self.finalizar_devolucao(id_livro, Some(id_unidade))
+    }
+
+    fn finalizar_devolucao(
+        &mut self,
+        id_livro: Uuid,
+        id_unidade: Option<Uuid>,
+    ) -> Result<(), ErroBiblioteca> {
         // Encontrar o empréstimo ativo do livro
         let emprestimo_id = self
             .emprestimos
@@ -209,7 +360,7 @@ impl Biblioteca {
 
         // Finalizar empréstimo
         if let Some(emprestimo) = self.emprestimos.get_mut(&emprestimo_id) {
-            emprestimo.finalizar();
+            emprestimo.finalizar_na_unidade(id_unidade);
         }
 
         // Devolver livro
@@ -220,6 +371,10 @@ impl Biblioteca {
 
         livro.devolver()?;
 
+        if id_unidade.is_some() {
+            livro.unidade_atual = id_unidade;
+        }
+
         Ok(())
     }
 
@@ -236,8 +391,253 @@ impl Biblioteca {
             .filter(|e| e.id_usuario == id_usuario && e.status == StatusEmprestimo::Ativo)
             .collect()
     }
+
+    // Listas de Leitura
+    pub fn adicionar_a_lista_leitura(
+        &mut self,
+        id_usuario: Uuid,
+        id_livro: Uuid,
+    ) -> Result<(), ErroBiblioteca> {
+        if !self.livros.contains_key(&id_livro) {
+            return Err(ErroBiblioteca::LivroNaoEncontrado(format!("{}", id_livro)));
+        }
+
+        let usuario = self
+            .usuarios
+            .get_mut(&id_usuario)
+            .ok_or_else(|| ErroBiblioteca::UsuarioNaoEncontrado(format!("{}", id_usuario)))?;
+
+        if !usuario.adicionar_a_lista(id_livro) {
+            return Err(ErroBiblioteca::EstadoInvalido(
+                "Livro já está na lista de leitura".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn remover_da_lista_leitura(
+        &mut self,
+        id_usuario: Uuid,
+        id_livro: Uuid,
+    ) -> Result<(), ErroBiblioteca> {
+        let usuario = self
+            .usuarios
+            .get_mut(&id_usuario)
+            .ok_or_else(|| ErroBiblioteca::UsuarioNaoEncontrado(format!("{}", id_usuario)))?;
+
+        if !usuario.remover_da_lista(id_livro) {
+            return Err(ErroBiblioteca::LivroNaoEncontrado(format!(
+                "{} não está na lista de leitura",
+                id_livro
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn lista_leitura(&self, id_usuario: Uuid) -> Result<Vec<&Livro>, ErroBiblioteca> {
+        let usuario = self
+            .usuarios
+            .get(&id_usuario)
+            .ok_or_else(|| ErroBiblioteca::UsuarioNaoEncontrado(format!("{}", id_usuario)))?;
+
+        Ok(usuario
+            .lista_leitura
+            .iter()
+            .filter_map(|id| self.livros.get(id))
+            .collect())
+    }
+
+    // Recomendações a partir do histórico de empréstimos
+    pub fn recomendar_para_livro(
+        &self,
+        id_livro: Uuid,
+        quantidade: usize,
+    ) -> Result<Vec<Recomendacao>, ErroBiblioteca> {
+        if !self.livros.contains_key(&id_livro) {
+            return Err(ErroBiblioteca::LivroNaoEncontrado(format!("{}", id_livro)));
+        }
+
+        let mut excluir = self.livros_removidos();
+        excluir.insert(id_livro);
+        Ok(self
+            .coocorrencias()
+            .recomendar_para_livro(id_livro, quantidade, &excluir))
+    }
+
+    // Sugestões para o usuário, sem repetir o que ele já pegou emprestado
+    pub fn recomendar_para_usuario(
+        &self,
+        id_usuario: Uuid,
+        quantidade: usize,
+    ) -> Result<Vec<Recomendacao>, ErroBiblioteca> {
+        if !self.usuarios.contains_key(&id_usuario) {
+            return Err(ErroBiblioteca::UsuarioNaoEncontrado(format!(
+                "{}",
+                id_usuario
+            )));
+        }
+
+        let historico: HashSet<Uuid> = self
+            .emprestimos
+            .values()
+            .filter(|e| e.id_usuario == id_usuario)
+            .map(|e| e.id_livro)
+            .collect();
+
+        Ok(self.coocorrencias().recomendar_para_historico(
+            &historico,
+            quantidade,
+            &self.livros_removidos(),
+        ))
+    }
+
+    fn coocorrencias(&self) -> Coocorrencias {
+        Coocorrencias::calcular(self.emprestimos.values())
+    }
+
+    // Livros que aparecem no histórico mas já foram removidos do acervo
+    fn livros_removidos(&self) -> HashSet<Uuid> {
+        self.emprestimos
+            .values()
+            .map(|e| e.id_livro)
+            .filter(|id| !self.livros.contains_key(id))
+            .collect()
+    }
+
+    // Gerenciamento de Unidades
+    pub fn adicionar_unidade(&mut self, nome: String) -> Result<Uuid, ErroBiblioteca> {
+        let unidade = Unidade::new(nome);
+        let id = unidade.id;
+        self.unidades.insert(id, unidade);
+        Ok(id)
+    }
+
+    pub fn buscar_unidade_por_id(&self, id: Uuid) -> Option<&Unidade> {
+        self.unidades.get(&id)
+    }
+
+    pub fn listar_unidades(&self) -> Vec<&Unidade> {
+        self.unidades.values().collect()
+    }
+
+    // Associa a uma unidade um livro cadastrado antes do suporte a unidades
+    pub fn associar_livro_unidade(
+        &mut self,
+        id_livro: Uuid,
+        id_unidade: Uuid,
+    ) -> Result<(), ErroBiblioteca> {
+        self.validar_unidade(id_unidade)?;
+
+        let livro = self
+            .livros
+            .get_mut(&id_livro)
+            .ok_or_else(|| ErroBiblioteca::LivroNaoEncontrado(format!("{}", id_livro)))?;
+
+        if livro.unidade_origem.is_some() {
+            return Err(ErroBiblioteca::EstadoInvalido(
+                "Livro já pertence a uma unidade".to_string(),
+            ));
+        }
+
+        livro.unidade_origem = Some(id_unidade);
+        livro.unidade_atual = Some(id_unidade);
+        Ok(())
+    }
+
+    pub fn listar_livros_na_unidade(&self, id_unidade: Uuid) -> Vec<&Livro> {
+        self.livros
+            .values()
+            .filter(|l| l.unidade_atual == Some(id_unidade) && l.status != StatusLivro::EmTransito)
+            .collect()
+    }
+
+    pub fn listar_livros_disponiveis_na_unidade(&self, id_unidade: Uuid) -> Vec<&Livro> {
+        self.livros
+            .values()
+            .filter(|l| l.unidade_atual == Some(id_unidade) && l.status == StatusLivro::Disponivel)
+            .collect()
+    }
+
+    // Gerenciamento de Transferências
+    pub fn transferir_livro(
+        &mut self,
+        id_livro: Uuid,
+        id_destino: Uuid,
+    ) -> Result<Uuid, ErroBiblioteca> {
+        self.validar_unidade(id_destino)?;
+
+        let livro = self
+            .livros
+            .get_mut(&id_livro)
+            .ok_or_else(|| ErroBiblioteca::LivroNaoEncontrado(format!("{}", id_livro)))?;
+
+        let id_origem = livro.unidade_atual.ok_or_else(|| {
+            ErroBiblioteca::EstadoInvalido("Livro não está associado a uma unidade".to_string())
+        })?;
+
+        if id_origem == id_destino {
+            return Err(ErroBiblioteca::EstadoInvalido(
+                "Livro já se encontra na unidade de destino".to_string(),
+            ));
+        }
+
+        livro.enviar_transferencia()?;
+
+        let transferencia = Transferencia::new(id_livro, id_origem, id_destino);
+        let id_transferencia = transferencia.id_transferencia;
+        self.transferencias.insert(id_transferencia, transferencia);
+
+        Ok(id_transferencia)
+    }
+
+    pub fn receber_transferencia(&mut self, id_transferencia: Uuid) -> Result<(), ErroBiblioteca> {
+        let transferencia = self
+            .transferencias
+            .get_mut(&id_transferencia)
+            .ok_or_else(|| {
+                ErroBiblioteca::TransferenciaNaoEncontrada(format!("{}", id_transferencia))
+            })?;
+
+        if transferencia.status != StatusTransferencia::EmTransito {
+            return Err(ErroBiblioteca::EstadoInvalido(
+                "Transferência já foi recebida".to_string(),
+            ));
+        }
+
+        let livro = self
+            .livros
+            .get_mut(&transferencia.id_livro)
+            .ok_or_else(|| {
+                ErroBiblioteca::LivroNaoEncontrado(format!("{}", transferencia.id_livro))
+            })?;
+
+        livro.receber_transferencia(transferencia.unidade_destino)?;
+        transferencia.receber();
+
+        Ok(())
+    }
+
+    pub fn listar_transferencias_em_transito(&self) -> Vec<&Transferencia> {
+        self.transferencias
+            .values()
+            .filter(|t| t.status == StatusTransferencia::EmTransito)
+            .collect()
+    }
+
+    fn validar_unidade(&self, id_unidade: Uuid) -> Result<(), ErroBiblioteca> {
+        if !self.unidades.contains_key(&id_unidade) {
+            return Err(ErroBiblioteca::UnidadeNaoEncontrada(format!(
+                "{}",
+                id_unidade
+            )));
+        }
+        Ok(())
+    }
 }
 
+#[cfg(test)]
+mod testes_propriedades;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +753,338 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_emprestimo_e_devolucao_em_unidades_diferentes() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+        let mut biblioteca = Biblioteca::new(caminho);
+
+        let central = biblioteca.adicionar_unidade("Central".to_string()).unwrap();
+        let norte = biblioteca.adicionar_unidade("Norte".to_string()).unwrap();
+        let id_livro = biblioteca
+            .adicionar_livro_na_unidade(
+                "1984".to_string(),
+                "George Orwell".to_string(),
+                1949,
+                central,
+            )
+            .unwrap();
+        let id_usuario = biblioteca
+            .adicionar_usuario("João Silva".to_string())
+            .unwrap();
+
+        // O livro não está na unidade Norte
+        assert!(biblioteca
+            .emprestar_livro_na_unidade(id_usuario, id_livro, norte)
+            .is_err());
+
+        let id_emprestimo = biblioteca
+            .emprestar_livro_na_unidade(id_usuario, id_livro, central)
+            .unwrap();
+        biblioteca
+            .devolver_livro_na_unidade(id_livro, norte)
+            .unwrap();
+
+        let emprestimo = &biblioteca.emprestimos[&id_emprestimo];
+        assert_eq!(emprestimo.unidade_retirada, Some(central));
+        assert_eq!(emprestimo.unidade_devolucao, Some(norte));
+
+        let livro = biblioteca.buscar_livro_por_id(id_livro).unwrap();
+        assert_eq!(livro.unidade_origem, Some(central));
+        assert_eq!(livro.unidade_atual, Some(norte));
+        assert_eq!(
+            biblioteca.listar_livros_disponiveis_na_unidade(norte).len(),
+            1
+        );
+        assert!(biblioteca
+            .listar_livros_disponiveis_na_unidade(central)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_transferencia_entre_unidades() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+        let mut biblioteca = Biblioteca::new(caminho);
+
+        let central = biblioteca.adicionar_unidade("Central".to_string()).unwrap();
+        let norte = biblioteca.adicionar_unidade("Norte".to_string()).unwrap();
+        let id_livro = biblioteca
+            .adicionar_livro_na_unidade(
+                "1984".to_string(),
+                "George Orwell".to_string(),
+                1949,
+                central,
+            )
+            .unwrap();
+        let id_usuario = biblioteca
+            .adicionar_usuario("João Silva".to_string())
+            .unwrap();
+
+        let id_transferencia = biblioteca.transferir_livro(id_livro, norte).unwrap();
+        assert_eq!(
+            biblioteca.buscar_livro_por_id(id_livro).unwrap().status,
+            StatusLivro::EmTransito
+        );
+        assert_eq!(biblioteca.listar_transferencias_em_transito().len(), 1);
+        assert!(biblioteca.emprestar_livro(id_usuario, id_livro).is_err());
+        assert!(biblioteca.remover_livro(id_livro).is_err());
+        assert!(biblioteca.listar_livros_na_unidade(central).is_empty());
+
+        biblioteca.receber_transferencia(id_transferencia).unwrap();
+        assert!(biblioteca.receber_transferencia(id_transferencia).is_err());
+        assert!(biblioteca.listar_transferencias_em_transito().is_empty());
+        assert_eq!(
+            biblioteca.listar_livros_disponiveis_na_unidade(norte).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_transferir_para_mesma_unidade() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+        let mut biblioteca = Biblioteca::new(caminho);
+
+        let central = biblioteca.adicionar_unidade("Central".to_string()).unwrap();
+        let id_livro = biblioteca
+            .adicionar_livro("1984".to_string(), "George Orwell".to_string(), 1949)
+            .unwrap();
+
+        // Livro sem unidade não pode ser transferido até ser associado
+        assert!(biblioteca.transferir_livro(id_livro, central).is_err());
+        biblioteca
+            .associar_livro_unidade(id_livro, central)
+            .unwrap();
+        assert!(biblioteca.transferir_livro(id_livro, central).is_err());
+        assert!(biblioteca
+            .transferir_livro(id_livro, Uuid::new_v4())
+            .is_err());
+    }
+
+    #[test]
+    fn test_persistencia_unidades() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+
+        let (norte, id_livro) = {
+            let mut biblioteca = Biblioteca::new(caminho.clone());
+            let central = biblioteca.adicionar_unidade("Central".to_string()).unwrap();
+            let norte = biblioteca.adicionar_unidade("Norte".to_string()).unwrap();
+            let id_livro = biblioteca
+                .adicionar_livro_na_unidade(
+                    "1984".to_string(),
+                    "George Orwell".to_string(),
+                    1949,
+                    central,
+                )
+                .unwrap();
+            biblioteca.transferir_livro(id_livro, norte).unwrap();
+            biblioteca.salvar().unwrap();
+            (norte, id_livro)
+        };
+
+        let biblioteca = Biblioteca::carregar(&caminho).unwrap();
+        assert_eq!(biblioteca.listar_unidades().len(), 2);
+        let transferencias = biblioteca.listar_transferencias_em_transito();
+        assert_eq!(transferencias.len(), 1);
+        assert_eq!(transferencias[0].unidade_destino, norte);
+        assert_eq!(transferencias[0].id_livro, id_livro);
+    }
+
+    #[test]
+    fn test_carregar_arquivo_sem_unidades() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+        std::fs::write(
+            &caminho,
+            r#"{"livros":{"67e55044-10b1-426f-9247-bb680e5fe0c8":{"id":"67e55044-10b1-426f-9247-bb680e5fe0c8","titulo":"1984","autor":"George Orwell","ano":1949,"status":"Disponivel"}},"usuarios":{},"emprestimos":{}}"#,
+        )
+        .unwrap();
+
+        let biblioteca = Biblioteca::carregar(&caminho).unwrap();
+        let livros = biblioteca.listar_todos_livros();
+        assert_eq!(livros.len(), 1);
+        assert_eq!(livros[0].unidade_origem, None);
+        assert!(biblioteca.listar_unidades().is_empty());
+    }
+
+    #[test]
+    fn test_persistencia_cifrada() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+
+        {
+            let mut biblioteca =
+                Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("segredo").unwrap())
+                    .unwrap();
+            biblioteca
+                .adicionar_usuario("João Silva".to_string())
+                .unwrap();
+            biblioteca.salvar().unwrap();
+        }
+
+        // O nome do usuário não aparece em texto puro
+        let bytes = std::fs::read(&caminho).unwrap();
+        assert!(!String::from_utf8_lossy(&bytes).contains("João Silva"));
+
+        let biblioteca =
+            Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("segredo").unwrap()).unwrap();
+        assert_eq!(biblioteca.listar_usuarios()[0].nome, "João Silva");
+    }
+
+    #[test]
+    fn test_carregar_cifrada_com_chave_errada() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+
+        let biblioteca =
+            Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("segredo").unwrap()).unwrap();
+        biblioteca.salvar().unwrap();
+
+        let erro = Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("outra").unwrap())
+            .err()
+            .unwrap();
+        assert!(matches!(erro, ErroBiblioteca::ErroPersistencia(_)));
+        assert!(erro.to_string().contains("Chave incorreta"));
+
+        let erro = Biblioteca::carregar(&caminho).err().unwrap();
+        assert!(erro.to_string().contains("cifrado"));
+    }
+
+    #[test]
+    fn test_rotacionar_chave() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+
+        // Arquivo em texto puro passa a ser cifrado com a primeira rotação
+        let mut biblioteca = Biblioteca::new(caminho.clone());
+        biblioteca
+            .adicionar_livro("1984".to_string(), "George Orwell".to_string(), 1949)
+            .unwrap();
+        biblioteca.salvar().unwrap();
+        biblioteca
+            .rotacionar_chave(Chave::de_senha("antiga").unwrap())
+            .unwrap();
+        biblioteca
+            .rotacionar_chave(Chave::de_senha("nova").unwrap())
+            .unwrap();
+
+        assert!(
+            Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("antiga").unwrap()).is_err()
+        );
+        let biblioteca =
+            Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("nova").unwrap()).unwrap();
+        assert!(biblioteca.esta_cifrada());
+        assert_eq!(biblioteca.listar_todos_livros().len(), 1);
+    }
+
+    #[test]
+    fn test_falha_ao_salvar_mantem_arquivo_anterior() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+
+        let mut biblioteca =
+            Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("antiga").unwrap()).unwrap();
+        biblioteca
+            .adicionar_livro("1984".to_string(), "George Orwell".to_string(), 1949)
+            .unwrap();
+        biblioteca.salvar().unwrap();
+        let original = std::fs::read(&caminho).unwrap();
+
+        // Uma pasta no lugar do arquivo temporário faz a gravação falhar
+        std::fs::create_dir(dir.path().join("test.json.tmp")).unwrap();
+        assert!(biblioteca
+            .rotacionar_chave(Chave::de_senha("nova").unwrap())
+            .is_err());
+        assert_eq!(std::fs::read(&caminho).unwrap(), original);
+        assert!(Biblioteca::carregar_cifrada(&caminho, Chave::de_senha("antiga").unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_recomendacoes_por_historico() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+        let mut biblioteca = Biblioteca::new(caminho);
+
+        let duna = biblioteca
+            .adicionar_livro("Duna".to_string(), "Frank Herbert".to_string(), 1965)
+            .unwrap();
+        let fundacao = biblioteca
+            .adicionar_livro("Fundação".to_string(), "Isaac Asimov".to_string(), 1951)
+            .unwrap();
+        let neuromancer = biblioteca
+            .adicionar_livro(
+                "Neuromancer".to_string(),
+                "William Gibson".to_string(),
+                1984,
+            )
+            .unwrap();
+        let ana = biblioteca.adicionar_usuario("Ana".to_string()).unwrap();
+        let bruno = biblioteca.adicionar_usuario("Bruno".to_string()).unwrap();
+
+        for livro in [duna, fundacao, neuromancer] {
+            biblioteca.emprestar_livro(ana, livro).unwrap();
+            biblioteca.devolver_livro(livro).unwrap();
+        }
+        biblioteca.emprestar_livro(bruno, duna).unwrap();
+
+        let para_livro = biblioteca.recomendar_para_livro(duna, 5).unwrap();
+        assert_eq!(para_livro.len(), 2);
+        assert!(para_livro.iter().all(|r| r.id_livro != duna));
+
+        // Bruno já pegou Duna, então recebe os outros dois
+        let para_bruno = biblioteca.recomendar_para_usuario(bruno, 1).unwrap();
+        assert_eq!(para_bruno.len(), 1);
+        assert_ne!(para_bruno[0].id_livro, duna);
+
+        // Ana já leu tudo
+        assert!(biblioteca
+            .recomendar_para_usuario(ana, 5)
+            .unwrap()
+            .is_empty());
+
+        biblioteca.remover_livro(neuromancer).unwrap();
+        let para_livro = biblioteca.recomendar_para_livro(fundacao, 5).unwrap();
+        assert_eq!(para_livro.len(), 1);
+        assert_eq!(para_livro[0].id_livro, duna);
+    }
+
+    #[test]
+    fn test_lista_leitura() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+        let mut biblioteca = Biblioteca::new(caminho.clone());
+
+        let id_livro = biblioteca
+            .adicionar_livro("1984".to_string(), "George Orwell".to_string(), 1949)
+            .unwrap();
+        let id_usuario = biblioteca
+            .adicionar_usuario("João Silva".to_string())
+            .unwrap();
+
+        biblioteca
+            .adicionar_a_lista_leitura(id_usuario, id_livro)
+            .unwrap();
+        assert!(biblioteca
+            .adicionar_a_lista_leitura(id_usuario, id_livro)
+            .is_err());
+        assert!(biblioteca
+            .adicionar_a_lista_leitura(id_usuario, Uuid::new_v4())
+            .is_err());
+        biblioteca.salvar().unwrap();
+
+        let mut biblioteca = Biblioteca::carregar(&caminho).unwrap();
+        assert_eq!(biblioteca.lista_leitura(id_usuario).unwrap().len(), 1);
+
+        // Ao pegar o livro emprestado ele sai da lista
+        biblioteca.emprestar_livro(id_usuario, id_livro).unwrap();
+        assert!(biblioteca.lista_leitura(id_usuario).unwrap().is_empty());
+        assert!(biblioteca
+            .remover_da_lista_leitura(id_usuario, id_livro)
+            .is_err());
+    }
+
     #[test]
     fn test_buscar_livros_por_titulo() {
         let dir = tempdir().unwrap();
@@ -0,0 +1,229 @@
+// Testes baseados em propriedades: sequências aleatórias de operações são
+// executadas na Biblioteca e num modelo de referência simples. O proptest
+// reduz automaticamente a sequência até o menor caso que ainda falha.
+
+use super::*;
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use std::collections::HashSet;
+use tempfile::tempdir;
+
+#[derive(Debug, Clone)]
+enum Operacao {
+    AdicionarLivro { titulo: String, ano: u16 },
+    AdicionarUsuario { nome: String },
+    Emprestar { usuario: usize, livro: usize },
+    Devolver { livro: usize },
+    RemoverLivro { livro: usize },
+    SalvarECarregar,
+}
+
+fn operacao() -> impl Strategy<Value = Operacao> {
+    prop_oneof![
+        2 => ("[a-z]{1,8}", 1000u16..2025)
+            .prop_map(|(titulo, ano)| Operacao::AdicionarLivro { titulo, ano }),
+        1 => "[a-z]{1,8}".prop_map(|nome| Operacao::AdicionarUsuario { nome }),
+        4 => (0usize..32, 0usize..32)
+            .prop_map(|(usuario, livro)| Operacao::Emprestar { usuario, livro }),
+        3 => (0usize..32).prop_map(|livro| Operacao::Devolver { livro }),
+        1 => (0usize..32).prop_map(|livro| Operacao::RemoverLivro { livro }),
+        1 => Just(Operacao::SalvarECarregar),
+    ]
+}
+
+// Modelo de referência: para cada livro, o usuário que está com ele (se houver)
+#[derive(Default)]
+struct Modelo {
+    livros: HashMap<Uuid, Option<Uuid>>,
+    usuarios: HashSet<Uuid>,
+}
+
+impl Modelo {
+    fn emprestar(&mut self, id_usuario: Uuid, id_livro: Uuid) -> bool {
+        if !self.usuarios.contains(&id_usuario) {
+            return false;
+        }
+        match self.livros.get_mut(&id_livro) {
+            Some(portador @ None) => {
+                *portador = Some(id_usuario);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn devolver(&mut self, id_livro: Uuid) -> bool {
+        match self.livros.get_mut(&id_livro) {
+            Some(portador @ Some(_)) => {
+                *portador = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn remover(&mut self, id_livro: Uuid) -> bool {
+        match self.livros.get(&id_livro) {
+            Some(None) => {
+                self.livros.remove(&id_livro);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+// Índices gerados são reduzidos ao tamanho atual da lista de ids
+fn escolher(ids: &[Uuid], indice: usize) -> Uuid {
+    ids[indice % ids.len()]
+}
+
+fn verificar_invariantes(biblioteca: &Biblioteca, modelo: &Modelo) -> Result<(), TestCaseError> {
+    // Um livro está Emprestado se e somente se existe exatamente um empréstimo ativo
+    for livro in biblioteca.livros.values() {
+        let ativos = biblioteca
+            .emprestimos
+            .values()
+            .filter(|e| e.id_livro == livro.id && e.status == StatusEmprestimo::Ativo)
+            .count();
+        match livro.status {
+            StatusLivro::Emprestado => prop_assert_eq!(ativos, 1, "livro {}", livro.id),
+            StatusLivro::Disponivel | StatusLivro::EmTransito => {
+                prop_assert_eq!(ativos, 0, "livro {}", livro.id)
+            }
+        }
+    }
+
+    // Empréstimos ativos sempre apontam para livros e usuários existentes
+    for emprestimo in biblioteca.listar_emprestimos_ativos() {
+        prop_assert!(biblioteca.livros.contains_key(&emprestimo.id_livro));
+        prop_assert!(biblioteca.usuarios.contains_key(&emprestimo.id_usuario));
+    }
+
+    // O estado observável coincide com o modelo
+    prop_assert_eq!(biblioteca.livros.len(), modelo.livros.len());
+    prop_assert_eq!(biblioteca.usuarios.len(), modelo.usuarios.len());
+    for (id_livro, portador) in &modelo.livros {
+        let livro = biblioteca.buscar_livro_por_id(*id_livro);
+        prop_assert!(livro.is_some(), "livro {} ausente", id_livro);
+        let emprestimo_ativo = biblioteca
+            .listar_emprestimos_ativos()
+            .into_iter()
+            .find(|e| e.id_livro == *id_livro)
+            .map(|e| e.id_usuario);
+        prop_assert_eq!(&emprestimo_ativo, portador);
+    }
+
+    Ok(())
+}
+
+fn salvar_e_carregar(biblioteca: &Biblioteca) -> Result<Biblioteca, TestCaseError> {
+    biblioteca
+        .salvar()
+        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    let carregada = Biblioteca::carregar(&biblioteca.caminho_arquivo)
+        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+    prop_assert_eq!(&carregada.livros, &biblioteca.livros);
+    prop_assert_eq!(&carregada.usuarios, &biblioteca.usuarios);
+    prop_assert_eq!(&carregada.emprestimos, &biblioteca.emprestimos);
+
+    Ok(carregada)
+}
+
+fn executar(operacoes: &[Operacao]) -> Result<(), TestCaseError> {
+    let dir = tempdir().map_err(|e| TestCaseError::fail(e.to_string()))?;
+    let mut biblioteca = Biblioteca::new(dir.path().join("propriedades.json"));
+    let mut modelo = Modelo::default();
+
+    // Ids já vistos, incluindo um id que nunca existe, para exercitar os erros
+    let mut ids_livros = vec![Uuid::nil()];
+    let mut ids_usuarios = vec![Uuid::nil()];
+
+    for operacao in operacoes {
+        match operacao {
+            Operacao::AdicionarLivro { titulo, ano } => {
+                let id = biblioteca
+                    .adicionar_livro(titulo.clone(), "Autor".to_string(), *ano)
+                    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+                modelo.livros.insert(id, None);
+                ids_livros.push(id);
+            }
+            Operacao::AdicionarUsuario { nome } => {
+                let id = biblioteca
+                    .adicionar_usuario(nome.clone())
+                    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+                modelo.usuarios.insert(id);
+                ids_usuarios.push(id);
+            }
+            Operacao::Emprestar { usuario, livro } => {
+                let id_usuario = escolher(&ids_usuarios, *usuario);
+                let id_livro = escolher(&ids_livros, *livro);
+                let esperado = modelo.emprestar(id_usuario, id_livro);
+                let obtido = biblioteca.emprestar_livro(id_usuario, id_livro);
+                prop_assert_eq!(obtido.is_ok(), esperado, "{:?}", obtido);
+            }
+            Operacao::Devolver { livro } => {
+                let id_livro = escolher(&ids_livros, *livro);
+                let esperado = modelo.devolver(id_livro);
+                let obtido = biblioteca.devolver_livro(id_livro);
+                prop_assert_eq!(obtido.is_ok(), esperado, "{:?}", obtido);
+            }
+            Operacao::RemoverLivro { livro } => {
+                let id_livro = escolher(&ids_livros, *livro);
+                let esperado = modelo.remover(id_livro);
+                let obtido = biblioteca.remover_livro(id_livro);
+                prop_assert_eq!(obtido.is_ok(), esperado, "{:?}", obtido);
+            }
+            Operacao::SalvarECarregar => {
+                biblioteca = salvar_e_carregar(&biblioteca)?;
+            }
+        }
+
+        verificar_invariantes(&biblioteca, &modelo)?;
+    }
+
+    // Salvar seguido de carregar preserva todo o estado
+    salvar_e_carregar(&biblioteca)?;
+
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn test_sequencias_aleatorias_respeitam_o_modelo(
+        operacoes in prop::collection::vec(operacao(), 0..60)
+    ) {
+        executar(&operacoes)?;
+    }
+}
+
+#[test]
+fn test_modelo_emprestimo_duplo() {
+    // Caso fixo: segundo empréstimo do mesmo livro precisa falhar nos dois lados
+    let primeiro = 1;
+    let operacoes = vec![
+        Operacao::AdicionarLivro {
+            titulo: "livro".to_string(),
+            ano: 2000,
+        },
+        Operacao::AdicionarUsuario {
+            nome: "ana".to_string(),
+        },
+        Operacao::Emprestar {
+            usuario: primeiro,
+            livro: primeiro,
+        },
+        Operacao::Emprestar {
+            usuario: primeiro,
+            livro: primeiro,
+        },
+        Operacao::SalvarECarregar,
+        Operacao::Devolver { livro: primeiro },
+        Operacao::RemoverLivro { livro: primeiro },
+    ];
+
+    executar(&operacoes).unwrap();
+}
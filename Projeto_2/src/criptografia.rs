@@ -0,0 +1,186 @@
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::errors::ErroBiblioteca;
+
+// Formato do arquivo cifrado:
+// MAGICO (8 bytes) | versão (1 byte) | sal (16 bytes) | nonce (24 bytes) | dados cifrados
+// O cabeçalho inteiro é autenticado junto com os dados (AAD).
+const MAGICO: &[u8; 8] = b"BIBCRYPT";
+const VERSAO: u8 = 1;
+const TAMANHO_SAL: usize = 16;
+const TAMANHO_NONCE: usize = 24;
+const TAMANHO_CABECALHO: usize = MAGICO.len() + 1 + TAMANHO_SAL + TAMANHO_NONCE;
+const TAMANHO_ARQUIVO_CHAVE: usize = 32;
+
+// Segredo a partir do qual a chave de cifragem é derivada (senha ou arquivo de chave)
+#[derive(Clone)]
+pub struct Chave {
+    segredo: Vec<u8>,
+}
+
+// Nunca exibir o segredo em mensagens de depuração
+impl fmt::Debug for Chave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Chave(***)")
+    }
+}
+
+impl Chave {
+    pub fn de_senha(senha: &str) -> Result<Self, ErroBiblioteca> {
+        if senha.is_empty() {
+            return Err(ErroBiblioteca::ErroPersistencia(
+                "A senha não pode ser vazia".to_string(),
+            ));
+        }
+        Ok(Chave {
+            segredo: senha.as_bytes().to_vec(),
+        })
+    }
+
+    pub fn de_arquivo(caminho: &Path) -> Result<Self, ErroBiblioteca> {
+        let segredo = fs::read(caminho).map_err(|e| {
+            ErroBiblioteca::ErroPersistencia(format!("Erro ao ler arquivo de chave: {}", e))
+        })?;
+        if segredo.is_empty() {
+            return Err(ErroBiblioteca::ErroPersistencia(
+                "Arquivo de chave vazio".to_string(),
+            ));
+        }
+        Ok(Chave { segredo })
+    }
+
+    // Cria um arquivo de chave com bytes aleatórios
+    pub fn gerar_arquivo(caminho: &Path) -> Result<Self, ErroBiblioteca> {
+        let mut segredo = vec![0u8; TAMANHO_ARQUIVO_CHAVE];
+        OsRng.fill_bytes(&mut segredo);
+        fs::write(caminho, &segredo).map_err(|e| {
+            ErroBiblioteca::ErroPersistencia(format!("Erro ao criar arquivo de chave: {}", e))
+        })?;
+        Ok(Chave { segredo })
+    }
+
+    fn derivar(&self, sal: &[u8]) -> Result<Key, ErroBiblioteca> {
+        let mut chave = Key::default();
+        Argon2::default()
+            .hash_password_into(&self.segredo, sal, &mut chave)
+            .map_err(|e| {
+                ErroBiblioteca::ErroPersistencia(format!("Erro ao derivar chave: {}", e))
+            })?;
+        Ok(chave)
+    }
+}
+
+pub fn esta_cifrado(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGICO)
+}
+
+pub fn cifrar(dados: &[u8], chave: &Chave) -> Result<Vec<u8>, ErroBiblioteca> {
+    let mut sal = [0u8; TAMANHO_SAL];
+    OsRng.fill_bytes(&mut sal);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+    let mut saida = Vec::with_capacity(TAMANHO_CABECALHO + dados.len() + 16);
+    saida.extend_from_slice(MAGICO);
+    saida.push(VERSAO);
+    saida.extend_from_slice(&sal);
+    saida.extend_from_slice(&nonce);
+
+    let cifra = XChaCha20Poly1305::new(&chave.derivar(&sal)?);
+    let cifrado = cifra
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: dados,
+                aad: &saida,
+            },
+        )
+        .map_err(|_| ErroBiblioteca::ErroPersistencia("Erro ao cifrar dados".to_string()))?;
+
+    saida.extend_from_slice(&cifrado);
+    Ok(saida)
+}
+
+pub fn decifrar(bytes: &[u8], chave: &Chave) -> Result<Vec<u8>, ErroBiblioteca> {
+    if !esta_cifrado(bytes) || bytes.len() < TAMANHO_CABECALHO {
+        return Err(ErroBiblioteca::ErroPersistencia(
+            "Arquivo não está no formato cifrado da biblioteca".to_string(),
+        ));
+    }
+
+    let versao = bytes[MAGICO.len()];
+    if versao != VERSAO {
+        return Err(ErroBiblioteca::ErroPersistencia(format!(
+            "Versão de arquivo cifrado não suportada: {}",
+            versao
+        )));
+    }
+
+    let (cabecalho, cifrado) = bytes.split_at(TAMANHO_CABECALHO);
+    let sal = &cabecalho[MAGICO.len() + 1..MAGICO.len() + 1 + TAMANHO_SAL];
+    let nonce = XNonce::from_slice(&cabecalho[MAGICO.len() + 1 + TAMANHO_SAL..]);
+
+    let cifra = XChaCha20Poly1305::new(&chave.derivar(sal)?);
+    cifra
+        .decrypt(
+            nonce,
+            Payload {
+                msg: cifrado,
+                aad: cabecalho,
+            },
+        )
+        .map_err(|_| {
+            ErroBiblioteca::ErroPersistencia(
+                "Chave incorreta ou arquivo cifrado corrompido".to_string(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_cifrar_e_decifrar() {
+        let chave = Chave::de_senha("segredo").unwrap();
+        let cifrado = cifrar(b"{\"livros\":{}}", &chave).unwrap();
+
+        assert!(esta_cifrado(&cifrado));
+        assert_eq!(decifrar(&cifrado, &chave).unwrap(), b"{\"livros\":{}}");
+    }
+
+    #[test]
+    fn test_chave_incorreta() {
+        let cifrado = cifrar(b"dados", &Chave::de_senha("certa").unwrap()).unwrap();
+        let erro = decifrar(&cifrado, &Chave::de_senha("errada").unwrap()).unwrap_err();
+
+        assert!(erro.to_string().contains("Chave incorreta"));
+    }
+
+    #[test]
+    fn test_cabecalho_adulterado() {
+        let chave = Chave::de_senha("segredo").unwrap();
+        let mut cifrado = cifrar(b"dados", &chave).unwrap();
+        cifrado[MAGICO.len() + 1] ^= 0xff;
+
+        assert!(decifrar(&cifrado, &chave).is_err());
+    }
+
+    #[test]
+    fn test_arquivo_de_chave() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("biblioteca.key");
+        let gerada = Chave::gerar_arquivo(&caminho).unwrap();
+        let lida = Chave::de_arquivo(&caminho).unwrap();
+
+        let cifrado = cifrar(b"dados", &gerada).unwrap();
+        assert_eq!(decifrar(&cifrado, &lida).unwrap(), b"dados");
+        assert_eq!(format!("{:?}", lida), "Chave(***)");
+    }
+}
@@ -9,7 +9,7 @@ pub enum StatusEmprestimo {
     Devolvido,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Emprestimo {
     pub id_emprestimo: Uuid,
     pub id_livro: Uuid,
@@ -17,6 +17,11 @@ pub struct Emprestimo {
     pub data_emprestimo: NaiveDate,
     pub data_devolucao_prevista: NaiveDate,
     pub status: StatusEmprestimo,
+    // Unidades onde o livro foi retirado e devolvido, quando conhecidas
+    #[serde(default)]
+    pub unidade_retirada: Option<Uuid>,
+    #[serde(default)]
+    pub unidade_devolucao: Option<Uuid>,
 }
 
 impl Emprestimo {
@@ -31,12 +36,19 @@ impl Emprestimo {
             data_emprestimo,
             data_devolucao_prevista,
             status: StatusEmprestimo::Ativo,
+            unidade_retirada: None,
+            unidade_devolucao: None,
         }
     }
 
     pub fn finalizar(&mut self) {
         self.status = StatusEmprestimo::Devolvido;
     }
+
+    pub fn finalizar_na_unidade(&mut self, id_unidade: Option<Uuid>) {
+        self.unidade_devolucao = id_unidade;
+        self.finalizar();
+    }
 }
 
 impl Identificavel for Emprestimo {
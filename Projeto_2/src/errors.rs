@@ -5,6 +5,8 @@ pub enum ErroBiblioteca {
     LivroNaoEncontrado(String),
     UsuarioNaoEncontrado(String),
     EmprestimoNaoEncontrado(String),
+    UnidadeNaoEncontrada(String),
+    TransferenciaNaoEncontrada(String),
     EstadoInvalido(String),
     ErroPersistencia(String),
 }
@@ -21,6 +23,12 @@ impl fmt::Display for ErroBiblioteca {
             ErroBiblioteca::EmprestimoNaoEncontrado(id) => {
                 write!(f, "Empréstimo não encontrado: {}", id)
             }
+            ErroBiblioteca::UnidadeNaoEncontrada(id) => {
+                write!(f, "Unidade não encontrada: {}", id)
+            }
+            ErroBiblioteca::TransferenciaNaoEncontrada(id) => {
+                write!(f, "Transferência não encontrada: {}", id)
+            }
             ErroBiblioteca::EstadoInvalido(msg) => {
                 write!(f, "Estado inválido: {}", msg)
             }
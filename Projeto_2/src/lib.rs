@@ -1,7 +1,12 @@
 pub mod biblioteca;
+pub mod criptografia;
 pub mod emprestimos;
 pub mod errors;
 pub mod generics;
 pub mod livros;
+pub mod recomendacoes;
 pub mod traits;
+pub mod transferencias;
+pub mod tui;
+pub mod unidades;
 pub mod usuarios;
@@ -7,15 +7,22 @@ use uuid::Uuid;
 pub enum StatusLivro {
     Disponivel,
     Emprestado,
+    EmTransito,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Livro {
     pub id: Uuid,
     pub titulo: String,
     pub autor: String,
     pub ano: u16,
     pub status: StatusLivro,
+    // Unidade à qual o livro pertence e unidade onde ele se encontra agora.
+    // Ausentes em dados antigos, de antes do suporte a várias unidades.
+    #[serde(default)]
+    pub unidade_origem: Option<Uuid>,
+    #[serde(default)]
+    pub unidade_atual: Option<Uuid>,
 }
 
 impl Livro {
@@ -26,6 +33,16 @@ impl Livro {
             autor,
             ano,
             status: StatusLivro::Disponivel,
+            unidade_origem: None,
+            unidade_atual: None,
+        }
+    }
+
+    pub fn com_unidade(titulo: String, autor: String, ano: u16, id_unidade: Uuid) -> Self {
+        Livro {
+            unidade_origem: Some(id_unidade),
+            unidade_atual: Some(id_unidade),
+            ..Livro::new(titulo, autor, ano)
         }
     }
 
@@ -38,6 +55,9 @@ impl Livro {
             StatusLivro::Emprestado => Err(ErroBiblioteca::EstadoInvalido(
                 "Livro já está emprestado".to_string(),
             )),
+            StatusLivro::EmTransito => Err(ErroBiblioteca::EstadoInvalido(
+                "Livro está em trânsito entre unidades".to_string(),
+            )),
         }
     }
 
@@ -50,6 +70,34 @@ impl Livro {
             StatusLivro::Disponivel => Err(ErroBiblioteca::EstadoInvalido(
                 "Livro já está disponível".to_string(),
             )),
+            StatusLivro::EmTransito => Err(ErroBiblioteca::EstadoInvalido(
+                "Livro está em trânsito entre unidades".to_string(),
+            )),
+        }
+    }
+
+    pub fn enviar_transferencia(&mut self) -> Result<(), ErroBiblioteca> {
+        match self.status {
+            StatusLivro::Disponivel => {
+                self.status = StatusLivro::EmTransito;
+                Ok(())
+            }
+            _ => Err(ErroBiblioteca::EstadoInvalido(
+                "Somente livros disponíveis podem ser transferidos".to_string(),
+            )),
+        }
+    }
+
+    pub fn receber_transferencia(&mut self, id_unidade: Uuid) -> Result<(), ErroBiblioteca> {
+        match self.status {
+            StatusLivro::EmTransito => {
+                self.status = StatusLivro::Disponivel;
+                self.unidade_atual = Some(id_unidade);
+                Ok(())
+            }
+            _ => Err(ErroBiblioteca::EstadoInvalido(
+                "Livro não está em trânsito".to_string(),
+            )),
         }
     }
 }
@@ -95,6 +143,27 @@ mod tests {
         assert_eq!(livro.status, StatusLivro::Disponivel);
     }
 
+    #[test]
+    fn test_transferencia_livro() {
+        let origem = Uuid::new_v4();
+        let destino = Uuid::new_v4();
+        let mut livro = Livro::com_unidade(
+            "1984".to_string(),
+            "George Orwell".to_string(),
+            1949,
+            origem,
+        );
+
+        livro.enviar_transferencia().unwrap();
+        assert_eq!(livro.status, StatusLivro::EmTransito);
+        assert!(livro.emprestar().is_err());
+
+        livro.receber_transferencia(destino).unwrap();
+        assert_eq!(livro.status, StatusLivro::Disponivel);
+        assert_eq!(livro.unidade_atual, Some(destino));
+        assert_eq!(livro.unidade_origem, Some(origem));
+    }
+
     #[test]
     fn test_identificavel_trait() {
         let livro = Livro::new("1984".to_string(), "George Orwell".to_string(), 1949);
@@ -1,14 +1,60 @@
 use biblioteca_virtual::biblioteca::Biblioteca;
-use std::io::{self, Write};
+use biblioteca_virtual::criptografia::{self, Chave};
+use biblioteca_virtual::errors::ErroBiblioteca;
+use biblioteca_virtual::recomendacoes::Recomendacao;
+use biblioteca_virtual::tui;
+use std::io::{self, IsTerminal, Write};
 use std::path::PathBuf;
 use uuid::Uuid;
 
+const QUANTIDADE_RECOMENDACOES: usize = 5;
+
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
     let caminho = PathBuf::from("biblioteca_dados.json");
-    let mut biblioteca = Biblioteca::carregar(&caminho).unwrap_or_else(|e| {
-        println!("Aviso ao carregar: {}. Criando nova biblioteca.", e);
-        Biblioteca::new(caminho.clone())
-    });
+
+    // Modo cifrado: --senha (pergunta a senha) ou --chave-arquivo <caminho>
+    let chave = match ler_chave(&args, "--senha", "--chave-arquivo", "Senha: ", false) {
+        Ok(chave) => chave,
+        Err(e) => {
+            println!("Erro: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let mut biblioteca = match chave {
+        Some(chave) => match Biblioteca::carregar_cifrada(&caminho, chave) {
+            Ok(biblioteca) => biblioteca,
+            // Nunca substituir um arquivo cifrado por uma biblioteca vazia
+            Err(e) => {
+                println!("Erro ao carregar: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => Biblioteca::carregar(&caminho).unwrap_or_else(|e| {
+            if criptografia::esta_cifrado(&std::fs::read(&caminho).unwrap_or_default()) {
+                println!("Erro ao carregar: {}", e);
+                std::process::exit(1);
+            }
+            println!("Aviso ao carregar: {}. Criando nova biblioteca.", e);
+            Biblioteca::new(caminho.clone())
+        }),
+    };
+
+    // Rotação de chave: rotacionar-chave --nova-senha | --nova-chave-arquivo <caminho>
+    if args.iter().any(|arg| arg == "rotacionar-chave") {
+        rotacionar_chave(&mut biblioteca, &args);
+        return;
+    }
+
+    // Interface de tela cheia: cargo run -- --tui
+    if args.iter().any(|arg| arg == "--tui") {
+        if let Err(e) = tui::executar(&mut biblioteca) {
+            println!("Erro na interface de terminal: {}", e);
+        }
+        salvar_biblioteca(&biblioteca);
+        return;
+    }
 
     println!("=== Sistema de Gerenciamento de Biblioteca Virtual ===\n");
 
@@ -27,7 +73,9 @@ fn main() {
             "7" => emprestar_livro(&mut biblioteca),
             "8" => devolver_livro(&mut biblioteca),
             "9" => listar_emprestimos(&biblioteca),
-            "10" => {
+            "10" => gerenciar_unidades(&mut biblioteca),
+            "11" => recomendacoes_e_listas(&mut biblioteca),
+            "12" => {
                 salvar_biblioteca(&biblioteca);
                 println!("\nEncerrando sistema...");
                 break;
@@ -48,7 +96,9 @@ fn exibir_menu() {
     println!("7.  Emprestar Livro");
     println!("8.  Devolver Livro");
     println!("9.  Listar Empréstimos Ativos");
-    println!("10. Salvar e Sair");
+    println!("10. Unidades e Transferências");
+    println!("11. Recomendações e Listas de Leitura");
+    println!("12. Salvar e Sair");
     println!();
 }
 
@@ -65,15 +115,25 @@ fn adicionar_livro(biblioteca: &mut Biblioteca) {
     let titulo = ler_entrada("Título: ").trim().to_string();
     let autor = ler_entrada("Autor: ").trim().to_string();
     let ano_str = ler_entrada("Ano de publicação: ").trim().to_string();
+    let id_unidade = match ler_unidade_opcional() {
+        Some(id) => id,
+        None => return,
+    };
 
     match ano_str.parse::<u16>() {
-        Ok(ano) => match biblioteca.adicionar_livro(titulo, autor, ano) {
-            Ok(id) => {
-                println!("\nLivro adicionado com sucesso! ID: {}", id);
-                salvar_biblioteca(biblioteca);
+        Ok(ano) => {
+            let resultado = match id_unidade {
+                Some(id) => biblioteca.adicionar_livro_na_unidade(titulo, autor, ano, id),
+                None => biblioteca.adicionar_livro(titulo, autor, ano),
+            };
+            match resultado {
+                Ok(id) => {
+                    println!("\nLivro adicionado com sucesso! ID: {}", id);
+                    salvar_biblioteca(biblioteca);
+                }
+                Err(e) => println!("\nErro ao adicionar livro: {}", e),
             }
-            Err(e) => println!("\nErro ao adicionar livro: {}", e),
-        },
+        }
         Err(_) => println!("\nAno inválido!"),
     }
     println!();
@@ -242,7 +302,17 @@ fn emprestar_livro(biblioteca: &mut Biblioteca) {
         }
     };
 
-    match biblioteca.emprestar_livro(id_usuario, id_livro) {
+    let id_unidade = match ler_unidade_opcional() {
+        Some(id) => id,
+        None => return,
+    };
+
+    let resultado = match id_unidade {
+        Some(id) => biblioteca.emprestar_livro_na_unidade(id_usuario, id_livro, id),
+        None => biblioteca.emprestar_livro(id_usuario, id_livro),
+    };
+
+    match resultado {
         Ok(id_emprestimo) => {
             println!(
                 "\nLivro emprestado com sucesso! ID do empréstimo: {}",
@@ -259,15 +329,30 @@ fn devolver_livro(biblioteca: &mut Biblioteca) {
     println!("\n--- Devolver Livro ---");
     let id_livro_str = ler_entrada("ID do livro: ").trim().to_string();
 
-    match Uuid::parse_str(&id_livro_str) {
-        Ok(id_livro) => match biblioteca.devolver_livro(id_livro) {
-            Ok(_) => {
-                println!("\nLivro devolvido com sucesso!");
-                salvar_biblioteca(biblioteca);
-            }
-            Err(e) => println!("\nErro ao devolver livro: {}", e),
-        },
-        Err(_) => println!("\nID inválido!"),
+    let id_livro = match Uuid::parse_str(&id_livro_str) {
+        Ok(id) => id,
+        Err(_) => {
+            println!("\nID inválido!");
+            return;
+        }
+    };
+
+    let id_unidade = match ler_unidade_opcional() {
+        Some(id) => id,
+        None => return,
+    };
+
+    let resultado = match id_unidade {
+        Some(id) => biblioteca.devolver_livro_na_unidade(id_livro, id),
+        None => biblioteca.devolver_livro(id_livro),
+    };
+
+    match resultado {
+        Ok(_) => {
+            println!("\nLivro devolvido com sucesso!");
+            salvar_biblioteca(biblioteca);
+        }
+        Err(e) => println!("\nErro ao devolver livro: {}", e),
     }
     println!();
 }
@@ -294,6 +379,302 @@ fn listar_emprestimos(biblioteca: &Biblioteca) {
     println!();
 }
 
+fn gerenciar_unidades(biblioteca: &mut Biblioteca) {
+    println!("\n--- Unidades e Transferências ---");
+    println!("1. Adicionar unidade");
+    println!("2. Listar unidades");
+    println!("3. Associar livro a uma unidade");
+    println!("4. Transferir livro");
+    println!("5. Receber transferência");
+    println!("6. Listar transferências em trânsito");
+
+    let opcao = ler_entrada("Escolha uma opção: ");
+
+    match opcao.trim() {
+        "1" => {
+            let nome = ler_entrada("Nome da unidade: ").trim().to_string();
+            match biblioteca.adicionar_unidade(nome) {
+                Ok(id) => {
+                    println!("\nUnidade adicionada com sucesso! ID: {}", id);
+                    salvar_biblioteca(biblioteca);
+                }
+                Err(e) => println!("\nErro ao adicionar unidade: {}", e),
+            }
+        }
+        "2" => {
+            let unidades = biblioteca.listar_unidades();
+            if unidades.is_empty() {
+                println!("\nNenhuma unidade cadastrada.");
+            } else {
+                println!("\nUnidades cadastradas:");
+                for unidade in unidades {
+                    println!(
+                        "- ID: {}\n  Nome: {}\n  Livros na unidade: {}\n  Disponíveis: {}\n",
+                        unidade.id,
+                        unidade.nome,
+                        biblioteca.listar_livros_na_unidade(unidade.id).len(),
+                        biblioteca
+                            .listar_livros_disponiveis_na_unidade(unidade.id)
+                            .len()
+                    );
+                }
+            }
+        }
+        "3" => {
+            let id_livro_str = ler_entrada("ID do livro: ").trim().to_string();
+            let id_unidade_str = ler_entrada("ID da unidade: ").trim().to_string();
+            match (
+                Uuid::parse_str(&id_livro_str),
+                Uuid::parse_str(&id_unidade_str),
+            ) {
+                (Ok(id_livro), Ok(id_unidade)) => {
+                    match biblioteca.associar_livro_unidade(id_livro, id_unidade) {
+                        Ok(_) => {
+                            println!("\nLivro associado à unidade com sucesso!");
+                            salvar_biblioteca(biblioteca);
+                        }
+                        Err(e) => println!("\nErro ao associar livro: {}", e),
+                    }
+                }
+                _ => println!("\nID inválido!"),
+            }
+        }
+        "4" => {
+            let id_livro_str = ler_entrada("ID do livro: ").trim().to_string();
+            let id_destino_str = ler_entrada("ID da unidade de destino: ").trim().to_string();
+            match (
+                Uuid::parse_str(&id_livro_str),
+                Uuid::parse_str(&id_destino_str),
+            ) {
+                (Ok(id_livro), Ok(id_destino)) => {
+                    match biblioteca.transferir_livro(id_livro, id_destino) {
+                        Ok(id) => {
+                            println!("\nLivro enviado! ID da transferência: {}", id);
+                            salvar_biblioteca(biblioteca);
+                        }
+                        Err(e) => println!("\nErro ao transferir livro: {}", e),
+                    }
+                }
+                _ => println!("\nID inválido!"),
+            }
+        }
+        "5" => {
+            let id_str = ler_entrada("ID da transferência: ").trim().to_string();
+            match Uuid::parse_str(&id_str) {
+                Ok(id) => match biblioteca.receber_transferencia(id) {
+                    Ok(_) => {
+                        println!("\nTransferência recebida com sucesso!");
+                        salvar_biblioteca(biblioteca);
+                    }
+                    Err(e) => println!("\nErro ao receber transferência: {}", e),
+                },
+                Err(_) => println!("\nID inválido!"),
+            }
+        }
+        "6" => {
+            let transferencias = biblioteca.listar_transferencias_em_transito();
+            if transferencias.is_empty() {
+                println!("\nNenhuma transferência em trânsito.");
+            } else {
+                println!("\nTransferências em trânsito:");
+                for t in transferencias {
+                    println!(
+                        "- ID Transferência: {}\n  ID Livro: {}\n  Origem: {}\n  Destino: {}\n  Data de Envio: {}\n",
+                        t.id_transferencia, t.id_livro, t.unidade_origem, t.unidade_destino, t.data_envio
+                    );
+                }
+            }
+        }
+        _ => println!("\nOpção inválida!"),
+    }
+    println!();
+}
+
+fn recomendacoes_e_listas(biblioteca: &mut Biblioteca) {
+    println!("\n--- Recomendações e Listas de Leitura ---");
+    println!("1. Quem pegou este livro também pegou...");
+    println!("2. Recomendações para um usuário");
+    println!("3. Ver lista de leitura");
+    println!("4. Adicionar livro à lista de leitura");
+    println!("5. Remover livro da lista de leitura");
+
+    let opcao = ler_entrada("Escolha uma opção: ");
+
+    match opcao.trim() {
+        "1" => {
+            let id_str = ler_entrada("ID do livro: ").trim().to_string();
+            match Uuid::parse_str(&id_str) {
+                Ok(id) => match biblioteca.recomendar_para_livro(id, QUANTIDADE_RECOMENDACOES) {
+                    Ok(recomendacoes) => exibir_recomendacoes(biblioteca, &recomendacoes),
+                    Err(e) => println!("\nErro ao recomendar: {}", e),
+                },
+                Err(_) => println!("\nID inválido!"),
+            }
+        }
+        "2" => {
+            let id_str = ler_entrada("ID do usuário: ").trim().to_string();
+            match Uuid::parse_str(&id_str) {
+                Ok(id) => match biblioteca.recomendar_para_usuario(id, QUANTIDADE_RECOMENDACOES) {
+                    Ok(recomendacoes) => exibir_recomendacoes(biblioteca, &recomendacoes),
+                    Err(e) => println!("\nErro ao recomendar: {}", e),
+                },
+                Err(_) => println!("\nID inválido!"),
+            }
+        }
+        "3" => {
+            let id_str = ler_entrada("ID do usuário: ").trim().to_string();
+            match Uuid::parse_str(&id_str) {
+                Ok(id) => match biblioteca.lista_leitura(id) {
+                    Ok(livros) if livros.is_empty() => println!("\nLista de leitura vazia."),
+                    Ok(livros) => {
+                        println!("\nLista de leitura:");
+                        for (posicao, livro) in livros.iter().enumerate() {
+                            println!(
+                                "{}. {} - {} ({:?})\n   ID: {}",
+                                posicao + 1,
+                                livro.titulo,
+                                livro.autor,
+                                livro.status,
+                                livro.id
+                            );
+                        }
+                    }
+                    Err(e) => println!("\nErro ao exibir lista: {}", e),
+                },
+                Err(_) => println!("\nID inválido!"),
+            }
+        }
+        "4" | "5" => {
+            let id_usuario_str = ler_entrada("ID do usuário: ").trim().to_string();
+            let id_livro_str = ler_entrada("ID do livro: ").trim().to_string();
+            match (
+                Uuid::parse_str(&id_usuario_str),
+                Uuid::parse_str(&id_livro_str),
+            ) {
+                (Ok(id_usuario), Ok(id_livro)) => {
+                    let resultado = if opcao.trim() == "4" {
+                        biblioteca.adicionar_a_lista_leitura(id_usuario, id_livro)
+                    } else {
+                        biblioteca.remover_da_lista_leitura(id_usuario, id_livro)
+                    };
+                    match resultado {
+                        Ok(_) => {
+                            println!("\nLista de leitura atualizada!");
+                            salvar_biblioteca(biblioteca);
+                        }
+                        Err(e) => println!("\nErro ao atualizar lista: {}", e),
+                    }
+                }
+                _ => println!("\nID inválido!"),
+            }
+        }
+        _ => println!("\nOpção inválida!"),
+    }
+    println!();
+}
+
+fn exibir_recomendacoes(biblioteca: &Biblioteca, recomendacoes: &[Recomendacao]) {
+    if recomendacoes.is_empty() {
+        println!("\nNenhuma recomendação disponível ainda.");
+        return;
+    }
+
+    println!("\nRecomendações:");
+    for recomendacao in recomendacoes {
+        if let Some(livro) = biblioteca.buscar_livro_por_id(recomendacao.id_livro) {
+            println!(
+                "- {} - {} ({} leitor(es) em comum)\n  ID: {}",
+                livro.titulo, livro.autor, recomendacao.pontuacao, livro.id
+            );
+        }
+    }
+}
+
+// Lê um ID de unidade opcional: Some(None) quando o usuário deixa em branco,
+// None quando o ID digitado é inválido
+fn ler_unidade_opcional() -> Option<Option<Uuid>> {
+    let id_str = ler_entrada("ID da unidade (Enter para nenhuma): ")
+        .trim()
+        .to_string();
+
+    if id_str.is_empty() {
+        return Some(None);
+    }
+
+    match Uuid::parse_str(&id_str) {
+        Ok(id) => Some(Some(id)),
+        Err(_) => {
+            println!("\nID de unidade inválido!");
+            None
+        }
+    }
+}
+
+fn rotacionar_chave(biblioteca: &mut Biblioteca, args: &[String]) {
+    match ler_chave(
+        args,
+        "--nova-senha",
+        "--nova-chave-arquivo",
+        "Nova senha: ",
+        true,
+    ) {
+        Ok(Some(nova_chave)) => match biblioteca.rotacionar_chave(nova_chave) {
+            Ok(_) => println!("Chave rotacionada com sucesso! O arquivo foi cifrado novamente."),
+            Err(e) => {
+                println!("Erro ao rotacionar chave: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Ok(None) => {
+            println!("Informe --nova-senha ou --nova-chave-arquivo <caminho>");
+            std::process::exit(1);
+        }
+        Err(e) => {
+            println!("Erro: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+// Obtém a chave a partir das opções da linha de comando. Com `gerar_arquivo`,
+// um arquivo de chave inexistente é criado com bytes aleatórios.
+fn ler_chave(
+    args: &[String],
+    opcao_senha: &str,
+    opcao_arquivo: &str,
+    mensagem_senha: &str,
+    gerar_arquivo: bool,
+) -> Result<Option<Chave>, ErroBiblioteca> {
+    if let Some(posicao) = args.iter().position(|arg| arg == opcao_arquivo) {
+        let caminho = args.get(posicao + 1).map(PathBuf::from).ok_or_else(|| {
+            ErroBiblioteca::ErroPersistencia(format!("{} exige um caminho", opcao_arquivo))
+        })?;
+
+        if gerar_arquivo && !caminho.exists() {
+            println!("Gerando novo arquivo de chave em {}", caminho.display());
+            return Chave::gerar_arquivo(&caminho).map(Some);
+        }
+        return Chave::de_arquivo(&caminho).map(Some);
+    }
+
+    if args.iter().any(|arg| arg == opcao_senha) {
+        let senha = ler_senha(mensagem_senha)?;
+        return Chave::de_senha(senha.trim()).map(Some);
+    }
+
+    Ok(None)
+}
+
+// Lê a senha sem mostrá-la no terminal. Com a entrada redirecionada (sem
+// terminal), ela é lida como uma linha comum.
+fn ler_senha(mensagem: &str) -> Result<String, ErroBiblioteca> {
+    if !io::stdin().is_terminal() {
+        return Ok(ler_entrada(mensagem));
+    }
+    rpassword::prompt_password(mensagem)
+        .map_err(|e| ErroBiblioteca::ErroPersistencia(format!("Erro ao ler a senha: {}", e)))
+}
+
 fn salvar_biblioteca(biblioteca: &Biblioteca) {
     if let Err(e) = biblioteca.salvar() {
         println!("Erro ao salvar dados: {}", e);
@@ -0,0 +1,190 @@
+use crate::emprestimos::Emprestimo;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recomendacao {
+    pub id_livro: Uuid,
+    // Quantidade de leitores em comum que levaram a recomendação
+    pub pontuacao: u32,
+}
+
+// Matriz item-a-item: para cada livro, quantos usuários também pegaram cada outro livro
+pub struct Coocorrencias {
+    contagens: HashMap<Uuid, HashMap<Uuid, u32>>,
+}
+
+impl Coocorrencias {
+    pub fn calcular<'a, I>(emprestimos: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Emprestimo>,
+    {
+        // Cada usuário conta uma única vez por par, mesmo que tenha repetido empréstimos
+        let historico = livros_por_usuario(emprestimos);
+
+        let mut contagens: HashMap<Uuid, HashMap<Uuid, u32>> = HashMap::new();
+        for livros in historico.values() {
+            for a in livros {
+                for b in livros {
+                    if a != b {
+                        *contagens.entry(*a).or_default().entry(*b).or_default() += 1;
+                    }
+                }
+            }
+        }
+
+        Coocorrencias { contagens }
+    }
+
+    pub fn contagem(&self, id_livro: Uuid, outro: Uuid) -> u32 {
+        self.contagens
+            .get(&id_livro)
+            .and_then(|vizinhos| vizinhos.get(&outro))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    // "Quem pegou este livro também pegou..."
+    pub fn recomendar_para_livro(
+        &self,
+        id_livro: Uuid,
+        quantidade: usize,
+        excluir: &HashSet<Uuid>,
+    ) -> Vec<Recomendacao> {
+        let mut pontuacoes: HashMap<Uuid, u32> = HashMap::new();
+        if let Some(vizinhos) = self.contagens.get(&id_livro) {
+            for (outro, contagem) in vizinhos {
+                if !excluir.contains(outro) {
+                    *pontuacoes.entry(*outro).or_default() += contagem;
+                }
+            }
+        }
+        ordenar(pontuacoes, quantidade)
+    }
+
+    // Soma as coocorrências de todos os livros do histórico, ignorando os já lidos
+    pub fn recomendar_para_historico(
+        &self,
+        historico: &HashSet<Uuid>,
+        quantidade: usize,
+        excluir: &HashSet<Uuid>,
+    ) -> Vec<Recomendacao> {
+        let mut pontuacoes: HashMap<Uuid, u32> = HashMap::new();
+        for id_livro in historico {
+            if let Some(vizinhos) = self.contagens.get(id_livro) {
+                for (outro, contagem) in vizinhos {
+                    if !historico.contains(outro) && !excluir.contains(outro) {
+                        *pontuacoes.entry(*outro).or_default() += contagem;
+                    }
+                }
+            }
+        }
+        ordenar(pontuacoes, quantidade)
+    }
+}
+
+pub fn livros_por_usuario<'a, I>(emprestimos: I) -> HashMap<Uuid, HashSet<Uuid>>
+where
+    I: IntoIterator<Item = &'a Emprestimo>,
+{
+    let mut historico: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+    for emprestimo in emprestimos {
+        historico
+            .entry(emprestimo.id_usuario)
+            .or_default()
+            .insert(emprestimo.id_livro);
+    }
+    historico
+}
+
+// Maior pontuação primeiro; empates resolvidos pelo id para um resultado estável
+fn ordenar(pontuacoes: HashMap<Uuid, u32>, quantidade: usize) -> Vec<Recomendacao> {
+    let mut recomendacoes: Vec<Recomendacao> = pontuacoes
+        .into_iter()
+        .map(|(id_livro, pontuacao)| Recomendacao {
+            id_livro,
+            pontuacao,
+        })
+        .collect();
+    recomendacoes.sort_by(|a, b| {
+        b.pontuacao
+            .cmp(&a.pontuacao)
+            .then(a.id_livro.cmp(&b.id_livro))
+    });
+    recomendacoes.truncate(quantidade);
+    recomendacoes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(n: usize) -> Vec<Uuid> {
+        (0..n).map(|_| Uuid::new_v4()).collect()
+    }
+
+    #[test]
+    fn test_coocorrencia_conta_usuarios_distintos() {
+        let livros = ids(3);
+        let usuarios = ids(2);
+        let emprestimos = vec![
+            Emprestimo::new(livros[0], usuarios[0]),
+            Emprestimo::new(livros[1], usuarios[0]),
+            // Empréstimo repetido não aumenta a contagem
+            Emprestimo::new(livros[1], usuarios[0]),
+            Emprestimo::new(livros[0], usuarios[1]),
+            Emprestimo::new(livros[1], usuarios[1]),
+            Emprestimo::new(livros[2], usuarios[1]),
+        ];
+
+        let matriz = Coocorrencias::calcular(&emprestimos);
+        assert_eq!(matriz.contagem(livros[0], livros[1]), 2);
+        assert_eq!(matriz.contagem(livros[1], livros[0]), 2);
+        assert_eq!(matriz.contagem(livros[0], livros[2]), 1);
+        assert_eq!(matriz.contagem(livros[0], livros[0]), 0);
+    }
+
+    #[test]
+    fn test_recomendar_para_livro_ordenado() {
+        let livros = ids(3);
+        let usuarios = ids(2);
+        let emprestimos = vec![
+            Emprestimo::new(livros[0], usuarios[0]),
+            Emprestimo::new(livros[1], usuarios[0]),
+            Emprestimo::new(livros[0], usuarios[1]),
+            Emprestimo::new(livros[1], usuarios[1]),
+            Emprestimo::new(livros[2], usuarios[1]),
+        ];
+
+        let matriz = Coocorrencias::calcular(&emprestimos);
+        let recomendacoes = matriz.recomendar_para_livro(livros[0], 5, &HashSet::new());
+        assert_eq!(recomendacoes.len(), 2);
+        assert_eq!(recomendacoes[0].id_livro, livros[1]);
+        assert_eq!(recomendacoes[0].pontuacao, 2);
+
+        let apenas_um = matriz.recomendar_para_livro(livros[0], 1, &HashSet::new());
+        assert_eq!(apenas_um.len(), 1);
+    }
+
+    #[test]
+    fn test_recomendar_para_historico_exclui_lidos() {
+        let livros = ids(4);
+        let usuarios = ids(3);
+        let emprestimos = vec![
+            Emprestimo::new(livros[0], usuarios[0]),
+            Emprestimo::new(livros[1], usuarios[0]),
+            Emprestimo::new(livros[3], usuarios[0]),
+            Emprestimo::new(livros[1], usuarios[1]),
+            Emprestimo::new(livros[2], usuarios[1]),
+            Emprestimo::new(livros[0], usuarios[2]),
+        ];
+
+        let matriz = Coocorrencias::calcular(&emprestimos);
+        let historico: HashSet<Uuid> = [livros[0]].into_iter().collect();
+        let excluir: HashSet<Uuid> = [livros[3]].into_iter().collect();
+        let recomendacoes = matriz.recomendar_para_historico(&historico, 5, &excluir);
+
+        assert_eq!(recomendacoes.len(), 1);
+        assert_eq!(recomendacoes[0].id_livro, livros[1]);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::traits::Identificavel;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum StatusTransferencia {
+    EmTransito,
+    Recebida,
+}
+
+// Movimentação de um livro entre duas unidades
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Transferencia {
+    pub id_transferencia: Uuid,
+    pub id_livro: Uuid,
+    pub unidade_origem: Uuid,
+    pub unidade_destino: Uuid,
+    pub data_envio: NaiveDate,
+    pub data_recebimento: Option<NaiveDate>,
+    pub status: StatusTransferencia,
+}
+
+impl Transferencia {
+    pub fn new(id_livro: Uuid, unidade_origem: Uuid, unidade_destino: Uuid) -> Self {
+        Transferencia {
+            id_transferencia: Uuid::new_v4(),
+            id_livro,
+            unidade_origem,
+            unidade_destino,
+            data_envio: Utc::now().date_naive(),
+            data_recebimento: None,
+            status: StatusTransferencia::EmTransito,
+        }
+    }
+
+    pub fn receber(&mut self) {
+        self.status = StatusTransferencia::Recebida;
+        self.data_recebimento = Some(Utc::now().date_naive());
+    }
+}
+
+impl Identificavel for Transferencia {
+    fn id(&self) -> Uuid {
+        self.id_transferencia
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_criar_transferencia() {
+        let id_livro = Uuid::new_v4();
+        let origem = Uuid::new_v4();
+        let destino = Uuid::new_v4();
+        let transferencia = Transferencia::new(id_livro, origem, destino);
+
+        assert_eq!(transferencia.unidade_origem, origem);
+        assert_eq!(transferencia.unidade_destino, destino);
+        assert_eq!(transferencia.status, StatusTransferencia::EmTransito);
+        assert!(transferencia.data_recebimento.is_none());
+    }
+
+    #[test]
+    fn test_receber_transferencia() {
+        let mut transferencia = Transferencia::new(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        transferencia.receber();
+
+        assert_eq!(transferencia.status, StatusTransferencia::Recebida);
+        assert!(transferencia.data_recebimento.is_some());
+    }
+}
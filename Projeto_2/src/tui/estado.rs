@@ -0,0 +1,493 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use uuid::Uuid;
+
+use crate::biblioteca::Biblioteca;
+use crate::emprestimos::Emprestimo;
+use crate::livros::{Livro, StatusLivro};
+use crate::usuarios::Usuario;
+
+const PASSO_PAGINA: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aba {
+    Livros,
+    Usuarios,
+    Emprestimos,
+}
+
+impl Aba {
+    pub const TODAS: [Aba; 3] = [Aba::Livros, Aba::Usuarios, Aba::Emprestimos];
+
+    pub fn indice(self) -> usize {
+        match self {
+            Aba::Livros => 0,
+            Aba::Usuarios => 1,
+            Aba::Emprestimos => 2,
+        }
+    }
+
+    pub fn titulo(self) -> &'static str {
+        match self {
+            Aba::Livros => "Livros",
+            Aba::Usuarios => "Usuários",
+            Aba::Emprestimos => "Empréstimos",
+        }
+    }
+
+    fn proxima(self) -> Aba {
+        Aba::TODAS[(self.indice() + 1) % Aba::TODAS.len()]
+    }
+
+    fn anterior(self) -> Aba {
+        Aba::TODAS[(self.indice() + Aba::TODAS.len() - 1) % Aba::TODAS.len()]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Modo {
+    Normal,
+    Busca,
+    SelecionarUsuario { id_livro: Uuid },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mensagem {
+    pub texto: String,
+    pub erro: bool,
+}
+
+// Estado da interface, independente do terminal para poder ser testado sem tela
+pub struct EstadoTui {
+    pub aba: Aba,
+    pub modo: Modo,
+    pub sair: bool,
+    pub mensagem: Option<Mensagem>,
+    pub busca_usuario: String,
+    pub selecao_usuario: usize,
+    buscas: [String; 3],
+    selecoes: [usize; 3],
+}
+
+impl Default for EstadoTui {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EstadoTui {
+    pub fn new() -> Self {
+        EstadoTui {
+            aba: Aba::Livros,
+            modo: Modo::Normal,
+            sair: false,
+            mensagem: None,
+            busca_usuario: String::new(),
+            selecao_usuario: 0,
+            buscas: Default::default(),
+            selecoes: [0; 3],
+        }
+    }
+
+    pub fn busca(&self) -> &str {
+        &self.buscas[self.aba.indice()]
+    }
+
+    // Índice da linha selecionada na aba atual, limitado ao total de linhas visíveis
+    pub fn selecionado(&self, total: usize) -> Option<usize> {
+        if total == 0 {
+            None
+        } else {
+            Some(self.selecoes[self.aba.indice()].min(total - 1))
+        }
+    }
+
+    // Consultas usadas tanto pela renderização quanto pelas ações
+    pub fn livros_visiveis<'a>(&self, biblioteca: &'a Biblioteca) -> Vec<&'a Livro> {
+        let termo = self.buscas[Aba::Livros.indice()].to_lowercase();
+        let mut livros: Vec<&Livro> = biblioteca
+            .listar_todos_livros()
+            .into_iter()
+            .filter(|l| {
+                l.titulo.to_lowercase().contains(&termo) || l.autor.to_lowercase().contains(&termo)
+            })
+            .collect();
+        livros.sort_by(|a, b| a.titulo.cmp(&b.titulo).then(a.autor.cmp(&b.autor)));
+        livros
+    }
+
+    pub fn usuarios_visiveis<'a>(&self, biblioteca: &'a Biblioteca) -> Vec<&'a Usuario> {
+        filtrar_usuarios(biblioteca, &self.buscas[Aba::Usuarios.indice()])
+    }
+
+    pub fn usuarios_para_emprestimo<'a>(&self, biblioteca: &'a Biblioteca) -> Vec<&'a Usuario> {
+        filtrar_usuarios(biblioteca, &self.busca_usuario)
+    }
+
+    pub fn emprestimos_visiveis<'a>(&self, biblioteca: &'a Biblioteca) -> Vec<&'a Emprestimo> {
+        let termo = self.buscas[Aba::Emprestimos.indice()].to_lowercase();
+        let mut emprestimos: Vec<&Emprestimo> = biblioteca
+            .listar_emprestimos_ativos()
+            .into_iter()
+            .filter(|e| {
+                let titulo = biblioteca
+                    .buscar_livro_por_id(e.id_livro)
+                    .map(|l| l.titulo.to_lowercase())
+                    .unwrap_or_default();
+                let nome = biblioteca
+                    .buscar_usuario_por_id(e.id_usuario)
+                    .map(|u| u.nome.to_lowercase())
+                    .unwrap_or_default();
+                titulo.contains(&termo) || nome.contains(&termo)
+            })
+            .collect();
+        emprestimos.sort_by_key(|e| (e.data_devolucao_prevista, e.id_emprestimo));
+        emprestimos
+    }
+
+    fn total_linhas(&self, biblioteca: &Biblioteca) -> usize {
+        match self.aba {
+            Aba::Livros => self.livros_visiveis(biblioteca).len(),
+            Aba::Usuarios => self.usuarios_visiveis(biblioteca).len(),
+            Aba::Emprestimos => self.emprestimos_visiveis(biblioteca).len(),
+        }
+    }
+
+    // Ponto de entrada dos eventos de teclado
+    pub fn tratar_tecla(&mut self, biblioteca: &mut Biblioteca, tecla: KeyEvent) {
+        if tecla.modifiers.contains(KeyModifiers::CONTROL) && tecla.code == KeyCode::Char('c') {
+            self.sair = true;
+            return;
+        }
+
+        match self.modo.clone() {
+            Modo::Normal => self.tecla_normal(biblioteca, tecla.code),
+            Modo::Busca => self.tecla_busca(tecla.code),
+            Modo::SelecionarUsuario { id_livro } => {
+                self.tecla_selecao_usuario(biblioteca, id_livro, tecla.code)
+            }
+        }
+    }
+
+    fn tecla_normal(&mut self, biblioteca: &mut Biblioteca, codigo: KeyCode) {
+        let total = self.total_linhas(biblioteca);
+
+        match codigo {
+            KeyCode::Char('q') => self.sair = true,
+            KeyCode::Tab | KeyCode::Right => self.aba = self.aba.proxima(),
+            KeyCode::BackTab | KeyCode::Left => self.aba = self.aba.anterior(),
+            KeyCode::Char('1') => self.aba = Aba::Livros,
+            KeyCode::Char('2') => self.aba = Aba::Usuarios,
+            KeyCode::Char('3') => self.aba = Aba::Emprestimos,
+            KeyCode::Up | KeyCode::Char('k') => self.mover(total, -1),
+            KeyCode::Down | KeyCode::Char('j') => self.mover(total, 1),
+            KeyCode::PageUp => self.mover(total, -(PASSO_PAGINA as isize)),
+            KeyCode::PageDown => self.mover(total, PASSO_PAGINA as isize),
+            KeyCode::Home => self.selecoes[self.aba.indice()] = 0,
+            KeyCode::End => self.selecoes[self.aba.indice()] = total.saturating_sub(1),
+            KeyCode::Char('/') => {
+                self.modo = Modo::Busca;
+                self.mensagem = None;
+            }
+            KeyCode::Esc => self.limpar_busca(),
+            KeyCode::Char('e') => self.iniciar_emprestimo(biblioteca),
+            KeyCode::Char('d') => self.devolver_selecionado(biblioteca),
+            _ => {}
+        }
+    }
+
+    fn tecla_busca(&mut self, codigo: KeyCode) {
+        let indice = self.aba.indice();
+        match codigo {
+            KeyCode::Enter => self.modo = Modo::Normal,
+            KeyCode::Esc => {
+                self.limpar_busca();
+                self.modo = Modo::Normal;
+            }
+            KeyCode::Backspace => {
+                self.buscas[indice].pop();
+                self.selecoes[indice] = 0;
+            }
+            KeyCode::Char(c) => {
+                self.buscas[indice].push(c);
+                self.selecoes[indice] = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn tecla_selecao_usuario(
+        &mut self,
+        biblioteca: &mut Biblioteca,
+        id_livro: Uuid,
+        codigo: KeyCode,
+    ) {
+        let total = self.usuarios_para_emprestimo(biblioteca).len();
+
+        match codigo {
+            KeyCode::Esc => {
+                self.modo = Modo::Normal;
+                self.mensagem = Some(Mensagem::info("Empréstimo cancelado"));
+            }
+            KeyCode::Up => self.selecao_usuario = self.selecao_usuario.saturating_sub(1),
+            KeyCode::Down if self.selecao_usuario + 1 < total => self.selecao_usuario += 1,
+            KeyCode::Backspace => {
+                self.busca_usuario.pop();
+                self.selecao_usuario = 0;
+            }
+            KeyCode::Char(c) => {
+                self.busca_usuario.push(c);
+                self.selecao_usuario = 0;
+            }
+            KeyCode::Enter => {
+                let id_usuario = match self
+                    .usuarios_para_emprestimo(biblioteca)
+                    .get(self.selecao_usuario)
+                {
+                    Some(usuario) => usuario.id,
+                    None => {
+                        self.mensagem = Some(Mensagem::erro("Nenhum usuário selecionado"));
+                        return;
+                    }
+                };
+
+                self.modo = Modo::Normal;
+                match biblioteca.emprestar_livro(id_usuario, id_livro) {
+                    Ok(_) => self.persistir(biblioteca, "Livro emprestado com sucesso!"),
+                    Err(e) => self.mensagem = Some(Mensagem::erro(&e.to_string())),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mover(&mut self, total: usize, delta: isize) {
+        let indice = self.aba.indice();
+        if total == 0 {
+            self.selecoes[indice] = 0;
+            return;
+        }
+        let atual = self.selecoes[indice].min(total - 1) as isize;
+        self.selecoes[indice] = (atual + delta).clamp(0, total as isize - 1) as usize;
+    }
+
+    fn limpar_busca(&mut self) {
+        let indice = self.aba.indice();
+        self.buscas[indice].clear();
+        self.selecoes[indice] = 0;
+    }
+
+    fn iniciar_emprestimo(&mut self, biblioteca: &Biblioteca) {
+        if self.aba != Aba::Livros {
+            self.mensagem = Some(Mensagem::erro(
+                "Selecione um livro na aba Livros para emprestar",
+            ));
+            return;
+        }
+
+        let livros = self.livros_visiveis(biblioteca);
+        let livro = match self.selecionado(livros.len()).map(|i| livros[i]) {
+            Some(livro) => livro,
+            None => {
+                self.mensagem = Some(Mensagem::erro("Nenhum livro selecionado"));
+                return;
+            }
+        };
+
+        if livro.status != StatusLivro::Disponivel {
+            self.mensagem = Some(Mensagem::erro("Livro não está disponível para empréstimo"));
+            return;
+        }
+
+        if biblioteca.listar_usuarios().is_empty() {
+            self.mensagem = Some(Mensagem::erro("Nenhum usuário cadastrado"));
+            return;
+        }
+
+        self.busca_usuario.clear();
+        self.selecao_usuario = 0;
+        self.mensagem = None;
+        self.modo = Modo::SelecionarUsuario { id_livro: livro.id };
+    }
+
+    fn devolver_selecionado(&mut self, biblioteca: &mut Biblioteca) {
+        let id_livro = match self.aba {
+            Aba::Livros => {
+                let livros = self.livros_visiveis(biblioteca);
+                self.selecionado(livros.len()).map(|i| livros[i].id)
+            }
+            Aba::Emprestimos => {
+                let emprestimos = self.emprestimos_visiveis(biblioteca);
+                self.selecionado(emprestimos.len())
+                    .map(|i| emprestimos[i].id_livro)
+            }
+            Aba::Usuarios => None,
+        };
+
+        let id_livro = match id_livro {
+            Some(id) => id,
+            None => {
+                self.mensagem = Some(Mensagem::erro("Nenhum livro ou empréstimo selecionado"));
+                return;
+            }
+        };
+
+        match biblioteca.devolver_livro(id_livro) {
+            Ok(_) => self.persistir(biblioteca, "Livro devolvido com sucesso!"),
+            Err(e) => self.mensagem = Some(Mensagem::erro(&e.to_string())),
+        }
+    }
+
+    // Mantém o mesmo comportamento da CLI: salva após cada operação bem-sucedida
+    fn persistir(&mut self, biblioteca: &Biblioteca, sucesso: &str) {
+        self.mensagem = Some(match biblioteca.salvar() {
+            Ok(_) => Mensagem::info(sucesso),
+            Err(e) => Mensagem::erro(&format!("Erro ao salvar dados: {}", e)),
+        });
+    }
+}
+
+impl Mensagem {
+    fn info(texto: &str) -> Self {
+        Mensagem {
+            texto: texto.to_string(),
+            erro: false,
+        }
+    }
+
+    fn erro(texto: &str) -> Self {
+        Mensagem {
+            texto: texto.to_string(),
+            erro: true,
+        }
+    }
+}
+
+fn filtrar_usuarios<'a>(biblioteca: &'a Biblioteca, termo: &str) -> Vec<&'a Usuario> {
+    let termo = termo.to_lowercase();
+    let mut usuarios: Vec<&Usuario> = biblioteca
+        .listar_usuarios()
+        .into_iter()
+        .filter(|u| u.nome.to_lowercase().contains(&termo))
+        .collect();
+    usuarios.sort_by(|a, b| a.nome.cmp(&b.nome).then(a.id.cmp(&b.id)));
+    usuarios
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn tecla(codigo: KeyCode) -> KeyEvent {
+        KeyEvent::new(codigo, KeyModifiers::NONE)
+    }
+
+    fn digitar(estado: &mut EstadoTui, biblioteca: &mut Biblioteca, texto: &str) {
+        for c in texto.chars() {
+            estado.tratar_tecla(biblioteca, tecla(KeyCode::Char(c)));
+        }
+    }
+
+    fn biblioteca_exemplo(caminho: std::path::PathBuf) -> (Biblioteca, Uuid, Uuid) {
+        let mut biblioteca = Biblioteca::new(caminho);
+        let id_livro = biblioteca
+            .adicionar_livro("1984".to_string(), "George Orwell".to_string(), 1949)
+            .unwrap();
+        biblioteca
+            .adicionar_livro(
+                "Dom Casmurro".to_string(),
+                "Machado de Assis".to_string(),
+                1899,
+            )
+            .unwrap();
+        let id_usuario = biblioteca
+            .adicionar_usuario("João Silva".to_string())
+            .unwrap();
+        (biblioteca, id_livro, id_usuario)
+    }
+
+    #[test]
+    fn test_navegacao_entre_abas() {
+        let dir = tempdir().unwrap();
+        let (mut biblioteca, _, _) = biblioteca_exemplo(dir.path().join("test.json"));
+        let mut estado = EstadoTui::new();
+
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Tab));
+        assert_eq!(estado.aba, Aba::Usuarios);
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::BackTab));
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::BackTab));
+        assert_eq!(estado.aba, Aba::Emprestimos);
+    }
+
+    #[test]
+    fn test_selecao_limitada_ao_total() {
+        let dir = tempdir().unwrap();
+        let (mut biblioteca, _, _) = biblioteca_exemplo(dir.path().join("test.json"));
+        let mut estado = EstadoTui::new();
+
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::PageDown));
+        assert_eq!(estado.selecionado(2), Some(1));
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Up));
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Up));
+        assert_eq!(estado.selecionado(2), Some(0));
+    }
+
+    #[test]
+    fn test_busca_incremental() {
+        let dir = tempdir().unwrap();
+        let (mut biblioteca, _, _) = biblioteca_exemplo(dir.path().join("test.json"));
+        let mut estado = EstadoTui::new();
+
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Char('/')));
+        digitar(&mut estado, &mut biblioteca, "mach");
+        let livros = estado.livros_visiveis(&biblioteca);
+        assert_eq!(livros.len(), 1);
+        assert_eq!(livros[0].titulo, "Dom Casmurro");
+
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Esc));
+        assert_eq!(estado.modo, Modo::Normal);
+        assert_eq!(estado.livros_visiveis(&biblioteca).len(), 2);
+    }
+
+    #[test]
+    fn test_emprestar_e_devolver_pelo_teclado() {
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("test.json");
+        let (mut biblioteca, id_livro, id_usuario) = biblioteca_exemplo(caminho.clone());
+        let mut estado = EstadoTui::new();
+
+        // "1984" é o primeiro livro na ordenação por título
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Char('e')));
+        assert_eq!(estado.modo, Modo::SelecionarUsuario { id_livro });
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Enter));
+
+        assert_eq!(estado.modo, Modo::Normal);
+        assert_eq!(
+            biblioteca.buscar_livro_por_id(id_livro).unwrap().status,
+            StatusLivro::Emprestado
+        );
+        assert_eq!(biblioteca.listar_emprestimos_usuario(id_usuario).len(), 1);
+        assert!(caminho.exists());
+
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Char('3')));
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Char('d')));
+        assert_eq!(
+            biblioteca.buscar_livro_por_id(id_livro).unwrap().status,
+            StatusLivro::Disponivel
+        );
+        assert!(!estado.mensagem.as_ref().unwrap().erro);
+    }
+
+    #[test]
+    fn test_emprestar_livro_indisponivel_gera_erro() {
+        let dir = tempdir().unwrap();
+        let (mut biblioteca, id_livro, id_usuario) =
+            biblioteca_exemplo(dir.path().join("test.json"));
+        biblioteca.emprestar_livro(id_usuario, id_livro).unwrap();
+        let mut estado = EstadoTui::new();
+
+        estado.tratar_tecla(&mut biblioteca, tecla(KeyCode::Char('e')));
+        assert_eq!(estado.modo, Modo::Normal);
+        assert!(estado.mensagem.as_ref().unwrap().erro);
+    }
+}
@@ -0,0 +1,282 @@
+use chrono::Utc;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Row, Table, TableState, Tabs};
+use ratatui::Frame;
+
+use super::estado::{Aba, EstadoTui, Modo};
+use crate::biblioteca::Biblioteca;
+use crate::livros::StatusLivro;
+
+const AJUDA: &str =
+    "Tab/1-3: abas | ↑↓ PgUp PgDn: navegar | /: buscar | e: emprestar | d: devolver | q: sair";
+
+pub fn desenhar(frame: &mut Frame, estado: &EstadoTui, biblioteca: &Biblioteca) {
+    let [area_abas, area_tabela, area_busca, area_status] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(3),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let abas = Tabs::new(Aba::TODAS.iter().map(|a| a.titulo()))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Biblioteca Virtual "),
+        )
+        .select(estado.aba.indice())
+        .highlight_style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Cyan),
+        );
+    frame.render_widget(abas, area_abas);
+
+    match estado.aba {
+        Aba::Livros => desenhar_livros(frame, area_tabela, estado, biblioteca),
+        Aba::Usuarios => desenhar_usuarios(frame, area_tabela, estado, biblioteca),
+        Aba::Emprestimos => desenhar_emprestimos(frame, area_tabela, estado, biblioteca),
+    }
+
+    let prefixo = if estado.modo == Modo::Busca {
+        "/"
+    } else {
+        "Busca: "
+    };
+    frame.render_widget(
+        Paragraph::new(format!("{}{}", prefixo, estado.busca())),
+        area_busca,
+    );
+
+    let status = match &estado.mensagem {
+        Some(m) if m.erro => Line::styled(m.texto.as_str(), Style::default().fg(Color::Red)),
+        Some(m) => Line::styled(m.texto.as_str(), Style::default().fg(Color::Green)),
+        None => Line::styled(AJUDA, Style::default().fg(Color::DarkGray)),
+    };
+    frame.render_widget(Paragraph::new(status), area_status);
+
+    if let Modo::SelecionarUsuario { .. } = estado.modo {
+        desenhar_selecao_usuario(frame, estado, biblioteca);
+    }
+}
+
+fn desenhar_livros(frame: &mut Frame, area: Rect, estado: &EstadoTui, biblioteca: &Biblioteca) {
+    let livros = estado.livros_visiveis(biblioteca);
+    let linhas: Vec<Row> = livros
+        .iter()
+        .map(|l| {
+            let cor = match l.status {
+                StatusLivro::Disponivel => Color::Green,
+                StatusLivro::Emprestado => Color::Yellow,
+                StatusLivro::EmTransito => Color::Blue,
+            };
+            Row::new(vec![
+                l.titulo.clone(),
+                l.autor.clone(),
+                l.ano.to_string(),
+                format!("{:?}", l.status),
+            ])
+            .style(Style::default().fg(cor))
+        })
+        .collect();
+
+    let tabela = Table::new(
+        linhas,
+        [
+            Constraint::Percentage(40),
+            Constraint::Percentage(35),
+            Constraint::Length(6),
+            Constraint::Length(12),
+        ],
+    )
+    .header(cabecalho(&["Título", "Autor", "Ano", "Status"]));
+
+    desenhar_tabela(
+        frame,
+        area,
+        tabela,
+        estado.selecionado(livros.len()),
+        livros.len(),
+    );
+}
+
+fn desenhar_usuarios(frame: &mut Frame, area: Rect, estado: &EstadoTui, biblioteca: &Biblioteca) {
+    let usuarios = estado.usuarios_visiveis(biblioteca);
+    let linhas: Vec<Row> = usuarios
+        .iter()
+        .map(|u| {
+            let ativos = biblioteca.listar_emprestimos_usuario(u.id).len();
+            Row::new(vec![u.nome.clone(), ativos.to_string(), u.id.to_string()])
+        })
+        .collect();
+
+    let tabela = Table::new(
+        linhas,
+        [
+            Constraint::Percentage(40),
+            Constraint::Length(18),
+            Constraint::Min(36),
+        ],
+    )
+    .header(cabecalho(&["Nome", "Empréstimos ativos", "ID"]));
+
+    desenhar_tabela(
+        frame,
+        area,
+        tabela,
+        estado.selecionado(usuarios.len()),
+        usuarios.len(),
+    );
+}
+
+fn desenhar_emprestimos(
+    frame: &mut Frame,
+    area: Rect,
+    estado: &EstadoTui,
+    biblioteca: &Biblioteca,
+) {
+    let hoje = Utc::now().date_naive();
+    let emprestimos = estado.emprestimos_visiveis(biblioteca);
+    let linhas: Vec<Row> = emprestimos
+        .iter()
+        .map(|e| {
+            let titulo = biblioteca
+                .buscar_livro_por_id(e.id_livro)
+                .map(|l| l.titulo.clone())
+                .unwrap_or_else(|| e.id_livro.to_string());
+            let nome = biblioteca
+                .buscar_usuario_por_id(e.id_usuario)
+                .map(|u| u.nome.clone())
+                .unwrap_or_else(|| e.id_usuario.to_string());
+            // Empréstimos vencidos aparecem em vermelho
+            let cor = if e.data_devolucao_prevista < hoje {
+                Color::Red
+            } else {
+                Color::Yellow
+            };
+            Row::new(vec![
+                titulo,
+                nome,
+                e.data_emprestimo.to_string(),
+                e.data_devolucao_prevista.to_string(),
+            ])
+            .style(Style::default().fg(cor))
+        })
+        .collect();
+
+    let tabela = Table::new(
+        linhas,
+        [
+            Constraint::Percentage(35),
+            Constraint::Percentage(30),
+            Constraint::Length(12),
+            Constraint::Length(12),
+        ],
+    )
+    .header(cabecalho(&["Livro", "Usuário", "Empréstimo", "Devolução"]));
+
+    desenhar_tabela(
+        frame,
+        area,
+        tabela,
+        estado.selecionado(emprestimos.len()),
+        emprestimos.len(),
+    );
+}
+
+fn desenhar_selecao_usuario(frame: &mut Frame, estado: &EstadoTui, biblioteca: &Biblioteca) {
+    let [area] = Layout::horizontal([Constraint::Percentage(60)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Percentage(60)])
+        .flex(Flex::Center)
+        .areas(area);
+
+    let usuarios = estado.usuarios_para_emprestimo(biblioteca);
+    let linhas: Vec<Row> = usuarios
+        .iter()
+        .map(|u| Row::new(vec![u.nome.clone()]))
+        .collect();
+
+    let tabela = Table::new(linhas, [Constraint::Percentage(100)])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Emprestar para: {} ", estado.busca_usuario)),
+        )
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut estado_tabela = TableState::default();
+    if !usuarios.is_empty() {
+        estado_tabela.select(Some(estado.selecao_usuario.min(usuarios.len() - 1)));
+    }
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(tabela, area, &mut estado_tabela);
+}
+
+fn desenhar_tabela(
+    frame: &mut Frame,
+    area: Rect,
+    tabela: Table,
+    selecionado: Option<usize>,
+    total: usize,
+) {
+    let tabela = tabela
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} registro(s) ", total)),
+        )
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+
+    let mut estado_tabela = TableState::default();
+    estado_tabela.select(selecionado);
+    frame.render_stateful_widget(tabela, area, &mut estado_tabela);
+}
+
+fn cabecalho<'a>(colunas: &[&'a str]) -> Row<'a> {
+    Row::new(colunas.to_vec()).style(Style::default().add_modifier(Modifier::BOLD))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use tempfile::tempdir;
+
+    fn conteudo(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|linha| linha.iter().map(|c| c.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_desenhar_tabela_de_livros() {
+        let dir = tempdir().unwrap();
+        let mut biblioteca = Biblioteca::new(dir.path().join("test.json"));
+        biblioteca
+            .adicionar_livro("1984".to_string(), "George Orwell".to_string(), 1949)
+            .unwrap();
+        let estado = EstadoTui::new();
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|frame| desenhar(frame, &estado, &biblioteca))
+            .unwrap();
+
+        let tela = conteudo(&terminal);
+        assert!(tela.contains("1984"));
+        assert!(tela.contains("George Orwell"));
+        assert!(tela.contains("1 registro(s)"));
+    }
+}
@@ -0,0 +1,33 @@
+pub mod estado;
+pub mod interface;
+
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+use std::io;
+
+use crate::biblioteca::Biblioteca;
+use estado::EstadoTui;
+
+// Inicializa o terminal em tela cheia e executa o laço de eventos até o usuário sair
+pub fn executar(biblioteca: &mut Biblioteca) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let resultado = laco_eventos(&mut terminal, biblioteca);
+    ratatui::restore();
+    resultado
+}
+
+fn laco_eventos(terminal: &mut DefaultTerminal, biblioteca: &mut Biblioteca) -> io::Result<()> {
+    let mut estado = EstadoTui::new();
+
+    while !estado.sair {
+        terminal.draw(|frame| interface::desenhar(frame, &estado, biblioteca))?;
+
+        if let Event::Key(tecla) = event::read()? {
+            if tecla.kind == KeyEventKind::Press {
+                estado.tratar_tecla(biblioteca, tecla);
+            }
+        }
+    }
+
+    Ok(())
+}
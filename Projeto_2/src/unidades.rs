@@ -0,0 +1,42 @@
+use crate::traits::Identificavel;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Unidade (sala de leitura) onde os livros ficam e onde ocorrem empréstimos e devoluções
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Unidade {
+    pub id: Uuid,
+    pub nome: String,
+}
+
+impl Unidade {
+    pub fn new(nome: String) -> Self {
+        Unidade {
+            id: Uuid::new_v4(),
+            nome,
+        }
+    }
+}
+
+impl Identificavel for Unidade {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_criar_unidade() {
+        let unidade = Unidade::new("Sala Central".to_string());
+        assert_eq!(unidade.nome, "Sala Central");
+    }
+
+    #[test]
+    fn test_identificavel_trait() {
+        let unidade = Unidade::new("Sala Central".to_string());
+        assert_eq!(unidade.id(), unidade.id);
+    }
+}
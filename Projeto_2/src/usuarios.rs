@@ -2,10 +2,13 @@ use crate::traits::Identificavel;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Usuario {
     pub id: Uuid,
     pub nome: String,
+    // Livros que o usuário deseja ler, em ordem de interesse
+    #[serde(default)]
+    pub lista_leitura: Vec<Uuid>,
 }
 
 impl Usuario {
@@ -13,8 +16,25 @@ impl Usuario {
         Usuario {
             id: Uuid::new_v4(),
             nome,
+            lista_leitura: Vec::new(),
         }
     }
+
+    // Retorna false se o livro já estava na lista
+    pub fn adicionar_a_lista(&mut self, id_livro: Uuid) -> bool {
+        if self.lista_leitura.contains(&id_livro) {
+            return false;
+        }
+        self.lista_leitura.push(id_livro);
+        true
+    }
+
+    // Retorna false se o livro não estava na lista
+    pub fn remover_da_lista(&mut self, id_livro: Uuid) -> bool {
+        let tamanho = self.lista_leitura.len();
+        self.lista_leitura.retain(|id| *id != id_livro);
+        self.lista_leitura.len() != tamanho
+    }
 }
 
 impl Identificavel for Usuario {
@@ -33,6 +53,18 @@ mod tests {
         assert_eq!(usuario.nome, "João Silva");
     }
 
+    #[test]
+    fn test_lista_leitura() {
+        let mut usuario = Usuario::new("João Silva".to_string());
+        let id_livro = Uuid::new_v4();
+
+        assert!(usuario.adicionar_a_lista(id_livro));
+        assert!(!usuario.adicionar_a_lista(id_livro));
+        assert_eq!(usuario.lista_leitura, vec![id_livro]);
+        assert!(usuario.remover_da_lista(id_livro));
+        assert!(!usuario.remover_da_lista(id_livro));
+    }
+
     #[test]
     fn test_identificavel_trait() {
         let usuario = Usuario::new("João Silva".to_string());
@@ -0,0 +1,305 @@
+use std::fmt;
+
+use crate::errors::ErroRpg;
+use crate::habilidades::{Alcance, AlvoHabilidade, Habilidade};
+use crate::itens::{Categoria, Item, Protecao, TipoArma};
+use crate::personagem::Atributos;
+use crate::status::TipoStatus;
+
+/// Comportamento de uma classe de personagem
+///
+/// As classes não guardam estado: o personagem aponta para uma instância
+/// estática (ex: `&Guerreiro`), e o que muda entre personagens da mesma
+/// classe fica no próprio `Personagem`.
+pub trait Classe: fmt::Debug + Sync {
+    fn nome(&self) -> &'static str;
+
+    /// Atributos no nível 1
+    fn atributos_base(&self) -> Atributos;
+
+    /// Atributos ganhos a cada nível acima do 1
+    fn crescimento(&self) -> Atributos;
+
+    /// Chance de um golpe ser crítico (0 a 1)
+    fn chance_critico(&self) -> f64 {
+        0.05
+    }
+
+    /// Fração da defesa do alvo ignorada pelos golpes (0 a 1)
+    fn penetracao(&self) -> f64 {
+        0.0
+    }
+
+    /// Descrição do golpe no registro da batalha (ex: "golpeia")
+    fn verbo_ataque(&self) -> &'static str;
+
+    /// Tipos de arma que a classe sabe usar
+    fn armas_permitidas(&self) -> &'static [TipoArma];
+
+    /// Armadura mais pesada que a classe consegue vestir
+    fn protecao_maxima(&self) -> Protecao;
+
+    /// Peso máximo que o personagem carrega no inventário
+    fn carga_maxima(&self) -> u32;
+
+    /// Habilidades da classe, da mais prioritária para a menos
+    fn habilidades(&self) -> Vec<Habilidade>;
+
+    fn atributos_no_nivel(&self, nivel: u32) -> Atributos {
+        self.atributos_base()
+            .somar(self.crescimento(), nivel.saturating_sub(1))
+    }
+
+    /// A classe pode equipar o item (consumíveis nunca são equipáveis)
+    fn pode_equipar(&self, item: &Item) -> bool {
+        match item.categoria {
+            Categoria::Arma { tipo_arma, .. } => self.armas_permitidas().contains(&tipo_arma),
+            Categoria::Armadura { protecao, .. } => protecao <= self.protecao_maxima(),
+            Categoria::Consumivel { .. } => false,
+        }
+    }
+}
+
+/// Combatente corpo a corpo: muita vida e defesa, pouca velocidade
+#[derive(Debug, Clone, Copy)]
+pub struct Guerreiro;
+
+/// Conjurador: ataque alto que atravessa metade da defesa, mas pouca vida
+#[derive(Debug, Clone, Copy)]
+pub struct Mago;
+
+/// Atirador: o mais rápido, com chance alta de acerto crítico
+#[derive(Debug, Clone, Copy)]
+pub struct Arqueiro;
+
+impl Classe for Guerreiro {
+    fn nome(&self) -> &'static str {
+        "Guerreiro"
+    }
+
+    fn atributos_base(&self) -> Atributos {
+        Atributos {
+            vida: 120,
+            mana: 30,
+            ataque: 14,
+            defesa: 12,
+            velocidade: 8,
+        }
+    }
+
+    fn crescimento(&self) -> Atributos {
+        Atributos {
+            vida: 12,
+            mana: 3,
+            ataque: 3,
+            defesa: 3,
+            velocidade: 1,
+        }
+    }
+
+    fn verbo_ataque(&self) -> &'static str {
+        "golpeia"
+    }
+
+    fn armas_permitidas(&self) -> &'static [TipoArma] {
+        &[TipoArma::Espada, TipoArma::Machado, TipoArma::Adaga]
+    }
+
+    fn protecao_maxima(&self) -> Protecao {
+        Protecao::Pesada
+    }
+
+    fn carga_maxima(&self) -> u32 {
+        60
+    }
+
+    fn habilidades(&self) -> Vec<Habilidade> {
+        vec![
+            Habilidade::new("grito_de_guerra", "Grito de guerra", 12, 4)
+                .com_alvo(AlvoHabilidade::Proprio, Alcance::Unico)
+                .com_status(TipoStatus::Fortalecimento, 30, 3),
+            Habilidade::new("muralha", "Muralha", 8, 3)
+                .com_alvo(AlvoHabilidade::Proprio, Alcance::Unico)
+                .com_status(TipoStatus::Escudo, 25, 3),
+            Habilidade::new("golpe_poderoso", "Golpe poderoso", 10, 2).com_dano(1.6),
+        ]
+    }
+}
+
+impl Classe for Mago {
+    fn nome(&self) -> &'static str {
+        "Mago"
+    }
+
+    fn atributos_base(&self) -> Atributos {
+        Atributos {
+            vida: 80,
+            mana: 80,
+            ataque: 18,
+            defesa: 5,
+            velocidade: 10,
+        }
+    }
+
+    fn crescimento(&self) -> Atributos {
+        Atributos {
+            vida: 7,
+            mana: 8,
+            ataque: 4,
+            defesa: 1,
+            velocidade: 1,
+        }
+    }
+
+    fn penetracao(&self) -> f64 {
+        0.5
+    }
+
+    fn verbo_ataque(&self) -> &'static str {
+        "lança um feitiço em"
+    }
+
+    fn armas_permitidas(&self) -> &'static [TipoArma] {
+        &[TipoArma::Cajado, TipoArma::Adaga]
+    }
+
+    fn protecao_maxima(&self) -> Protecao {
+        Protecao::Leve
+    }
+
+    fn carga_maxima(&self) -> u32 {
+        35
+    }
+
+    fn habilidades(&self) -> Vec<Habilidade> {
+        vec![
+            Habilidade::new("cura", "Cura", 15, 2)
+                .com_alvo(AlvoHabilidade::Aliado, Alcance::Unico)
+                .com_cura(35),
+            Habilidade::new("raio_congelante", "Raio congelante", 15, 4)
+                .com_dano(0.8)
+                .com_status(TipoStatus::Atordoamento, 0, 1),
+            Habilidade::new("bola_de_fogo", "Bola de fogo", 20, 2)
+                .com_alvo(AlvoHabilidade::Inimigo, Alcance::Area)
+                .com_dano(1.2),
+        ]
+    }
+}
+
+impl Classe for Arqueiro {
+    fn nome(&self) -> &'static str {
+        "Arqueiro"
+    }
+
+    fn atributos_base(&self) -> Atributos {
+        Atributos {
+            vida: 95,
+            mana: 45,
+            ataque: 15,
+            defesa: 7,
+            velocidade: 14,
+        }
+    }
+
+    fn crescimento(&self) -> Atributos {
+        Atributos {
+            vida: 9,
+            mana: 4,
+            ataque: 3,
+            defesa: 2,
+            velocidade: 2,
+        }
+    }
+
+    fn chance_critico(&self) -> f64 {
+        0.2
+    }
+
+    fn verbo_ataque(&self) -> &'static str {
+        "atira uma flecha em"
+    }
+
+    fn armas_permitidas(&self) -> &'static [TipoArma] {
+        &[TipoArma::Arco, TipoArma::Adaga]
+    }
+
+    fn protecao_maxima(&self) -> Protecao {
+        Protecao::Media
+    }
+
+    fn carga_maxima(&self) -> u32 {
+        45
+    }
+
+    fn habilidades(&self) -> Vec<Habilidade> {
+        vec![
+            Habilidade::new("flecha_envenenada", "Flecha envenenada", 10, 2)
+                .com_dano(0.7)
+                .com_status(TipoStatus::Veneno, 6, 3),
+            Habilidade::new("chuva_de_flechas", "Chuva de flechas", 18, 3)
+                .com_alvo(AlvoHabilidade::Inimigo, Alcance::Area)
+                .com_dano(0.9),
+        ]
+    }
+}
+
+/// Todas as classes disponíveis, na ordem de exibição
+pub const CLASSES: [&dyn Classe; 3] = [&Guerreiro, &Mago, &Arqueiro];
+
+/// Procura uma classe pelo nome, sem diferenciar maiúsculas de minúsculas
+pub fn classe_por_nome(nome: &str) -> Result<&'static dyn Classe, ErroRpg> {
+    CLASSES
+        .into_iter()
+        .find(|classe| classe.nome().eq_ignore_ascii_case(nome.trim()))
+        .ok_or_else(|| ErroRpg::ClasseDesconhecida(nome.trim().to_string()))
+}
+
+/// Grava a classe pelo nome, para `#[serde(with = "classes::pelo_nome")]`
+pub mod pelo_nome {
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    use super::{Classe, classe_por_nome};
+
+    pub fn serialize<S: Serializer>(
+        classe: &&'static dyn Classe,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(classe.nome())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<&'static dyn Classe, D::Error> {
+        let nome = String::deserialize(deserializer)?;
+        classe_por_nome(&nome).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atributos_crescem_com_o_nivel() {
+        assert_eq!(Guerreiro.atributos_no_nivel(1), Guerreiro.atributos_base());
+        assert_eq!(
+            Mago.atributos_no_nivel(5),
+            Atributos {
+                vida: 108,
+                mana: 112,
+                ataque: 34,
+                defesa: 9,
+                velocidade: 14,
+            }
+        );
+    }
+
+    #[test]
+    fn test_classe_por_nome() {
+        assert_eq!(classe_por_nome(" arqueiro").unwrap().nome(), "Arqueiro");
+        assert_eq!(
+            classe_por_nome("Ladino").unwrap_err(),
+            ErroRpg::ClasseDesconhecida("Ladino".to_string())
+        );
+    }
+}
@@ -0,0 +1,911 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+use std::collections::BTreeMap;
+
+use crate::eventos::{EventoCombate, OrigemDano};
+use crate::habilidades::{Alcance, AlvoHabilidade, Habilidade};
+use crate::ia::{Estrategia, MenorVida};
+use crate::itens::Efeito;
+use crate::personagem::Personagem;
+use crate::status::{Efeitos, TipoStatus};
+
+/// Número de rodadas depois do qual a batalha termina empatada
+pub const MAXIMO_RODADAS: u32 = 100;
+
+/// Multiplicador de dano de um golpe crítico
+pub const MULTIPLICADOR_CRITICO: f64 = 1.5;
+
+/// Chance de esquiva ganha por ponto de velocidade acima do atacante
+const ESQUIVA_POR_VELOCIDADE: f64 = 0.02;
+
+/// Maior chance de esquiva possível
+const ESQUIVA_MAXIMA: f64 = 0.3;
+
+/// Porcentagem da vida máxima a partir da qual o personagem usa cura
+pub const LIMITE_CURA: u32 = 35;
+
+/// Mana recuperada no início de cada turno
+pub const REGENERACAO_MANA: u32 = 4;
+
+/// Gerador de números aleatórios das batalhas
+///
+/// A mesma semente sempre produz a mesma sequência, então uma batalha
+/// pode ser reproduzida por completo a partir da semente.
+pub type Gerador = Pcg64;
+
+/// Cria o gerador de uma batalha a partir da semente
+pub fn gerador(semente: u64) -> Gerador {
+    Pcg64::seed_from_u64(semente)
+}
+
+/// Resultado de um golpe
+///
+/// # Campos
+/// - `dano`: Dano calculado (zero quando o alvo esquiva)
+/// - `critico`: O golpe foi crítico
+/// - `esquivou`: O alvo escapou do golpe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Golpe {
+    pub dano: u32,
+    pub critico: bool,
+    pub esquivou: bool,
+}
+
+/// Calcula o golpe de um personagem em outro
+///
+/// O alvo pode esquivar se for mais rápido. Se não esquivar, o dano é o
+/// ataque multiplicado por `poder` (1 no ataque básico), com variação de
+/// ±15%, menos metade da defesa (descontada a penetração da classe do
+/// atacante), nunca abaixo de 1.
+pub fn calcular_golpe<R: Rng>(
+    atacante: &Personagem,
+    alvo: &Personagem,
+    poder: f64,
+    rng: &mut R,
+) -> Golpe {
+    let (atributos_atacante, atributos_alvo) =
+        (atacante.atributos_efetivos(), alvo.atributos_efetivos());
+    let vantagem = atributos_alvo
+        .velocidade
+        .saturating_sub(atributos_atacante.velocidade);
+    let chance_esquiva = (vantagem as f64 * ESQUIVA_POR_VELOCIDADE).min(ESQUIVA_MAXIMA);
+    if rng.gen_bool(chance_esquiva) {
+        return Golpe {
+            dano: 0,
+            critico: false,
+            esquivou: true,
+        };
+    }
+
+    let defesa = atributos_alvo.defesa as f64 * (1.0 - atacante.classe.penetracao());
+    let variacao = rng.gen_range(0.85..=1.15);
+    let critico = rng.gen_bool(atacante.classe.chance_critico());
+
+    let mut dano = (atributos_atacante.ataque as f64 * poder * variacao - defesa / 2.0).max(1.0);
+    if critico {
+        dano *= MULTIPLICADOR_CRITICO;
+    }
+    Golpe {
+        dano: dano.round() as u32,
+        critico,
+        esquivou: false,
+    }
+}
+
+/// Personagem em batalha, com o estado que só existe durante a luta
+///
+/// # Campos
+/// - `personagem`: O personagem, com vida, mana e inventário atualizados
+/// - `lado`: Lado do personagem (0 ou 1)
+/// - `efeitos`: Efeitos de status ativos
+/// - `recargas`: Turnos que faltam para cada habilidade (pelo id) voltar
+/// - `estrategia`: Como o personagem escolhe os inimigos que ataca
+#[derive(Debug, Clone)]
+pub struct Combatente {
+    pub personagem: Personagem,
+    pub lado: usize,
+    pub efeitos: Efeitos,
+    pub recargas: BTreeMap<String, u32>,
+    pub estrategia: &'static dyn Estrategia,
+}
+
+impl Combatente {
+    /// Combatente sem efeitos e com todas as habilidades disponíveis
+    pub fn new(personagem: Personagem, lado: usize, estrategia: &'static dyn Estrategia) -> Self {
+        Combatente {
+            personagem,
+            lado,
+            efeitos: Efeitos::default(),
+            recargas: BTreeMap::new(),
+            estrategia,
+        }
+    }
+
+    /// A classe tem alguma habilidade que cura aliados
+    pub fn cura_aliados(&self) -> bool {
+        self.personagem
+            .habilidades()
+            .iter()
+            .any(|habilidade| habilidade.cura > 0 && habilidade.alvo != AlvoHabilidade::Inimigo)
+    }
+
+    /// Há mana para a habilidade e ela não está em recarga
+    pub fn pode_usar(&self, habilidade: &Habilidade) -> bool {
+        self.personagem.mana >= habilidade.custo_mana
+            && self.recargas.get(&habilidade.id).copied().unwrap_or(0) == 0
+    }
+
+    /// Vida em `LIMITE_CURA`% da máxima ou menos
+    fn precisa_de_cura(&self) -> bool {
+        self.personagem.vida * 100 <= self.personagem.vida_maxima() * LIMITE_CURA
+    }
+}
+
+/// O que um combatente decidiu fazer no seu turno (posições em `combatentes`)
+#[derive(Debug, Clone, PartialEq)]
+enum Decisao {
+    Atacar(usize),
+    UsarItem { id: String, alvo: usize },
+    UsarHabilidade(Habilidade, Vec<usize>),
+}
+
+/// Batalha por turnos entre dois lados, cada um com um ou mais personagens
+///
+/// A cada rodada os personagens vivos agem do mais rápido para o mais lento;
+/// empates de velocidade são decididos pelo gerador da batalha. Todos são
+/// controlados pelo computador: a estratégia de cada lado escolhe os
+/// inimigos atacados (`MenorVida`, se nenhuma for definida). Tudo o que
+/// acontece vira um `EventoCombate`, e a batalha também é um iterador que
+/// joga as rodadas conforme os eventos são consumidos.
+///
+/// Os nomes identificam autor e alvo nos eventos, então os personagens
+/// devem ter nomes diferentes.
+#[derive(Debug, Clone)]
+pub struct Batalha {
+    combatentes: Vec<Combatente>,
+    rng: Gerador,
+    rodada: u32,
+    eventos: Vec<EventoCombate>,
+    /// Eventos já entregues pelo iterador
+    entregues: usize,
+    encerrada: bool,
+}
+
+/// Resultado de uma batalha encerrada
+///
+/// # Campos
+/// - `lados`: Os personagens de cada lado como terminaram a batalha
+/// - `vencedor`: Lado vencedor (0 ou 1), ou `None` em caso de empate
+/// - `rodadas`: Número de rodadas jogadas
+/// - `eventos`: Tudo o que aconteceu, em ordem
+#[derive(Debug, Clone)]
+pub struct ResultadoBatalha {
+    pub lados: [Vec<Personagem>; 2],
+    pub vencedor: Option<usize>,
+    pub rodadas: u32,
+    pub eventos: Vec<EventoCombate>,
+}
+
+impl ResultadoBatalha {
+    /// Personagens do lado vencedor (vazio em caso de empate)
+    pub fn vencedores(&self) -> &[Personagem] {
+        self.vencedor.map_or(&[], |lado| &self.lados[lado])
+    }
+}
+
+impl Batalha {
+    /// Prepara uma batalha de um contra um
+    ///
+    /// # Parâmetros
+    /// - `semente`: Semente do gerador; a mesma semente com os mesmos
+    ///   personagens sempre produz a mesma batalha
+    pub fn new(primeiro: Personagem, segundo: Personagem, semente: u64) -> Self {
+        Batalha::entre_lados(vec![primeiro], vec![segundo], semente)
+    }
+
+    /// Prepara uma batalha entre dois grupos de personagens
+    pub fn entre_lados(lado_a: Vec<Personagem>, lado_b: Vec<Personagem>, semente: u64) -> Self {
+        let combatentes = [lado_a, lado_b]
+            .into_iter()
+            .enumerate()
+            .flat_map(|(lado, personagens)| {
+                personagens
+                    .into_iter()
+                    .map(move |personagem| Combatente::new(personagem, lado, &MenorVida))
+            })
+            .collect();
+        Batalha {
+            combatentes,
+            rng: gerador(semente),
+            rodada: 0,
+            eventos: Vec::new(),
+            entregues: 0,
+            encerrada: false,
+        }
+    }
+
+    /// Define a estratégia de todos os personagens de um lado (0 ou 1)
+    pub fn com_estrategia(mut self, lado: usize, estrategia: &'static dyn Estrategia) -> Self {
+        for combatente in &mut self.combatentes {
+            if combatente.lado == lado {
+                combatente.estrategia = estrategia;
+            }
+        }
+        self
+    }
+
+    pub fn combatentes(&self) -> &[Combatente] {
+        &self.combatentes
+    }
+
+    /// Eventos de todas as rodadas jogadas até agora
+    pub fn eventos(&self) -> &[EventoCombate] {
+        &self.eventos
+    }
+
+    pub fn encerrada(&self) -> bool {
+        self.encerrada
+    }
+
+    fn vivo(&self, posicao: usize) -> bool {
+        self.combatentes[posicao].personagem.esta_vivo()
+    }
+
+    fn nome(&self, posicao: usize) -> String {
+        self.combatentes[posicao].personagem.nome.clone()
+    }
+
+    /// Posições dos personagens vivos de um lado
+    fn vivos_do_lado(&self, lado: usize) -> Vec<usize> {
+        (0..self.combatentes.len())
+            .filter(|&posicao| self.combatentes[posicao].lado == lado && self.vivo(posicao))
+            .collect()
+    }
+
+    /// O único lado que ainda tem personagens vivos, se houver
+    fn lado_vencedor(&self) -> Option<usize> {
+        match (
+            self.vivos_do_lado(0).is_empty(),
+            self.vivos_do_lado(1).is_empty(),
+        ) {
+            (false, true) => Some(0),
+            (true, false) => Some(1),
+            _ => None,
+        }
+    }
+
+    fn terminou(&self) -> bool {
+        self.vivos_do_lado(0).is_empty()
+            || self.vivos_do_lado(1).is_empty()
+            || self.rodada >= MAXIMO_RODADAS
+    }
+
+    /// Ordem de ação da rodada: mais rápido primeiro
+    fn ordem_da_rodada(&mut self) -> Vec<usize> {
+        let mut ordem: Vec<usize> = (0..self.combatentes.len())
+            .filter(|&posicao| self.vivo(posicao))
+            .collect();
+        // Embaralha antes de ordenar (ordenação estável) para sortear os empates
+        ordem.shuffle(&mut self.rng);
+        ordem.sort_by_key(|&posicao| {
+            std::cmp::Reverse(
+                self.combatentes[posicao]
+                    .personagem
+                    .atributos_efetivos()
+                    .velocidade,
+            )
+        });
+        ordem
+    }
+
+    /// Joga uma rodada, se a batalha ainda não terminou
+    ///
+    /// # Retorno
+    /// Eventos da rodada (com o `Fim`, se ela encerrou a batalha)
+    pub fn jogar_rodada(&mut self) -> &[EventoCombate] {
+        let inicio = self.eventos.len();
+        if self.encerrada {
+            return &self.eventos[inicio..];
+        }
+
+        if !self.terminou() {
+            self.rodada += 1;
+            self.eventos.push(EventoCombate::InicioRodada {
+                rodada: self.rodada,
+            });
+            for posicao in self.ordem_da_rodada() {
+                if self.lado_vencedor().is_some() {
+                    break;
+                }
+                // Quem caiu antes da sua vez não age
+                if self.vivo(posicao) {
+                    self.jogar_turno(posicao);
+                }
+            }
+        }
+
+        if self.terminou() {
+            self.encerrada = true;
+            self.eventos.push(EventoCombate::Fim {
+                vencedor: self.lado_vencedor(),
+                rodadas: self.rodada,
+            });
+        }
+        &self.eventos[inicio..]
+    }
+
+    /// Turno de um personagem: recargas, mana, veneno, a ação e a passagem
+    /// dos efeitos
+    fn jogar_turno(&mut self, posicao: usize) {
+        let combatente = &mut self.combatentes[posicao];
+        for recarga in combatente.recargas.values_mut() {
+            *recarga = recarga.saturating_sub(1);
+        }
+        combatente.personagem.recuperar_mana(REGENERACAO_MANA);
+
+        let veneno = combatente.efeitos.intensidade(TipoStatus::Veneno);
+        if veneno > 0 {
+            self.causar_dano(posicao, veneno, false, OrigemDano::Veneno);
+            if !self.vivo(posicao) {
+                return;
+            }
+        }
+
+        if self.combatentes[posicao]
+            .efeitos
+            .tem(TipoStatus::Atordoamento)
+        {
+            self.eventos.push(EventoCombate::TurnoPerdido {
+                personagem: self.nome(posicao),
+            });
+        } else {
+            let decisao = self.decidir(posicao);
+            self.agir(posicao, decisao);
+        }
+
+        if self.vivo(posicao) {
+            for tipo in self.combatentes[posicao].efeitos.passar_turno() {
+                self.eventos.push(EventoCombate::StatusEncerrado {
+                    alvo: self.nome(posicao),
+                    tipo,
+                });
+            }
+        }
+    }
+
+    /// Escolhe a ação do turno
+    ///
+    /// Primeiro os consumíveis: cura quando a vida está em `LIMITE_CURA`% ou
+    /// menos, item de dano quando ele basta para derrubar um inimigo. Depois
+    /// a primeira habilidade disponível que tenha efeito útil e, fora isso,
+    /// o ataque básico. Os inimigos atacados saem da estratégia do autor.
+    fn decidir(&mut self, posicao: usize) -> Decisao {
+        let autor = &self.combatentes[posicao];
+        let inventario = &autor.personagem.inventario;
+        let inimigos = self.vivos_do_lado(1 - autor.lado);
+
+        if autor.precisa_de_cura()
+            && let Some(cura) = inventario.consumivel(Efeito::Cura)
+        {
+            return Decisao::UsarItem {
+                id: cura.id.clone(),
+                alvo: posicao,
+            };
+        }
+        let item_de_dano = inventario.pilhas().iter().find_map(|pilha| {
+            let Some((Efeito::Dano, pontos)) = pilha.item.efeito() else {
+                return None;
+            };
+            let derrubados: Vec<usize> = inimigos
+                .iter()
+                .copied()
+                .filter(|&inimigo| self.combatentes[inimigo].personagem.vida <= pontos)
+                .collect();
+            (!derrubados.is_empty()).then(|| (pilha.item.id.clone(), derrubados))
+        });
+        if let Some((id, derrubados)) = item_de_dano {
+            let alvo = self.escolher_alvo(posicao, derrubados);
+            return Decisao::UsarItem { id, alvo };
+        }
+
+        for habilidade in autor.personagem.habilidades() {
+            if !self.combatentes[posicao].pode_usar(&habilidade) {
+                continue;
+            }
+            let Some(mut alvos) = self.alvos_da_habilidade(posicao, &habilidade) else {
+                continue;
+            };
+            if habilidade.alvo == AlvoHabilidade::Inimigo && habilidade.alcance == Alcance::Unico {
+                alvos = vec![self.escolher_alvo(posicao, alvos)];
+            }
+            return Decisao::UsarHabilidade(habilidade, alvos);
+        }
+
+        let alvo = self.escolher_alvo(posicao, inimigos);
+        Decisao::Atacar(alvo)
+    }
+
+    /// Aplica a estratégia do autor a uma lista de inimigos vivos
+    ///
+    /// # Retorno
+    /// Posição do inimigo escolhido em `combatentes`
+    fn escolher_alvo(&mut self, autor: usize, candidatos: Vec<usize>) -> usize {
+        let lista: Vec<&Combatente> = candidatos
+            .iter()
+            .map(|&candidato| &self.combatentes[candidato])
+            .collect();
+        let escolhido = self.combatentes[autor]
+            .estrategia
+            .escolher_alvo(&lista, &mut self.rng);
+        candidatos[escolhido]
+    }
+
+    /// Alvos em que a habilidade teria efeito
+    ///
+    /// Habilidades de dano valem em qualquer inimigo; cura só para quem
+    /// precisa e efeito de status só para quem ainda não está sob ele. Numa
+    /// habilidade de alvo único contra inimigos vêm todos os úteis, para a
+    /// estratégia do autor escolher.
+    ///
+    /// # Retorno
+    /// `None` se a habilidade seria desperdiçada
+    fn alvos_da_habilidade(&self, posicao: usize, habilidade: &Habilidade) -> Option<Vec<usize>> {
+        let lado = self.combatentes[posicao].lado;
+        let candidatos = match habilidade.alvo {
+            AlvoHabilidade::Inimigo => self.vivos_do_lado(1 - lado),
+            AlvoHabilidade::Aliado => self.vivos_do_lado(lado),
+            AlvoHabilidade::Proprio => vec![posicao],
+        };
+        let mut uteis: Vec<usize> = candidatos
+            .into_iter()
+            .filter(|&candidato| {
+                let combatente = &self.combatentes[candidato];
+                habilidade.causa_dano()
+                    || (habilidade.cura > 0 && combatente.precisa_de_cura())
+                    || habilidade
+                        .status
+                        .is_some_and(|status| !combatente.efeitos.tem(status.tipo))
+            })
+            .collect();
+        if uteis.is_empty() {
+            return None;
+        }
+
+        // Entre aliados, o que está com a menor fração da vida máxima
+        if habilidade.alcance == Alcance::Unico && habilidade.alvo == AlvoHabilidade::Aliado {
+            uteis.sort_by_key(|&aliado| {
+                let personagem = &self.combatentes[aliado].personagem;
+                personagem.vida as u64 * 1000 / personagem.vida_maxima().max(1) as u64
+            });
+            uteis.truncate(1);
+        }
+        Some(uteis)
+    }
+
+    fn agir(&mut self, posicao: usize, decisao: Decisao) {
+        match decisao {
+            Decisao::Atacar(alvo) => {
+                self.eventos.push(EventoCombate::Ataque {
+                    autor: self.nome(posicao),
+                    verbo: self.combatentes[posicao].personagem.classe.verbo_ataque(),
+                    alvo: self.nome(alvo),
+                });
+                self.golpear(posicao, alvo, 1.0, OrigemDano::Ataque);
+            }
+            Decisao::UsarItem { id, alvo } => self.usar_item(posicao, &id, alvo),
+            Decisao::UsarHabilidade(habilidade, alvos) => {
+                self.usar_habilidade(posicao, &habilidade, &alvos)
+            }
+        }
+    }
+
+    /// Calcula e aplica um golpe, com o bônus de fortalecimento do autor
+    ///
+    /// # Retorno
+    /// `false` se o alvo esquivou
+    fn golpear(&mut self, autor: usize, alvo: usize, poder: f64, origem: OrigemDano) -> bool {
+        let fortalecimento = self.combatentes[autor]
+            .efeitos
+            .intensidade(TipoStatus::Fortalecimento);
+        let poder = poder * (100 + fortalecimento) as f64 / 100.0;
+        let golpe = calcular_golpe(
+            &self.combatentes[autor].personagem,
+            &self.combatentes[alvo].personagem,
+            poder,
+            &mut self.rng,
+        );
+
+        if golpe.esquivou {
+            self.eventos.push(EventoCombate::Esquiva {
+                alvo: self.nome(alvo),
+            });
+            return false;
+        }
+        self.causar_dano(alvo, golpe.dano, golpe.critico, origem);
+        true
+    }
+
+    /// Aplica dano, passando primeiro pelo escudo do alvo
+    fn causar_dano(&mut self, alvo: usize, dano: u32, critico: bool, origem: OrigemDano) {
+        let combatente = &mut self.combatentes[alvo];
+        let tinha_escudo = combatente.efeitos.tem(TipoStatus::Escudo);
+        let absorvido = combatente.efeitos.absorver(dano);
+        let pontos = combatente.personagem.receber_dano(dano - absorvido);
+        let quebrou_escudo = tinha_escudo && !combatente.efeitos.tem(TipoStatus::Escudo);
+
+        self.eventos.push(EventoCombate::Dano {
+            alvo: self.nome(alvo),
+            origem,
+            pontos,
+            critico,
+            absorvido,
+            vida_restante: self.combatentes[alvo].personagem.vida,
+        });
+        if quebrou_escudo {
+            self.eventos.push(EventoCombate::StatusEncerrado {
+                alvo: self.nome(alvo),
+                tipo: TipoStatus::Escudo,
+            });
+        }
+        if !self.vivo(alvo) {
+            self.combatentes[alvo].efeitos = Efeitos::default();
+            self.eventos.push(EventoCombate::Derrota {
+                personagem: self.nome(alvo),
+            });
+        }
+    }
+
+    fn curar(&mut self, alvo: usize, pontos: u32) {
+        let pontos = self.combatentes[alvo].personagem.curar(pontos);
+        self.eventos.push(EventoCombate::Cura {
+            alvo: self.nome(alvo),
+            pontos,
+            vida_restante: self.combatentes[alvo].personagem.vida,
+        });
+    }
+
+    fn usar_item(&mut self, autor: usize, id: &str, alvo: usize) {
+        let personagem = &mut self.combatentes[autor].personagem;
+        let nome_item = personagem
+            .inventario
+            .buscar(id)
+            .map(|item| item.nome.clone())
+            .expect("consumível escolhido do próprio inventário");
+        let (efeito, pontos) = personagem
+            .gastar_consumivel(id)
+            .expect("consumível escolhido do próprio inventário");
+
+        self.eventos.push(EventoCombate::Item {
+            autor: self.nome(autor),
+            item: nome_item.clone(),
+        });
+        match efeito {
+            Efeito::Cura => self.curar(alvo, pontos),
+            Efeito::Dano => self.causar_dano(alvo, pontos, false, OrigemDano::Item(nome_item)),
+        }
+    }
+
+    fn usar_habilidade(&mut self, autor: usize, habilidade: &Habilidade, alvos: &[usize]) {
+        let combatente = &mut self.combatentes[autor];
+        combatente.personagem.gastar_mana(habilidade.custo_mana);
+        combatente
+            .recargas
+            .insert(habilidade.id.clone(), habilidade.recarga);
+        self.eventos.push(EventoCombate::Habilidade {
+            autor: self.nome(autor),
+            habilidade: habilidade.nome.clone(),
+            alvos: alvos.iter().map(|&alvo| self.nome(alvo)).collect(),
+            mana_gasta: habilidade.custo_mana,
+        });
+
+        for &alvo in alvos {
+            if !self.vivo(alvo) {
+                continue;
+            }
+            // Uma esquiva evita também a cura e o efeito da habilidade
+            if habilidade.causa_dano() {
+                let origem = OrigemDano::Habilidade(habilidade.nome.clone());
+                if !self.golpear(autor, alvo, habilidade.poder, origem) {
+                    continue;
+                }
+            }
+            if habilidade.cura > 0 {
+                self.curar(alvo, habilidade.cura);
+            }
+            if let Some(status) = habilidade.status
+                && self.vivo(alvo)
+            {
+                let status = self.combatentes[alvo].efeitos.aplicar(status);
+                self.eventos.push(EventoCombate::StatusAplicado {
+                    alvo: self.nome(alvo),
+                    status,
+                });
+            }
+        }
+    }
+
+    /// Joga rodadas até um dos lados cair ou acabar o limite de rodadas
+    pub fn executar(mut self) -> ResultadoBatalha {
+        while !self.encerrada {
+            self.jogar_rodada();
+        }
+
+        let vencedor = self.lado_vencedor();
+        let mut lados: [Vec<Personagem>; 2] = [Vec::new(), Vec::new()];
+        for combatente in self.combatentes {
+            lados[combatente.lado].push(combatente.personagem);
+        }
+        ResultadoBatalha {
+            lados,
+            vencedor,
+            rodadas: self.rodada,
+            eventos: self.eventos,
+        }
+    }
+}
+
+/// Entrega os eventos em ordem, jogando novas rodadas quando preciso
+impl Iterator for Batalha {
+    type Item = EventoCombate;
+
+    fn next(&mut self) -> Option<EventoCombate> {
+        while self.entregues == self.eventos.len() {
+            if self.encerrada {
+                return None;
+            }
+            self.jogar_rodada();
+        }
+        self.entregues += 1;
+        Some(self.eventos[self.entregues - 1].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::{Arqueiro, Classe, Guerreiro, Mago};
+    use crate::itens::Item;
+    use crate::status::Status;
+
+    fn guerreiro() -> Personagem {
+        Personagem::new("Conan", &Guerreiro, 3).unwrap()
+    }
+
+    fn mago() -> Personagem {
+        Personagem::new("Merlin", &Mago, 3).unwrap()
+    }
+
+    fn consumivel(id: &str, efeito: &str, pontos: u32) -> Item {
+        serde_json::from_str(&format!(
+            r#"{{"id": "{id}", "nome": "{id}", "peso": 1, "categoria": "consumivel",
+                "efeito": "{efeito}", "pontos": {pontos}}}"#
+        ))
+        .unwrap()
+    }
+
+    /// Quem agiu, se o evento for uma ação
+    fn autor(evento: &EventoCombate) -> Option<&str> {
+        match evento {
+            EventoCombate::Ataque { autor, .. }
+            | EventoCombate::Habilidade { autor, .. }
+            | EventoCombate::Item { autor, .. } => Some(autor),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_mesma_semente_mesma_batalha() {
+        let primeira = Batalha::new(guerreiro(), mago(), 42).executar();
+        let segunda = Batalha::new(guerreiro(), mago(), 42).executar();
+
+        assert_eq!(primeira.eventos, segunda.eventos);
+        assert_eq!(primeira.vencedor, segunda.vencedor);
+
+        // Outras sementes mudam o rumo da batalha
+        assert!((0..5).any(|semente| {
+            Batalha::new(guerreiro(), mago(), semente)
+                .executar()
+                .eventos
+                != primeira.eventos
+        }));
+    }
+
+    #[test]
+    fn test_iterador_entrega_os_mesmos_eventos() {
+        let eventos: Vec<EventoCombate> = Batalha::new(guerreiro(), mago(), 9).collect();
+
+        assert_eq!(
+            eventos,
+            Batalha::new(guerreiro(), mago(), 9).executar().eventos
+        );
+        assert_eq!(eventos[0], EventoCombate::InicioRodada { rodada: 1 });
+        assert!(matches!(eventos.last(), Some(EventoCombate::Fim { .. })));
+    }
+
+    #[test]
+    fn test_mais_rapido_age_primeiro() {
+        let arqueiro = Personagem::new("Legolas", &Arqueiro, 1).unwrap();
+        for semente in 0..20 {
+            let mut batalha = Batalha::new(guerreiro(), arqueiro.clone(), semente);
+            let primeira_acao = batalha.jogar_rodada().iter().find_map(autor);
+            assert_eq!(primeira_acao, Some("Legolas"));
+        }
+    }
+
+    #[test]
+    fn test_batalha_termina_com_vencedor_vivo() {
+        for semente in 0..20 {
+            let resultado = Batalha::new(guerreiro(), mago(), semente).executar();
+            let vencedor = &resultado.vencedores()[0];
+            let perdedor = &resultado.lados[1 - resultado.vencedor.unwrap()][0];
+
+            assert!(vencedor.esta_vivo());
+            assert!(!perdedor.esta_vivo());
+            assert!(resultado.eventos.contains(&EventoCombate::Derrota {
+                personagem: perdedor.nome.clone()
+            }));
+            assert!(resultado.rodadas <= MAXIMO_RODADAS);
+        }
+    }
+
+    #[test]
+    fn test_golpe_causa_pelo_menos_um_de_dano() {
+        let fraco = Personagem::new("Aprendiz", &Guerreiro, 1).unwrap();
+        let mut forte = Personagem::new("Muralha", &Guerreiro, 1).unwrap();
+        forte.atributos.defesa = 500;
+        let mut rng = gerador(7);
+
+        for _ in 0..50 {
+            let golpe = calcular_golpe(&fraco, &forte, 1.0, &mut rng);
+            assert!(golpe.esquivou || golpe.dano >= 1);
+            // 1 de dano mínimo, ou 2 quando o golpe é crítico
+            assert!(golpe.dano <= 2);
+        }
+    }
+
+    #[test]
+    fn test_decisao_com_consumiveis() {
+        let mut conan = guerreiro();
+        let pocao = consumivel("pocao", "cura", 40);
+        conan.inventario.adicionar(pocao, 1).unwrap();
+        conan
+            .inventario
+            .adicionar(consumivel("bomba", "dano", 30), 1)
+            .unwrap();
+        // Sem mana, nenhuma habilidade disponível
+        conan.mana = 0;
+        let mut batalha = Batalha::new(conan, mago(), 1);
+
+        assert_eq!(batalha.decidir(0), Decisao::Atacar(1));
+
+        batalha.combatentes[1].personagem.vida = 30;
+        assert_eq!(
+            batalha.decidir(0),
+            Decisao::UsarItem {
+                id: "bomba".to_string(),
+                alvo: 1
+            }
+        );
+
+        // Com a vida baixa, curar vem antes de tudo
+        let conan = &mut batalha.combatentes[0].personagem;
+        conan.vida = conan.vida_maxima() * LIMITE_CURA / 100;
+        assert_eq!(
+            batalha.decidir(0),
+            Decisao::UsarItem {
+                id: "pocao".to_string(),
+                alvo: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_consumiveis_usados_na_batalha() {
+        let mut conan = guerreiro();
+        conan
+            .inventario
+            .adicionar(consumivel("pocao", "cura", 40), 2)
+            .unwrap();
+        let merlin = Personagem::new("Merlin", &Mago, 6).unwrap();
+
+        let resultado = Batalha::new(conan, merlin, 3).executar();
+        let curas = resultado
+            .eventos
+            .iter()
+            .filter(
+                |evento| matches!(evento, EventoCombate::Item { autor, .. } if autor == "Conan"),
+            )
+            .count();
+
+        assert!(curas > 0);
+        assert_eq!(
+            resultado.lados[0][0].inventario.quantidade("pocao"),
+            2 - curas as u32
+        );
+    }
+
+    #[test]
+    fn test_habilidade_gasta_mana_e_entra_em_recarga() {
+        let legolas = Personagem::new("Legolas", &Arqueiro, 3).unwrap();
+        let mana_inicial = legolas.mana;
+        let mut batalha = Batalha::new(legolas, guerreiro(), 5);
+        let flecha = &Arqueiro.habilidades()[0];
+
+        let eventos = batalha.jogar_rodada().to_vec();
+        assert!(eventos.contains(&EventoCombate::Habilidade {
+            autor: "Legolas".to_string(),
+            habilidade: flecha.nome.clone(),
+            alvos: vec!["Conan".to_string()],
+            mana_gasta: flecha.custo_mana,
+        }));
+        let legolas = &batalha.combatentes[0];
+        assert_eq!(legolas.personagem.mana, mana_inicial - flecha.custo_mana);
+        assert_eq!(legolas.recargas[&flecha.id], flecha.recarga);
+        assert!(!legolas.pode_usar(flecha));
+
+        // O veneno da flecha age no turno seguinte do alvo
+        assert!(batalha.combatentes[1].efeitos.tem(TipoStatus::Veneno));
+        let rodada = batalha.jogar_rodada();
+        assert!(rodada.iter().any(|evento| matches!(
+            evento,
+            EventoCombate::Dano {
+                origem: OrigemDano::Veneno,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_habilidade_em_area_atinge_todos_os_inimigos() {
+        let inimigos: Vec<Personagem> = ["A", "B", "C"]
+            .iter()
+            .map(|nome| Personagem::new(nome, &Guerreiro, 1).unwrap())
+            .collect();
+        let mut batalha = Batalha::entre_lados(vec![mago()], inimigos, 3);
+        // Cura não é necessária e o raio está em recarga: sobra a bola de fogo
+        batalha.combatentes[0]
+            .recargas
+            .insert("raio_congelante".to_string(), 5);
+
+        let alvos = batalha
+            .jogar_rodada()
+            .iter()
+            .find_map(|evento| match evento {
+                EventoCombate::Habilidade { alvos, .. } => Some(alvos.clone()),
+                _ => None,
+            });
+        assert_eq!(alvos, Some(vec!["A".into(), "B".into(), "C".into()]));
+    }
+
+    #[test]
+    fn test_atordoamento_e_escudo() {
+        let mut batalha = Batalha::new(guerreiro(), mago(), 11);
+        let merlin = &mut batalha.combatentes[1];
+        merlin.efeitos.aplicar(Status {
+            tipo: TipoStatus::Atordoamento,
+            intensidade: 0,
+            duracao: 1,
+        });
+        merlin.efeitos.aplicar(Status {
+            tipo: TipoStatus::Escudo,
+            intensidade: 1000,
+            duracao: 5,
+        });
+        // Sem mana, o guerreiro só ataca
+        batalha.combatentes[0].personagem.mana = 0;
+
+        let eventos = batalha.jogar_rodada().to_vec();
+        assert!(eventos.contains(&EventoCombate::TurnoPerdido {
+            personagem: "Merlin".to_string()
+        }));
+        assert!(!eventos.iter().any(|evento| autor(evento) == Some("Merlin")));
+        assert!(eventos.contains(&EventoCombate::StatusEncerrado {
+            alvo: "Merlin".to_string(),
+            tipo: TipoStatus::Atordoamento,
+        }));
+
+        let merlin = &batalha.combatentes[1].personagem;
+        assert_eq!(merlin.vida, merlin.vida_maxima());
+    }
+}
@@ -0,0 +1,168 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::errors::ErroRpg;
+
+/// Nó em que toda conversa começa
+pub const NO_INICIAL: &str = "inicio";
+
+/// Personagem do mapa com quem o grupo conversa
+///
+/// # Campos
+/// - `nome`: Nome exibido
+/// - `dialogo`: Nós da árvore de diálogo, pelo id; a conversa começa em `NO_INICIAL`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Npc {
+    pub nome: String,
+    pub dialogo: BTreeMap<String, NoDialogo>,
+}
+
+/// Fala do NPC e as respostas possíveis
+///
+/// Um nó sem opções encerra a conversa.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NoDialogo {
+    pub fala: String,
+    #[serde(default)]
+    pub opcoes: Vec<OpcaoDialogo>,
+}
+
+/// Resposta do jogador
+///
+/// # Campos
+/// - `texto`: O que o jogador diz
+/// - `proximo`: Nó seguinte; sem ele, a conversa termina
+/// - `missao`: Missão (pelo id) aceita ao escolher esta resposta
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpcaoDialogo {
+    pub texto: String,
+    pub proximo: Option<String>,
+    pub missao: Option<String>,
+}
+
+impl Npc {
+    /// Confere se todos os nós citados existem
+    pub(crate) fn validar(&self, id: &str) -> Result<(), ErroRpg> {
+        if !self.dialogo.contains_key(NO_INICIAL) {
+            return Err(ErroRpg::MundoInvalido(format!(
+                "o diálogo de {} não tem o nó \"{}\"",
+                id, NO_INICIAL
+            )));
+        }
+        let destinos = self
+            .dialogo
+            .values()
+            .flat_map(|no| &no.opcoes)
+            .filter_map(|opcao| opcao.proximo.as_ref());
+        for destino in destinos {
+            if !self.dialogo.contains_key(destino) {
+                return Err(ErroRpg::MundoInvalido(format!(
+                    "o diálogo de {} cita o nó inexistente \"{}\"",
+                    id, destino
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Missões oferecidas em alguma resposta do diálogo
+    pub(crate) fn missoes(&self) -> impl Iterator<Item = &String> {
+        self.dialogo
+            .values()
+            .flat_map(|no| &no.opcoes)
+            .filter_map(|opcao| opcao.missao.as_ref())
+    }
+}
+
+/// Conversa em andamento com um NPC
+#[derive(Debug, Clone)]
+pub struct Conversa<'a> {
+    npc: &'a Npc,
+    no: Option<&'a NoDialogo>,
+}
+
+impl<'a> Conversa<'a> {
+    pub fn new(npc: &'a Npc) -> Self {
+        Conversa {
+            npc,
+            no: npc.dialogo.get(NO_INICIAL),
+        }
+    }
+
+    /// Nó atual, ou `None` quando a conversa acabou
+    pub fn atual(&self) -> Option<&'a NoDialogo> {
+        self.no
+    }
+
+    /// Escolhe uma resposta (a partir de 0) e avança a conversa
+    ///
+    /// # Retorno
+    /// A missão oferecida pela resposta, se houver
+    pub fn escolher(&mut self, opcao: usize) -> Result<Option<&'a str>, ErroRpg> {
+        let escolhida = self
+            .no
+            .and_then(|no| no.opcoes.get(opcao))
+            .ok_or(ErroRpg::OpcaoInvalida(opcao + 1))?;
+        self.no = escolhida
+            .proximo
+            .as_ref()
+            .and_then(|proximo| self.npc.dialogo.get(proximo));
+        Ok(escolhida.missao.as_deref())
+    }
+
+    /// Se a conversa chegou ao fim: nó sem opções ou resposta sem próximo nó
+    pub fn encerrada(&self) -> bool {
+        self.no.is_none_or(|no| no.opcoes.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NPC: &str = r#"
+        nome = "Ferreiro"
+
+        [dialogo.inicio]
+        fala = "Precisa de algo?"
+        opcoes = [
+            { texto = "Trabalho", proximo = "trabalho" },
+            { texto = "Nada" },
+        ]
+
+        [dialogo.trabalho]
+        fala = "Lobos rondam a estrada."
+        opcoes = [{ texto = "Eu cuido deles", proximo = "fim", missao = "lobos" }]
+
+        [dialogo.fim]
+        fala = "Boa sorte."
+    "#;
+
+    #[test]
+    fn test_percorrer_dialogo() {
+        let npc: Npc = toml::from_str(NPC).unwrap();
+        npc.validar("ferreiro").unwrap();
+
+        let mut conversa = Conversa::new(&npc);
+        assert_eq!(conversa.atual().unwrap().fala, "Precisa de algo?");
+        assert_eq!(conversa.escolher(5).unwrap_err(), ErroRpg::OpcaoInvalida(6));
+        assert_eq!(conversa.escolher(0).unwrap(), None);
+        assert_eq!(conversa.escolher(0).unwrap(), Some("lobos"));
+        assert_eq!(conversa.atual().unwrap().fala, "Boa sorte.");
+        assert!(conversa.encerrada());
+
+        let mut conversa = Conversa::new(&npc);
+        conversa.escolher(1).unwrap();
+        assert!(conversa.atual().is_none() && conversa.encerrada());
+    }
+
+    #[test]
+    fn test_dialogo_com_no_inexistente() {
+        let npc: Npc =
+            toml::from_str(&NPC.replace("proximo = \"fim\"", "proximo = \"x\"")).unwrap();
+        assert!(matches!(
+            npc.validar("ferreiro"),
+            Err(ErroRpg::MundoInvalido(_))
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::itens::Encaixe;
+
+/// Erros retornados pelas operações do simulador
+#[derive(Debug, PartialEq)]
+pub enum ErroRpg {
+    /// Um campo obrigatório foi informado vazio (ex: "nome")
+    CampoVazio(String),
+    /// O nível precisa estar entre 1 e `NIVEL_MAXIMO`
+    NivelInvalido(u32),
+    /// Nenhuma classe tem o nome informado
+    ClasseDesconhecida(String),
+    /// O item não está no catálogo ou no inventário
+    ItemNaoEncontrado(String),
+    /// Dois itens do arquivo de dados usam o mesmo id
+    ItemDuplicado(String),
+    /// Não há espaço livre no inventário
+    InventarioCheio { espacos: usize },
+    /// O item passaria do peso que o personagem consegue carregar
+    PesoExcedido {
+        peso_maximo: u32,
+        peso_necessario: u32,
+    },
+    /// O item não é arma nem armadura
+    ItemNaoEquipavel(String),
+    /// A classe do personagem não pode usar o item
+    EquipamentoProibido { classe: String, item: String },
+    /// Não há nada equipado no encaixe
+    EncaixeVazio(Encaixe),
+    /// O item não é consumível
+    ItemNaoConsumivel(String),
+    /// A classe não tem habilidade com o id informado
+    HabilidadeDesconhecida { classe: String, habilidade: String },
+    /// Nenhuma estratégia de IA tem o nome informado
+    EstrategiaDesconhecida(String),
+    /// Dois personagens da mesma batalha usam o mesmo nome
+    NomeRepetido(String),
+    /// O cenário de simulação não tem os dois lados com personagens
+    CenarioInvalido(String),
+    /// O número do slot de save está fora de 1 a `NUMERO_SLOTS`
+    SlotInvalido(u32),
+    /// Não há save no slot
+    SlotVazio(u32),
+    /// O arquivo de save está danificado ou foi alterado
+    SaveCorrompido(String),
+    /// O save foi gravado por outra versão do formato
+    VersaoIncompativel { encontrada: u32, suportada: u32 },
+    /// Nenhum local do mapa tem o id informado
+    LocalDesconhecido(String),
+    /// O local não tem saída na direção informada
+    SemSaida(String),
+    /// Nenhuma missão tem o id informado
+    MissaoDesconhecida(String),
+    /// A resposta escolhida não está entre as opções do diálogo
+    OpcaoInvalida(usize),
+    /// O mapa cita locais, zonas, NPCs ou inimigos que não existem
+    MundoInvalido(String),
+    /// Erro ao ler ou interpretar um arquivo de dados
+    ErroPersistencia(String),
+}
+
+impl fmt::Display for ErroRpg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErroRpg::CampoVazio(campo) => write!(f, "O campo \"{}\" não pode ficar vazio", campo),
+            ErroRpg::NivelInvalido(nivel) => write!(f, "Nível inválido: {}", nivel),
+            ErroRpg::ClasseDesconhecida(nome) => write!(f, "Classe desconhecida: {}", nome),
+            ErroRpg::ItemNaoEncontrado(id) => write!(f, "Item não encontrado: {}", id),
+            ErroRpg::ItemDuplicado(id) => write!(f, "Item repetido no arquivo de dados: {}", id),
+            ErroRpg::InventarioCheio { espacos } => {
+                write!(
+                    f,
+                    "Inventário cheio (todos os {} espaços ocupados)",
+                    espacos
+                )
+            }
+            ErroRpg::PesoExcedido {
+                peso_maximo,
+                peso_necessario,
+            } => write!(
+                f,
+                "Peso excedido: a carga ficaria em {} de no máximo {}",
+                peso_necessario, peso_maximo
+            ),
+            ErroRpg::ItemNaoEquipavel(nome) => write!(f, "{} não pode ser equipado", nome),
+            ErroRpg::EquipamentoProibido { classe, item } => {
+                write!(f, "A classe {} não pode usar {}", classe, item)
+            }
+            ErroRpg::EncaixeVazio(encaixe) => write!(f, "Nenhuma {} equipada", encaixe),
+            ErroRpg::ItemNaoConsumivel(nome) => write!(f, "{} não é um consumível", nome),
+            ErroRpg::HabilidadeDesconhecida { classe, habilidade } => {
+                write!(f, "A classe {} não tem a habilidade {}", classe, habilidade)
+            }
+            ErroRpg::EstrategiaDesconhecida(nome) => write!(f, "Estratégia desconhecida: {}", nome),
+            ErroRpg::NomeRepetido(nome) => {
+                write!(f, "Já existe um personagem chamado {} na batalha", nome)
+            }
+            ErroRpg::CenarioInvalido(msg) => write!(f, "Cenário inválido: {}", msg),
+            ErroRpg::SlotInvalido(slot) => write!(f, "Slot de save inválido: {}", slot),
+            ErroRpg::SlotVazio(slot) => write!(f, "Não há jogo salvo no slot {}", slot),
+            ErroRpg::SaveCorrompido(msg) => write!(f, "Save corrompido: {}", msg),
+            ErroRpg::VersaoIncompativel {
+                encontrada,
+                suportada,
+            } if encontrada < suportada => write!(
+                f,
+                "Save da versão {}, mais antiga que a suportada ({})",
+                encontrada, suportada
+            ),
+            ErroRpg::VersaoIncompativel {
+                encontrada,
+                suportada,
+            } => write!(
+                f,
+                "Save da versão {}, mais nova que a suportada ({})",
+                encontrada, suportada
+            ),
+            ErroRpg::LocalDesconhecido(id) => write!(f, "Local desconhecido: {}", id),
+            ErroRpg::SemSaida(direcao) => write!(f, "Não há caminho para {}", direcao),
+            ErroRpg::MissaoDesconhecida(id) => write!(f, "Missão desconhecida: {}", id),
+            ErroRpg::OpcaoInvalida(opcao) => write!(f, "Opção inválida: {}", opcao),
+            ErroRpg::MundoInvalido(motivo) => write!(f, "Mapa inválido: {}", motivo),
+            ErroRpg::ErroPersistencia(msg) => write!(f, "Erro de persistência: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ErroRpg {}
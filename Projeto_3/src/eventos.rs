@@ -0,0 +1,159 @@
+use serde::Serialize;
+use std::fmt;
+
+use crate::status::{Status, TipoStatus};
+
+/// De onde veio um dano
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(tag = "tipo", content = "nome", rename_all = "snake_case")]
+pub enum OrigemDano {
+    Ataque,
+    Habilidade(String),
+    Item(String),
+    Veneno,
+}
+
+/// Fato ocorrido na batalha
+///
+/// A batalha produz uma sequência desses eventos, que descreve por completo
+/// tudo o que aconteceu: quem agiu, o resultado de cada ação e cada efeito
+/// de status. Uma interface gráfica ou um teste podem reconstruir a batalha
+/// só a partir deles. Os nomes dos personagens identificam autor e alvo.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "evento", rename_all = "snake_case")]
+pub enum EventoCombate {
+    InicioRodada {
+        rodada: u32,
+    },
+    /// Ataque básico; o resultado vem nos eventos seguintes
+    Ataque {
+        autor: String,
+        verbo: &'static str,
+        alvo: String,
+    },
+    /// Uso de habilidade, com a mana já descontada
+    Habilidade {
+        autor: String,
+        habilidade: String,
+        alvos: Vec<String>,
+        mana_gasta: u32,
+    },
+    /// Uso de um consumível do inventário
+    Item {
+        autor: String,
+        item: String,
+    },
+    Esquiva {
+        alvo: String,
+    },
+    /// Dano recebido; `absorvido` é a parte que ficou no escudo
+    Dano {
+        alvo: String,
+        origem: OrigemDano,
+        pontos: u32,
+        critico: bool,
+        absorvido: u32,
+        vida_restante: u32,
+    },
+    Cura {
+        alvo: String,
+        pontos: u32,
+        vida_restante: u32,
+    },
+    /// Efeito aplicado, já combinado com o que o alvo tinha
+    StatusAplicado {
+        alvo: String,
+        status: Status,
+    },
+    StatusEncerrado {
+        alvo: String,
+        tipo: TipoStatus,
+    },
+    /// O personagem estava atordoado e não agiu
+    TurnoPerdido {
+        personagem: String,
+    },
+    Derrota {
+        personagem: String,
+    },
+    /// Último evento da batalha; `vencedor` é o lado vencedor (0 ou 1)
+    Fim {
+        vencedor: Option<usize>,
+        rodadas: u32,
+    },
+}
+
+impl fmt::Display for EventoCombate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventoCombate::InicioRodada { rodada } => write!(f, "--- Rodada {} ---", rodada),
+            EventoCombate::Ataque { autor, verbo, alvo } => {
+                write!(f, "{} {} {}", autor, verbo, alvo)
+            }
+            EventoCombate::Habilidade {
+                autor,
+                habilidade,
+                alvos,
+                mana_gasta,
+            } => write!(
+                f,
+                "{} usa {} em {} ({} MP)",
+                autor,
+                habilidade,
+                alvos.join(", "),
+                mana_gasta
+            ),
+            EventoCombate::Item { autor, item } => write!(f, "{} usa {}", autor, item),
+            EventoCombate::Esquiva { alvo } => write!(f, "  {} esquivou!", alvo),
+            EventoCombate::Dano {
+                alvo,
+                origem,
+                pontos,
+                critico,
+                absorvido,
+                vida_restante,
+            } => {
+                write!(f, "  {} sofre {} de dano", alvo, pontos)?;
+                if *origem == OrigemDano::Veneno {
+                    write!(f, " do veneno")?;
+                }
+                if *critico {
+                    write!(f, " (crítico!)")?;
+                }
+                if *absorvido > 0 {
+                    write!(f, " [escudo absorveu {}]", absorvido)?;
+                }
+                write!(f, " - fica com {} HP", vida_restante)
+            }
+            EventoCombate::Cura {
+                alvo,
+                pontos,
+                vida_restante,
+            } => write!(
+                f,
+                "  {} recupera {} HP - fica com {} HP",
+                alvo, pontos, vida_restante
+            ),
+            EventoCombate::StatusAplicado { alvo, status } => write!(
+                f,
+                "  {} sob {} ({}, {} turnos)",
+                alvo, status.tipo, status.intensidade, status.duracao
+            ),
+            EventoCombate::StatusEncerrado { alvo, tipo } => {
+                write!(f, "  {}: {} acabou", alvo, tipo)
+            }
+            EventoCombate::TurnoPerdido { personagem } => {
+                write!(f, "{} está atordoado e perde o turno", personagem)
+            }
+            EventoCombate::Derrota { personagem } => write!(f, "  {} foi derrotado!", personagem),
+            EventoCombate::Fim {
+                vencedor: Some(lado),
+                rodadas,
+            } => write!(f, "Fim: lado {} venceu em {} rodadas", lado + 1, rodadas),
+            EventoCombate::Fim {
+                vencedor: None,
+                rodadas,
+            } => write!(f, "Fim: empate depois de {} rodadas", rodadas),
+        }
+    }
+}
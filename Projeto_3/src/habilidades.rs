@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::status::{Status, TipoStatus};
+
+/// Quem a habilidade pode atingir
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlvoHabilidade {
+    Inimigo,
+    /// Qualquer personagem do mesmo lado, inclusive quem usa
+    Aliado,
+    /// Somente quem usa
+    Proprio,
+}
+
+/// Quantos personagens a habilidade atinge
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Alcance {
+    /// Um só alvo
+    Unico,
+    /// Todos os alvos vivos do lado atingido
+    Area,
+}
+
+/// Habilidade especial de uma classe
+///
+/// # Campos
+/// - `id`: Identificador da habilidade (ex: "bola_de_fogo")
+/// - `nome`: Nome exibido
+/// - `custo_mana`: Mana gasta a cada uso
+/// - `recarga`: Turnos de quem usa até a habilidade ficar disponível de
+///   novo (com 2, usada num turno, volta dois turnos depois)
+/// - `alvo`, `alcance`: Quem e quantos são atingidos
+/// - `poder`: Multiplicador do ataque no dano (0 para habilidades sem dano)
+/// - `cura`: Pontos de vida recuperados por cada alvo
+/// - `status`: Efeito aplicado em cada alvo atingido
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Habilidade {
+    pub id: String,
+    pub nome: String,
+    pub custo_mana: u32,
+    #[serde(default)]
+    pub recarga: u32,
+    pub alvo: AlvoHabilidade,
+    pub alcance: Alcance,
+    #[serde(default)]
+    pub poder: f64,
+    #[serde(default)]
+    pub cura: u32,
+    #[serde(default)]
+    pub status: Option<Status>,
+}
+
+impl Habilidade {
+    /// Habilidade sem efeito, para ser completada com os métodos `com_*`
+    pub fn new(id: &str, nome: &str, custo_mana: u32, recarga: u32) -> Self {
+        Habilidade {
+            id: id.to_string(),
+            nome: nome.to_string(),
+            custo_mana,
+            recarga,
+            alvo: AlvoHabilidade::Inimigo,
+            alcance: Alcance::Unico,
+            poder: 0.0,
+            cura: 0,
+            status: None,
+        }
+    }
+
+    pub fn com_alvo(mut self, alvo: AlvoHabilidade, alcance: Alcance) -> Self {
+        self.alvo = alvo;
+        self.alcance = alcance;
+        self
+    }
+
+    pub fn com_dano(mut self, poder: f64) -> Self {
+        self.poder = poder;
+        self
+    }
+
+    pub fn com_cura(mut self, cura: u32) -> Self {
+        self.cura = cura;
+        self
+    }
+
+    pub fn com_status(mut self, tipo: TipoStatus, intensidade: u32, duracao: u32) -> Self {
+        self.status = Some(Status {
+            tipo,
+            intensidade,
+            duracao,
+        });
+        self
+    }
+
+    pub fn causa_dano(&self) -> bool {
+        self.poder > 0.0
+    }
+}
+
+impl fmt::Display for Habilidade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} MP", self.nome, self.custo_mana)?;
+        if self.recarga > 0 {
+            write!(f, ", recarga {}", self.recarga)?;
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,211 @@
+use rand::Rng;
+use std::fmt;
+
+use crate::combate::{Combatente, Gerador};
+use crate::errors::ErroRpg;
+use crate::status::TipoStatus;
+
+/// Política de escolha de alvo dos personagens controlados pelo computador
+///
+/// Assim como as classes, as estratégias não guardam estado: cada lado da
+/// batalha aponta para uma instância estática (ex: `&MenorVida`). A
+/// estratégia decide o alvo dos ataques, dos itens de dano e das
+/// habilidades de alvo único contra inimigos.
+pub trait Estrategia: fmt::Debug + Sync {
+    fn nome(&self) -> &'static str;
+
+    /// Escolhe um dos candidatos, todos inimigos vivos
+    ///
+    /// # Parâmetros
+    /// - `candidatos`: Alvos possíveis (nunca vazio)
+    /// - `rng`: Gerador da batalha, para estratégias com sorteio
+    ///
+    /// # Retorno
+    /// Posição do alvo escolhido em `candidatos`
+    fn escolher_alvo(&self, candidatos: &[&Combatente], rng: &mut Gerador) -> usize;
+}
+
+/// Ataca quem está com menos vida, para derrubar inimigos o quanto antes
+#[derive(Debug, Clone, Copy)]
+pub struct MenorVida;
+
+/// Ataca quem causa mais dano (ataque efetivo, contando fortalecimento)
+#[derive(Debug, Clone, Copy)]
+pub struct MaiorAmeaca;
+
+/// Ataca um inimigo sorteado
+#[derive(Debug, Clone, Copy)]
+pub struct Aleatorio;
+
+/// Ataca primeiro quem sabe curar; entre eles, ou sem eles, quem tem menos vida
+#[derive(Debug, Clone, Copy)]
+pub struct CurandeiroPrimeiro;
+
+/// Posição do candidato com a menor chave (o primeiro, em caso de empate)
+fn posicao_do_menor<K: Ord>(candidatos: &[&Combatente], chave: impl Fn(&Combatente) -> K) -> usize {
+    (0..candidatos.len())
+        .min_by_key(|&posicao| chave(candidatos[posicao]))
+        .unwrap_or(0)
+}
+
+/// Dano que o combatente causa num ataque básico, antes da defesa do alvo
+fn ameaca(combatente: &Combatente) -> u32 {
+    let fortalecimento = combatente.efeitos.intensidade(TipoStatus::Fortalecimento);
+    combatente.personagem.atributos_efetivos().ataque * (100 + fortalecimento) / 100
+}
+
+impl Estrategia for MenorVida {
+    fn nome(&self) -> &'static str {
+        "Menor vida"
+    }
+
+    fn escolher_alvo(&self, candidatos: &[&Combatente], _rng: &mut Gerador) -> usize {
+        posicao_do_menor(candidatos, |c| c.personagem.vida)
+    }
+}
+
+impl Estrategia for MaiorAmeaca {
+    fn nome(&self) -> &'static str {
+        "Maior ameaça"
+    }
+
+    fn escolher_alvo(&self, candidatos: &[&Combatente], _rng: &mut Gerador) -> usize {
+        posicao_do_menor(candidatos, |c| std::cmp::Reverse(ameaca(c)))
+    }
+}
+
+impl Estrategia for Aleatorio {
+    fn nome(&self) -> &'static str {
+        "Aleatório"
+    }
+
+    fn escolher_alvo(&self, candidatos: &[&Combatente], rng: &mut Gerador) -> usize {
+        rng.gen_range(0..candidatos.len())
+    }
+}
+
+impl Estrategia for CurandeiroPrimeiro {
+    fn nome(&self) -> &'static str {
+        "Curandeiro primeiro"
+    }
+
+    fn escolher_alvo(&self, candidatos: &[&Combatente], _rng: &mut Gerador) -> usize {
+        posicao_do_menor(candidatos, |c| (!c.cura_aliados(), c.personagem.vida))
+    }
+}
+
+/// Todas as estratégias disponíveis, na ordem de exibição
+pub const ESTRATEGIAS: [&dyn Estrategia; 4] =
+    [&MenorVida, &MaiorAmeaca, &Aleatorio, &CurandeiroPrimeiro];
+
+/// Procura uma estratégia pelo nome, sem diferenciar maiúsculas de minúsculas
+///
+/// Aceita o nome exibido ("Menor vida") ou com `_` no lugar dos espaços
+/// ("menor_vida"), como nos arquivos de cenário.
+pub fn estrategia_por_nome(nome: &str) -> Result<&'static dyn Estrategia, ErroRpg> {
+    let procurado = sem_acento(&nome.trim().replace('_', " ").to_lowercase());
+    ESTRATEGIAS
+        .into_iter()
+        .find(|estrategia| sem_acento(&estrategia.nome().to_lowercase()) == procurado)
+        .ok_or_else(|| ErroRpg::EstrategiaDesconhecida(nome.trim().to_string()))
+}
+
+/// Troca as letras minúsculas acentuadas pelas sem acento
+fn sem_acento(texto: &str) -> String {
+    texto
+        .chars()
+        .map(|letra| match letra {
+            'á' | 'ã' | 'â' => 'a',
+            'é' | 'ê' => 'e',
+            'í' => 'i',
+            'ó' | 'õ' | 'ô' => 'o',
+            'ú' => 'u',
+            'ç' => 'c',
+            outra => outra,
+        })
+        .collect()
+}
+
+/// Grava a estratégia pelo nome, para `#[serde(with = "ia::pelo_nome")]`
+pub mod pelo_nome {
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    use super::{Estrategia, estrategia_por_nome};
+
+    pub fn serialize<S: Serializer>(
+        estrategia: &&'static dyn Estrategia,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(estrategia.nome())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<&'static dyn Estrategia, D::Error> {
+        let nome = String::deserialize(deserializer)?;
+        estrategia_por_nome(&nome).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::{Arqueiro, Guerreiro, Mago};
+    use crate::combate::gerador;
+    use crate::personagem::Personagem;
+    use crate::status::Status;
+
+    fn combatente(personagem: Personagem) -> Combatente {
+        Combatente::new(personagem, 1, &MenorVida)
+    }
+
+    /// Guerreiro ferido, mago com vida cheia e arqueiro fortalecido
+    fn inimigos() -> Vec<Combatente> {
+        let mut guerreiro = Personagem::new("Conan", &Guerreiro, 5).unwrap();
+        guerreiro.vida = 20;
+        let mut arqueiro = combatente(Personagem::new("Legolas", &Arqueiro, 5).unwrap());
+        arqueiro.efeitos.aplicar(Status {
+            tipo: TipoStatus::Fortalecimento,
+            intensidade: 50,
+            duracao: 2,
+        });
+        vec![
+            combatente(guerreiro),
+            combatente(Personagem::new("Merlin", &Mago, 5).unwrap()),
+            arqueiro,
+        ]
+    }
+
+    #[test]
+    fn test_estrategias_escolhem_alvos_diferentes() {
+        let inimigos = inimigos();
+        let candidatos: Vec<&Combatente> = inimigos.iter().collect();
+        let mut rng = gerador(1);
+
+        assert_eq!(MenorVida.escolher_alvo(&candidatos, &mut rng), 0);
+        // Mago nv. 5 tem 34 de ataque; arqueiro nv. 5 tem 27, com +50% fica 40
+        assert_eq!(MaiorAmeaca.escolher_alvo(&candidatos, &mut rng), 2);
+        assert_eq!(CurandeiroPrimeiro.escolher_alvo(&candidatos, &mut rng), 1);
+
+        let sorteados: Vec<usize> = (0..30)
+            .map(|_| Aleatorio.escolher_alvo(&candidatos, &mut rng))
+            .collect();
+        assert!((0..3).all(|alvo| sorteados.contains(&alvo)));
+    }
+
+    #[test]
+    fn test_estrategia_por_nome() {
+        assert_eq!(
+            estrategia_por_nome("maior_ameaca").unwrap().nome(),
+            "Maior ameaça"
+        );
+        assert_eq!(
+            estrategia_por_nome(" Menor vida").unwrap().nome(),
+            "Menor vida"
+        );
+        assert_eq!(
+            estrategia_por_nome("covarde").unwrap_err(),
+            ErroRpg::EstrategiaDesconhecida("covarde".to_string())
+        );
+    }
+}
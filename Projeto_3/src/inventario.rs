@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ErroRpg;
+use crate::itens::{Efeito, Item};
+
+/// Número de espaços da mochila de todo personagem
+pub const ESPACOS_INVENTARIO: usize = 12;
+
+/// Maior quantidade de um consumível num mesmo espaço
+pub const MAXIMO_POR_PILHA: u32 = 10;
+
+/// Unidades de um mesmo item ocupando um espaço do inventário
+///
+/// Armas e armaduras ocupam um espaço cada; consumíveis iguais se empilham
+/// até `MAXIMO_POR_PILHA`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Pilha {
+    pub item: Item,
+    pub quantidade: u32,
+}
+
+/// Mochila do personagem, limitada em espaços e em peso
+///
+/// # Campos
+/// - `espacos`: Número máximo de pilhas
+/// - `peso_maximo`: Peso total que o personagem consegue carregar
+/// - `pilhas`: Itens guardados, na ordem em que entraram
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Inventario {
+    espacos: usize,
+    peso_maximo: u32,
+    pilhas: Vec<Pilha>,
+}
+
+impl Inventario {
+    pub fn new(espacos: usize, peso_maximo: u32) -> Self {
+        Inventario {
+            espacos,
+            peso_maximo,
+            pilhas: Vec::new(),
+        }
+    }
+
+    pub fn pilhas(&self) -> &[Pilha] {
+        &self.pilhas
+    }
+
+    pub fn peso_maximo(&self) -> u32 {
+        self.peso_maximo
+    }
+
+    pub fn peso_atual(&self) -> u32 {
+        self.pilhas
+            .iter()
+            .map(|pilha| pilha.item.peso * pilha.quantidade)
+            .sum()
+    }
+
+    pub fn espacos_livres(&self) -> usize {
+        self.espacos.saturating_sub(self.pilhas.len())
+    }
+
+    /// Quantidade guardada de um item
+    pub fn quantidade(&self, id: &str) -> u32 {
+        self.pilhas
+            .iter()
+            .filter(|pilha| pilha.item.id == id)
+            .map(|pilha| pilha.quantidade)
+            .sum()
+    }
+
+    pub fn buscar(&self, id: &str) -> Result<&Item, ErroRpg> {
+        self.pilhas
+            .iter()
+            .map(|pilha| &pilha.item)
+            .find(|item| item.id == id)
+            .ok_or_else(|| ErroRpg::ItemNaoEncontrado(id.to_string()))
+    }
+
+    /// Primeiro consumível guardado com o efeito pedido
+    pub fn consumivel(&self, efeito: Efeito) -> Option<&Item> {
+        self.pilhas
+            .iter()
+            .map(|pilha| &pilha.item)
+            .find(|item| matches!(item.efeito(), Some((e, _)) if e == efeito))
+    }
+
+    /// Guarda unidades de um item
+    ///
+    /// Nada é guardado se faltar peso ou espaço para todas as unidades.
+    pub fn adicionar(&mut self, item: Item, quantidade: u32) -> Result<(), ErroRpg> {
+        let peso_necessario = self.peso_atual() + item.peso * quantidade;
+        if peso_necessario > self.peso_maximo {
+            return Err(ErroRpg::PesoExcedido {
+                peso_maximo: self.peso_maximo,
+                peso_necessario,
+            });
+        }
+
+        let empilha = item.e_consumivel();
+        let espacos_necessarios = if empilha {
+            let livre_nas_pilhas: u32 = self
+                .pilhas
+                .iter()
+                .filter(|pilha| pilha.item.id == item.id)
+                .map(|pilha| MAXIMO_POR_PILHA - pilha.quantidade)
+                .sum();
+            quantidade
+                .saturating_sub(livre_nas_pilhas)
+                .div_ceil(MAXIMO_POR_PILHA) as usize
+        } else {
+            quantidade as usize
+        };
+        if espacos_necessarios > self.espacos_livres() {
+            return Err(ErroRpg::InventarioCheio {
+                espacos: self.espacos,
+            });
+        }
+
+        let mut restante = quantidade;
+        if empilha {
+            for pilha in self.pilhas.iter_mut().filter(|p| p.item.id == item.id) {
+                let cabe = (MAXIMO_POR_PILHA - pilha.quantidade).min(restante);
+                pilha.quantidade += cabe;
+                restante -= cabe;
+            }
+        }
+        let por_pilha = if empilha { MAXIMO_POR_PILHA } else { 1 };
+        while restante > 0 {
+            let quantidade = restante.min(por_pilha);
+            self.pilhas.push(Pilha {
+                item: item.clone(),
+                quantidade,
+            });
+            restante -= quantidade;
+        }
+        Ok(())
+    }
+
+    /// Tira uma unidade de um item do inventário
+    ///
+    /// # Retorno
+    /// O item retirado
+    pub fn remover(&mut self, id: &str) -> Result<Item, ErroRpg> {
+        // Tira da última pilha, para esvaziar as incompletas primeiro
+        let posicao = self
+            .pilhas
+            .iter()
+            .rposition(|pilha| pilha.item.id == id)
+            .ok_or_else(|| ErroRpg::ItemNaoEncontrado(id.to_string()))?;
+
+        let pilha = &mut self.pilhas[posicao];
+        pilha.quantidade -= 1;
+        let item = pilha.item.clone();
+        if pilha.quantidade == 0 {
+            self.pilhas.remove(posicao);
+        }
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::itens::{Categoria, Modificadores, TipoArma};
+
+    fn pocao() -> Item {
+        Item {
+            id: "pocao".to_string(),
+            nome: "Poção".to_string(),
+            peso: 1,
+            categoria: Categoria::Consumivel {
+                efeito: Efeito::Cura,
+                pontos: 30,
+            },
+        }
+    }
+
+    fn machado() -> Item {
+        Item {
+            id: "machado".to_string(),
+            nome: "Machado".to_string(),
+            peso: 8,
+            categoria: Categoria::Arma {
+                tipo_arma: TipoArma::Machado,
+                modificadores: Modificadores::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_consumiveis_se_empilham() {
+        let mut inventario = Inventario::new(3, 100);
+        inventario.adicionar(pocao(), 4).unwrap();
+        inventario.adicionar(pocao(), 12).unwrap();
+
+        assert_eq!(inventario.quantidade("pocao"), 16);
+        assert_eq!(inventario.pilhas().len(), 2);
+        assert_eq!(inventario.espacos_livres(), 1);
+
+        // Armas não se empilham: duas precisam de dois espaços
+        assert_eq!(
+            inventario.adicionar(machado(), 2),
+            Err(ErroRpg::InventarioCheio { espacos: 3 })
+        );
+        assert_eq!(inventario.quantidade("machado"), 0);
+
+        inventario.remover("pocao").unwrap();
+        assert_eq!(inventario.pilhas()[1].quantidade, 5);
+    }
+
+    #[test]
+    fn test_limite_de_peso() {
+        let mut inventario = Inventario::new(10, 20);
+        inventario.adicionar(machado(), 2).unwrap();
+
+        assert_eq!(
+            inventario.adicionar(pocao(), 5),
+            Err(ErroRpg::PesoExcedido {
+                peso_maximo: 20,
+                peso_necessario: 21,
+            })
+        );
+        inventario.adicionar(pocao(), 4).unwrap();
+        assert_eq!(inventario.peso_atual(), 20);
+
+        inventario.remover("machado").unwrap();
+        assert_eq!(inventario.peso_atual(), 12);
+        assert_eq!(
+            inventario.remover("arco"),
+            Err(ErroRpg::ItemNaoEncontrado("arco".to_string()))
+        );
+    }
+}
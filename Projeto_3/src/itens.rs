@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::errors::ErroRpg;
+
+/// Bônus (ou penalidade, se negativo) que um item dá aos atributos
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct Modificadores {
+    pub vida: i32,
+    pub mana: i32,
+    pub ataque: i32,
+    pub defesa: i32,
+    pub velocidade: i32,
+}
+
+impl Modificadores {
+    pub fn somar(self, outro: Modificadores) -> Modificadores {
+        Modificadores {
+            vida: self.vida + outro.vida,
+            mana: self.mana + outro.mana,
+            ataque: self.ataque + outro.ataque,
+            defesa: self.defesa + outro.defesa,
+            velocidade: self.velocidade + outro.velocidade,
+        }
+    }
+}
+
+impl fmt::Display for Modificadores {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let partes: Vec<String> = [
+            ("HP", self.vida),
+            ("MP", self.mana),
+            ("ATQ", self.ataque),
+            ("DEF", self.defesa),
+            ("VEL", self.velocidade),
+        ]
+        .into_iter()
+        .filter(|&(_, valor)| valor != 0)
+        .map(|(sigla, valor)| format!("{} {:+}", sigla, valor))
+        .collect();
+        write!(f, "{}", partes.join(", "))
+    }
+}
+
+/// Tipo de arma, usado nas regras de equipamento de cada classe
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TipoArma {
+    Espada,
+    Machado,
+    Adaga,
+    Cajado,
+    Arco,
+}
+
+/// Peso da proteção de uma armadura
+///
+/// A ordem importa: uma classe que pode usar armadura média também pode
+/// usar a leve.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Protecao {
+    Leve,
+    Media,
+    Pesada,
+}
+
+/// Efeito de um consumível
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Efeito {
+    /// Recupera pontos de vida de quem usa
+    Cura,
+    /// Causa dano direto no adversário, sem esquiva nem defesa
+    Dano,
+}
+
+/// O que o item é, com os dados próprios de cada categoria
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "categoria", rename_all = "snake_case")]
+pub enum Categoria {
+    Arma {
+        tipo_arma: TipoArma,
+        #[serde(default)]
+        modificadores: Modificadores,
+    },
+    Armadura {
+        protecao: Protecao,
+        #[serde(default)]
+        modificadores: Modificadores,
+    },
+    /// Gasto ao ser usado; unidades do mesmo consumível se empilham
+    Consumivel { efeito: Efeito, pontos: u32 },
+}
+
+/// Espaço de equipamento do personagem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encaixe {
+    Arma,
+    Armadura,
+}
+
+impl fmt::Display for Encaixe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            Encaixe::Arma => "arma",
+            Encaixe::Armadura => "armadura",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Item definido no catálogo
+///
+/// # Campos
+/// - `id`: Identificador único no catálogo (ex: "espada_longa")
+/// - `nome`: Nome exibido
+/// - `peso`: Unidades de peso que o item ocupa na carga do personagem
+/// - `categoria`: Arma, armadura ou consumível
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub nome: String,
+    #[serde(default)]
+    pub peso: u32,
+    #[serde(flatten)]
+    pub categoria: Categoria,
+}
+
+impl Item {
+    /// Espaço de equipamento em que o item é usado, se for equipável
+    pub fn encaixe(&self) -> Option<Encaixe> {
+        match self.categoria {
+            Categoria::Arma { .. } => Some(Encaixe::Arma),
+            Categoria::Armadura { .. } => Some(Encaixe::Armadura),
+            Categoria::Consumivel { .. } => None,
+        }
+    }
+
+    /// Modificadores que o item dá enquanto está equipado
+    pub fn modificadores(&self) -> Modificadores {
+        match self.categoria {
+            Categoria::Arma { modificadores, .. } | Categoria::Armadura { modificadores, .. } => {
+                modificadores
+            }
+            Categoria::Consumivel { .. } => Modificadores::default(),
+        }
+    }
+
+    pub fn e_consumivel(&self) -> bool {
+        matches!(self.categoria, Categoria::Consumivel { .. })
+    }
+
+    /// Efeito e pontos do item, se for consumível
+    pub fn efeito(&self) -> Option<(Efeito, u32)> {
+        match self.categoria {
+            Categoria::Consumivel { efeito, pontos } => Some((efeito, pontos)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (peso {}) - ", self.nome, self.peso)?;
+        match &self.categoria {
+            Categoria::Arma { modificadores, .. } => write!(f, "arma: {}", modificadores),
+            Categoria::Armadura { modificadores, .. } => write!(f, "armadura: {}", modificadores),
+            Categoria::Consumivel { efeito, pontos } => {
+                let efeito = match efeito {
+                    Efeito::Cura => "cura",
+                    Efeito::Dano => "dano",
+                };
+                write!(f, "consumível: {} {}", efeito, pontos)
+            }
+        }
+    }
+}
+
+/// Arquivo de itens: uma lista `itens`, em JSON ou TOML
+#[derive(Debug, Serialize, Deserialize)]
+struct ArquivoItens {
+    itens: Vec<Item>,
+}
+
+/// Itens disponíveis no jogo, carregados de um arquivo de dados
+///
+/// Novos itens entram editando o arquivo, sem recompilar o simulador.
+#[derive(Debug, Clone, Default)]
+pub struct Catalogo {
+    itens: BTreeMap<String, Item>,
+}
+
+impl Catalogo {
+    /// Carrega o catálogo de um arquivo `.json` ou `.toml`
+    pub fn carregar(caminho: &Path) -> Result<Self, ErroRpg> {
+        let texto = std::fs::read_to_string(caminho).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Erro ao ler {}: {}", caminho.display(), e))
+        })?;
+        match caminho.extension().and_then(|extensao| extensao.to_str()) {
+            Some("toml") => Catalogo::de_toml(&texto),
+            _ => Catalogo::de_json(&texto),
+        }
+    }
+
+    pub fn de_json(texto: &str) -> Result<Self, ErroRpg> {
+        let arquivo: ArquivoItens = serde_json::from_str(texto)
+            .map_err(|e| ErroRpg::ErroPersistencia(format!("Arquivo de itens inválido: {}", e)))?;
+        Catalogo::montar(arquivo.itens)
+    }
+
+    pub fn de_toml(texto: &str) -> Result<Self, ErroRpg> {
+        let arquivo: ArquivoItens = toml::from_str(texto)
+            .map_err(|e| ErroRpg::ErroPersistencia(format!("Arquivo de itens inválido: {}", e)))?;
+        Catalogo::montar(arquivo.itens)
+    }
+
+    fn montar(lista: Vec<Item>) -> Result<Self, ErroRpg> {
+        let mut itens = BTreeMap::new();
+        for item in lista {
+            if item.id.trim().is_empty() {
+                return Err(ErroRpg::CampoVazio("id".to_string()));
+            }
+            if item.nome.trim().is_empty() {
+                return Err(ErroRpg::CampoVazio("nome".to_string()));
+            }
+            if itens.contains_key(&item.id) {
+                return Err(ErroRpg::ItemDuplicado(item.id));
+            }
+            itens.insert(item.id.clone(), item);
+        }
+        Ok(Catalogo { itens })
+    }
+
+    pub fn buscar(&self, id: &str) -> Result<&Item, ErroRpg> {
+        self.itens
+            .get(id)
+            .ok_or_else(|| ErroRpg::ItemNaoEncontrado(id.to_string()))
+    }
+
+    /// Itens do catálogo, em ordem de id
+    pub fn itens(&self) -> impl Iterator<Item = &Item> {
+        self.itens.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.itens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.itens.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const ITENS_TOML: &str = r#"
+        [[itens]]
+        id = "espada_curta"
+        nome = "Espada curta"
+        peso = 4
+        categoria = "arma"
+        tipo_arma = "espada"
+        modificadores = { ataque = 4 }
+
+        [[itens]]
+        id = "cota_de_malha"
+        nome = "Cota de malha"
+        peso = 12
+        categoria = "armadura"
+        protecao = "pesada"
+        modificadores = { defesa = 6, velocidade = -2 }
+
+        [[itens]]
+        id = "pocao"
+        nome = "Poção de cura"
+        peso = 1
+        categoria = "consumivel"
+        efeito = "cura"
+        pontos = 30
+    "#;
+
+    #[test]
+    fn test_carregar_catalogo_toml_e_json() {
+        let catalogo = Catalogo::de_toml(ITENS_TOML).unwrap();
+        assert_eq!(catalogo.len(), 3);
+        let malha = catalogo.buscar("cota_de_malha").unwrap();
+        assert_eq!(malha.encaixe(), Some(Encaixe::Armadura));
+        assert_eq!(
+            malha.modificadores(),
+            Modificadores {
+                defesa: 6,
+                velocidade: -2,
+                ..Modificadores::default()
+            }
+        );
+
+        // O mesmo catálogo gravado em JSON é lido igual
+        let dir = tempdir().unwrap();
+        let caminho = dir.path().join("itens.json");
+        let lista: Vec<Item> = catalogo.itens().cloned().collect();
+        std::fs::write(
+            &caminho,
+            serde_json::to_string(&ArquivoItens { itens: lista }).unwrap(),
+        )
+        .unwrap();
+        let json = Catalogo::carregar(&caminho).unwrap();
+        assert_eq!(json.buscar("pocao"), catalogo.buscar("pocao"));
+        assert_eq!(
+            json.buscar("machado").unwrap_err(),
+            ErroRpg::ItemNaoEncontrado("machado".to_string())
+        );
+    }
+
+    #[test]
+    fn test_catalogo_rejeita_id_repetido() {
+        let texto = r#"{"itens": [
+            {"id": "pocao", "nome": "Poção", "categoria": "consumivel", "efeito": "cura", "pontos": 10},
+            {"id": "pocao", "nome": "Outra", "categoria": "consumivel", "efeito": "cura", "pontos": 20}
+        ]}"#;
+        assert_eq!(
+            Catalogo::de_json(texto).unwrap_err(),
+            ErroRpg::ItemDuplicado("pocao".to_string())
+        );
+        assert!(matches!(
+            Catalogo::de_json(r#"{"itens": [{"id": "x", "nome": "X", "categoria": "anel"}]}"#),
+            Err(ErroRpg::ErroPersistencia(_))
+        ));
+    }
+}
@@ -0,0 +1,259 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+use crate::combate::{Batalha, Gerador, ResultadoBatalha, gerador};
+use crate::errors::ErroRpg;
+use crate::ia::{self, Estrategia};
+use crate::itens::Item;
+use crate::missoes::{Diario, Objetivo};
+use crate::mundo::{Encontro, Mundo};
+use crate::personagem::Personagem;
+use crate::progressao::{Progressao, SubidaNivel};
+
+/// Estado de uma partida, tudo o que vai para um arquivo de save
+///
+/// As sementes das batalhas saem do gerador da partida, então uma partida
+/// carregada de um save continua exatamente como continuaria a original.
+///
+/// # Campos
+/// - `grupo`: Personagens do jogador, com inventário e equipamento
+/// - `estrategia`: Como o grupo escolhe os inimigos que ataca
+/// - `batalhas`: Batalhas jogadas na partida
+/// - `vitorias`: Batalhas vencidas pelo grupo
+/// - `local`: Onde o grupo está no mapa
+/// - `diario`: Missões aceitas e concluídas
+/// - `coletados`: Itens do mapa já pegos, como `local/item`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Jogo {
+    pub grupo: Vec<Personagem>,
+    #[serde(with = "ia::pelo_nome")]
+    pub estrategia: &'static dyn Estrategia,
+    pub batalhas: u32,
+    pub vitorias: u32,
+    pub local: String,
+    pub diario: Diario,
+    coletados: BTreeSet<String>,
+    rng: Gerador,
+}
+
+/// O que aconteceu numa batalha da partida
+///
+/// # Campos
+/// - `resultado`: A batalha, como foi jogada
+/// - `xp`: XP ganho por cada sobrevivente do grupo (zero sem vitória)
+/// - `subidas`: Subidas de nível do grupo depois da batalha
+#[derive(Debug, Clone)]
+pub struct Desfecho {
+    pub resultado: ResultadoBatalha,
+    pub xp: u64,
+    pub subidas: Vec<SubidaNivel>,
+}
+
+/// Missão concluída e o que o grupo recebeu por ela
+///
+/// # Campos
+/// - `missao`: Nome da missão
+/// - `xp`: XP dado a cada personagem do grupo
+/// - `itens`: Nomes dos itens recebidos
+/// - `sem_espaco`: Nomes dos itens que não couberam em nenhum inventário
+/// - `subidas`: Subidas de nível causadas pelo XP
+#[derive(Debug, Clone)]
+pub struct Conclusao {
+    pub missao: String,
+    pub xp: u64,
+    pub itens: Vec<String>,
+    pub sem_espaco: Vec<String>,
+    pub subidas: Vec<SubidaNivel>,
+}
+
+impl Jogo {
+    /// Começa uma partida
+    ///
+    /// # Parâmetros
+    /// - `semente`: Semente do gerador da partida
+    pub fn new(grupo: Vec<Personagem>, estrategia: &'static dyn Estrategia, semente: u64) -> Self {
+        Jogo {
+            grupo,
+            estrategia,
+            batalhas: 0,
+            vitorias: 0,
+            local: String::new(),
+            diario: Diario::default(),
+            coletados: BTreeSet::new(),
+            rng: gerador(semente),
+        }
+    }
+
+    /// Joga uma batalha do grupo (lado 0) contra os inimigos (lado 1)
+    ///
+    /// O grupo sai da batalha como terminou: vida e mana só voltam no
+    /// próximo `descansar`, e os consumíveis usados não voltam mais. Numa
+    /// vitória, cada sobrevivente do grupo ganha o XP dos inimigos derrotados.
+    ///
+    /// # Parâmetros
+    /// - `progressao`: Regras de XP e de subida de nível
+    pub fn batalhar(
+        &mut self,
+        inimigos: Vec<Personagem>,
+        estrategia_inimiga: &'static dyn Estrategia,
+        progressao: &Progressao,
+    ) -> Desfecho {
+        let semente = self.rng.next_u64();
+        let resultado = Batalha::entre_lados(self.grupo.clone(), inimigos, semente)
+            .com_estrategia(0, self.estrategia)
+            .com_estrategia(1, estrategia_inimiga)
+            .executar();
+
+        self.grupo = resultado.lados[0].clone();
+        self.batalhas += 1;
+        let mut xp = 0;
+        let mut subidas = Vec::new();
+        if resultado.vencedor == Some(0) {
+            self.vitorias += 1;
+            xp = progressao.xp_da_vitoria(&resultado.lados[1]);
+            for personagem in self.grupo.iter_mut().filter(|p| p.esta_vivo()) {
+                subidas.extend(progressao.ganhar_xp(personagem, xp));
+            }
+        }
+        Desfecho {
+            resultado,
+            xp,
+            subidas,
+        }
+    }
+
+    /// Recupera vida e mana de todo o grupo, inclusive dos derrotados
+    pub fn descansar(&mut self) {
+        for personagem in &mut self.grupo {
+            personagem.curar(personagem.vida_maxima());
+            personagem.recuperar_mana(personagem.mana_maxima());
+        }
+    }
+
+    /// Põe o grupo no início do mapa, se ele não estiver num local dele
+    pub fn entrar(&mut self, mundo: &Mundo) {
+        if mundo.local(&self.local).is_err() {
+            self.local = mundo.inicio.clone();
+        }
+    }
+
+    /// Leva o grupo pela saída do local atual
+    ///
+    /// # Retorno
+    /// O encontro sorteado na zona do destino, se houver
+    pub fn mover(
+        &mut self,
+        mundo: &Mundo,
+        direcao: &str,
+        progressao: &Progressao,
+    ) -> Result<Option<Encontro>, ErroRpg> {
+        let destino = mundo.destino(&self.local, direcao)?.to_string();
+        self.diario.registrar_chegada(&mundo.missoes, &destino);
+        self.local = destino;
+        mundo.sortear_encontro(&self.local, &mut self.rng, progressao)
+    }
+
+    /// Luta contra um encontro do mapa
+    ///
+    /// Numa vitória, os inimigos contam para as missões. Numa derrota, o
+    /// grupo acorda no início do mapa, descansado.
+    pub fn enfrentar(
+        &mut self,
+        mundo: &Mundo,
+        encontro: Encontro,
+        progressao: &Progressao,
+    ) -> Desfecho {
+        let desfecho = self.batalhar(encontro.inimigos, encontro.estrategia, progressao);
+        match desfecho.resultado.vencedor {
+            Some(0) => {
+                for id in &encontro.ids {
+                    self.diario.registrar_derrota(&mundo.missoes, id);
+                }
+            }
+            Some(_) => {
+                self.local = mundo.inicio.clone();
+                self.descansar();
+            }
+            None => {}
+        }
+        desfecho
+    }
+
+    /// Itens do local atual que o grupo ainda não pegou
+    pub fn itens_no_local<'a>(&self, mundo: &'a Mundo) -> Vec<&'a Item> {
+        let Ok(local) = mundo.local(&self.local) else {
+            return Vec::new();
+        };
+        local
+            .itens
+            .iter()
+            .filter(|id| !self.coletados.contains(&format!("{}/{}", self.local, id)))
+            .filter_map(|id| mundo.item(id).ok())
+            .collect()
+    }
+
+    /// Pega os itens do local, cada um com o primeiro personagem em que couber
+    ///
+    /// # Retorno
+    /// Os itens pegos; os que não couberam continuam no local
+    pub fn pegar_itens(&mut self, mundo: &Mundo) -> Vec<Item> {
+        let mut pegos = Vec::new();
+        for item in self.itens_no_local(mundo) {
+            if self.guardar(item) {
+                self.coletados.insert(format!("{}/{}", self.local, item.id));
+                pegos.push(item.clone());
+            }
+        }
+        pegos
+    }
+
+    /// Guarda o item no inventário do primeiro personagem com espaço
+    fn guardar(&mut self, item: &Item) -> bool {
+        self.grupo
+            .iter_mut()
+            .any(|personagem| personagem.inventario.adicionar(item.clone(), 1).is_ok())
+    }
+
+    /// Conclui as missões com o objetivo cumprido e entrega as recompensas
+    ///
+    /// Os itens pedidos por missões de obter são tirados do grupo.
+    pub fn concluir_missoes(&mut self, mundo: &Mundo, progressao: &Progressao) -> Vec<Conclusao> {
+        let mut conclusoes = Vec::new();
+        for id in self.diario.prontas(&mundo.missoes, &self.grupo) {
+            let missao = &mundo.missoes[&id];
+            if let Objetivo::Obter { item, quantidade } = &missao.objetivo {
+                let mut faltam = *quantidade;
+                for personagem in &mut self.grupo {
+                    while faltam > 0 && personagem.inventario.remover(item).is_ok() {
+                        faltam -= 1;
+                    }
+                }
+            }
+            self.diario.concluir(&id);
+
+            let recompensa = &missao.recompensa;
+            let mut conclusao = Conclusao {
+                missao: missao.nome.clone(),
+                xp: recompensa.xp,
+                itens: Vec::new(),
+                sem_espaco: Vec::new(),
+                subidas: Vec::new(),
+            };
+            for personagem in &mut self.grupo {
+                conclusao
+                    .subidas
+                    .extend(progressao.ganhar_xp(personagem, recompensa.xp));
+            }
+            for item in recompensa.itens.iter().filter_map(|id| mundo.item(id).ok()) {
+                if self.guardar(item) {
+                    conclusao.itens.push(item.nome.clone());
+                } else {
+                    conclusao.sem_espaco.push(item.nome.clone());
+                }
+            }
+            conclusoes.push(conclusao);
+        }
+        conclusoes
+    }
+}
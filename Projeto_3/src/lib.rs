@@ -0,0 +1,17 @@
+pub mod classes;
+pub mod combate;
+pub mod dialogo;
+pub mod errors;
+pub mod eventos;
+pub mod habilidades;
+pub mod ia;
+pub mod inventario;
+pub mod itens;
+pub mod jogo;
+pub mod missoes;
+pub mod mundo;
+pub mod persistencia;
+pub mod personagem;
+pub mod progressao;
+pub mod simulacao;
+pub mod status;
@@ -1,3 +1,623 @@
+use rpg::classes::{CLASSES, Classe, classe_por_nome};
+use rpg::dialogo::Conversa;
+use rpg::errors::ErroRpg;
+use rpg::ia::{ESTRATEGIAS, Estrategia};
+use rpg::itens::{Catalogo, Item};
+use rpg::jogo::{Desfecho, Jogo};
+use rpg::mundo::Mundo;
+use rpg::persistencia::{NUMERO_SLOTS, Slots};
+use rpg::personagem::Personagem;
+use rpg::progressao::Progressao;
+use rpg::simulacao::Cenario;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Arquivo de itens usado quando nenhum é informado na linha de comando
+const ARQUIVO_ITENS: &str = "dados/itens.toml";
+
+/// Regras de progressão usadas quando nenhum arquivo é informado
+const ARQUIVO_PROGRESSAO: &str = "dados/progressao.toml";
+
+/// Mapa do modo de exploração
+const ARQUIVO_MUNDO: &str = "dados/mundo.toml";
+
+/// Maior número de personagens de cada lado no modo interativo
+const MAXIMO_POR_LADO: usize = 4;
+
+/// Pasta onde ficam os slots de save
+const PASTA_SAVES: &str = "saves";
+
+/// Formas de executar o programa
+const USO: &str = "Uso: Projeto_3 [arquivo de itens]
+       Projeto_3 simular <arquivo de cenário> [arquivo de itens]
+       Projeto_3 progressao <classe> <nível> [arquivo de progressão]";
+
+/// Lê uma linha digitada pelo usuário, sem espaços nas pontas
+///
+/// # Retorno
+/// `None` quando a entrada termina (Ctrl+D ou fim do arquivo)
+fn read_input() -> Option<String> {
+    io::stdout().flush().expect("Falha ao exibir a mensagem");
+    let mut input = String::new();
+    let lidos = io::stdin()
+        .read_line(&mut input)
+        .expect("Falha ao ler a entrada");
+    (lidos > 0).then(|| input.trim().to_string())
+}
+
+/// Pergunta a classe até o usuário escolher uma das opções
+fn ler_classe() -> Option<&'static dyn Classe> {
+    loop {
+        for (posicao, classe) in CLASSES.iter().enumerate() {
+            println!("  {}. {}", posicao + 1, classe.nome());
+        }
+        print!("Classe: ");
+        match read_input()?.parse::<usize>() {
+            Ok(opcao) if (1..=CLASSES.len()).contains(&opcao) => return Some(CLASSES[opcao - 1]),
+            _ => println!("  Erro: Escolha uma das classes da lista."),
+        }
+    }
+}
+
+/// Pergunta a estratégia de IA de um lado
+fn ler_estrategia() -> Option<&'static dyn Estrategia> {
+    loop {
+        for (posicao, estrategia) in ESTRATEGIAS.iter().enumerate() {
+            println!("  {}. {}", posicao + 1, estrategia.nome());
+        }
+        print!("Estratégia: ");
+        match read_input()?.parse::<usize>() {
+            Ok(opcao) if (1..=ESTRATEGIAS.len()).contains(&opcao) => {
+                return Some(ESTRATEGIAS[opcao - 1]);
+            }
+            _ => println!("  Erro: Escolha uma das estratégias da lista."),
+        }
+    }
+}
+
+/// Mostra equipamento e inventário do personagem
+fn exibir_equipamento(personagem: &Personagem) {
+    let nome_item =
+        |item: Option<&Item>| item.map_or("(nenhuma)".to_string(), |item| item.nome.clone());
+    println!("  Arma: {}", nome_item(personagem.arma.as_ref()));
+    println!("  Armadura: {}", nome_item(personagem.armadura.as_ref()));
+    println!(
+        "  Inventário (peso {}/{}):",
+        personagem.inventario.peso_atual(),
+        personagem.inventario.peso_maximo()
+    );
+    for pilha in personagem.inventario.pilhas() {
+        println!("    {}x {}", pilha.quantidade, pilha.item.nome);
+    }
+}
+
+/// Deixa o usuário escolher itens do catálogo para o personagem
+///
+/// Armas e armaduras permitidas para a classe são equipadas na hora.
+fn escolher_itens(personagem: &mut Personagem, catalogo: &Catalogo) -> Option<()> {
+    if catalogo.is_empty() {
+        return Some(());
+    }
+    let itens: Vec<_> = catalogo.itens().collect();
+
+    loop {
+        println!("\nItens disponíveis:");
+        for (posicao, item) in itens.iter().enumerate() {
+            let aviso = match item.encaixe() {
+                Some(_) if !personagem.classe.pode_equipar(item) => " [classe não pode usar]",
+                _ => "",
+            };
+            println!("  {}. {}{}", posicao + 1, item, aviso);
+        }
+        exibir_equipamento(personagem);
+        print!("Item para levar (vazio para terminar): ");
+        let texto = read_input()?;
+        if texto.is_empty() {
+            break;
+        }
+
+        let Some(item) = texto
+            .parse::<usize>()
+            .ok()
+            .and_then(|opcao| itens.get(opcao.wrapping_sub(1)))
+        else {
+            println!("  Erro: Escolha um dos itens da lista.");
+            continue;
+        };
+        if let Err(e) = personagem.inventario.adicionar((*item).clone(), 1) {
+            println!("  Erro: {}", e);
+            continue;
+        }
+        if item.encaixe().is_some()
+            && let Err(e) = personagem.equipar(&item.id)
+        {
+            println!("  Aviso: {} (o item ficou no inventário)", e);
+        }
+    }
+
+    // Começa a batalha com a vida cheia, já contando o equipamento
+    personagem.curar(personagem.vida_maxima());
+    Some(())
+}
+
+/// Pergunta nome, classe e nível até formar um personagem válido
+///
+/// # Parâmetros
+/// - `usados`: Nomes já escolhidos para a batalha, que não podem se repetir
+fn criar_personagem(
+    numero: usize,
+    catalogo: &Catalogo,
+    progressao: &Progressao,
+    usados: &[String],
+) -> Option<Personagem> {
+    println!("\n--- Personagem {} ---", numero);
+    loop {
+        print!("Nome: ");
+        let nome = read_input()?;
+        if usados.contains(&nome) {
+            println!("  Erro: {}", ErroRpg::NomeRepetido(nome));
+            continue;
+        }
+        let classe = ler_classe()?;
+        print!("Nível: ");
+        let nivel = match read_input()?.parse() {
+            Ok(nivel) => nivel,
+            Err(_) => {
+                println!("  Erro: Por favor, insira um número válido.");
+                continue;
+            }
+        };
+
+        match progressao.criar_personagem(&nome, classe, nivel) {
+            Ok(mut personagem) => {
+                escolher_itens(&mut personagem, catalogo)?;
+                return Some(personagem);
+            }
+            Err(e) => println!("  Erro: {}", e),
+        }
+    }
+}
+
+/// Monta um lado da batalha: quantos personagens, cada um e a estratégia
+fn criar_lado(
+    lado: usize,
+    catalogo: &Catalogo,
+    progressao: &Progressao,
+    usados: &mut Vec<String>,
+) -> Option<(Vec<Personagem>, &'static dyn Estrategia)> {
+    println!("\n===== Lado {} =====", lado + 1);
+    let quantidade = loop {
+        print!("Personagens no lado (1 a {}): ", MAXIMO_POR_LADO);
+        match read_input()?.parse::<usize>() {
+            Ok(quantidade) if (1..=MAXIMO_POR_LADO).contains(&quantidade) => break quantidade,
+            _ => println!("  Erro: Por favor, insira um número válido."),
+        }
+    };
+
+    let mut personagens = Vec::new();
+    for numero in 1..=quantidade {
+        let personagem = criar_personagem(numero, catalogo, progressao, usados)?;
+        usados.push(personagem.nome.clone());
+        personagens.push(personagem);
+    }
+    println!("\nComo o lado {} escolhe quem atacar?", lado + 1);
+    Some((personagens, ler_estrategia()?))
+}
+
+/// Modo sem interação: joga as batalhas do cenário e mostra as estatísticas
+fn simular(caminho: &Path, catalogo: &Catalogo, progressao: &Progressao) -> Result<(), ErroRpg> {
+    let cenario = Cenario::carregar(caminho, catalogo, progressao)?;
+    for (lado, personagens) in cenario.lados.iter().enumerate() {
+        println!("Lado {} ({}):", lado + 1, cenario.estrategias[lado].nome());
+        for personagem in personagens {
+            println!("  {}", personagem);
+        }
+    }
+    println!(
+        "\nSimulando {} batalhas a partir da semente {}...\n",
+        cenario.batalhas, cenario.semente
+    );
+    println!("{}", cenario.simular());
+    Ok(())
+}
+
+/// Mostra como uma classe evolui do nível 1 até o `nivel` pelas regras
+fn exibir_progressao(classe: &str, nivel: u32, progressao: &Progressao) -> Result<(), ErroRpg> {
+    let classe = classe_por_nome(classe)?;
+    let linhas = progressao.simular(classe, nivel)?;
+
+    println!("Progressão de {} até o nível {}:\n", classe.nome(), nivel);
+    println!(
+        "{:>5} {:>8} {:>5} {:>5} {:>5} {:>5} {:>5}  Habilidades novas",
+        "Nível", "XP", "HP", "MP", "ATQ", "DEF", "VEL"
+    );
+    for linha in linhas {
+        let atributos = linha.atributos;
+        // Níveis sem habilidade nova não deixam espaços no fim da linha
+        let texto = format!(
+            "{:>5} {:>8} {:>5} {:>5} {:>5} {:>5} {:>5}  {}",
+            linha.nivel,
+            linha.xp_total,
+            atributos.vida,
+            atributos.mana,
+            atributos.ataque,
+            atributos.defesa,
+            atributos.velocidade,
+            linha.habilidades.join(", ")
+        );
+        println!("{}", texto.trim_end());
+    }
+    Ok(())
+}
+
+/// Carrega as regras de progressão do arquivo, ou as padrão se ele falhar
+fn carregar_progressao(caminho: &Path) -> Progressao {
+    Progressao::carregar(caminho).unwrap_or_else(|e| {
+        println!("Aviso: {}. Usando a progressão padrão.", e);
+        Progressao::default()
+    })
+}
+
+/// Carrega uma partida salva ou cria o grupo de uma nova
+fn iniciar_jogo(catalogo: &Catalogo, progressao: &Progressao, slots: &Slots) -> Option<Jogo> {
+    let ocupados = slots.ocupados();
+    if !ocupados.is_empty() {
+        println!("\nJogos salvos nos slots: {:?}", ocupados);
+        loop {
+            print!("Slot para carregar (vazio para nova partida): ");
+            let texto = read_input()?;
+            if texto.is_empty() {
+                break;
+            }
+            match texto.parse().map(|slot| slots.carregar(slot)) {
+                Ok(Ok(jogo)) => {
+                    println!(
+                        "Partida carregada: {} vitórias em {} batalhas.",
+                        jogo.vitorias, jogo.batalhas
+                    );
+                    return Some(jogo);
+                }
+                Ok(Err(e)) => println!("  Erro: {}", e),
+                Err(_) => println!("  Erro: Por favor, insira um número válido."),
+            }
+        }
+    }
+
+    let (grupo, estrategia) = criar_lado(0, catalogo, progressao, &mut Vec::new())?;
+    print!("\nSemente da partida (vazio para aleatória): ");
+    let semente = read_input()?.parse().unwrap_or_else(|_| rand::random());
+    println!("Semente: {}", semente);
+    Some(Jogo::new(grupo, estrategia, semente))
+}
+
+/// Mostra os eventos e o resultado de uma batalha da partida
+fn exibir_desfecho(desfecho: &Desfecho) {
+    let resultado = &desfecho.resultado;
+    for evento in &resultado.eventos {
+        println!("{}", evento);
+    }
+    match resultado.vencedor {
+        Some(lado) => {
+            println!(
+                "\nO lado {} venceu em {} rodadas!",
+                lado + 1,
+                resultado.rodadas
+            );
+            for vencedor in resultado.vencedores() {
+                println!("  {} - {} HP", vencedor.nome, vencedor.vida);
+            }
+        }
+        None => println!("\nEmpate depois de {} rodadas.", resultado.rodadas),
+    }
+    if desfecho.xp > 0 {
+        println!("Os sobreviventes ganharam {} XP.", desfecho.xp);
+    }
+    for subida in &desfecho.subidas {
+        println!("{}", subida);
+    }
+}
+
+/// Mostra o grupo, com o XP que falta para cada um subir de nível
+fn exibir_grupo(jogo: &Jogo, progressao: &Progressao) {
+    println!("\nSeu grupo ({}):", jogo.estrategia.nome());
+    for personagem in &jogo.grupo {
+        println!(
+            "  {} | XP {}/{}",
+            personagem,
+            personagem.xp,
+            progressao.curva.xp_para_subir(personagem.nivel)
+        );
+    }
+}
+
+/// Pergunta em qual slot salvar a partida, até salvar ou o usuário desistir
+fn perguntar_slot(jogo: &Jogo, slots: &Slots) -> Option<()> {
+    loop {
+        print!(
+            "\nSalvar em qual slot? (1 a {}, vazio para não salvar): ",
+            NUMERO_SLOTS
+        );
+        let texto = read_input()?;
+        if texto.is_empty() {
+            break;
+        }
+        match texto.parse().map(|slot| (slot, slots.salvar(slot, jogo))) {
+            Ok((slot, Ok(()))) => {
+                println!("Jogo salvo no slot {}.", slot);
+                break;
+            }
+            Ok((_, Err(e))) => println!("  Erro: {}", e),
+            Err(_) => println!("  Erro: Por favor, insira um número válido."),
+        }
+    }
+    Some(())
+}
+
+/// Batalhas do grupo contra inimigos criados pelo usuário, até ele parar
+fn jogar(
+    jogo: &mut Jogo,
+    catalogo: &Catalogo,
+    progressao: &Progressao,
+    slots: &Slots,
+) -> Option<()> {
+    loop {
+        jogo.descansar();
+        exibir_grupo(jogo, progressao);
+
+        let mut usados: Vec<String> = jogo.grupo.iter().map(|p| p.nome.clone()).collect();
+        let (inimigos, estrategia) = criar_lado(1, catalogo, progressao, &mut usados)?;
+        println!();
+        let desfecho = jogo.batalhar(inimigos, estrategia, progressao);
+        exibir_desfecho(&desfecho);
+        println!(
+            "Partida: {} vitórias em {} batalhas.",
+            jogo.vitorias, jogo.batalhas
+        );
+
+        perguntar_slot(jogo, slots)?;
+
+        print!("\nNova batalha? (s/n): ");
+        if !read_input()?.eq_ignore_ascii_case("s") {
+            return Some(());
+        }
+    }
+}
+
+/// Mostra onde o grupo está: descrição, saídas, NPCs e itens
+fn descrever_local(jogo: &Jogo, mundo: &Mundo) {
+    let Ok(local) = mundo.local(&jogo.local) else {
+        return;
+    };
+    println!("\n== {} ==\n{}", local.nome, local.descricao);
+    let saidas: Vec<&str> = local.saidas.keys().map(String::as_str).collect();
+    println!("Saídas: {}", saidas.join(", "));
+    for id in &local.npcs {
+        println!("Aqui está {}.", mundo.npcs[id].nome);
+    }
+    let itens: Vec<&str> = jogo
+        .itens_no_local(mundo)
+        .into_iter()
+        .map(|item| item.nome.as_str())
+        .collect();
+    if !itens.is_empty() {
+        println!("No chão: {}", itens.join(", "));
+    }
+    if local.descanso {
+        println!("Dá para descansar aqui.");
+    }
+}
+
+/// Conversa com um NPC do local, aceitando as missões oferecidas
+fn conversar(jogo: &mut Jogo, mundo: &Mundo) -> Option<()> {
+    let npcs = mundo
+        .local(&jogo.local)
+        .map_or(&[][..], |local| &local.npcs);
+    let id = match npcs {
+        [] => {
+            println!("Não há ninguém para conversar aqui.");
+            return Some(());
+        }
+        [id] => id,
+        _ => loop {
+            for (posicao, id) in npcs.iter().enumerate() {
+                println!("  {}. {}", posicao + 1, mundo.npcs[id].nome);
+            }
+            print!("Falar com: ");
+            match read_input()?.parse::<usize>() {
+                Ok(opcao) if (1..=npcs.len()).contains(&opcao) => break &npcs[opcao - 1],
+                _ => println!("  Erro: Escolha alguém da lista."),
+            }
+        },
+    };
+
+    let npc = &mundo.npcs[id];
+    let mut conversa = Conversa::new(npc);
+    while let Some(no) = conversa.atual() {
+        println!("\n{}: \"{}\"", npc.nome, no.fala);
+        if conversa.encerrada() {
+            break;
+        }
+        for (posicao, opcao) in no.opcoes.iter().enumerate() {
+            println!("  {}. {}", posicao + 1, opcao.texto);
+        }
+        print!("Resposta: ");
+        let escolha = read_input()?
+            .parse::<usize>()
+            .map_err(|_| ErroRpg::OpcaoInvalida(0))
+            .and_then(|opcao| conversa.escolher(opcao.wrapping_sub(1)));
+        match escolha {
+            Ok(Some(missao)) if jogo.diario.aceitar(missao) => {
+                println!("Missão aceita: {}", mundo.missoes[missao].nome);
+            }
+            Ok(Some(_)) => println!("Você já aceitou essa missão."),
+            Ok(None) => {}
+            Err(_) => println!("  Erro: Escolha uma das respostas."),
+        }
+    }
+    Some(())
+}
+
+/// Mostra as missões ativas e o progresso de cada uma
+fn exibir_missoes(jogo: &Jogo, mundo: &Mundo) {
+    let mut ativas = jogo.diario.ativas().peekable();
+    if ativas.peek().is_none() {
+        println!("Nenhuma missão ativa.");
+    }
+    for id in ativas {
+        let missao = &mundo.missoes[id];
+        println!(
+            "  {} - {} ({}/{})",
+            missao.nome,
+            missao.descricao,
+            jogo.diario.progresso(id, missao, &jogo.grupo),
+            missao.objetivo.meta()
+        );
+    }
+}
+
+/// Anda pelo mapa até o usuário sair
+fn explorar(jogo: &mut Jogo, mundo: &Mundo, progressao: &Progressao, slots: &Slots) -> Option<()> {
+    const AJUDA: &str = "Comandos: <direção> (ou ir <direção>), olhar, falar, pegar, \
+                         missoes, grupo, descansar, salvar, sair";
+    println!("\n{}", AJUDA);
+    descrever_local(jogo, mundo);
+    loop {
+        print!("\n> ");
+        let texto = read_input()?.to_lowercase();
+        let comando = texto.strip_prefix("ir ").unwrap_or(&texto).trim();
+        match comando {
+            "" => continue,
+            "ajuda" => println!("{}", AJUDA),
+            "olhar" => descrever_local(jogo, mundo),
+            "falar" => conversar(jogo, mundo)?,
+            "missoes" | "missões" => exibir_missoes(jogo, mundo),
+            "grupo" => exibir_grupo(jogo, progressao),
+            "salvar" => perguntar_slot(jogo, slots)?,
+            "sair" => return Some(()),
+            "pegar" => {
+                let pegos = jogo.pegar_itens(mundo);
+                if pegos.is_empty() {
+                    println!("Não há nada que caiba no inventário do grupo.");
+                }
+                for item in pegos {
+                    println!("Pegou: {}", item.nome);
+                }
+            }
+            "descansar" => match mundo.local(&jogo.local) {
+                Ok(local) if local.descanso => {
+                    jogo.descansar();
+                    println!("O grupo descansou e recuperou vida e mana.");
+                }
+                _ => println!("Não é seguro descansar aqui."),
+            },
+            direcao => match jogo.mover(mundo, direcao, progressao) {
+                Ok(encontro) => {
+                    descrever_local(jogo, mundo);
+                    if let Some(encontro) = encontro {
+                        println!("\nInimigos à vista!");
+                        for inimigo in &encontro.inimigos {
+                            println!("  {}", inimigo);
+                        }
+                        println!();
+                        let desfecho = jogo.enfrentar(mundo, encontro, progressao);
+                        exibir_desfecho(&desfecho);
+                        if desfecho.resultado.vencedor == Some(1) {
+                            println!("\nO grupo foi derrotado e acorda no início, descansado.");
+                            descrever_local(jogo, mundo);
+                        }
+                    }
+                }
+                Err(e) => println!("  Erro: {}", e),
+            },
+        }
+
+        for conclusao in jogo.concluir_missoes(mundo, progressao) {
+            println!("\nMissão concluída: {}!", conclusao.missao);
+            if conclusao.xp > 0 {
+                println!("O grupo ganhou {} XP.", conclusao.xp);
+            }
+            for item in &conclusao.itens {
+                println!("Recebeu: {}", item);
+            }
+            for item in &conclusao.sem_espaco {
+                println!("Sem espaço para {}, que ficou para trás.", item);
+            }
+            for subida in &conclusao.subidas {
+                println!("{}", subida);
+            }
+        }
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let argumentos: Vec<String> = std::env::args().skip(1).collect();
+    if argumentos.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USO);
+        return;
+    }
+    // Nenhum comando aceita opções; qualquer outra coisa com "-" seria lida como arquivo
+    if let Some(opcao) = argumentos.iter().find(|arg| arg.starts_with('-')) {
+        eprintln!("Opção desconhecida: {}\n{}", opcao, USO);
+        std::process::exit(2);
+    }
+
+    println!("=== Simulador de Batalhas RPG ===");
+
+    if argumentos.first().map(String::as_str) == Some("progressao") {
+        let nivel = argumentos.get(2).and_then(|nivel| nivel.parse().ok());
+        let (Some(classe), Some(nivel)) = (argumentos.get(1), nivel) else {
+            eprintln!("Uso: progressao <classe> <nível> [arquivo de progressão]");
+            std::process::exit(2);
+        };
+        let caminho = argumentos.get(3).map_or(ARQUIVO_PROGRESSAO, String::as_str);
+        let progressao = carregar_progressao(Path::new(caminho));
+        if let Err(e) = exibir_progressao(classe, nivel, &progressao) {
+            eprintln!("Erro: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let (cenario, arquivo_itens) = match argumentos.first().map(String::as_str) {
+        Some("simular") => match argumentos.get(1) {
+            Some(cenario) => (Some(PathBuf::from(cenario)), argumentos.get(2)),
+            None => {
+                eprintln!("Uso: simular <arquivo de cenário> [arquivo de itens]");
+                std::process::exit(2);
+            }
+        },
+        _ => (None, argumentos.first()),
+    };
+    let caminho = arquivo_itens.map_or_else(|| PathBuf::from(ARQUIVO_ITENS), PathBuf::from);
+    let catalogo = Catalogo::carregar(&caminho).unwrap_or_else(|e| {
+        println!("Aviso: {}. Batalhas sem itens.", e);
+        Catalogo::default()
+    });
+    let progressao = carregar_progressao(Path::new(ARQUIVO_PROGRESSAO));
+
+    if let Some(cenario) = cenario {
+        if let Err(e) = simular(&cenario, &catalogo, &progressao) {
+            eprintln!("Erro: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mundo = Mundo::carregar(Path::new(ARQUIVO_MUNDO), &catalogo)
+        .inspect_err(|e| println!("Aviso: {}. Modo arena, sem mapa.", e))
+        .ok();
+    let slots = Slots::new(PASTA_SAVES);
+    if let Some(mut jogo) = iniciar_jogo(&catalogo, &progressao, &slots) {
+        match &mundo {
+            Some(mundo) => {
+                jogo.entrar(mundo);
+                explorar(&mut jogo, mundo, &progressao, &slots);
+            }
+            None => {
+                jogar(&mut jogo, &catalogo, &progressao, &slots);
+            }
+        }
+    }
+
+    println!("Até a próxima!");
 }
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::personagem::Personagem;
+
+/// O que uma missão pede
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "tipo", rename_all = "snake_case")]
+pub enum Objetivo {
+    /// Derrotar inimigos de um tipo (o `id` do inimigo na zona)
+    Derrotar { inimigo: String, quantidade: u32 },
+    /// Ter itens no inventário do grupo; eles são entregues ao concluir
+    Obter { item: String, quantidade: u32 },
+    /// Chegar a um local do mapa
+    Alcancar { local: String },
+}
+
+impl Objetivo {
+    /// Progresso necessário para concluir
+    pub fn meta(&self) -> u32 {
+        match self {
+            Objetivo::Derrotar { quantidade, .. } | Objetivo::Obter { quantidade, .. } => {
+                *quantidade
+            }
+            Objetivo::Alcancar { .. } => 1,
+        }
+    }
+}
+
+/// Recompensa de uma missão concluída
+///
+/// # Campos
+/// - `xp`: XP dado a cada personagem do grupo
+/// - `itens`: Ids de itens do catálogo
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Recompensa {
+    #[serde(default)]
+    pub xp: u64,
+    #[serde(default)]
+    pub itens: Vec<String>,
+}
+
+/// Missão oferecida por um NPC
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Missao {
+    pub nome: String,
+    pub descricao: String,
+    pub objetivo: Objetivo,
+    #[serde(default)]
+    pub recompensa: Recompensa,
+}
+
+/// Missões aceitas e concluídas pelo grupo, guardado no save
+///
+/// # Campos
+/// - `ativas`: Progresso de cada missão aceita e ainda não concluída
+/// - `concluidas`: Missões já concluídas
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Diario {
+    ativas: BTreeMap<String, u32>,
+    concluidas: BTreeSet<String>,
+}
+
+impl Diario {
+    /// Aceita a missão
+    ///
+    /// # Retorno
+    /// `false` se ela já estava ativa ou concluída
+    pub fn aceitar(&mut self, id: &str) -> bool {
+        if self.concluidas.contains(id) || self.ativas.contains_key(id) {
+            return false;
+        }
+        self.ativas.insert(id.to_string(), 0);
+        true
+    }
+
+    /// Ids das missões ativas
+    pub fn ativas(&self) -> impl Iterator<Item = &String> {
+        self.ativas.keys()
+    }
+
+    pub fn concluida(&self, id: &str) -> bool {
+        self.concluidas.contains(id)
+    }
+
+    /// Conta um inimigo derrotado nas missões que pedem esse inimigo
+    pub fn registrar_derrota(&mut self, missoes: &BTreeMap<String, Missao>, inimigo: &str) {
+        self.avancar(missoes, |objetivo| {
+            matches!(objetivo, Objetivo::Derrotar { inimigo: alvo, .. } if alvo == inimigo)
+        });
+    }
+
+    /// Marca a chegada ao local nas missões que pedem esse local
+    pub fn registrar_chegada(&mut self, missoes: &BTreeMap<String, Missao>, local: &str) {
+        self.avancar(
+            missoes,
+            |objetivo| matches!(objetivo, Objetivo::Alcancar { local: alvo } if alvo == local),
+        );
+    }
+
+    fn avancar(&mut self, missoes: &BTreeMap<String, Missao>, conta: impl Fn(&Objetivo) -> bool) {
+        for (id, progresso) in &mut self.ativas {
+            if let Some(missao) = missoes.get(id)
+                && conta(&missao.objetivo)
+            {
+                *progresso = (*progresso + 1).min(missao.objetivo.meta());
+            }
+        }
+    }
+
+    /// Progresso da missão ativa; itens são contados no inventário do grupo
+    pub fn progresso(&self, id: &str, missao: &Missao, grupo: &[Personagem]) -> u32 {
+        match &missao.objetivo {
+            Objetivo::Obter { item, quantidade } => grupo
+                .iter()
+                .map(|personagem| personagem.inventario.quantidade(item))
+                .sum::<u32>()
+                .min(*quantidade),
+            _ => self.ativas.get(id).copied().unwrap_or(0),
+        }
+    }
+
+    /// Missões ativas com o objetivo cumprido, prontas para concluir
+    pub fn prontas(&self, missoes: &BTreeMap<String, Missao>, grupo: &[Personagem]) -> Vec<String> {
+        self.ativas
+            .keys()
+            .filter(|id| {
+                missoes.get(*id).is_some_and(|missao| {
+                    self.progresso(id, missao, grupo) >= missao.objetivo.meta()
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Passa a missão de ativa para concluída
+    pub fn concluir(&mut self, id: &str) {
+        if self.ativas.remove(id).is_some() {
+            self.concluidas.insert(id.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::Guerreiro;
+    use crate::itens::Item;
+
+    fn missoes() -> BTreeMap<String, Missao> {
+        toml::from_str(
+            r#"
+            [lobos]
+            nome = "Caça aos lobos"
+            descricao = "Derrote 2 lobos."
+            objetivo = { tipo = "derrotar", inimigo = "lobo", quantidade = 2 }
+            recompensa = { xp = 50 }
+
+            [ervas]
+            nome = "Ervas"
+            descricao = "Traga 2 ervas."
+            objetivo = { tipo = "obter", item = "erva", quantidade = 2 }
+
+            [torre]
+            nome = "A torre"
+            descricao = "Chegue à torre."
+            objetivo = { tipo = "alcancar", local = "torre" }
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_progresso_das_missoes() {
+        let missoes = missoes();
+        let mut diario = Diario::default();
+        let mut grupo = vec![Personagem::new("Conan", &Guerreiro, 1).unwrap()];
+        for id in ["lobos", "ervas", "torre"] {
+            assert!(diario.aceitar(id));
+        }
+        assert!(!diario.aceitar("lobos"));
+
+        diario.registrar_derrota(&missoes, "lobo");
+        diario.registrar_derrota(&missoes, "goblin");
+        diario.registrar_chegada(&missoes, "torre");
+        assert_eq!(diario.progresso("lobos", &missoes["lobos"], &grupo), 1);
+        assert_eq!(diario.prontas(&missoes, &grupo), vec!["torre"]);
+
+        diario.registrar_derrota(&missoes, "lobo");
+        diario.registrar_derrota(&missoes, "lobo");
+        assert_eq!(diario.progresso("lobos", &missoes["lobos"], &grupo), 2);
+
+        let erva: Item = serde_json::from_str(
+            r#"{"id": "erva", "nome": "Erva", "peso": 1, "categoria": "consumivel",
+                "efeito": "cura", "pontos": 10}"#,
+        )
+        .unwrap();
+        grupo[0].inventario.adicionar(erva, 2).unwrap();
+        assert_eq!(
+            diario.prontas(&missoes, &grupo),
+            vec!["ervas", "lobos", "torre"]
+        );
+
+        diario.concluir("lobos");
+        assert!(diario.concluida("lobos"));
+        assert!(!diario.aceitar("lobos"));
+        assert_eq!(diario.ativas().count(), 2);
+    }
+}
@@ -0,0 +1,455 @@
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::classes::{self, Classe};
+use crate::combate::Gerador;
+use crate::dialogo::Npc;
+use crate::errors::ErroRpg;
+use crate::ia::{self, ESTRATEGIAS, Estrategia};
+use crate::itens::{Catalogo, Item};
+use crate::missoes::{Missao, Objetivo};
+use crate::personagem::{NIVEL_MAXIMO, Personagem};
+use crate::progressao::Progressao;
+
+/// Lugar do mapa
+///
+/// # Campos
+/// - `nome`, `descricao`: Texto exibido ao chegar
+/// - `zona`: Zona de encontros do local; sem ela, o local é seguro
+/// - `saidas`: Local de destino (pelo id) de cada direção, como "norte" ou "entrar"
+/// - `npcs`: Ids dos NPCs que estão no local
+/// - `itens`: Ids de itens do catálogo que o grupo pode pegar, uma vez só
+/// - `descanso`: Se o grupo pode descansar no local
+#[derive(Debug, Clone, Deserialize)]
+pub struct Local {
+    pub nome: String,
+    pub descricao: String,
+    pub zona: Option<String>,
+    #[serde(default)]
+    pub saidas: BTreeMap<String, String>,
+    #[serde(default)]
+    pub npcs: Vec<String>,
+    #[serde(default)]
+    pub itens: Vec<String>,
+    #[serde(default)]
+    pub descanso: bool,
+}
+
+/// Inimigo que pode aparecer numa zona
+///
+/// # Campos
+/// - `id`: Tipo do inimigo, usado nos objetivos das missões
+/// - `nivel_minimo`, `nivel_maximo`: Faixa do nível sorteado
+/// - `itens`: Ids de itens do catálogo; armas e armaduras são equipadas
+#[derive(Debug, Clone, Deserialize)]
+pub struct InimigoZona {
+    pub id: String,
+    pub nome: String,
+    #[serde(with = "classes::pelo_nome")]
+    pub classe: &'static dyn Classe,
+    pub nivel_minimo: u32,
+    pub nivel_maximo: u32,
+    #[serde(default)]
+    pub itens: Vec<String>,
+}
+
+/// Região com encontros aleatórios
+///
+/// # Campos
+/// - `chance`: Chance (de 0 a 1) de um encontro a cada chegada num local da zona
+/// - `maximo`: Maior número de inimigos num encontro
+/// - `estrategia`: Como os inimigos escolhem quem atacar
+/// - `inimigos`: Inimigos sorteados nos encontros
+#[derive(Debug, Clone, Deserialize)]
+pub struct Zona {
+    pub chance: f64,
+    #[serde(default = "maximo_padrao")]
+    pub maximo: usize,
+    #[serde(with = "ia::pelo_nome", default = "estrategia_padrao")]
+    pub estrategia: &'static dyn Estrategia,
+    pub inimigos: Vec<InimigoZona>,
+}
+
+fn maximo_padrao() -> usize {
+    1
+}
+
+fn estrategia_padrao() -> &'static dyn Estrategia {
+    ESTRATEGIAS[0]
+}
+
+/// Inimigos sorteados numa zona, prontos para a batalha
+///
+/// # Campos
+/// - `inimigos`: Personagens do encontro
+/// - `ids`: Tipo de cada inimigo, na mesma ordem
+/// - `estrategia`: Estratégia dos inimigos
+#[derive(Debug, Clone)]
+pub struct Encontro {
+    pub inimigos: Vec<Personagem>,
+    pub ids: Vec<String>,
+    pub estrategia: &'static dyn Estrategia,
+}
+
+/// Mapa do jogo, carregado de um arquivo de dados
+///
+/// O mapa é um grafo: cada local lista as saídas para outros locais. Locais,
+/// zonas, NPCs e missões são guardados pelo id.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Mundo {
+    pub inicio: String,
+    pub locais: BTreeMap<String, Local>,
+    #[serde(default)]
+    pub zonas: BTreeMap<String, Zona>,
+    #[serde(default)]
+    pub npcs: BTreeMap<String, Npc>,
+    #[serde(default)]
+    pub missoes: BTreeMap<String, Missao>,
+    #[serde(skip)]
+    catalogo: Catalogo,
+}
+
+impl Mundo {
+    /// Carrega o mapa de um arquivo `.json` ou `.toml`
+    ///
+    /// # Parâmetros
+    /// - `catalogo`: Catálogo onde estão os itens citados no mapa
+    pub fn carregar(caminho: &Path, catalogo: &Catalogo) -> Result<Self, ErroRpg> {
+        let texto = std::fs::read_to_string(caminho).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Erro ao ler {}: {}", caminho.display(), e))
+        })?;
+        match caminho.extension().and_then(|extensao| extensao.to_str()) {
+            Some("toml") => Mundo::de_toml(&texto, catalogo),
+            _ => Mundo::de_json(&texto, catalogo),
+        }
+    }
+
+    pub fn de_json(texto: &str, catalogo: &Catalogo) -> Result<Self, ErroRpg> {
+        let mundo: Mundo = serde_json::from_str(texto)
+            .map_err(|e| ErroRpg::ErroPersistencia(format!("Mapa inválido: {}", e)))?;
+        mundo.validar(catalogo)
+    }
+
+    pub fn de_toml(texto: &str, catalogo: &Catalogo) -> Result<Self, ErroRpg> {
+        let mundo: Mundo = toml::from_str(texto)
+            .map_err(|e| ErroRpg::ErroPersistencia(format!("Mapa inválido: {}", e)))?;
+        mundo.validar(catalogo)
+    }
+
+    /// Confere se tudo o que o mapa cita existe
+    fn validar(mut self, catalogo: &Catalogo) -> Result<Self, ErroRpg> {
+        let invalido = |motivo: String| Err(ErroRpg::MundoInvalido(motivo));
+        self.local(&self.inicio)?;
+
+        for (id, local) in &self.locais {
+            for (direcao, destino) in &local.saidas {
+                if !self.locais.contains_key(destino) {
+                    return invalido(format!(
+                        "a saída {} de {} leva ao local inexistente {}",
+                        direcao, id, destino
+                    ));
+                }
+            }
+            if let Some(zona) = &local.zona
+                && !self.zonas.contains_key(zona)
+            {
+                return invalido(format!("{} fica na zona inexistente {}", id, zona));
+            }
+            if let Some(npc) = local.npcs.iter().find(|npc| !self.npcs.contains_key(*npc)) {
+                return invalido(format!("{} tem o NPC inexistente {}", id, npc));
+            }
+            for item in &local.itens {
+                catalogo.buscar(item)?;
+            }
+        }
+
+        for (id, zona) in &self.zonas {
+            if !(0.0..=1.0).contains(&zona.chance) || zona.maximo == 0 || zona.inimigos.is_empty() {
+                return invalido(format!(
+                    "a zona {} precisa de chance entre 0 e 1, máximo e inimigos",
+                    id
+                ));
+            }
+            for inimigo in &zona.inimigos {
+                if inimigo.nivel_minimo == 0
+                    || inimigo.nivel_minimo > inimigo.nivel_maximo
+                    || inimigo.nivel_maximo > NIVEL_MAXIMO
+                {
+                    return invalido(format!("faixa de nível inválida para {}", inimigo.id));
+                }
+                for item in &inimigo.itens {
+                    catalogo.buscar(item)?;
+                }
+            }
+        }
+
+        for (id, npc) in &self.npcs {
+            npc.validar(id)?;
+            if let Some(missao) = npc.missoes().find(|m| !self.missoes.contains_key(*m)) {
+                return Err(ErroRpg::MissaoDesconhecida(missao.clone()));
+            }
+        }
+
+        for (id, missao) in &self.missoes {
+            match &missao.objetivo {
+                Objetivo::Derrotar { inimigo, .. } => {
+                    let existe = self
+                        .zonas
+                        .values()
+                        .flat_map(|zona| &zona.inimigos)
+                        .any(|candidato| &candidato.id == inimigo);
+                    if !existe {
+                        return invalido(format!(
+                            "a missão {} pede o inimigo inexistente {}",
+                            id, inimigo
+                        ));
+                    }
+                }
+                Objetivo::Obter { item, .. } => {
+                    catalogo.buscar(item)?;
+                }
+                Objetivo::Alcancar { local } => {
+                    self.local(local)?;
+                }
+            }
+            if missao.objetivo.meta() == 0 {
+                return invalido(format!("a missão {} pede quantidade zero", id));
+            }
+            for item in &missao.recompensa.itens {
+                catalogo.buscar(item)?;
+            }
+        }
+
+        self.catalogo = catalogo.clone();
+        Ok(self)
+    }
+
+    pub fn local(&self, id: &str) -> Result<&Local, ErroRpg> {
+        self.locais
+            .get(id)
+            .ok_or_else(|| ErroRpg::LocalDesconhecido(id.to_string()))
+    }
+
+    /// Local aonde a saída leva, pela direção (sem diferenciar maiúsculas)
+    pub fn destino(&self, local: &str, direcao: &str) -> Result<&str, ErroRpg> {
+        self.local(local)?
+            .saidas
+            .iter()
+            .find(|(saida, _)| saida.eq_ignore_ascii_case(direcao.trim()))
+            .map(|(_, destino)| destino.as_str())
+            .ok_or_else(|| ErroRpg::SemSaida(direcao.trim().to_string()))
+    }
+
+    pub fn item(&self, id: &str) -> Result<&Item, ErroRpg> {
+        self.catalogo.buscar(id)
+    }
+
+    /// Sorteia um encontro na zona do local
+    ///
+    /// # Retorno
+    /// `None` quando o local é seguro ou o sorteio não deu encontro
+    pub fn sortear_encontro(
+        &self,
+        local: &str,
+        rng: &mut Gerador,
+        progressao: &Progressao,
+    ) -> Result<Option<Encontro>, ErroRpg> {
+        let Some(zona) = self
+            .local(local)?
+            .zona
+            .as_ref()
+            .and_then(|z| self.zonas.get(z))
+        else {
+            return Ok(None);
+        };
+        if !rng.gen_bool(zona.chance) {
+            return Ok(None);
+        }
+
+        let quantidade = rng.gen_range(1..=zona.maximo);
+        let sorteados: Vec<&InimigoZona> = (0..quantidade)
+            .map(|_| &zona.inimigos[rng.gen_range(0..zona.inimigos.len())])
+            .collect();
+
+        // Inimigos repetidos ganham um número no nome, para não se confundirem
+        let mut numeros: BTreeMap<&str, u32> = BTreeMap::new();
+        let mut encontro = Encontro {
+            inimigos: Vec::new(),
+            ids: Vec::new(),
+            estrategia: zona.estrategia,
+        };
+        for inimigo in &sorteados {
+            let repetido = sorteados.iter().filter(|i| i.id == inimigo.id).count() > 1;
+            let numero = numeros.entry(&inimigo.id).or_default();
+            *numero += 1;
+            let nome = if repetido {
+                format!("{} {}", inimigo.nome, numero)
+            } else {
+                inimigo.nome.clone()
+            };
+
+            let nivel = rng.gen_range(inimigo.nivel_minimo..=inimigo.nivel_maximo);
+            let mut personagem = progressao.criar_personagem(&nome, inimigo.classe, nivel)?;
+            for id in &inimigo.itens {
+                personagem.inventario.adicionar(self.item(id)?.clone(), 1)?;
+                if self.item(id)?.encaixe().is_some() {
+                    personagem.equipar(id)?;
+                }
+            }
+            personagem.curar(personagem.vida_maxima());
+            encontro.inimigos.push(personagem);
+            encontro.ids.push(inimigo.id.clone());
+        }
+        Ok(Some(encontro))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::Guerreiro;
+    use crate::combate::gerador;
+    use crate::jogo::Jogo;
+
+    const MAPA: &str = r#"
+        inicio = "vila"
+
+        [locais.vila]
+        nome = "Vila"
+        descricao = "Casas de pedra."
+        saidas = { norte = "floresta" }
+        npcs = ["anciao"]
+        descanso = true
+
+        [locais.floresta]
+        nome = "Floresta"
+        descricao = "Árvores escuras."
+        zona = "mata"
+        saidas = { sul = "vila" }
+        itens = ["erva"]
+
+        [zonas.mata]
+        chance = 1.0
+        maximo = 3
+        inimigos = [
+            { id = "lobo", nome = "Lobo", classe = "Arqueiro", nivel_minimo = 1, nivel_maximo = 2 },
+        ]
+
+        [npcs.anciao]
+        nome = "Ancião"
+        [npcs.anciao.dialogo.inicio]
+        fala = "Os lobos voltaram."
+        opcoes = [{ texto = "Eu ajudo", missao = "lobos" }]
+
+        [missoes.lobos]
+        nome = "Caça aos lobos"
+        descricao = "Derrote 2 lobos na floresta."
+        objetivo = { tipo = "derrotar", inimigo = "lobo", quantidade = 2 }
+        recompensa = { xp = 40, itens = ["erva"] }
+    "#;
+
+    fn catalogo() -> Catalogo {
+        Catalogo::de_toml(
+            r#"
+            [[itens]]
+            id = "erva"
+            nome = "Erva"
+            peso = 1
+            categoria = "consumivel"
+            efeito = "cura"
+            pontos = 10
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_mapa_e_encontros() {
+        let mundo = Mundo::de_toml(MAPA, &catalogo()).unwrap();
+        assert_eq!(mundo.destino("vila", "Norte").unwrap(), "floresta");
+        assert_eq!(
+            mundo.destino("vila", "leste").unwrap_err(),
+            ErroRpg::SemSaida("leste".to_string())
+        );
+
+        let mut rng = gerador(3);
+        let progressao = Progressao::default();
+        assert!(
+            mundo
+                .sortear_encontro("vila", &mut rng, &progressao)
+                .unwrap()
+                .is_none()
+        );
+        for _ in 0..20 {
+            let encontro = mundo
+                .sortear_encontro("floresta", &mut rng, &progressao)
+                .unwrap()
+                .unwrap();
+            assert!((1..=3).contains(&encontro.inimigos.len()));
+            assert!(encontro.ids.iter().all(|id| id == "lobo"));
+            let mut nomes: Vec<_> = encontro.inimigos.iter().map(|i| &i.nome).collect();
+            nomes.dedup();
+            assert_eq!(nomes.len(), encontro.inimigos.len());
+        }
+    }
+
+    #[test]
+    fn test_mapa_com_referencias_quebradas() {
+        let erro = |texto: String| Mundo::de_toml(&texto, &catalogo()).unwrap_err();
+        assert!(matches!(
+            erro(MAPA.replace("sul = \"vila\"", "sul = \"castelo\"")),
+            ErroRpg::MundoInvalido(_)
+        ));
+        assert_eq!(
+            erro(MAPA.replace("missao = \"lobos\"", "missao = \"dragao\"")),
+            ErroRpg::MissaoDesconhecida("dragao".to_string())
+        );
+        assert_eq!(
+            erro(MAPA.replace("itens = [\"erva\"]", "itens = [\"espada\"]")),
+            ErroRpg::ItemNaoEncontrado("espada".to_string())
+        );
+    }
+
+    #[test]
+    fn test_explorar_e_concluir_missao() {
+        let mundo = Mundo::de_toml(MAPA, &catalogo()).unwrap();
+        let progressao = Progressao::default();
+        let grupo = vec![
+            Personagem::new("Conan", &Guerreiro, 10).unwrap(),
+            Personagem::new("Brutus", &Guerreiro, 10).unwrap(),
+        ];
+        let mut jogo = Jogo::new(grupo, ESTRATEGIAS[0], 5);
+        jogo.entrar(&mundo);
+        assert_eq!(jogo.local, "vila");
+        assert!(jogo.diario.aceitar("lobos"));
+
+        let encontro = jogo.mover(&mundo, "norte", &progressao).unwrap().unwrap();
+        assert_eq!(jogo.local, "floresta");
+        assert_eq!(jogo.pegar_itens(&mundo).len(), 1);
+        assert!(jogo.itens_no_local(&mundo).is_empty());
+
+        let mut encontro = Some(encontro);
+        while jogo.diario.ativas().count() > 0 {
+            let desfecho = jogo.enfrentar(&mundo, encontro.take().unwrap(), &progressao);
+            assert_eq!(desfecho.resultado.vencedor, Some(0));
+            let conclusoes = jogo.concluir_missoes(&mundo, &progressao);
+            if let Some(conclusao) = conclusoes.first() {
+                assert_eq!(conclusao.missao, "Caça aos lobos");
+                assert_eq!(conclusao.itens, vec!["Erva"]);
+                break;
+            }
+            jogo.descansar();
+            jogo.mover(&mundo, "sul", &progressao).unwrap();
+            encontro = jogo.mover(&mundo, "norte", &progressao).unwrap();
+        }
+        assert!(jogo.diario.concluida("lobos"));
+        let ervas: u32 = jogo
+            .grupo
+            .iter()
+            .map(|p| p.inventario.quantidade("erva"))
+            .sum();
+        assert_eq!(ervas, 2);
+    }
+}
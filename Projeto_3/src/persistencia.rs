@@ -0,0 +1,332 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::errors::ErroRpg;
+use crate::jogo::Jogo;
+use crate::personagem::{NIVEL_MAXIMO, Personagem};
+
+/// Versão atual do formato dos saves
+///
+/// Deve aumentar sempre que a estrutura de `Jogo` (ou de algo guardado nele)
+/// mudar de um jeito que saves antigos deixem de ser lidos corretamente.
+pub const VERSAO_SAVE: u32 = 3;
+
+/// Número de slots de save disponíveis
+pub const NUMERO_SLOTS: u32 = 3;
+
+/// Arquivo de save, como é gravado
+///
+/// # Campos
+/// - `versao`: Versão do formato (`VERSAO_SAVE` na gravação)
+/// - `verificacao`: Soma de verificação do `jogo`, para detectar arquivos danificados
+/// - `jogo`: O estado da partida
+#[derive(Serialize)]
+struct Save<'a> {
+    versao: u32,
+    verificacao: u64,
+    jogo: &'a Jogo,
+}
+
+/// Arquivo de save, como é lido
+#[derive(Deserialize)]
+struct SaveLido {
+    verificacao: u64,
+    jogo: Jogo,
+}
+
+/// Só a versão, lida antes do resto para recusar formatos diferentes com
+/// uma mensagem clara
+#[derive(Deserialize)]
+struct Cabecalho {
+    versao: Option<u32>,
+}
+
+/// Grava o conteúdo de forma atômica
+///
+/// Os dados vão para um arquivo temporário na mesma pasta, que só substitui
+/// o arquivo original (com `rename`) depois de gravado por completo. Uma falha
+/// no meio da gravação nunca deixa o save anterior pela metade.
+pub fn gravar_atomico(caminho: &Path, conteudo: &[u8]) -> Result<(), ErroRpg> {
+    let mut nome = caminho.file_name().unwrap_or_default().to_os_string();
+    nome.push(".tmp");
+    let temporario = caminho.with_file_name(nome);
+
+    let resultado = (|| {
+        let mut arquivo = File::create(&temporario)?;
+        arquivo.write_all(conteudo)?;
+        arquivo.sync_all()?;
+        fs::rename(&temporario, caminho)
+    })();
+
+    resultado.map_err(|e| {
+        let _ = fs::remove_file(&temporario);
+        ErroRpg::ErroPersistencia(format!("Erro ao salvar o jogo: {}", e))
+    })
+}
+
+/// Converte a partida para o JSON do save
+pub fn para_json(jogo: &Jogo) -> Result<String, ErroRpg> {
+    let save = Save {
+        versao: VERSAO_SAVE,
+        verificacao: verificacao(jogo)?,
+        jogo,
+    };
+    serde_json::to_string_pretty(&save)
+        .map_err(|e| ErroRpg::ErroPersistencia(format!("Erro ao gerar o save: {}", e)))
+}
+
+/// Lê a partida de um JSON de save
+///
+/// Recusa saves de outra versão do formato, arquivos danificados (JSON
+/// inválido ou soma de verificação diferente) e partidas impossíveis, como
+/// um personagem com mais vida que o máximo.
+pub fn de_json(texto: &str) -> Result<Jogo, ErroRpg> {
+    let cabecalho: Cabecalho = serde_json::from_str(texto)
+        .map_err(|e| ErroRpg::SaveCorrompido(format!("JSON inválido: {}", e)))?;
+    match cabecalho.versao {
+        None => return Err(ErroRpg::SaveCorrompido("versão ausente".to_string())),
+        Some(VERSAO_SAVE) => {}
+        Some(encontrada) => {
+            return Err(ErroRpg::VersaoIncompativel {
+                encontrada,
+                suportada: VERSAO_SAVE,
+            });
+        }
+    }
+
+    let save: SaveLido =
+        serde_json::from_str(texto).map_err(|e| ErroRpg::SaveCorrompido(e.to_string()))?;
+    if verificacao(&save.jogo)? != save.verificacao {
+        return Err(ErroRpg::SaveCorrompido(
+            "a soma de verificação não confere".to_string(),
+        ));
+    }
+    validar(&save.jogo)?;
+    Ok(save.jogo)
+}
+
+/// Soma de verificação (FNV-1a de 64 bits) do JSON compacto da partida
+fn verificacao(jogo: &Jogo) -> Result<u64, ErroRpg> {
+    let texto = serde_json::to_string(jogo)
+        .map_err(|e| ErroRpg::ErroPersistencia(format!("Erro ao gerar o save: {}", e)))?;
+    Ok(texto.bytes().fold(0xcbf29ce484222325, |soma, byte| {
+        (soma ^ byte as u64).wrapping_mul(0x100000001b3)
+    }))
+}
+
+/// Confere as regras que nenhuma partida de verdade quebra
+fn validar(jogo: &Jogo) -> Result<(), ErroRpg> {
+    if jogo.grupo.is_empty() {
+        return Err(ErroRpg::SaveCorrompido("grupo vazio".to_string()));
+    }
+    let mut nomes = BTreeSet::new();
+    for personagem in &jogo.grupo {
+        if !nomes.insert(personagem.nome.as_str()) {
+            return Err(ErroRpg::SaveCorrompido(format!(
+                "nome repetido: {}",
+                personagem.nome
+            )));
+        }
+        validar_personagem(personagem).map_err(|motivo| {
+            ErroRpg::SaveCorrompido(format!("{}: {}", personagem.nome, motivo))
+        })?;
+    }
+    if jogo.vitorias > jogo.batalhas {
+        return Err(ErroRpg::SaveCorrompido(
+            "mais vitórias que batalhas".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validar_personagem(personagem: &Personagem) -> Result<(), &'static str> {
+    if !(1..=NIVEL_MAXIMO).contains(&personagem.nivel) {
+        return Err("nível inválido");
+    }
+    // O crescimento depende do arquivo de progressão, então só dá para
+    // garantir que nenhum atributo ficou abaixo do nível 1 da classe
+    let (atributos, base) = (personagem.atributos, personagem.classe.atributos_base());
+    if atributos.vida < base.vida
+        || atributos.mana < base.mana
+        || atributos.ataque < base.ataque
+        || atributos.defesa < base.defesa
+        || atributos.velocidade < base.velocidade
+    {
+        return Err("atributos abaixo dos da classe");
+    }
+    let habilidades = personagem.classe.habilidades();
+    if personagem
+        .desbloqueadas
+        .iter()
+        .any(|id| !habilidades.iter().any(|habilidade| &habilidade.id == id))
+    {
+        return Err("habilidade desconhecida para a classe");
+    }
+    if personagem.vida > personagem.vida_maxima() || personagem.mana > personagem.mana_maxima() {
+        return Err("vida ou mana acima do máximo");
+    }
+    let equipamento = personagem.arma.iter().chain(&personagem.armadura);
+    if equipamento
+        .clone()
+        .any(|item| !personagem.classe.pode_equipar(item))
+    {
+        return Err("equipamento proibido para a classe");
+    }
+    let peso_equipado: u32 = equipamento.map(|item| item.peso).sum();
+    if personagem.inventario.peso_atual() + peso_equipado > personagem.inventario.peso_maximo() {
+        return Err("carga acima do máximo");
+    }
+    Ok(())
+}
+
+/// Slots de save numa pasta, um arquivo `slot_<n>.json` por slot
+#[derive(Debug, Clone)]
+pub struct Slots {
+    pasta: PathBuf,
+}
+
+impl Slots {
+    pub fn new(pasta: impl Into<PathBuf>) -> Self {
+        Slots {
+            pasta: pasta.into(),
+        }
+    }
+
+    /// Arquivo do slot (de 1 a `NUMERO_SLOTS`)
+    pub fn caminho(&self, slot: u32) -> Result<PathBuf, ErroRpg> {
+        if !(1..=NUMERO_SLOTS).contains(&slot) {
+            return Err(ErroRpg::SlotInvalido(slot));
+        }
+        Ok(self.pasta.join(format!("slot_{}.json", slot)))
+    }
+
+    /// Salva a partida no slot, substituindo o que houver nele
+    pub fn salvar(&self, slot: u32, jogo: &Jogo) -> Result<(), ErroRpg> {
+        let caminho = self.caminho(slot)?;
+        fs::create_dir_all(&self.pasta).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Erro ao criar a pasta de saves: {}", e))
+        })?;
+        gravar_atomico(&caminho, para_json(jogo)?.as_bytes())
+    }
+
+    pub fn carregar(&self, slot: u32) -> Result<Jogo, ErroRpg> {
+        let caminho = self.caminho(slot)?;
+        if !caminho.exists() {
+            return Err(ErroRpg::SlotVazio(slot));
+        }
+        let texto = fs::read_to_string(&caminho).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Erro ao ler {}: {}", caminho.display(), e))
+        })?;
+        de_json(&texto)
+    }
+
+    /// Slots que têm um save gravado
+    pub fn ocupados(&self) -> Vec<u32> {
+        (1..=NUMERO_SLOTS)
+            .filter(|&slot| self.caminho(slot).is_ok_and(|caminho| caminho.exists()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::{Arqueiro, Guerreiro, Mago};
+    use crate::eventos::EventoCombate;
+    use crate::ia::{MaiorAmeaca, MenorVida};
+    use crate::itens::Item;
+    use crate::progressao::Progressao;
+
+    fn jogo() -> Jogo {
+        let mut conan = Personagem::new("Conan", &Guerreiro, 4).unwrap();
+        let pocao: Item = serde_json::from_str(
+            r#"{"id": "pocao", "nome": "Poção", "peso": 1, "categoria": "consumivel",
+                "efeito": "cura", "pontos": 30}"#,
+        )
+        .unwrap();
+        conan.inventario.adicionar(pocao, 3).unwrap();
+        let merlin = Personagem::new("Merlin", &Mago, 4).unwrap();
+        Jogo::new(vec![conan, merlin], &MaiorAmeaca, 99)
+    }
+
+    fn inimigos() -> Vec<Personagem> {
+        vec![
+            Personagem::new("Orc", &Guerreiro, 3).unwrap(),
+            Personagem::new("Goblin", &Arqueiro, 3).unwrap(),
+        ]
+    }
+
+    fn eventos(jogo: &mut Jogo) -> Vec<EventoCombate> {
+        jogo.batalhar(inimigos(), &MenorVida, &Progressao::default())
+            .resultado
+            .eventos
+    }
+
+    #[test]
+    fn test_jogo_carregado_continua_igual() {
+        let mut original = jogo();
+        eventos(&mut original);
+
+        let pasta = tempfile::tempdir().unwrap();
+        let slots = Slots::new(pasta.path());
+        slots.salvar(2, &original).unwrap();
+        assert_eq!(slots.ocupados(), vec![2]);
+        let mut carregado = slots.carregar(2).unwrap();
+
+        assert_eq!(carregado.batalhas, 1);
+        assert_eq!(carregado.estrategia.nome(), "Maior ameaça");
+        assert_eq!(
+            serde_json::to_string(&carregado).unwrap(),
+            serde_json::to_string(&original).unwrap()
+        );
+        // O gerador também foi salvo: as próximas batalhas são as mesmas
+        for _ in 0..3 {
+            original.descansar();
+            carregado.descansar();
+            assert_eq!(eventos(&mut carregado), eventos(&mut original));
+        }
+    }
+
+    #[test]
+    fn test_slots_invalidos_e_vazios() {
+        let pasta = tempfile::tempdir().unwrap();
+        let slots = Slots::new(pasta.path().join("saves"));
+
+        assert!(slots.ocupados().is_empty());
+        assert_eq!(slots.carregar(1).unwrap_err(), ErroRpg::SlotVazio(1));
+        assert_eq!(
+            slots.salvar(NUMERO_SLOTS + 1, &jogo()).unwrap_err(),
+            ErroRpg::SlotInvalido(NUMERO_SLOTS + 1)
+        );
+    }
+
+    #[test]
+    fn test_saves_corrompidos_ou_de_outra_versao() {
+        let texto = para_json(&jogo()).unwrap();
+        let corrompido = |texto: &str| matches!(de_json(texto), Err(ErroRpg::SaveCorrompido(_)));
+
+        assert!(corrompido(&texto[..texto.len() / 2]));
+        assert!(corrompido(
+            &texto.replace("\"vitorias\": 0", "\"vitorias\": 5")
+        ));
+        assert!(corrompido(
+            &texto.replace(&format!("\"versao\": {},", VERSAO_SAVE), "")
+        ));
+
+        let antigo = texto.replace(
+            &format!("\"versao\": {}", VERSAO_SAVE),
+            &format!("\"versao\": {}", VERSAO_SAVE - 1),
+        );
+        assert_eq!(
+            de_json(&antigo).unwrap_err(),
+            ErroRpg::VersaoIncompativel {
+                encontrada: VERSAO_SAVE - 1,
+                suportada: VERSAO_SAVE
+            }
+        );
+        assert!(de_json(&texto).is_ok());
+    }
+}
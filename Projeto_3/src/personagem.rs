@@ -0,0 +1,393 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::classes::{self, Classe};
+use crate::errors::ErroRpg;
+use crate::habilidades::Habilidade;
+use crate::inventario::{ESPACOS_INVENTARIO, Inventario};
+use crate::itens::{Efeito, Encaixe, Item, Modificadores};
+
+/// Maior nível que um personagem pode ter
+pub const NIVEL_MAXIMO: u32 = 99;
+
+/// Atributos de combate de um personagem
+///
+/// # Campos
+/// - `vida`: Pontos de vida máximos (HP)
+/// - `mana`: Pontos de mana máximos (MP), gastos nas habilidades
+/// - `ataque`: Força dos golpes
+/// - `defesa`: Reduz o dano recebido
+/// - `velocidade`: Define a ordem de ação na rodada e a chance de esquiva
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Atributos {
+    pub vida: u32,
+    pub mana: u32,
+    pub ataque: u32,
+    pub defesa: u32,
+    pub velocidade: u32,
+}
+
+impl Atributos {
+    /// Soma atributo a atributo, repetindo `outro` `vezes` vezes
+    pub fn somar(self, outro: Atributos, vezes: u32) -> Atributos {
+        Atributos {
+            vida: self.vida + outro.vida * vezes,
+            mana: self.mana + outro.mana * vezes,
+            ataque: self.ataque + outro.ataque * vezes,
+            defesa: self.defesa + outro.defesa * vezes,
+            velocidade: self.velocidade + outro.velocidade * vezes,
+        }
+    }
+
+    /// Aplica os modificadores de equipamento
+    ///
+    /// Atributos nunca ficam negativos, e a vida fica em pelo menos 1.
+    pub fn aplicar(self, modificadores: Modificadores) -> Atributos {
+        let ajustar =
+            |valor: u32, bonus: i32, minimo: i64| (valor as i64 + bonus as i64).max(minimo) as u32;
+        Atributos {
+            vida: ajustar(self.vida, modificadores.vida, 1),
+            mana: ajustar(self.mana, modificadores.mana, 0),
+            ataque: ajustar(self.ataque, modificadores.ataque, 0),
+            defesa: ajustar(self.defesa, modificadores.defesa, 0),
+            velocidade: ajustar(self.velocidade, modificadores.velocidade, 0),
+        }
+    }
+}
+
+/// Personagem que participa das batalhas
+///
+/// # Campos
+/// - `nome`: Nome exibido no registro da batalha
+/// - `classe`: Classe do personagem, que define atributos e estilo de ataque
+/// - `nivel`: Nível atual (1 a `NIVEL_MAXIMO`)
+/// - `xp`: Experiência acumulada desde a última subida de nível
+/// - `atributos`: Atributos da classe no nível atual, sem equipamento
+/// - `vida`: Pontos de vida restantes
+/// - `mana`: Pontos de mana restantes
+/// - `inventario`: Itens carregados, limitados pela carga da classe
+/// - `arma`, `armadura`: Itens equipados, que somam seus modificadores
+/// - `desbloqueadas`: Ids das habilidades da classe que o personagem já pode usar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Personagem {
+    pub nome: String,
+    #[serde(with = "classes::pelo_nome")]
+    pub classe: &'static dyn Classe,
+    pub nivel: u32,
+    pub xp: u64,
+    pub atributos: Atributos,
+    pub vida: u32,
+    pub mana: u32,
+    pub inventario: Inventario,
+    pub arma: Option<Item>,
+    pub armadura: Option<Item>,
+    pub desbloqueadas: Vec<String>,
+}
+
+impl Personagem {
+    /// Cria um personagem com a vida cheia e todas as habilidades da classe
+    ///
+    /// Para seguir as regras de progressão (crescimento e desbloqueio de
+    /// habilidades por nível), use `Progressao::criar_personagem`.
+    ///
+    /// # Parâmetros
+    /// - `nome`: Nome do personagem (não pode ser vazio)
+    /// - `classe`: Classe do personagem (ex: `&Guerreiro`)
+    /// - `nivel`: Nível inicial
+    pub fn new(nome: &str, classe: &'static dyn Classe, nivel: u32) -> Result<Self, ErroRpg> {
+        let nome = nome.trim();
+        if nome.is_empty() {
+            return Err(ErroRpg::CampoVazio("nome".to_string()));
+        }
+        if !(1..=NIVEL_MAXIMO).contains(&nivel) {
+            return Err(ErroRpg::NivelInvalido(nivel));
+        }
+
+        let atributos = classe.atributos_no_nivel(nivel);
+        Ok(Personagem {
+            nome: nome.to_string(),
+            classe,
+            nivel,
+            xp: 0,
+            atributos,
+            vida: atributos.vida,
+            mana: atributos.mana,
+            inventario: Inventario::new(ESPACOS_INVENTARIO, classe.carga_maxima()),
+            arma: None,
+            armadura: None,
+            desbloqueadas: classe.habilidades().into_iter().map(|h| h.id).collect(),
+        })
+    }
+
+    /// Habilidades desbloqueadas, da mais prioritária para a menos
+    pub fn habilidades(&self) -> Vec<Habilidade> {
+        self.classe
+            .habilidades()
+            .into_iter()
+            .filter(|habilidade| self.desbloqueadas.contains(&habilidade.id))
+            .collect()
+    }
+
+    /// Atributos com os modificadores do equipamento
+    pub fn atributos_efetivos(&self) -> Atributos {
+        let modificadores = [&self.arma, &self.armadura]
+            .into_iter()
+            .flatten()
+            .fold(Modificadores::default(), |soma, item| {
+                soma.somar(item.modificadores())
+            });
+        self.atributos.aplicar(modificadores)
+    }
+
+    pub fn vida_maxima(&self) -> u32 {
+        self.atributos_efetivos().vida
+    }
+
+    pub fn mana_maxima(&self) -> u32 {
+        self.atributos_efetivos().mana
+    }
+
+    pub fn equipado(&self, encaixe: Encaixe) -> Option<&Item> {
+        match encaixe {
+            Encaixe::Arma => self.arma.as_ref(),
+            Encaixe::Armadura => self.armadura.as_ref(),
+        }
+    }
+
+    fn encaixe_mut(&mut self, encaixe: Encaixe) -> &mut Option<Item> {
+        match encaixe {
+            Encaixe::Arma => &mut self.arma,
+            Encaixe::Armadura => &mut self.armadura,
+        }
+    }
+
+    /// Equipa um item do inventário
+    ///
+    /// O item que estava no mesmo encaixe volta para o inventário.
+    pub fn equipar(&mut self, id: &str) -> Result<(), ErroRpg> {
+        let item = self.inventario.buscar(id)?.clone();
+        let encaixe = item
+            .encaixe()
+            .ok_or_else(|| ErroRpg::ItemNaoEquipavel(item.nome.clone()))?;
+        if !self.classe.pode_equipar(&item) {
+            return Err(ErroRpg::EquipamentoProibido {
+                classe: self.classe.nome().to_string(),
+                item: item.nome,
+            });
+        }
+
+        // O item trocado ocupa o espaço do novo, mas pode pesar mais
+        if let Some(anterior) = self.equipado(encaixe) {
+            let peso_necessario = self.inventario.peso_atual() - item.peso + anterior.peso;
+            if peso_necessario > self.inventario.peso_maximo() {
+                return Err(ErroRpg::PesoExcedido {
+                    peso_maximo: self.inventario.peso_maximo(),
+                    peso_necessario,
+                });
+            }
+        }
+
+        self.inventario.remover(id)?;
+        if let Some(anterior) = self.encaixe_mut(encaixe).replace(item) {
+            self.inventario.adicionar(anterior, 1)?;
+        }
+        self.limitar_vida();
+        Ok(())
+    }
+
+    /// Tira o item do encaixe e o guarda no inventário
+    pub fn desequipar(&mut self, encaixe: Encaixe) -> Result<(), ErroRpg> {
+        let item = self
+            .equipado(encaixe)
+            .cloned()
+            .ok_or(ErroRpg::EncaixeVazio(encaixe))?;
+        self.inventario.adicionar(item, 1)?;
+        *self.encaixe_mut(encaixe) = None;
+        self.limitar_vida();
+        Ok(())
+    }
+
+    /// Gasta uma unidade de um consumível do inventário
+    ///
+    /// # Retorno
+    /// Efeito e pontos do consumível, para quem usou aplicar
+    pub fn gastar_consumivel(&mut self, id: &str) -> Result<(Efeito, u32), ErroRpg> {
+        let item = self.inventario.buscar(id)?;
+        let efeito = item
+            .efeito()
+            .ok_or_else(|| ErroRpg::ItemNaoConsumivel(item.nome.clone()))?;
+        self.inventario.remover(id)?;
+        Ok(efeito)
+    }
+
+    /// Recupera pontos de vida, sem passar da vida máxima
+    ///
+    /// # Retorno
+    /// Pontos efetivamente recuperados
+    pub fn curar(&mut self, pontos: u32) -> u32 {
+        let recuperado = pontos.min(self.vida_maxima().saturating_sub(self.vida));
+        self.vida += recuperado;
+        recuperado
+    }
+
+    /// Gasta mana, se houver o suficiente
+    ///
+    /// # Retorno
+    /// `false` (sem gastar nada) se a mana não bastar
+    pub fn gastar_mana(&mut self, custo: u32) -> bool {
+        if self.mana < custo {
+            return false;
+        }
+        self.mana -= custo;
+        true
+    }
+
+    /// Recupera mana, sem passar da mana máxima
+    pub fn recuperar_mana(&mut self, pontos: u32) -> u32 {
+        let recuperado = pontos.min(self.mana_maxima().saturating_sub(self.mana));
+        self.mana += recuperado;
+        recuperado
+    }
+
+    /// Mantém vida e mana dentro do máximo depois de trocar o equipamento
+    fn limitar_vida(&mut self) {
+        self.vida = self.vida.min(self.vida_maxima());
+        self.mana = self.mana.min(self.mana_maxima());
+    }
+
+    pub fn esta_vivo(&self) -> bool {
+        self.vida > 0
+    }
+
+    /// Tira pontos de vida do personagem, sem passar de zero
+    ///
+    /// # Retorno
+    /// Dano efetivamente sofrido
+    pub fn receber_dano(&mut self, dano: u32) -> u32 {
+        let sofrido = dano.min(self.vida);
+        self.vida -= sofrido;
+        sofrido
+    }
+}
+
+impl fmt::Display for Personagem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let atributos = self.atributos_efetivos();
+        write!(
+            f,
+            "{} ({} nv. {}) - HP {}/{} | MP {}/{} | ATQ {} | DEF {} | VEL {}",
+            self.nome,
+            self.classe.nome(),
+            self.nivel,
+            self.vida,
+            atributos.vida,
+            self.mana,
+            atributos.mana,
+            atributos.ataque,
+            atributos.defesa,
+            atributos.velocidade
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::{Guerreiro, Mago};
+
+    #[test]
+    fn test_novo_personagem() {
+        let personagem = Personagem::new("  Conan ", &Guerreiro, 1).unwrap();
+        assert_eq!(personagem.nome, "Conan");
+        assert_eq!(personagem.vida, personagem.atributos.vida);
+
+        assert_eq!(
+            Personagem::new(" ", &Guerreiro, 1).unwrap_err(),
+            ErroRpg::CampoVazio("nome".to_string())
+        );
+        assert_eq!(
+            Personagem::new("Conan", &Guerreiro, 0).unwrap_err(),
+            ErroRpg::NivelInvalido(0)
+        );
+    }
+
+    fn item(texto: &str) -> Item {
+        serde_json::from_str(texto).unwrap()
+    }
+
+    #[test]
+    fn test_equipar_respeita_a_classe() {
+        let mut personagem = Personagem::new("Merlin", &Mago, 1).unwrap();
+        let cajado = item(
+            r#"{"id": "cajado", "nome": "Cajado", "peso": 3, "categoria": "arma",
+                "tipo_arma": "cajado", "modificadores": {"ataque": 5}}"#,
+        );
+        let machado = item(
+            r#"{"id": "machado", "nome": "Machado", "peso": 8, "categoria": "arma",
+                "tipo_arma": "machado", "modificadores": {"ataque": 9}}"#,
+        );
+        let manto = item(
+            r#"{"id": "manto", "nome": "Manto", "peso": 2, "categoria": "armadura",
+                "protecao": "leve", "modificadores": {"vida": 10, "velocidade": -1}}"#,
+        );
+        personagem.inventario.adicionar(cajado, 1).unwrap();
+        personagem.inventario.adicionar(machado, 1).unwrap();
+        personagem.inventario.adicionar(manto, 1).unwrap();
+
+        assert_eq!(
+            personagem.equipar("machado"),
+            Err(ErroRpg::EquipamentoProibido {
+                classe: "Mago".to_string(),
+                item: "Machado".to_string(),
+            })
+        );
+        personagem.equipar("cajado").unwrap();
+        personagem.equipar("manto").unwrap();
+        assert_eq!(personagem.inventario.pilhas().len(), 1);
+
+        let efetivos = personagem.atributos_efetivos();
+        assert_eq!(efetivos.ataque, personagem.atributos.ataque + 5);
+        assert_eq!(efetivos.vida, personagem.atributos.vida + 10);
+        assert_eq!(efetivos.velocidade, personagem.atributos.velocidade - 1);
+
+        // Sem o manto, a vida volta ao máximo da classe
+        personagem.curar(100);
+        personagem.desequipar(Encaixe::Armadura).unwrap();
+        assert_eq!(personagem.vida, personagem.atributos.vida);
+        assert_eq!(
+            personagem.desequipar(Encaixe::Armadura),
+            Err(ErroRpg::EncaixeVazio(Encaixe::Armadura))
+        );
+    }
+
+    #[test]
+    fn test_gastar_consumivel() {
+        let mut personagem = Personagem::new("Conan", &Guerreiro, 1).unwrap();
+        let pocao = item(
+            r#"{"id": "pocao", "nome": "Poção", "peso": 1, "categoria": "consumivel",
+                "efeito": "cura", "pontos": 30}"#,
+        );
+        personagem.inventario.adicionar(pocao, 2).unwrap();
+        personagem.receber_dano(20);
+
+        let (efeito, pontos) = personagem.gastar_consumivel("pocao").unwrap();
+        assert_eq!(efeito, Efeito::Cura);
+        assert_eq!(personagem.curar(pontos), 20);
+        assert_eq!(personagem.inventario.quantidade("pocao"), 1);
+        assert_eq!(
+            personagem.equipar("pocao"),
+            Err(ErroRpg::ItemNaoEquipavel("Poção".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_receber_dano_nao_passa_de_zero() {
+        let mut personagem = Personagem::new("Conan", &Guerreiro, 1).unwrap();
+        let vida = personagem.vida;
+
+        assert_eq!(personagem.receber_dano(10), 10);
+        assert_eq!(personagem.receber_dano(1000), vida - 10);
+        assert_eq!(personagem.vida, 0);
+        assert!(!personagem.esta_vivo());
+    }
+}
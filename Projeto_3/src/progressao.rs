@@ -0,0 +1,447 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::classes::{Classe, classe_por_nome};
+use crate::errors::ErroRpg;
+use crate::personagem::{Atributos, NIVEL_MAXIMO, Personagem};
+
+/// XP por nível de cada inimigo derrotado, quando o arquivo não informa
+pub const XP_POR_NIVEL_PADRAO: u32 = 30;
+
+/// Curva de experiência: quanto XP cada nível pede para passar ao seguinte
+///
+/// Para sair do nível `n` são necessários `base * n^expoente` pontos,
+/// arredondados (e pelo menos 1).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct CurvaXp {
+    pub base: u32,
+    pub expoente: f64,
+}
+
+impl Default for CurvaXp {
+    fn default() -> Self {
+        CurvaXp {
+            base: 100,
+            expoente: 1.5,
+        }
+    }
+}
+
+impl CurvaXp {
+    /// XP para passar do `nivel` ao seguinte
+    pub fn xp_para_subir(&self, nivel: u32) -> u64 {
+        (self.base as f64 * (nivel as f64).powf(self.expoente))
+            .round()
+            .max(1.0) as u64
+    }
+
+    /// XP total para ir do nível 1 até o `nivel`
+    pub fn xp_ate(&self, nivel: u32) -> u64 {
+        (1..nivel).map(|n| self.xp_para_subir(n)).sum()
+    }
+}
+
+/// Regras de uma classe no arquivo de progressão
+///
+/// # Campos
+/// - `crescimento`: Atributos ganhos a cada nível; sem ele, vale o da classe
+/// - `habilidades`: Nível em que cada habilidade (pelo id) é desbloqueada;
+///   as que não aparecem estão disponíveis desde o nível 1
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+struct RegrasClasse {
+    crescimento: Option<Atributos>,
+    #[serde(default)]
+    habilidades: BTreeMap<String, u32>,
+}
+
+/// Regras de progressão dos personagens, carregadas de um arquivo de dados
+///
+/// Sem arquivo (`Progressao::default()`), vale a curva padrão, o
+/// crescimento definido em cada classe e todas as habilidades no nível 1.
+///
+/// # Campos
+/// - `curva`: XP necessário para cada nível
+/// - `xp_por_nivel_inimigo`: XP de uma vitória por nível de cada inimigo derrotado
+/// - `classes`: Regras de cada classe, pelo nome
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Progressao {
+    #[serde(default)]
+    pub curva: CurvaXp,
+    #[serde(default = "xp_por_nivel_padrao")]
+    pub xp_por_nivel_inimigo: u32,
+    #[serde(default)]
+    classes: BTreeMap<String, RegrasClasse>,
+}
+
+fn xp_por_nivel_padrao() -> u32 {
+    XP_POR_NIVEL_PADRAO
+}
+
+impl Default for Progressao {
+    fn default() -> Self {
+        Progressao {
+            curva: CurvaXp::default(),
+            xp_por_nivel_inimigo: XP_POR_NIVEL_PADRAO,
+            classes: BTreeMap::new(),
+        }
+    }
+}
+
+/// Uma subida de nível
+///
+/// # Campos
+/// - `personagem`: Nome de quem subiu
+/// - `nivel`: Novo nível
+/// - `ganho`: Atributos ganhos
+/// - `habilidades`: Nomes das habilidades desbloqueadas no novo nível
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubidaNivel {
+    pub personagem: String,
+    pub nivel: u32,
+    pub ganho: Atributos,
+    pub habilidades: Vec<String>,
+}
+
+impl fmt::Display for SubidaNivel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} subiu para o nível {}! (+{} HP, +{} MP, +{} ATQ, +{} DEF, +{} VEL)",
+            self.personagem,
+            self.nivel,
+            self.ganho.vida,
+            self.ganho.mana,
+            self.ganho.ataque,
+            self.ganho.defesa,
+            self.ganho.velocidade
+        )?;
+        for habilidade in &self.habilidades {
+            write!(f, "\n  Nova habilidade: {}", habilidade)?;
+        }
+        Ok(())
+    }
+}
+
+/// Situação de um personagem num nível, na simulação da progressão
+///
+/// # Campos
+/// - `nivel`: Nível alcançado
+/// - `xp_total`: XP acumulado desde o nível 1
+/// - `atributos`: Atributos no nível, sem equipamento
+/// - `habilidades`: Nomes das habilidades desbloqueadas neste nível
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinhaProgressao {
+    pub nivel: u32,
+    pub xp_total: u64,
+    pub atributos: Atributos,
+    pub habilidades: Vec<String>,
+}
+
+impl Progressao {
+    /// Carrega as regras de um arquivo `.json` ou `.toml`
+    pub fn carregar(caminho: &Path) -> Result<Self, ErroRpg> {
+        let texto = std::fs::read_to_string(caminho).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Erro ao ler {}: {}", caminho.display(), e))
+        })?;
+        match caminho.extension().and_then(|extensao| extensao.to_str()) {
+            Some("toml") => Progressao::de_toml(&texto),
+            _ => Progressao::de_json(&texto),
+        }
+    }
+
+    pub fn de_json(texto: &str) -> Result<Self, ErroRpg> {
+        let progressao: Progressao = serde_json::from_str(texto).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Arquivo de progressão inválido: {}", e))
+        })?;
+        progressao.validar()
+    }
+
+    pub fn de_toml(texto: &str) -> Result<Self, ErroRpg> {
+        let progressao: Progressao = toml::from_str(texto).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Arquivo de progressão inválido: {}", e))
+        })?;
+        progressao.validar()
+    }
+
+    /// Confere classes, habilidades e níveis, e guarda as classes pelo nome oficial
+    fn validar(mut self) -> Result<Self, ErroRpg> {
+        if self.curva.base == 0 || !self.curva.expoente.is_finite() {
+            return Err(ErroRpg::ErroPersistencia(
+                "A curva de XP precisa de base maior que zero e expoente válido".to_string(),
+            ));
+        }
+
+        let mut classes = BTreeMap::new();
+        for (nome, regras) in self.classes {
+            let classe = classe_por_nome(&nome)?;
+            let ids: Vec<String> = classe.habilidades().into_iter().map(|h| h.id).collect();
+            for (id, &nivel) in &regras.habilidades {
+                if !ids.contains(id) {
+                    return Err(ErroRpg::HabilidadeDesconhecida {
+                        classe: classe.nome().to_string(),
+                        habilidade: id.clone(),
+                    });
+                }
+                if !(1..=NIVEL_MAXIMO).contains(&nivel) {
+                    return Err(ErroRpg::NivelInvalido(nivel));
+                }
+            }
+            classes.insert(classe.nome().to_string(), regras);
+        }
+        self.classes = classes;
+        Ok(self)
+    }
+
+    fn regras(&self, classe: &dyn Classe) -> Option<&RegrasClasse> {
+        self.classes.get(classe.nome())
+    }
+
+    /// Atributos ganhos pela classe a cada nível
+    pub fn crescimento(&self, classe: &dyn Classe) -> Atributos {
+        self.regras(classe)
+            .and_then(|regras| regras.crescimento)
+            .unwrap_or_else(|| classe.crescimento())
+    }
+
+    pub fn atributos_no_nivel(&self, classe: &dyn Classe, nivel: u32) -> Atributos {
+        classe
+            .atributos_base()
+            .somar(self.crescimento(classe), nivel.saturating_sub(1))
+    }
+
+    /// Nível em que a habilidade da classe é desbloqueada
+    pub fn nivel_de_desbloqueio(&self, classe: &dyn Classe, habilidade: &str) -> u32 {
+        self.regras(classe)
+            .and_then(|regras| regras.habilidades.get(habilidade).copied())
+            .unwrap_or(1)
+    }
+
+    /// Ids das habilidades da classe disponíveis até o `nivel`, na ordem da classe
+    pub fn desbloqueadas_ate(&self, classe: &dyn Classe, nivel: u32) -> Vec<String> {
+        classe
+            .habilidades()
+            .into_iter()
+            .filter(|habilidade| self.nivel_de_desbloqueio(classe, &habilidade.id) <= nivel)
+            .map(|habilidade| habilidade.id)
+            .collect()
+    }
+
+    /// Cria um personagem com os atributos e habilidades do nível pelas regras
+    pub fn criar_personagem(
+        &self,
+        nome: &str,
+        classe: &'static dyn Classe,
+        nivel: u32,
+    ) -> Result<Personagem, ErroRpg> {
+        let mut personagem = Personagem::new(nome, classe, nivel)?;
+        personagem.atributos = self.atributos_no_nivel(classe, nivel);
+        personagem.vida = personagem.atributos.vida;
+        personagem.mana = personagem.atributos.mana;
+        personagem.desbloqueadas = self.desbloqueadas_ate(classe, nivel);
+        Ok(personagem)
+    }
+
+    /// XP ganho por quem vence os inimigos derrotados
+    pub fn xp_da_vitoria(&self, derrotados: &[Personagem]) -> u64 {
+        derrotados
+            .iter()
+            .map(|inimigo| inimigo.nivel as u64 * self.xp_por_nivel_inimigo as u64)
+            .sum()
+    }
+
+    /// Dá XP ao personagem, subindo quantos níveis o XP alcançar
+    ///
+    /// A cada nível os atributos crescem pela tabela da classe; a vida e a
+    /// mana atuais crescem junto com o máximo. No `NIVEL_MAXIMO` o XP não
+    /// acumula mais.
+    ///
+    /// # Retorno
+    /// As subidas de nível, em ordem
+    pub fn ganhar_xp(&self, personagem: &mut Personagem, xp: u64) -> Vec<SubidaNivel> {
+        let mut subidas = Vec::new();
+        personagem.xp += xp;
+        while personagem.nivel < NIVEL_MAXIMO {
+            let necessario = self.curva.xp_para_subir(personagem.nivel);
+            if personagem.xp < necessario {
+                break;
+            }
+            personagem.xp -= necessario;
+            subidas.push(self.subir_nivel(personagem));
+        }
+        if personagem.nivel == NIVEL_MAXIMO {
+            personagem.xp = 0;
+        }
+        subidas
+    }
+
+    fn subir_nivel(&self, personagem: &mut Personagem) -> SubidaNivel {
+        let classe = personagem.classe;
+        let antes = personagem.atributos;
+        personagem.nivel += 1;
+        personagem.atributos = self.atributos_no_nivel(classe, personagem.nivel);
+        let depois = personagem.atributos;
+        let ganho = Atributos {
+            vida: depois.vida.saturating_sub(antes.vida),
+            mana: depois.mana.saturating_sub(antes.mana),
+            ataque: depois.ataque.saturating_sub(antes.ataque),
+            defesa: depois.defesa.saturating_sub(antes.defesa),
+            velocidade: depois.velocidade.saturating_sub(antes.velocidade),
+        };
+        personagem.vida += ganho.vida;
+        personagem.mana += ganho.mana;
+
+        let mut habilidades = Vec::new();
+        for habilidade in classe.habilidades() {
+            if self.nivel_de_desbloqueio(classe, &habilidade.id) <= personagem.nivel
+                && !personagem.desbloqueadas.contains(&habilidade.id)
+            {
+                personagem.desbloqueadas.push(habilidade.id);
+                habilidades.push(habilidade.nome);
+            }
+        }
+        SubidaNivel {
+            personagem: personagem.nome.clone(),
+            nivel: personagem.nivel,
+            ganho,
+            habilidades,
+        }
+    }
+
+    /// Simula um personagem da classe do nível 1 até o `nivel_final`,
+    /// ganhando a cada vez o XP exato do próximo nível
+    ///
+    /// # Retorno
+    /// Uma linha por nível, a primeira com o nível 1
+    pub fn simular(
+        &self,
+        classe: &'static dyn Classe,
+        nivel_final: u32,
+    ) -> Result<Vec<LinhaProgressao>, ErroRpg> {
+        if !(1..=NIVEL_MAXIMO).contains(&nivel_final) {
+            return Err(ErroRpg::NivelInvalido(nivel_final));
+        }
+        let mut personagem = self.criar_personagem(classe.nome(), classe, 1)?;
+        let mut xp_total = 0;
+        let mut linhas = vec![LinhaProgressao {
+            nivel: 1,
+            xp_total,
+            atributos: personagem.atributos,
+            habilidades: personagem
+                .habilidades()
+                .into_iter()
+                .map(|h| h.nome)
+                .collect(),
+        }];
+
+        while personagem.nivel < nivel_final {
+            let xp = self.curva.xp_para_subir(personagem.nivel);
+            xp_total += xp;
+            for subida in self.ganhar_xp(&mut personagem, xp) {
+                linhas.push(LinhaProgressao {
+                    nivel: subida.nivel,
+                    xp_total,
+                    atributos: personagem.atributos,
+                    habilidades: subida.habilidades,
+                });
+            }
+        }
+        Ok(linhas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::{Arqueiro, Guerreiro, Mago};
+
+    const REGRAS: &str = r#"
+        xp_por_nivel_inimigo = 10
+
+        [curva]
+        base = 50
+        expoente = 2.0
+
+        [classes.guerreiro]
+        crescimento = { vida = 20, mana = 0, ataque = 2, defesa = 4, velocidade = 0 }
+        habilidades = { golpe_poderoso = 1, muralha = 3, grito_de_guerra = 5 }
+    "#;
+
+    #[test]
+    fn test_curva_de_xp() {
+        let curva = CurvaXp {
+            base: 50,
+            expoente: 2.0,
+        };
+        assert_eq!(curva.xp_para_subir(1), 50);
+        assert_eq!(curva.xp_para_subir(3), 450);
+        assert_eq!(curva.xp_ate(1), 0);
+        assert_eq!(curva.xp_ate(4), 50 + 200 + 450);
+    }
+
+    #[test]
+    fn test_subir_de_nivel_pelas_regras() {
+        let progressao = Progressao::de_toml(REGRAS).unwrap();
+        let mut conan = progressao.criar_personagem("Conan", &Guerreiro, 1).unwrap();
+        assert_eq!(conan.desbloqueadas, vec!["golpe_poderoso"]);
+        conan.receber_dano(30);
+
+        // 50 para o nível 2, 200 para o 3, e sobram 10
+        let subidas = progressao.ganhar_xp(&mut conan, 260);
+        assert_eq!(subidas.len(), 2);
+        assert_eq!((conan.nivel, conan.xp), (3, 10));
+        assert_eq!(subidas[1].habilidades, vec!["Muralha"]);
+        assert_eq!(subidas[0].ganho.defesa, 4);
+        assert_eq!(conan.atributos.vida, Guerreiro.atributos_base().vida + 40);
+        assert_eq!(conan.vida, conan.vida_maxima() - 30);
+        let ids: Vec<String> = conan.habilidades().into_iter().map(|h| h.id).collect();
+        assert_eq!(ids, vec!["muralha", "golpe_poderoso"]);
+
+        // Classes sem regras usam o crescimento da classe e têm tudo no nível 1
+        assert_eq!(
+            progressao.atributos_no_nivel(&Mago, 5),
+            Mago.atributos_no_nivel(5)
+        );
+        assert_eq!(progressao.desbloqueadas_ate(&Arqueiro, 1).len(), 2);
+        assert_eq!(
+            progressao.xp_da_vitoria(&[conan.clone(), conan.clone()]),
+            60
+        );
+    }
+
+    #[test]
+    fn test_regras_invalidas() {
+        let erro = Progressao::de_toml(&REGRAS.replace("muralha", "bola_de_fogo")).unwrap_err();
+        assert_eq!(
+            erro,
+            ErroRpg::HabilidadeDesconhecida {
+                classe: "Guerreiro".to_string(),
+                habilidade: "bola_de_fogo".to_string()
+            }
+        );
+        assert_eq!(
+            Progressao::de_toml(&REGRAS.replace("guerreiro", "ladino")).unwrap_err(),
+            ErroRpg::ClasseDesconhecida("ladino".to_string())
+        );
+    }
+
+    #[test]
+    fn test_simular_progressao() {
+        let progressao = Progressao::de_toml(REGRAS).unwrap();
+        let linhas = progressao.simular(&Guerreiro, 6).unwrap();
+
+        assert_eq!(linhas.len(), 6);
+        assert_eq!(linhas[5].nivel, 6);
+        assert_eq!(linhas[5].xp_total, progressao.curva.xp_ate(6));
+        assert_eq!(
+            linhas[5].atributos,
+            progressao.atributos_no_nivel(&Guerreiro, 6)
+        );
+        assert_eq!(linhas[4].habilidades, vec!["Grito de guerra"]);
+        assert_eq!(
+            progressao.simular(&Guerreiro, 0).unwrap_err(),
+            ErroRpg::NivelInvalido(0)
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+
+use crate::classes::classe_por_nome;
+use crate::combate::{Batalha, ResultadoBatalha};
+use crate::errors::ErroRpg;
+use crate::ia::{ESTRATEGIAS, Estrategia, estrategia_por_nome};
+use crate::itens::Catalogo;
+use crate::personagem::Personagem;
+use crate::progressao::Progressao;
+
+/// Número de batalhas simuladas quando o cenário não informa
+pub const BATALHAS_PADRAO: u32 = 1000;
+
+/// Personagem descrito no arquivo de cenário
+#[derive(Debug, Deserialize)]
+struct MembroCenario {
+    nome: String,
+    classe: String,
+    nivel: u32,
+    /// Ids de itens do catálogo; armas e armaduras são equipadas
+    #[serde(default)]
+    itens: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LadoCenario {
+    estrategia: Option<String>,
+    personagens: Vec<MembroCenario>,
+}
+
+/// Arquivo de cenário, em JSON ou TOML
+#[derive(Debug, Deserialize)]
+struct ArquivoCenario {
+    batalhas: Option<u32>,
+    #[serde(default)]
+    semente: u64,
+    lados: Vec<LadoCenario>,
+}
+
+/// Confronto entre dois grupos, para rodar em várias batalhas seguidas
+///
+/// # Campos
+/// - `lados`: Personagens de cada lado, como começam cada batalha
+/// - `estrategias`: Estratégia de cada lado
+/// - `batalhas`: Número de batalhas da simulação
+/// - `semente`: Semente da primeira batalha; a batalha `n` usa `semente + n`
+#[derive(Debug, Clone)]
+pub struct Cenario {
+    pub lados: [Vec<Personagem>; 2],
+    pub estrategias: [&'static dyn Estrategia; 2],
+    pub batalhas: u32,
+    pub semente: u64,
+}
+
+impl Cenario {
+    /// Carrega o cenário de um arquivo `.json` ou `.toml`
+    ///
+    /// # Parâmetros
+    /// - `catalogo`: Catálogo onde estão os itens citados no cenário
+    /// - `progressao`: Regras que definem atributos e habilidades de cada nível
+    pub fn carregar(
+        caminho: &Path,
+        catalogo: &Catalogo,
+        progressao: &Progressao,
+    ) -> Result<Self, ErroRpg> {
+        let texto = std::fs::read_to_string(caminho).map_err(|e| {
+            ErroRpg::ErroPersistencia(format!("Erro ao ler {}: {}", caminho.display(), e))
+        })?;
+        match caminho.extension().and_then(|extensao| extensao.to_str()) {
+            Some("toml") => Cenario::de_toml(&texto, catalogo, progressao),
+            _ => Cenario::de_json(&texto, catalogo, progressao),
+        }
+    }
+
+    pub fn de_json(
+        texto: &str,
+        catalogo: &Catalogo,
+        progressao: &Progressao,
+    ) -> Result<Self, ErroRpg> {
+        let arquivo: ArquivoCenario = serde_json::from_str(texto)
+            .map_err(|e| ErroRpg::ErroPersistencia(format!("Cenário inválido: {}", e)))?;
+        Cenario::montar(arquivo, catalogo, progressao)
+    }
+
+    pub fn de_toml(
+        texto: &str,
+        catalogo: &Catalogo,
+        progressao: &Progressao,
+    ) -> Result<Self, ErroRpg> {
+        let arquivo: ArquivoCenario = toml::from_str(texto)
+            .map_err(|e| ErroRpg::ErroPersistencia(format!("Cenário inválido: {}", e)))?;
+        Cenario::montar(arquivo, catalogo, progressao)
+    }
+
+    fn montar(
+        arquivo: ArquivoCenario,
+        catalogo: &Catalogo,
+        progressao: &Progressao,
+    ) -> Result<Self, ErroRpg> {
+        let [lado_a, lado_b]: [LadoCenario; 2] =
+            arquivo.lados.try_into().map_err(|lados: Vec<_>| {
+                ErroRpg::CenarioInvalido(format!("são necessários 2 lados, há {}", lados.len()))
+            })?;
+
+        let mut nomes = BTreeSet::new();
+        let mut montar_lado = |lado: LadoCenario, numero: usize| {
+            if lado.personagens.is_empty() {
+                return Err(ErroRpg::CenarioInvalido(format!(
+                    "o lado {} não tem personagens",
+                    numero
+                )));
+            }
+            let estrategia = match &lado.estrategia {
+                Some(nome) => estrategia_por_nome(nome)?,
+                None => ESTRATEGIAS[0],
+            };
+            let mut personagens = Vec::new();
+            for membro in lado.personagens {
+                if !nomes.insert(membro.nome.trim().to_string()) {
+                    return Err(ErroRpg::NomeRepetido(membro.nome));
+                }
+                personagens.push(montar_personagem(membro, catalogo, progressao)?);
+            }
+            Ok((personagens, estrategia))
+        };
+        let (personagens_a, estrategia_a) = montar_lado(lado_a, 1)?;
+        let (personagens_b, estrategia_b) = montar_lado(lado_b, 2)?;
+
+        Ok(Cenario {
+            lados: [personagens_a, personagens_b],
+            estrategias: [estrategia_a, estrategia_b],
+            batalhas: arquivo.batalhas.unwrap_or(BATALHAS_PADRAO),
+            semente: arquivo.semente,
+        })
+    }
+
+    /// Joga todas as batalhas do cenário, sem exibir nada
+    pub fn simular(&self) -> Relatorio {
+        let mut relatorio = Relatorio::default();
+        for numero in 0..self.batalhas {
+            let batalha = Batalha::entre_lados(
+                self.lados[0].clone(),
+                self.lados[1].clone(),
+                self.semente.wrapping_add(numero as u64),
+            )
+            .com_estrategia(0, self.estrategias[0])
+            .com_estrategia(1, self.estrategias[1]);
+            relatorio.registrar(&batalha.executar());
+        }
+        relatorio
+    }
+}
+
+/// Cria o personagem com os itens do catálogo, equipado e com a vida cheia
+fn montar_personagem(
+    membro: MembroCenario,
+    catalogo: &Catalogo,
+    progressao: &Progressao,
+) -> Result<Personagem, ErroRpg> {
+    let classe = classe_por_nome(&membro.classe)?;
+    let mut personagem = progressao.criar_personagem(&membro.nome, classe, membro.nivel)?;
+    for id in &membro.itens {
+        let item = catalogo.buscar(id)?;
+        personagem.inventario.adicionar(item.clone(), 1)?;
+        if item.encaixe().is_some() {
+            personagem.equipar(id)?;
+        }
+    }
+    personagem.curar(personagem.vida_maxima());
+    Ok(personagem)
+}
+
+/// Estatísticas de uma série de batalhas
+///
+/// # Campos
+/// - `batalhas`: Batalhas registradas
+/// - `vitorias`: Vitórias de cada lado
+/// - `empates`: Batalhas que chegaram ao limite de rodadas
+/// - `rodadas`: Soma das rodadas de todas as batalhas
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Relatorio {
+    pub batalhas: u32,
+    pub vitorias: [u32; 2],
+    pub empates: u32,
+    pub rodadas: u64,
+}
+
+impl Relatorio {
+    pub fn registrar(&mut self, resultado: &ResultadoBatalha) {
+        self.batalhas += 1;
+        match resultado.vencedor {
+            Some(lado) => self.vitorias[lado] += 1,
+            None => self.empates += 1,
+        }
+        self.rodadas += resultado.rodadas as u64;
+    }
+
+    /// Porcentagem de vitórias do lado (0 ou 1)
+    pub fn taxa_vitoria(&self, lado: usize) -> f64 {
+        self.porcentagem(self.vitorias[lado])
+    }
+
+    pub fn taxa_empate(&self) -> f64 {
+        self.porcentagem(self.empates)
+    }
+
+    /// Duração média das batalhas, em rodadas
+    pub fn media_rodadas(&self) -> f64 {
+        if self.batalhas == 0 {
+            return 0.0;
+        }
+        self.rodadas as f64 / self.batalhas as f64
+    }
+
+    fn porcentagem(&self, quantidade: u32) -> f64 {
+        if self.batalhas == 0 {
+            return 0.0;
+        }
+        quantidade as f64 * 100.0 / self.batalhas as f64
+    }
+}
+
+impl fmt::Display for Relatorio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Batalhas: {}", self.batalhas)?;
+        for lado in 0..2 {
+            writeln!(
+                f,
+                "Lado {}: {} vitórias ({:.1}%)",
+                lado + 1,
+                self.vitorias[lado],
+                self.taxa_vitoria(lado)
+            )?;
+        }
+        writeln!(f, "Empates: {} ({:.1}%)", self.empates, self.taxa_empate())?;
+        write!(f, "Duração média: {:.1} rodadas", self.media_rodadas())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CENARIO: &str = r#"
+        batalhas = 200
+        semente = 7
+
+        [[lados]]
+        estrategia = "maior_ameaca"
+        personagens = [
+            { nome = "Conan", classe = "Guerreiro", nivel = 4, itens = ["espada"] },
+            { nome = "Merlin", classe = "Mago", nivel = 4 },
+        ]
+
+        [[lados]]
+        personagens = [
+            { nome = "Legolas", classe = "Arqueiro", nivel = 4 },
+            { nome = "Robin", classe = "Arqueiro", nivel = 4, itens = ["pocao"] },
+        ]
+    "#;
+
+    fn catalogo() -> Catalogo {
+        Catalogo::de_toml(
+            r#"
+            [[itens]]
+            id = "espada"
+            nome = "Espada"
+            peso = 5
+            categoria = "arma"
+            tipo_arma = "espada"
+            modificadores = { ataque = 5 }
+
+            [[itens]]
+            id = "pocao"
+            nome = "Poção"
+            peso = 1
+            categoria = "consumivel"
+            efeito = "cura"
+            pontos = 30
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cenario_do_arquivo() {
+        let cenario = Cenario::de_toml(CENARIO, &catalogo(), &Progressao::default()).unwrap();
+
+        assert_eq!(cenario.batalhas, 200);
+        assert_eq!(cenario.estrategias[0].nome(), "Maior ameaça");
+        assert_eq!(cenario.estrategias[1].nome(), "Menor vida");
+        let conan = &cenario.lados[0][0];
+        assert_eq!(conan.arma.as_ref().unwrap().id, "espada");
+        assert_eq!(conan.vida, conan.vida_maxima());
+        assert_eq!(cenario.lados[1][1].inventario.quantidade("pocao"), 1);
+
+        let repetido = CENARIO.replace("Robin", "Legolas");
+        assert_eq!(
+            Cenario::de_toml(&repetido, &catalogo(), &Progressao::default()).unwrap_err(),
+            ErroRpg::NomeRepetido("Legolas".to_string())
+        );
+        let um_lado = &CENARIO[..CENARIO.rfind("[[lados]]").unwrap()];
+        assert!(matches!(
+            Cenario::de_toml(um_lado, &catalogo(), &Progressao::default()),
+            Err(ErroRpg::CenarioInvalido(_))
+        ));
+    }
+
+    #[test]
+    fn test_simulacao_reproduzivel() {
+        let cenario = Cenario::de_toml(CENARIO, &catalogo(), &Progressao::default()).unwrap();
+        let relatorio = cenario.simular();
+
+        assert_eq!(relatorio, cenario.simular());
+        assert_eq!(relatorio.batalhas, 200);
+        assert_eq!(
+            relatorio.vitorias[0] + relatorio.vitorias[1] + relatorio.empates,
+            200
+        );
+        let total = relatorio.taxa_vitoria(0) + relatorio.taxa_vitoria(1) + relatorio.taxa_empate();
+        assert!((total - 100.0).abs() < 1e-9);
+        assert!(relatorio.media_rodadas() >= 1.0);
+    }
+}
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Efeito temporário sobre um personagem durante a batalha
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TipoStatus {
+    /// Causa `intensidade` de dano no início de cada turno do afetado
+    Veneno,
+    /// O afetado perde o turno
+    Atordoamento,
+    /// Aumenta o dano do afetado em `intensidade`%
+    Fortalecimento,
+    /// Absorve até `intensidade` pontos de dano antes da vida
+    Escudo,
+}
+
+/// Como uma nova aplicação se combina com o mesmo efeito já ativo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Empilhamento {
+    /// As intensidades se somam, até `maximo` aplicações
+    Acumular { maximo: u32 },
+    /// Fica a aplicação de maior intensidade
+    MaisForte,
+    /// A intensidade não muda; só a duração é renovada
+    Renovar,
+}
+
+/// Número máximo de doses de veneno acumuladas num personagem
+pub const MAXIMO_DOSES_VENENO: u32 = 3;
+
+/// Número máximo de escudos somados num personagem
+pub const MAXIMO_ESCUDOS: u32 = 2;
+
+impl TipoStatus {
+    /// Regra de acúmulo do efeito
+    ///
+    /// Em todas as regras a duração passa a ser a maior entre a restante e
+    /// a da nova aplicação.
+    pub fn empilhamento(self) -> Empilhamento {
+        match self {
+            TipoStatus::Veneno => Empilhamento::Acumular {
+                maximo: MAXIMO_DOSES_VENENO,
+            },
+            TipoStatus::Escudo => Empilhamento::Acumular {
+                maximo: MAXIMO_ESCUDOS,
+            },
+            TipoStatus::Fortalecimento => Empilhamento::MaisForte,
+            TipoStatus::Atordoamento => Empilhamento::Renovar,
+        }
+    }
+}
+
+impl fmt::Display for TipoStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            TipoStatus::Veneno => "Veneno",
+            TipoStatus::Atordoamento => "Atordoamento",
+            TipoStatus::Fortalecimento => "Fortalecimento",
+            TipoStatus::Escudo => "Escudo",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+/// Efeito de status aplicado por uma habilidade
+///
+/// # Campos
+/// - `tipo`: Qual efeito
+/// - `intensidade`: Dano por turno, % de bônus ou pontos de escudo, conforme o tipo
+/// - `duracao`: Número de turnos do afetado em que o efeito dura
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Status {
+    pub tipo: TipoStatus,
+    #[serde(default)]
+    pub intensidade: u32,
+    pub duracao: u32,
+}
+
+/// Efeito ativo num personagem
+///
+/// # Campos
+/// - `status`: Intensidade e turnos restantes, já combinados pelas regras de acúmulo
+/// - `aplicacoes`: Quantas aplicações foram somadas (para `Empilhamento::Acumular`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusAtivo {
+    pub status: Status,
+    pub aplicacoes: u32,
+}
+
+/// Efeitos ativos num personagem, no máximo um de cada tipo
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Efeitos {
+    ativos: Vec<StatusAtivo>,
+}
+
+impl Efeitos {
+    pub fn ativos(&self) -> &[StatusAtivo] {
+        &self.ativos
+    }
+
+    pub fn buscar(&self, tipo: TipoStatus) -> Option<&Status> {
+        self.ativos
+            .iter()
+            .map(|ativo| &ativo.status)
+            .find(|status| status.tipo == tipo)
+    }
+
+    pub fn tem(&self, tipo: TipoStatus) -> bool {
+        self.buscar(tipo).is_some()
+    }
+
+    /// Intensidade atual do efeito (zero se não estiver ativo)
+    pub fn intensidade(&self, tipo: TipoStatus) -> u32 {
+        self.buscar(tipo).map_or(0, |status| status.intensidade)
+    }
+
+    /// Aplica um efeito seguindo a regra de acúmulo do tipo
+    ///
+    /// # Retorno
+    /// O efeito como ficou ativo depois da combinação
+    pub fn aplicar(&mut self, novo: Status) -> Status {
+        let Some(ativo) = self.ativos.iter_mut().find(|a| a.status.tipo == novo.tipo) else {
+            self.ativos.push(StatusAtivo {
+                status: novo,
+                aplicacoes: 1,
+            });
+            return novo;
+        };
+
+        let status = &mut ativo.status;
+        match novo.tipo.empilhamento() {
+            Empilhamento::Acumular { maximo } => {
+                if ativo.aplicacoes < maximo {
+                    ativo.aplicacoes += 1;
+                    status.intensidade += novo.intensidade;
+                }
+            }
+            Empilhamento::MaisForte => {
+                status.intensidade = status.intensidade.max(novo.intensidade)
+            }
+            Empilhamento::Renovar => {}
+        }
+        status.duracao = status.duracao.max(novo.duracao);
+        *status
+    }
+
+    /// Tira pontos do escudo
+    ///
+    /// # Retorno
+    /// Dano absorvido; um escudo sem pontos é removido
+    pub fn absorver(&mut self, dano: u32) -> u32 {
+        let Some(posicao) = self
+            .ativos
+            .iter()
+            .position(|ativo| ativo.status.tipo == TipoStatus::Escudo)
+        else {
+            return 0;
+        };
+        let escudo = &mut self.ativos[posicao].status;
+        let absorvido = dano.min(escudo.intensidade);
+        escudo.intensidade -= absorvido;
+        if escudo.intensidade == 0 {
+            self.ativos.remove(posicao);
+        }
+        absorvido
+    }
+
+    /// Passa um turno do afetado: todos os efeitos perdem um turno de duração
+    ///
+    /// # Retorno
+    /// Tipos dos efeitos que acabaram
+    pub fn passar_turno(&mut self) -> Vec<TipoStatus> {
+        for ativo in &mut self.ativos {
+            ativo.status.duracao = ativo.status.duracao.saturating_sub(1);
+        }
+        let encerrados = self
+            .ativos
+            .iter()
+            .filter(|ativo| ativo.status.duracao == 0)
+            .map(|ativo| ativo.status.tipo)
+            .collect();
+        self.ativos.retain(|ativo| ativo.status.duracao > 0);
+        encerrados
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(tipo: TipoStatus, intensidade: u32, duracao: u32) -> Status {
+        Status {
+            tipo,
+            intensidade,
+            duracao,
+        }
+    }
+
+    #[test]
+    fn test_regras_de_acumulo() {
+        let mut efeitos = Efeitos::default();
+
+        // Veneno soma as doses até o máximo e renova a duração
+        for duracao in [3, 1, 4, 2] {
+            efeitos.aplicar(status(TipoStatus::Veneno, 5, duracao));
+        }
+        assert_eq!(
+            efeitos.buscar(TipoStatus::Veneno),
+            Some(&status(TipoStatus::Veneno, 15, 4))
+        );
+
+        // Fortalecimento fica com o mais forte
+        efeitos.aplicar(status(TipoStatus::Fortalecimento, 30, 2));
+        efeitos.aplicar(status(TipoStatus::Fortalecimento, 10, 3));
+        assert_eq!(efeitos.intensidade(TipoStatus::Fortalecimento), 30);
+        assert_eq!(
+            efeitos.buscar(TipoStatus::Fortalecimento).unwrap().duracao,
+            3
+        );
+
+        // Atordoamento só renova a duração
+        efeitos.aplicar(status(TipoStatus::Atordoamento, 0, 1));
+        efeitos.aplicar(status(TipoStatus::Atordoamento, 0, 2));
+        assert_eq!(
+            efeitos.buscar(TipoStatus::Atordoamento),
+            Some(&status(TipoStatus::Atordoamento, 0, 2))
+        );
+        assert_eq!(efeitos.ativos().len(), 3);
+    }
+
+    #[test]
+    fn test_escudo_e_duracao() {
+        let mut efeitos = Efeitos::default();
+        efeitos.aplicar(status(TipoStatus::Escudo, 20, 2));
+        efeitos.aplicar(status(TipoStatus::Escudo, 10, 1));
+        efeitos.aplicar(status(TipoStatus::Veneno, 4, 1));
+
+        assert_eq!(efeitos.absorver(12), 12);
+        assert_eq!(efeitos.intensidade(TipoStatus::Escudo), 18);
+
+        assert_eq!(efeitos.passar_turno(), vec![TipoStatus::Veneno]);
+        assert_eq!(efeitos.absorver(50), 18);
+        assert!(!efeitos.tem(TipoStatus::Escudo));
+        assert!(efeitos.ativos().is_empty());
+    }
+}